@@ -1,5 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use geometry_tools::bounding::calculate_aabb_from_points;
+use geometry_tools::bounding::{calculate_aabb_from_points, calculate_aabb_from_points_vec3};
+use geometry_tools::soa::Vec3Soa;
 
 fn criterion_benchmark(c: &mut Criterion) {
     let positions = vec![glam::Vec3A::ZERO; 10000];
@@ -7,6 +8,20 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("calculate_aabb_from_points", |b| {
         b.iter(|| calculate_aabb_from_points(black_box(&positions)))
     });
+
+    let positions = vec![glam::Vec3::ZERO; 10000];
+
+    c.bench_function("calculate_aabb_from_points vec3", |b| {
+        b.iter(|| calculate_aabb_from_points(black_box(&positions)))
+    });
+
+    c.bench_function("calculate_aabb_from_points_vec3", |b| {
+        b.iter(|| calculate_aabb_from_points_vec3(black_box(&positions)))
+    });
+
+    let positions = Vec3Soa::from_vec3(&positions);
+
+    c.bench_function("Vec3Soa::aabb", |b| b.iter(|| black_box(&positions).aabb()));
 }
 
 criterion_group!(benches, criterion_benchmark);
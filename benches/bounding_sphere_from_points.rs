@@ -1,5 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use geometry_tools::bounding::calculate_bounding_sphere_from_points;
+use geometry_tools::soa::Vec3Soa;
 
 fn criterion_benchmark(c: &mut Criterion) {
     let positions = vec![glam::Vec3A::ZERO; 10000];
@@ -7,6 +8,12 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("calculate_bounding_sphere_from_points", |b| {
         b.iter(|| calculate_bounding_sphere_from_points(black_box(&positions)))
     });
+
+    let positions = Vec3Soa::from_points(&positions);
+
+    c.bench_function("Vec3Soa::bounding_sphere", |b| {
+        b.iter(|| black_box(&positions).bounding_sphere())
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
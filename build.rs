@@ -0,0 +1,22 @@
+fn main() {
+    #[cfg(feature = "ffi-header")]
+    generate_header();
+}
+
+#[cfg(feature = "ffi-header")]
+fn generate_header() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    // Build scripts should not modify the source directory.
+    // Tests check that the committed header in include matches this output.
+    let config = cbindgen::Config::from_file(format!("{crate_dir}/cbindgen.toml")).unwrap();
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("Unable to generate the C header")
+        .write_to_file(format!("{out_dir}/geometry_tools.h"));
+}
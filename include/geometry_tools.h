@@ -0,0 +1,529 @@
+#ifndef GEOMETRY_TOOLS_H
+#define GEOMETRY_TOOLS_H
+
+/* This file is generated by cbindgen using the ffi-header feature. Do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#if defined(__cplusplus)
+#define GEO_ALIGN_16 alignas(16)
+#else
+#define GEO_ALIGN_16 _Alignas(16)
+#endif
+
+/* Three floats followed by four bytes of padding to match the 16 byte alignment of glam::Vec3A. */
+typedef struct Vec3A {
+  GEO_ALIGN_16 float x;
+  float y;
+  float z;
+  float _padding;
+} Vec3A;
+
+/* Three tightly packed floats. */
+typedef struct Vec3 {
+  float x;
+  float y;
+  float z;
+} Vec3;
+
+/*
+ * A flag that may be set from another thread.
+ * The flag is a single byte with 0 for false and 1 for true like bool.
+ * Other languages like C# can pass a pointer to a byte and write it with a volatile or interlocked store.
+ */
+#if defined(__cplusplus)
+#include <atomic>
+typedef std::atomic<bool> AtomicBool;
+static_assert(sizeof(AtomicBool) == 1, "AtomicBool should be a single byte");
+#else
+typedef _Atomic bool AtomicBool;
+#endif
+
+typedef struct Vec2 {
+  float x;
+  float y;
+} Vec2;
+
+typedef struct Vec4 {
+  float x;
+  float y;
+  float z;
+  float w;
+} Vec4;
+
+
+/**
+ * The version of the C API returned by [geo_abi_version].
+ * This is incremented for any change to the signature of existing functions or types.
+ */
+#define GEO_ABI_VERSION 1
+
+/**
+ * The data type and component count of a vertex attribute.
+ * Normalized integer formats are converted to floats in the range `-1.0` to `1.0` for signed formats
+ * or `0.0` to `1.0` for unsigned formats.
+ * All values are little endian.
+ */
+typedef enum AttributeFormat {
+  ATTRIBUTE_FORMAT_FLOAT32X2,
+  ATTRIBUTE_FORMAT_FLOAT32X3,
+  ATTRIBUTE_FORMAT_FLOAT32X4,
+  ATTRIBUTE_FORMAT_FLOAT16X2,
+  ATTRIBUTE_FORMAT_FLOAT16X4,
+  ATTRIBUTE_FORMAT_SNORM8X4,
+  ATTRIBUTE_FORMAT_UNORM8X4,
+  ATTRIBUTE_FORMAT_SNORM16X2,
+  ATTRIBUTE_FORMAT_SNORM16X4,
+  ATTRIBUTE_FORMAT_UNORM16X2,
+  ATTRIBUTE_FORMAT_UNORM16X4,
+} AttributeFormat;
+
+/**
+ * The result of an FFI function.
+ */
+typedef enum FfiStatus {
+  /**
+   * The function completed successfully.
+   */
+  FFI_STATUS_SUCCESS = 0,
+  /**
+   * A pointer was null for a non empty buffer or required output.
+   */
+  FFI_STATUS_NULL_POINTER = 1,
+  /**
+   * Buffers that should have the same length had different lengths.
+   */
+  FFI_STATUS_LENGTH_MISMATCH = 2,
+  /**
+   * An index was outside the range of the vertex buffers.
+   */
+  FFI_STATUS_INDEX_OUT_OF_RANGE = 3,
+  /**
+   * The index count was not a multiple of 3.
+   */
+  FFI_STATUS_INVALID_INDEX_COUNT = 4,
+  /**
+   * An unexpected panic occurred and was caught before reaching the caller.
+   */
+  FFI_STATUS_PANIC = 5,
+  /**
+   * An attribute descriptor did not fit in its buffer or had an invalid stride.
+   */
+  FFI_STATUS_INVALID_ATTRIBUTE = 6,
+  /**
+   * The operation was cancelled by the caller.
+   */
+  FFI_STATUS_CANCELLED = 7,
+  /**
+   * A pointer did not have the required alignment for its type like 16 bytes for [glam::Vec3A].
+   */
+  FFI_STATUS_MISALIGNED = 8,
+} FfiStatus;
+
+/**
+ * An opaque handle that owns positions, indices, and cached adjacency
+ * for repeated calculations without passing the full mesh for each call.
+ * Create with [geo_mesh_create] and free with [geo_mesh_destroy].
+ */
+typedef struct GeoMesh GeoMesh;
+
+/**
+ * The location and format of a vertex attribute in a caller owned buffer
+ * like a single attribute in an interleaved vertex buffer.
+ */
+typedef struct AttributeDescriptor {
+  /**
+   * The start of the buffer.
+   */
+  uint8_t *data;
+  /**
+   * The total size of the buffer in bytes.
+   */
+  uint32_t byte_length;
+  /**
+   * The offset in bytes of the first element from the start of the buffer.
+   */
+  uint32_t offset;
+  /**
+   * The number of bytes between the start of each element.
+   */
+  uint32_t stride;
+  enum AttributeFormat format;
+} AttributeDescriptor;
+
+/**
+ * A progress callback for long running functions
+ * called with a progress value from `0.0` to `1.0` and the caller's `user_data` pointer.
+ * The callback is called on the same thread as the function.
+ */
+typedef void (*GeoProgressCallback)(float progress, void *user_data);
+
+
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * A version of [calculate_smooth_normals](crate::vectors::calculate_smooth_normals)
+ * that reads `vertex_count` positions and writes `vertex_count` normals using attribute descriptors.
+ * This allows using interleaved vertex buffers directly.
+ * The output normals are only written after all inputs are read,
+ * so `positions` and `normals` may describe attributes in the same buffer.
+ *
+ * Returns [FfiStatus::InvalidAttribute] if an attribute does not fit in its buffer
+ * or has a stride smaller than the size of its format.
+ *
+ * # Safety
+ *
+ * `positions` and `normals` must point to valid descriptors
+ * with `data` pointing to at least `byte_length` bytes.
+ * `indices` must have length `indices_length`.
+ */
+enum FfiStatus geo_calculate_smooth_normals_strided(const struct AttributeDescriptor *positions,
+                                                    const struct AttributeDescriptor *normals,
+                                                    uint32_t vertex_count,
+                                                    const uint32_t *indices,
+                                                    uint32_t indices_length);
+
+/**
+ * A version of [calculate_tangents](crate::vectors::calculate_tangents)
+ * that reads `vertex_count` positions, normals, and UVs and writes `vertex_count` tangents
+ * using attribute descriptors.
+ * The output tangents are only written after all inputs are read,
+ * so all the attributes may be in the same buffer.
+ *
+ * # Safety
+ *
+ * `positions`, `normals`, `uvs`, and `tangents` must point to valid descriptors
+ * with `data` pointing to at least `byte_length` bytes.
+ * `indices` must have length `indices_length`.
+ */
+enum FfiStatus geo_calculate_tangents_strided(const struct AttributeDescriptor *positions,
+                                              const struct AttributeDescriptor *normals,
+                                              const struct AttributeDescriptor *uvs,
+                                              const struct AttributeDescriptor *tangents,
+                                              uint32_t vertex_count,
+                                              const uint32_t *indices,
+                                              uint32_t indices_length);
+
+/**
+ * A wrapper for [calculate_bounding_sphere_from_points](crate::bounding::calculate_bounding_sphere_from_points).
+ * The function writes the xyz values of the center to `center` and the radius to `radius`.
+ *
+ * # Safety
+ *
+ * `points` must have length `points_length` and use the same 16 byte layout as
+ * [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+ * `center` must point to three floats, and `radius` must point to a single float.
+ */
+enum FfiStatus geo_calculate_bounding_sphere_from_points(const Vec3A *points,
+                                                         uint32_t points_length,
+                                                         float (*center)[3],
+                                                         float *radius);
+
+/**
+ * A wrapper for [calculate_aabb_from_points](crate::bounding::calculate_aabb_from_points).
+ * The function writes the xyz values of the minimum and maximum to `min` and `max`.
+ *
+ * # Safety
+ *
+ * `points` must have length `points_length` and use the same 16 byte layout as
+ * [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+ * `min` and `max` must each point to three floats.
+ */
+enum FfiStatus geo_calculate_aabb_from_points(const Vec3A *points,
+                                              uint32_t points_length,
+                                              float (*min)[3],
+                                              float (*max)[3]);
+
+/**
+ * A version of [calculate_bounding_sphere_from_points](crate::bounding::calculate_bounding_sphere_from_points) for tightly packed points
+ * with three floats for each point.
+ *
+ * # Safety
+ *
+ * `points` must have length `points_length` with a required size of at least `points_length * 12` bytes.
+ * `center` must point to three floats, and `radius` must point to a single float.
+ */
+enum FfiStatus geo_calculate_bounding_sphere_from_points_vec3(const Vec3 *points,
+                                                              uint32_t points_length,
+                                                              float (*center)[3],
+                                                              float *radius);
+
+/**
+ * A version of [calculate_aabb_from_points](crate::bounding::calculate_aabb_from_points) for tightly packed points
+ * with three floats for each point.
+ *
+ * # Safety
+ *
+ * `points` must have length `points_length` with a required size of at least `points_length * 12` bytes.
+ * `min` and `max` must each point to three floats.
+ */
+enum FfiStatus geo_calculate_aabb_from_points_vec3(const Vec3 *points,
+                                                   uint32_t points_length,
+                                                   float (*min)[3],
+                                                   float (*max)[3]);
+
+/**
+ * Returns the version of the C API.
+ * Callers should check that this matches the version the bindings were generated for.
+ */
+uint32_t geo_abi_version(void);
+
+/**
+ * Creates a mesh handle by copying `positions` and `indices` and writes the handle to `mesh`.
+ * The handle must be freed with [geo_mesh_destroy].
+ *
+ * # Safety
+ *
+ * `positions` and `indices` must have lengths `positions_length` and `indices_length`.
+ * `positions` uses the same 16 byte layout as [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+ * `mesh` must point to a pointer that can be written.
+ */
+enum FfiStatus geo_mesh_create(const Vec3A *positions,
+                               uint32_t positions_length,
+                               const uint32_t *indices,
+                               uint32_t indices_length,
+                               struct GeoMesh **mesh);
+
+/**
+ * Frees a mesh handle created by [geo_mesh_create].
+ * Null pointers are ignored.
+ *
+ * # Safety
+ *
+ * `mesh` must be null or a handle from [geo_mesh_create] that has not already been freed.
+ */
+void geo_mesh_destroy(struct GeoMesh *mesh);
+
+/**
+ * Replaces the positions of `mesh` while keeping the indices and cached adjacency.
+ * Returns [FfiStatus::LengthMismatch] if the vertex count changes.
+ *
+ * # Safety
+ *
+ * `mesh` must be a valid handle, and `positions` must have length `positions_length`.
+ */
+enum FfiStatus geo_mesh_set_positions(struct GeoMesh *mesh,
+                                      const Vec3A *positions,
+                                      uint32_t positions_length);
+
+/**
+ * Calculates smooth normals like [calculate_smooth_normals](crate::vectors::calculate_smooth_normals)
+ * and writes them to `normals`.
+ * Returns [FfiStatus::LengthMismatch] if `normals_length` is not the vertex count of `mesh`.
+ *
+ * # Safety
+ *
+ * `mesh` must be a valid handle, and `normals` must have length `normals_length`.
+ */
+enum FfiStatus geo_mesh_calculate_smooth_normals(const struct GeoMesh *mesh,
+                                                 Vec3A *normals,
+                                                 uint32_t normals_length);
+
+/**
+ * Calculates tangents like [calculate_tangents](crate::vectors::calculate_tangents)
+ * and writes them to `tangents`.
+ * Returns [FfiStatus::LengthMismatch] if `length` is not the vertex count of `mesh`.
+ *
+ * # Safety
+ *
+ * `mesh` must be a valid handle.
+ * `normals`, `uvs`, and `tangents` must all have length `length`.
+ */
+enum FfiStatus geo_mesh_calculate_tangents(const struct GeoMesh *mesh,
+                                           const Vec3A *normals,
+                                           const Vec2 *uvs,
+                                           Vec4 *tangents,
+                                           uint32_t length);
+
+/**
+ * Calculates the axis-aligned bounding box of the positions of `mesh`.
+ *
+ * # Safety
+ *
+ * `mesh` must be a valid handle. `min` and `max` must each point to three floats.
+ */
+enum FfiStatus geo_mesh_calculate_aabb(const struct GeoMesh *mesh,
+                                       float (*min)[3],
+                                       float (*max)[3]);
+
+/**
+ * Calculates a bounding sphere of the positions of `mesh`.
+ *
+ * # Safety
+ *
+ * `mesh` must be a valid handle.
+ * `center` must point to three floats, and `radius` must point to a single float.
+ */
+enum FfiStatus geo_mesh_calculate_bounding_sphere(const struct GeoMesh *mesh,
+                                                  float (*center)[3],
+                                                  float *radius);
+
+/**
+ * A wrapper for [simplify_mesh](crate::simplify::simplify_mesh).
+ * The function writes the simplified indices to the first `index_count` elements of `out_indices`
+ * and the remap table for each vertex to `out_remap`.
+ *
+ * `progress` is called with values from `0.0` to `1.0` and `user_data` if it is not null.
+ * The function returns [FfiStatus::Cancelled] without writing any outputs
+ * if `cancel` is not null and becomes `true` while the function runs.
+ *
+ * # Safety
+ *
+ * `positions` and `out_remap` must have length `positions_length`
+ * with the same 16 byte layout as [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+ * `indices` and `out_indices` must have length `indices_length`.
+ * `index_count`, `vertex_count`, and `error` must point to writable values.
+ * `cancel` must be null or point to a flag that remains valid until the function returns.
+ * The flag may be set from another thread while the function runs.
+ */
+enum FfiStatus geo_simplify_mesh(const Vec3A *positions,
+                                 uint32_t positions_length,
+                                 const uint32_t *indices,
+                                 uint32_t indices_length,
+                                 uint32_t target_triangle_count,
+                                 float max_error,
+                                 uint32_t *out_indices,
+                                 uint32_t *out_remap,
+                                 uint32_t *index_count,
+                                 uint32_t *vertex_count,
+                                 float *error,
+                                 GeoProgressCallback progress,
+                                 void *user_data,
+                                 const AtomicBool *cancel);
+
+/**
+ * A wrapper for [calculate_smooth_normals](crate::vectors::calculate_smooth_normals).
+ * `indices` and `indices_length` define the collection of vertex indices.
+ * The function overwrites the first `normals_length` elements of `normals` with the resulting smooth normals.
+ *
+ * Returns [FfiStatus::LengthMismatch] if `positions_length` and `normals_length` differ
+ * and [FfiStatus::IndexOutOfRange] if any index is not a valid vertex index.
+ * `normals` is not modified if the function fails.
+ *
+ * # Safety
+ *
+ * `positions` and `normals` must have lengths `positions_length` and `normals_length`.
+ * The memory layout of the `positions` and `normals` array should have the xyz values in the first three floats
+ * of each vector of four floats to ensure compatibility with the 16 byte alignment of the [Vec3A] type.
+ *
+ * Example: `x0 y0 z0 _ x1 y1 z1 _ x2 y2 z2 _ ...`
+ *
+ * The fourth value of each vector is included only for alignment purposes and does not affect the computation.
+ * This gives a required size of at least `positions_length * 16` bytes for both arrays.
+ */
+enum FfiStatus geo_calculate_smooth_normals(const Vec3A *positions,
+                                            uint32_t positions_length,
+                                            Vec3A *normals,
+                                            uint32_t normals_length,
+                                            const uint32_t *indices,
+                                            uint32_t indices_length);
+
+/**
+ * A version of [calculate_smooth_normals](crate::vectors::calculate_smooth_normals) for tightly packed positions and normals
+ * with three floats for each vector.
+ *
+ * Example: `x0 y0 z0 x1 y1 z1 x2 y2 z2 ...`
+ *
+ * This gives a required size of at least `positions_length * 12` bytes for both arrays.
+ * The function overwrites the first `normals_length` elements of `normals`.
+ *
+ * # Safety
+ *
+ * `positions` and `normals` must have lengths `positions_length` and `normals_length`.
+ */
+enum FfiStatus geo_calculate_smooth_normals_vec3(const Vec3 *positions,
+                                                 uint32_t positions_length,
+                                                 Vec3 *normals,
+                                                 uint32_t normals_length,
+                                                 const uint32_t *indices,
+                                                 uint32_t indices_length);
+
+/**
+ * A version of [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals) for 16 bit indices.
+ *
+ * # Safety
+ *
+ * See [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+ */
+enum FfiStatus geo_calculate_smooth_normals_u16(const Vec3A *positions,
+                                                uint32_t positions_length,
+                                                Vec3A *normals,
+                                                uint32_t normals_length,
+                                                const uint16_t *indices,
+                                                uint32_t indices_length);
+
+/**
+ * A version of [geo_calculate_smooth_normals_vec3](crate::ffi::geo_calculate_smooth_normals_vec3) for 16 bit indices.
+ *
+ * # Safety
+ *
+ * See [geo_calculate_smooth_normals_vec3](crate::ffi::geo_calculate_smooth_normals_vec3).
+ */
+enum FfiStatus geo_calculate_smooth_normals_vec3_u16(const Vec3 *positions,
+                                                     uint32_t positions_length,
+                                                     Vec3 *normals,
+                                                     uint32_t normals_length,
+                                                     const uint16_t *indices,
+                                                     uint32_t indices_length);
+
+/**
+ * A wrapper for [weld_positions](crate::weld::weld_positions).
+ * The function writes the remap table for each vertex to `out_remap`
+ * and the number of unique vertices to `vertex_count`.
+ *
+ * # Safety
+ *
+ * `positions` and `out_remap` must have length `positions_length`.
+ * `positions` uses the same 16 byte layout as [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+ * `vertex_count` must point to a writable value.
+ */
+enum FfiStatus geo_weld_positions(const Vec3A *positions,
+                                  uint32_t positions_length,
+                                  float epsilon,
+                                  uint32_t *out_remap,
+                                  uint32_t *vertex_count);
+
+/**
+ * A wrapper for [weld_positions_normals](crate::weld::weld_positions_normals)
+ * that writes the results like [geo_weld_positions].
+ *
+ * # Safety
+ *
+ * `positions`, `normals`, and `out_remap` must have length `positions_length`.
+ * `positions` and `normals` use the same 16 byte layout as [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+ * `vertex_count` must point to a writable value.
+ */
+enum FfiStatus geo_weld_positions_normals(const Vec3A *positions,
+                                          const Vec3A *normals,
+                                          uint32_t positions_length,
+                                          float epsilon,
+                                          float max_angle,
+                                          uint32_t *out_remap,
+                                          uint32_t *vertex_count);
+
+/**
+ * A version of [geo_weld_positions] for tightly packed positions with three floats for each position.
+ *
+ * # Safety
+ *
+ * `positions` and `out_remap` must have length `positions_length`.
+ * `vertex_count` must point to a writable value.
+ */
+enum FfiStatus geo_weld_positions_vec3(const Vec3 *positions,
+                                       uint32_t positions_length,
+                                       float epsilon,
+                                       uint32_t *out_remap,
+                                       uint32_t *vertex_count);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* GEOMETRY_TOOLS_H */
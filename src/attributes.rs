@@ -0,0 +1,1014 @@
+//! Functions for reading and writing vertex attributes in raw and interleaved vertex buffers.
+//!
+//! The decoded values can be used as inputs for the other functions in the crate.
+//! The outputs of the other functions can be packed into a single vertex buffer using [interleave_attributes].
+
+use glam::{Vec2, Vec3A, Vec4};
+use thiserror::Error;
+
+use crate::float16::{f16_to_f32, f32_to_f16};
+
+/// The data type and component count of a vertex attribute.
+/// Normalized integer formats are converted to floats in the range `-1.0` to `1.0` for signed formats
+/// or `0.0` to `1.0` for unsigned formats.
+/// All values are little endian.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeFormat {
+    Float32x2,
+    Float32x3,
+    Float32x4,
+    Float16x2,
+    Float16x4,
+    Snorm8x4,
+    Unorm8x4,
+    Snorm16x2,
+    Snorm16x4,
+    Unorm16x2,
+    Unorm16x4,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComponentType {
+    Float32,
+    Float16,
+    Snorm8,
+    Unorm8,
+    Snorm16,
+    Unorm16,
+    Sint8,
+    Uint8,
+    Sint16,
+    Uint16,
+    Uint32,
+}
+
+impl ComponentType {
+    fn size(self) -> usize {
+        match self {
+            ComponentType::Float32 | ComponentType::Uint32 => 4,
+            ComponentType::Float16
+            | ComponentType::Snorm16
+            | ComponentType::Unorm16
+            | ComponentType::Sint16
+            | ComponentType::Uint16 => 2,
+            ComponentType::Snorm8
+            | ComponentType::Unorm8
+            | ComponentType::Sint8
+            | ComponentType::Uint8 => 1,
+        }
+    }
+
+    fn decode(self, bytes: &[u8]) -> f32 {
+        match self {
+            ComponentType::Float32 => f32::from_le_bytes(bytes.try_into().unwrap()),
+            ComponentType::Float16 => f16_to_f32(u16::from_le_bytes(bytes.try_into().unwrap())),
+            // The minimum signed value is clamped to -1.0 to match graphics APIs.
+            ComponentType::Snorm8 => (bytes[0] as i8 as f32 / 127.0).max(-1.0),
+            ComponentType::Unorm8 => bytes[0] as f32 / 255.0,
+            ComponentType::Snorm16 => {
+                (i16::from_le_bytes(bytes.try_into().unwrap()) as f32 / 32767.0).max(-1.0)
+            }
+            ComponentType::Unorm16 => {
+                u16::from_le_bytes(bytes.try_into().unwrap()) as f32 / 65535.0
+            }
+            ComponentType::Sint8 => bytes[0] as i8 as f32,
+            ComponentType::Uint8 => bytes[0] as f32,
+            ComponentType::Sint16 => i16::from_le_bytes(bytes.try_into().unwrap()) as f32,
+            ComponentType::Uint16 => u16::from_le_bytes(bytes.try_into().unwrap()) as f32,
+            ComponentType::Uint32 => u32::from_le_bytes(bytes.try_into().unwrap()) as f32,
+        }
+    }
+
+    fn encode(self, value: f32, bytes: &mut Vec<u8>) {
+        // Float to integer casts saturate, so out of range values are clamped.
+        match self {
+            ComponentType::Float32 => bytes.extend_from_slice(&value.to_le_bytes()),
+            ComponentType::Float16 => bytes.extend_from_slice(&f32_to_f16(value).to_le_bytes()),
+            ComponentType::Snorm8 => {
+                bytes.push((value.clamp(-1.0, 1.0) * 127.0).round() as i8 as u8)
+            }
+            ComponentType::Unorm8 => bytes.push((value.clamp(0.0, 1.0) * 255.0).round() as u8),
+            ComponentType::Snorm16 => bytes.extend_from_slice(
+                &((value.clamp(-1.0, 1.0) * 32767.0).round() as i16).to_le_bytes(),
+            ),
+            ComponentType::Unorm16 => bytes.extend_from_slice(
+                &((value.clamp(0.0, 1.0) * 65535.0).round() as u16).to_le_bytes(),
+            ),
+            ComponentType::Sint8 => bytes.push(value.round() as i8 as u8),
+            ComponentType::Uint8 => bytes.push(value.round() as u8),
+            ComponentType::Sint16 => bytes.extend_from_slice(&(value.round() as i16).to_le_bytes()),
+            ComponentType::Uint16 => bytes.extend_from_slice(&(value.round() as u16).to_le_bytes()),
+            ComponentType::Uint32 => bytes.extend_from_slice(&(value.round() as u32).to_le_bytes()),
+        }
+    }
+}
+
+impl AttributeFormat {
+    fn component_type(self) -> ComponentType {
+        match self {
+            AttributeFormat::Float32x2
+            | AttributeFormat::Float32x3
+            | AttributeFormat::Float32x4 => ComponentType::Float32,
+            AttributeFormat::Float16x2 | AttributeFormat::Float16x4 => ComponentType::Float16,
+            AttributeFormat::Snorm8x4 => ComponentType::Snorm8,
+            AttributeFormat::Unorm8x4 => ComponentType::Unorm8,
+            AttributeFormat::Snorm16x2 | AttributeFormat::Snorm16x4 => ComponentType::Snorm16,
+            AttributeFormat::Unorm16x2 | AttributeFormat::Unorm16x4 => ComponentType::Unorm16,
+        }
+    }
+
+    /// The number of components for each element.
+    pub fn component_count(self) -> usize {
+        match self {
+            AttributeFormat::Float32x2
+            | AttributeFormat::Float16x2
+            | AttributeFormat::Snorm16x2
+            | AttributeFormat::Unorm16x2 => 2,
+            AttributeFormat::Float32x3 => 3,
+            AttributeFormat::Float32x4
+            | AttributeFormat::Float16x4
+            | AttributeFormat::Snorm8x4
+            | AttributeFormat::Unorm8x4
+            | AttributeFormat::Snorm16x4
+            | AttributeFormat::Unorm16x4 => 4,
+        }
+    }
+
+    /// The size in bytes of each element.
+    pub fn size(self) -> usize {
+        self.component_type().size() * self.component_count()
+    }
+}
+
+/// The component type of a glTF accessor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GltfComponentType {
+    Byte,
+    UnsignedByte,
+    Short,
+    UnsignedShort,
+    UnsignedInt,
+    Float,
+}
+
+impl GltfComponentType {
+    /// Converts the `componentType` value of a glTF accessor like `5126` for floats.
+    /// Returns `None` if the value is not a valid component type.
+    pub fn from_gl(component_type: u32) -> Option<Self> {
+        match component_type {
+            5120 => Some(Self::Byte),
+            5121 => Some(Self::UnsignedByte),
+            5122 => Some(Self::Short),
+            5123 => Some(Self::UnsignedShort),
+            5125 => Some(Self::UnsignedInt),
+            5126 => Some(Self::Float),
+            _ => None,
+        }
+    }
+
+    fn component_type(self, normalized: bool) -> Option<ComponentType> {
+        match (self, normalized) {
+            (Self::Byte, true) => Some(ComponentType::Snorm8),
+            (Self::Byte, false) => Some(ComponentType::Sint8),
+            (Self::UnsignedByte, true) => Some(ComponentType::Unorm8),
+            (Self::UnsignedByte, false) => Some(ComponentType::Uint8),
+            (Self::Short, true) => Some(ComponentType::Snorm16),
+            (Self::Short, false) => Some(ComponentType::Sint16),
+            (Self::UnsignedShort, true) => Some(ComponentType::Unorm16),
+            (Self::UnsignedShort, false) => Some(ComponentType::Uint16),
+            (Self::UnsignedInt, false) => Some(ComponentType::Uint32),
+            (Self::Float, false) => Some(ComponentType::Float32),
+            // glTF only allows normalized integer types.
+            (Self::UnsignedInt, true) | (Self::Float, true) => None,
+        }
+    }
+}
+
+/// Errors that can occur while creating an [AttributeReader] or interleaving attributes.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum AttributeError {
+    #[error(
+        "A stride of {} bytes is smaller than the element size of {} bytes.",
+        stride,
+        size
+    )]
+    InvalidStride { stride: usize, size: usize },
+    #[error(
+        "The attribute requires {} bytes but the buffer only has {} bytes.",
+        required_byte_count,
+        byte_count
+    )]
+    OutOfBounds {
+        required_byte_count: usize,
+        byte_count: usize,
+    },
+    #[error(
+        "A glTF accessor with component type {:?}, normalized {}, and {} components is not supported.",
+        component_type,
+        normalized,
+        component_count
+    )]
+    UnsupportedGltfAccessor {
+        component_type: GltfComponentType,
+        normalized: bool,
+        component_count: usize,
+    },
+    #[error(
+        "Attribute {} has {} elements but the first attribute has {} elements.",
+        index,
+        count,
+        expected
+    )]
+    CountMismatch {
+        index: usize,
+        count: usize,
+        expected: usize,
+    },
+}
+
+/// A view of a vertex attribute in a raw byte buffer
+/// like a single attribute in an interleaved vertex buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    stride: usize,
+    component_type: ComponentType,
+    component_count: usize,
+    count: usize,
+}
+
+impl<'a> AttributeReader<'a> {
+    /// Creates a reader for `count` elements starting at `offset` in `bytes`
+    /// with `stride` bytes between the start of each element.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::attributes::{AttributeFormat, AttributeReader};
+    use glam::{Vec2, Vec3A};
+
+    // Interleaved vertices with a float32x3 position and unorm16x2 UV.
+    let mut bytes = Vec::new();
+    for (position, uv) in [([1.0f32, 2.0, 3.0], [0u16, 65535]), ([4.0, 5.0, 6.0], [65535, 0])] {
+        for p in position {
+            bytes.extend_from_slice(&p.to_le_bytes());
+        }
+        for u in uv {
+            bytes.extend_from_slice(&u.to_le_bytes());
+        }
+    }
+
+    let positions = AttributeReader::new(&bytes, 0, 16, AttributeFormat::Float32x3, 2).unwrap();
+    assert_eq!(
+        vec![Vec3A::new(1.0, 2.0, 3.0), Vec3A::new(4.0, 5.0, 6.0)],
+        positions.read_vec3a()
+    );
+
+    let uvs = AttributeReader::new(&bytes, 12, 16, AttributeFormat::Unorm16x2, 2).unwrap();
+    assert_eq!(vec![Vec2::Y, Vec2::X], uvs.read_vec2());
+    ```
+     */
+    pub fn new(
+        bytes: &'a [u8],
+        offset: usize,
+        stride: usize,
+        format: AttributeFormat,
+        count: usize,
+    ) -> Result<Self, AttributeError> {
+        Self::from_components(
+            bytes,
+            offset,
+            stride,
+            format.component_type(),
+            format.component_count(),
+            count,
+        )
+    }
+
+    /// Creates a reader using the semantics of a glTF accessor.
+    /// `bytes` should be the data of the accessor's buffer view
+    /// and `offset` should be the accessor's `byteOffset`.
+    /// If `stride` is `None`, the elements are tightly packed.
+    /// Integer components that are not `normalized` are converted to floats without scaling.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::attributes::{AttributeReader, GltfComponentType};
+    use glam::Vec3A;
+
+    // Normalized signed byte normals padded to 4 bytes as required by glTF.
+    let bytes = [0, 0, 127, 0, 0, 129, 0, 0];
+    let component_type = GltfComponentType::from_gl(5120).unwrap();
+
+    let normals = AttributeReader::from_gltf(&bytes, 0, Some(4), component_type, true, 3, 2).unwrap();
+    assert_eq!(vec![Vec3A::Z, Vec3A::NEG_Y], normals.read_vec3a());
+    ```
+     */
+    pub fn from_gltf(
+        bytes: &'a [u8],
+        offset: usize,
+        stride: Option<usize>,
+        component_type: GltfComponentType,
+        normalized: bool,
+        component_count: usize,
+        count: usize,
+    ) -> Result<Self, AttributeError> {
+        let unsupported = AttributeError::UnsupportedGltfAccessor {
+            component_type,
+            normalized,
+            component_count,
+        };
+        if !(1..=4).contains(&component_count) {
+            return Err(unsupported);
+        }
+        let component = component_type
+            .component_type(normalized)
+            .ok_or(unsupported)?;
+
+        let stride = stride.unwrap_or(component.size() * component_count);
+        Self::from_components(bytes, offset, stride, component, component_count, count)
+    }
+
+    fn from_components(
+        bytes: &'a [u8],
+        offset: usize,
+        stride: usize,
+        component_type: ComponentType,
+        component_count: usize,
+        count: usize,
+    ) -> Result<Self, AttributeError> {
+        let size = component_type.size() * component_count;
+        if stride < size {
+            return Err(AttributeError::InvalidStride { stride, size });
+        }
+
+        // The last element does not need to be padded to the full stride.
+        let required_byte_count = match count {
+            0 => 0,
+            _ => offset + (count - 1) * stride + size,
+        };
+        if required_byte_count > bytes.len() {
+            return Err(AttributeError::OutOfBounds {
+                required_byte_count,
+                byte_count: bytes.len(),
+            });
+        }
+
+        Ok(Self {
+            bytes,
+            offset,
+            stride,
+            component_type,
+            component_count,
+            count,
+        })
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// The number of components for each element.
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// Decodes the element at `index` with missing components set to `0.0`.
+    /// Returns `None` if `index` is out of range.
+    pub fn get(&self, index: usize) -> Option<Vec4> {
+        if index >= self.count {
+            return None;
+        }
+
+        let component_size = self.component_type.size();
+        let start = self.offset + index * self.stride;
+
+        let mut value = [0.0; 4];
+        for (i, component) in value.iter_mut().take(self.component_count).enumerate() {
+            let component_start = start + i * component_size;
+            *component = self
+                .component_type
+                .decode(&self.bytes[component_start..component_start + component_size]);
+        }
+        Some(Vec4::from_array(value))
+    }
+
+    /// Decodes all elements with missing components set to `0.0`.
+    pub fn iter(&self) -> impl Iterator<Item = Vec4> + '_ {
+        (0..self.count).map(|i| self.get(i).unwrap())
+    }
+
+    /// Decodes all elements as 4 component values with missing components set to `0.0`.
+    pub fn read_vec4(&self) -> Vec<Vec4> {
+        self.iter().collect()
+    }
+
+    /// Decodes the first 3 components of all elements with missing components set to `0.0`.
+    pub fn read_vec3a(&self) -> Vec<Vec3A> {
+        self.iter().map(Vec3A::from_vec4).collect()
+    }
+
+    /// Decodes the first 2 components of all elements.
+    pub fn read_vec2(&self) -> Vec<Vec2> {
+        self.iter().map(|v| v.truncate().truncate()).collect()
+    }
+}
+
+/// The values for a vertex attribute to write to an interleaved vertex buffer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AttributeData<'a> {
+    Vec2(&'a [Vec2]),
+    Vec3A(&'a [Vec3A]),
+    Vec4(&'a [Vec4]),
+}
+
+impl<'a> AttributeData<'a> {
+    fn len(&self) -> usize {
+        match self {
+            AttributeData::Vec2(values) => values.len(),
+            AttributeData::Vec3A(values) => values.len(),
+            AttributeData::Vec4(values) => values.len(),
+        }
+    }
+
+    fn get(&self, index: usize) -> Vec4 {
+        match self {
+            AttributeData::Vec2(values) => values[index].extend(0.0).extend(0.0),
+            AttributeData::Vec3A(values) => values[index].extend(0.0),
+            AttributeData::Vec4(values) => values[index],
+        }
+    }
+}
+
+/// A vertex attribute and the format used to store it in an interleaved vertex buffer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VertexAttribute<'a> {
+    pub data: AttributeData<'a>,
+    pub format: AttributeFormat,
+}
+
+/// The location of an attribute in each vertex of an [InterleavedVertices] buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeLayout {
+    pub format: AttributeFormat,
+    /// The offset in bytes from the start of each vertex.
+    pub offset: usize,
+}
+
+/// A vertex buffer with all attributes for a vertex stored next to each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterleavedVertices {
+    pub bytes: Vec<u8>,
+    /// The size in bytes of each vertex.
+    pub stride: usize,
+    /// The layout of each attribute in the same order as the input attributes.
+    pub attributes: Vec<AttributeLayout>,
+    pub vertex_count: usize,
+}
+
+impl InterleavedVertices {
+    /// Creates a reader for the attribute at `index` in [InterleavedVertices::attributes].
+    /// Returns `None` if `index` is out of range.
+    pub fn reader(&self, index: usize) -> Option<AttributeReader<'_>> {
+        let layout = self.attributes.get(index)?;
+        AttributeReader::new(
+            &self.bytes,
+            layout.offset,
+            self.stride,
+            layout.format,
+            self.vertex_count,
+        )
+        .ok()
+    }
+}
+
+/// Packs `attributes` into a single little endian vertex buffer in the order they are specified.
+/// Values are converted to the attribute's format with extra components ignored
+/// and missing components set to `0.0`.
+/// Normalized integer formats clamp values to the range of the format.
+///
+/// All the formats have sizes that are a multiple of 4 bytes,
+/// so the attribute offsets and stride meet the alignment requirements of graphics APIs.
+/// # Examples
+/**
+```rust
+use geometry_tools::attributes::{
+    interleave_attributes, AttributeData, AttributeFormat, AttributeLayout, VertexAttribute,
+};
+use glam::{Vec2, Vec3A};
+
+let positions = vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let uvs = vec![Vec2::ZERO, Vec2::X, Vec2::Y];
+
+let vertices = interleave_attributes(&[
+    VertexAttribute {
+        data: AttributeData::Vec3A(&positions),
+        format: AttributeFormat::Float32x3,
+    },
+    VertexAttribute {
+        data: AttributeData::Vec2(&uvs),
+        format: AttributeFormat::Unorm16x2,
+    },
+])
+.unwrap();
+
+assert_eq!(16, vertices.stride);
+assert_eq!(48, vertices.bytes.len());
+assert_eq!(12, vertices.attributes[1].offset);
+assert_eq!(uvs, vertices.reader(1).unwrap().read_vec2());
+```
+ */
+pub fn interleave_attributes(
+    attributes: &[VertexAttribute],
+) -> Result<InterleavedVertices, AttributeError> {
+    let vertex_count = attributes.first().map(|a| a.data.len()).unwrap_or_default();
+    for (index, attribute) in attributes.iter().enumerate() {
+        if attribute.data.len() != vertex_count {
+            return Err(AttributeError::CountMismatch {
+                index,
+                count: attribute.data.len(),
+                expected: vertex_count,
+            });
+        }
+    }
+
+    let mut layouts = Vec::with_capacity(attributes.len());
+    let mut stride = 0;
+    for attribute in attributes {
+        layouts.push(AttributeLayout {
+            format: attribute.format,
+            offset: stride,
+        });
+        stride += attribute.format.size();
+    }
+
+    let mut bytes = Vec::with_capacity(stride * vertex_count);
+    for i in 0..vertex_count {
+        for attribute in attributes {
+            let component_type = attribute.format.component_type();
+            let value = attribute.data.get(i).to_array();
+            for component in &value[..attribute.format.component_count()] {
+                component_type.encode(*component, &mut bytes);
+            }
+        }
+    }
+
+    Ok(InterleavedVertices {
+        bytes,
+        stride,
+        attributes: layouts,
+        vertex_count,
+    })
+}
+
+/// Writes `values` to `bytes` starting at `offset` with `stride` bytes between each element.
+/// Extra components are ignored.
+pub(crate) fn write_attribute(
+    bytes: &mut [u8],
+    offset: usize,
+    stride: usize,
+    format: AttributeFormat,
+    values: impl Iterator<Item = Vec4>,
+) {
+    let component_type = format.component_type();
+    let mut element = Vec::with_capacity(format.size());
+    for (i, value) in values.enumerate() {
+        element.clear();
+        for component in &value.to_array()[..format.component_count()] {
+            component_type.encode(*component, &mut element);
+        }
+        let start = offset + i * stride;
+        bytes[start..start + element.len()].copy_from_slice(&element);
+    }
+}
+
+pub mod ffi {
+    use super::*;
+    use crate::ffi::{catch_status, check_triangle_indices, slice, FfiStatus};
+
+    /// The location and format of a vertex attribute in a caller owned buffer
+    /// like a single attribute in an interleaved vertex buffer.
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy)]
+    pub struct AttributeDescriptor {
+        /// The start of the buffer.
+        pub data: *mut u8,
+        /// The total size of the buffer in bytes.
+        pub byte_length: u32,
+        /// The offset in bytes of the first element from the start of the buffer.
+        pub offset: u32,
+        /// The number of bytes between the start of each element.
+        pub stride: u32,
+        pub format: AttributeFormat,
+    }
+
+    impl AttributeDescriptor {
+        unsafe fn bytes<'a>(&self) -> Result<&'a [u8], FfiStatus> {
+            slice(self.data, self.byte_length)
+        }
+
+        unsafe fn reader<'a>(&self, count: u32) -> Result<AttributeReader<'a>, FfiStatus> {
+            AttributeReader::new(
+                self.bytes()?,
+                self.offset as usize,
+                self.stride as usize,
+                self.format,
+                count as usize,
+            )
+            .map_err(|_| FfiStatus::InvalidAttribute)
+        }
+
+        unsafe fn write(&self, values: &[Vec4]) -> Result<(), FfiStatus> {
+            // Check the bounds before creating a mutable slice.
+            self.reader(values.len() as u32)?;
+            let bytes = crate::ffi::slice_mut(self.data, self.byte_length)?;
+            write_attribute(
+                bytes,
+                self.offset as usize,
+                self.stride as usize,
+                self.format,
+                values.iter().copied(),
+            );
+            Ok(())
+        }
+    }
+
+    /// A version of [calculate_smooth_normals](crate::vectors::calculate_smooth_normals)
+    /// that reads `vertex_count` positions and writes `vertex_count` normals using attribute descriptors.
+    /// This allows using interleaved vertex buffers directly.
+    /// The output normals are only written after all inputs are read,
+    /// so `positions` and `normals` may describe attributes in the same buffer.
+    ///
+    /// Returns [FfiStatus::InvalidAttribute] if an attribute does not fit in its buffer
+    /// or has a stride smaller than the size of its format.
+    ///
+    /// # Safety
+    ///
+    /// `positions` and `normals` must point to valid descriptors
+    /// with `data` pointing to at least `byte_length` bytes.
+    /// `indices` must have length `indices_length`.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_calculate_smooth_normals_strided(
+        positions: *const AttributeDescriptor,
+        normals: *const AttributeDescriptor,
+        vertex_count: u32,
+        indices: *const u32,
+        indices_length: u32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            let positions = positions.as_ref().ok_or(FfiStatus::NullPointer)?;
+            let normals = normals.as_ref().ok_or(FfiStatus::NullPointer)?;
+            let indices = slice(indices, indices_length)?;
+            check_triangle_indices(indices, vertex_count as usize)?;
+
+            let positions = positions.reader(vertex_count)?.read_vec3a();
+            let mut result = crate::vectors::calculate_smooth_normals(&positions, indices);
+            result.resize(positions.len(), Vec3A::ZERO);
+
+            let result: Vec<_> = result.into_iter().map(|n| n.extend(0.0)).collect();
+            normals.write(&result)
+        })
+    }
+
+    /// A version of [calculate_tangents](crate::vectors::calculate_tangents)
+    /// that reads `vertex_count` positions, normals, and UVs and writes `vertex_count` tangents
+    /// using attribute descriptors.
+    /// The output tangents are only written after all inputs are read,
+    /// so all the attributes may be in the same buffer.
+    ///
+    /// # Safety
+    ///
+    /// `positions`, `normals`, `uvs`, and `tangents` must point to valid descriptors
+    /// with `data` pointing to at least `byte_length` bytes.
+    /// `indices` must have length `indices_length`.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_calculate_tangents_strided(
+        positions: *const AttributeDescriptor,
+        normals: *const AttributeDescriptor,
+        uvs: *const AttributeDescriptor,
+        tangents: *const AttributeDescriptor,
+        vertex_count: u32,
+        indices: *const u32,
+        indices_length: u32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            let positions = positions.as_ref().ok_or(FfiStatus::NullPointer)?;
+            let normals = normals.as_ref().ok_or(FfiStatus::NullPointer)?;
+            let uvs = uvs.as_ref().ok_or(FfiStatus::NullPointer)?;
+            let tangents = tangents.as_ref().ok_or(FfiStatus::NullPointer)?;
+            let indices = slice(indices, indices_length)?;
+            check_triangle_indices(indices, vertex_count as usize)?;
+
+            let positions = positions.reader(vertex_count)?.read_vec3a();
+            let normals = normals.reader(vertex_count)?.read_vec3a();
+            let uvs = uvs.reader(vertex_count)?.read_vec2();
+            let mut result =
+                crate::vectors::calculate_tangents(&positions, &normals, &uvs, indices)
+                    .map_err(|_| FfiStatus::LengthMismatch)?;
+            result.resize(positions.len(), Vec4::ZERO);
+
+            tangents.write(&result)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_sizes() {
+        assert_eq!(12, AttributeFormat::Float32x3.size());
+        assert_eq!(8, AttributeFormat::Float16x4.size());
+        assert_eq!(4, AttributeFormat::Snorm8x4.size());
+        assert_eq!(4, AttributeFormat::Unorm16x2.size());
+    }
+
+    #[test]
+    fn reader_empty() {
+        let reader = AttributeReader::new(&[], 0, 12, AttributeFormat::Float32x3, 0).unwrap();
+        assert!(reader.is_empty());
+        assert_eq!(None, reader.get(0));
+        assert!(reader.read_vec3a().is_empty());
+    }
+
+    #[test]
+    fn reader_errors() {
+        assert_eq!(
+            Err(AttributeError::InvalidStride {
+                stride: 8,
+                size: 12
+            }),
+            AttributeReader::new(&[0; 24], 0, 8, AttributeFormat::Float32x3, 2)
+        );
+        assert_eq!(
+            Err(AttributeError::OutOfBounds {
+                required_byte_count: 28,
+                byte_count: 24
+            }),
+            AttributeReader::new(&[0; 24], 4, 12, AttributeFormat::Float32x3, 2)
+        );
+    }
+
+    #[test]
+    fn reader_last_element_without_padding() {
+        // The stride includes 4 bytes of padding that the last element doesn't need.
+        let reader = AttributeReader::new(&[0; 32], 0, 12, AttributeFormat::Float32x2, 3).unwrap();
+        assert_eq!(3, reader.len());
+    }
+
+    #[test]
+    fn reader_normalized_integers() {
+        let bytes = [127, 0x81, 0x80, 0, 255, 0, 128, 1];
+        let snorm = AttributeReader::new(&bytes, 0, 4, AttributeFormat::Snorm8x4, 1).unwrap();
+        assert_eq!(Some(Vec4::new(1.0, -1.0, -1.0, 0.0)), snorm.get(0));
+
+        let unorm = AttributeReader::new(&bytes, 4, 4, AttributeFormat::Unorm8x4, 1).unwrap();
+        assert_eq!(
+            Some(Vec4::new(1.0, 0.0, 128.0 / 255.0, 1.0 / 255.0)),
+            unorm.get(0)
+        );
+
+        let bytes = [0xff, 0x7f, 0x01, 0x80, 0x00, 0x80, 0x00, 0x00];
+        let snorm = AttributeReader::new(&bytes, 0, 8, AttributeFormat::Snorm16x4, 1).unwrap();
+        assert_eq!(Some(Vec4::new(1.0, -1.0, -1.0, 0.0)), snorm.get(0));
+
+        let unorm = AttributeReader::new(&bytes, 0, 4, AttributeFormat::Unorm16x2, 2).unwrap();
+        assert_eq!(
+            vec![
+                Vec2::new(32767.0 / 65535.0, 32769.0 / 65535.0),
+                Vec2::new(32768.0 / 65535.0, 0.0)
+            ],
+            unorm.read_vec2()
+        );
+    }
+
+    #[test]
+    fn reader_half_floats() {
+        let bytes = [0x00, 0x3c, 0x00, 0xc0, 0x00, 0x38, 0x00, 0x00];
+        let reader = AttributeReader::new(&bytes, 0, 8, AttributeFormat::Float16x4, 1).unwrap();
+        assert_eq!(vec![Vec3A::new(1.0, -2.0, 0.5)], reader.read_vec3a());
+        assert_eq!(vec![Vec4::new(1.0, -2.0, 0.5, 0.0)], reader.read_vec4());
+    }
+
+    #[test]
+    fn gltf_component_types() {
+        assert_eq!(
+            Some(GltfComponentType::Float),
+            GltfComponentType::from_gl(5126)
+        );
+        assert_eq!(None, GltfComponentType::from_gl(5124));
+    }
+
+    #[test]
+    fn gltf_unnormalized_integers() {
+        // Quantized positions as unsigned shorts with 2 bytes of padding.
+        let bytes = [1, 0, 2, 0, 0, 1, 0, 0, 255, 255, 0, 0, 3, 0, 0, 0];
+        let reader = AttributeReader::from_gltf(
+            &bytes,
+            0,
+            Some(8),
+            GltfComponentType::UnsignedShort,
+            false,
+            3,
+            2,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![Vec3A::new(1.0, 2.0, 256.0), Vec3A::new(65535.0, 0.0, 3.0)],
+            reader.read_vec3a()
+        );
+        assert_eq!(3, reader.component_count());
+    }
+
+    #[test]
+    fn gltf_tightly_packed() {
+        let bytes = [255, 0, 0, 255];
+        let reader = AttributeReader::from_gltf(
+            &bytes,
+            0,
+            None,
+            GltfComponentType::UnsignedByte,
+            true,
+            2,
+            2,
+        )
+        .unwrap();
+        assert_eq!(vec![Vec2::X, Vec2::Y], reader.read_vec2());
+    }
+
+    #[test]
+    fn gltf_unsupported_accessors() {
+        assert_eq!(
+            Err(AttributeError::UnsupportedGltfAccessor {
+                component_type: GltfComponentType::Float,
+                normalized: true,
+                component_count: 3
+            }),
+            AttributeReader::from_gltf(&[], 0, None, GltfComponentType::Float, true, 3, 0)
+        );
+        assert_eq!(
+            Err(AttributeError::UnsupportedGltfAccessor {
+                component_type: GltfComponentType::Byte,
+                normalized: false,
+                component_count: 5
+            }),
+            AttributeReader::from_gltf(&[], 0, None, GltfComponentType::Byte, false, 5, 0)
+        );
+    }
+
+    #[test]
+    fn interleave_empty() {
+        let vertices = interleave_attributes(&[]).unwrap();
+        assert!(vertices.bytes.is_empty());
+        assert_eq!(0, vertices.stride);
+        assert_eq!(0, vertices.vertex_count);
+    }
+
+    #[test]
+    fn interleave_count_mismatch() {
+        assert_eq!(
+            Err(AttributeError::CountMismatch {
+                index: 1,
+                count: 1,
+                expected: 2
+            }),
+            interleave_attributes(&[
+                VertexAttribute {
+                    data: AttributeData::Vec3A(&[Vec3A::ZERO, Vec3A::X]),
+                    format: AttributeFormat::Float32x3,
+                },
+                VertexAttribute {
+                    data: AttributeData::Vec2(&[Vec2::ZERO]),
+                    format: AttributeFormat::Float32x2,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn interleave_round_trip() {
+        let normals = [Vec3A::X, Vec3A::NEG_Y];
+        let tangents = [
+            Vec4::new(0.0, 1.0, 0.0, -1.0),
+            Vec4::new(1.0, 0.0, 0.0, 1.0),
+        ];
+        let colors = [Vec4::new(2.0, -1.0, 0.5, 1.0), Vec4::ONE];
+        let vertices = interleave_attributes(&[
+            VertexAttribute {
+                data: AttributeData::Vec3A(&normals),
+                format: AttributeFormat::Snorm8x4,
+            },
+            VertexAttribute {
+                data: AttributeData::Vec4(&tangents),
+                format: AttributeFormat::Float16x4,
+            },
+            VertexAttribute {
+                data: AttributeData::Vec4(&colors),
+                format: AttributeFormat::Unorm8x4,
+            },
+        ])
+        .unwrap();
+
+        assert_eq!(16, vertices.stride);
+        assert_eq!(
+            vec![0, 4, 12],
+            vertices
+                .attributes
+                .iter()
+                .map(|a| a.offset)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(normals.to_vec(), vertices.reader(0).unwrap().read_vec3a());
+        assert_eq!(tangents.to_vec(), vertices.reader(1).unwrap().read_vec4());
+        assert_eq!(
+            vec![Vec4::new(1.0, 0.0, 128.0 / 255.0, 1.0), Vec4::ONE],
+            vertices.reader(2).unwrap().read_vec4()
+        );
+        assert_eq!(None, vertices.reader(3));
+    }
+
+    #[test]
+    fn strided_ffi_interleaved() {
+        // Float32x3 positions, Snorm8x4 normals, Float32x2 UVs, Float16x4 tangents.
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+        let mut vertices = interleave_attributes(&[
+            VertexAttribute {
+                data: AttributeData::Vec3A(&positions),
+                format: AttributeFormat::Float32x3,
+            },
+            VertexAttribute {
+                data: AttributeData::Vec3A(&[Vec3A::ZERO; 3]),
+                format: AttributeFormat::Snorm8x4,
+            },
+            VertexAttribute {
+                data: AttributeData::Vec2(&uvs),
+                format: AttributeFormat::Float32x2,
+            },
+            VertexAttribute {
+                data: AttributeData::Vec4(&[Vec4::ZERO; 3]),
+                format: AttributeFormat::Float16x4,
+            },
+        ])
+        .unwrap();
+
+        let data = vertices.bytes.as_mut_ptr();
+        let descriptor = |i: usize| ffi::AttributeDescriptor {
+            data,
+            byte_length: vertices.bytes.len() as u32,
+            offset: vertices.attributes[i].offset as u32,
+            stride: vertices.stride as u32,
+            format: vertices.attributes[i].format,
+        };
+        let (p, n, uv, t) = (descriptor(0), descriptor(1), descriptor(2), descriptor(3));
+        let indices = [0, 1, 2];
+
+        unsafe {
+            assert_eq!(
+                crate::ffi::FfiStatus::Success,
+                ffi::geo_calculate_smooth_normals_strided(&p, &n, 3, indices.as_ptr(), 3)
+            );
+            assert_eq!(
+                crate::ffi::FfiStatus::Success,
+                ffi::geo_calculate_tangents_strided(&p, &n, &uv, &t, 3, indices.as_ptr(), 3)
+            );
+        }
+
+        assert_eq!(vec![Vec3A::Z; 3], vertices.reader(1).unwrap().read_vec3a());
+        assert_eq!(
+            vec![Vec4::new(1.0, 0.0, 0.0, 1.0); 3],
+            vertices.reader(3).unwrap().read_vec4()
+        );
+    }
+
+    #[test]
+    fn strided_ffi_out_of_bounds() {
+        let mut bytes = [0u8; 24];
+        let descriptor = ffi::AttributeDescriptor {
+            data: bytes.as_mut_ptr(),
+            byte_length: bytes.len() as u32,
+            offset: 0,
+            stride: 12,
+            format: AttributeFormat::Float32x3,
+        };
+        let indices = [0, 1, 2];
+        let status = unsafe {
+            ffi::geo_calculate_smooth_normals_strided(
+                &descriptor,
+                &descriptor,
+                3,
+                indices.as_ptr(),
+                3,
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::InvalidAttribute, status);
+    }
+}
@@ -0,0 +1,231 @@
+//! Functions for rasterizing meshes into texture space for baking.
+//!
+//! Each texel of the output stores the surface point covered by the texel's center in UV space.
+//! Bakers can then cast rays from these points using [Bvh](crate::bvh::Bvh)
+//! to calculate values like ambient occlusion or normals from a high poly mesh.
+
+use glam::{UVec2, Vec2, Vec3A};
+
+use crate::barycentric::{calculate_barycentrics_2d, interpolate_barycentric};
+use crate::query::closest_point_on_triangle;
+
+/// The interpolated surface attributes for each texel calculated by [rasterize_texels].
+/// Texel `(x, y)` is stored at index `y * width + x`
+/// and covers the UV coordinates `((x + 0.5) / width, (y + 0.5) / height)` at its center.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TexelSamples {
+    pub size: UVec2,
+    /// The interpolated world position for each texel or zero for empty texels.
+    pub positions: Vec<Vec3A>,
+    /// The normalized interpolated normal for each texel or zero for empty texels.
+    pub normals: Vec<Vec3A>,
+    /// The triangle sampled by each texel or [None] for empty texels.
+    pub triangles: Vec<Option<u32>>,
+}
+
+/// Rasterizes the UV layout of a mesh into a texture with dimensions `size`
+/// and interpolates the `positions` and `normals` at the center of each covered texel.
+/// Texels covered by multiple triangles use the last triangle in `indices`.
+///
+/// Texels are only covered if their center is inside a triangle,
+/// so `dilation` expands the rasterized triangles outward by the given number of texels
+/// to avoid seams from texture filtering and mipmapping.
+/// Dilated texels sample the closest point in UV space on a triangle of a neighboring covered texel,
+/// so their positions are still on the surface of the mesh.
+///
+/// # Panics
+/// Panics if `positions`, `normals`, and `uvs` do not all have the same length.
+/// # Examples
+/**
+```rust
+use geometry_tools::bake::rasterize_texels;
+use glam::{UVec2, Vec2, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let normals = [Vec3A::Z; 3];
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+
+let texels = rasterize_texels(&positions, &normals, &uvs, &[0, 1, 2], UVec2::new(4, 4), 0);
+assert_eq!(Vec3A::new(0.125, 0.125, 0.0), texels.positions[0]);
+assert_eq!(Vec3A::Z, texels.normals[0]);
+assert_eq!(Some(0), texels.triangles[0]);
+
+// The texel in the top right corner is outside the triangle.
+assert_eq!(None, texels.triangles[15]);
+
+// Dilate by two texels to also fill the corner.
+let texels = rasterize_texels(&positions, &normals, &uvs, &[0, 1, 2], UVec2::new(4, 4), 2);
+assert_eq!(Some(0), texels.triangles[15]);
+assert_eq!(Vec3A::new(0.5, 0.5, 0.0), texels.positions[15]);
+```
+ */
+pub fn rasterize_texels<P, N>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[u32],
+    size: UVec2,
+    dilation: u32,
+) -> TexelSamples
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    assert!(
+        positions.len() == normals.len() && normals.len() == uvs.len(),
+        "positions, normals, and uvs should have the same length"
+    );
+
+    let (width, height) = (size.x as usize, size.y as usize);
+    let mut samples = TexelSamples {
+        size,
+        positions: vec![Vec3A::ZERO; width * height],
+        normals: vec![Vec3A::ZERO; width * height],
+        triangles: vec![None; width * height],
+    };
+
+    let sample = |samples: &mut TexelSamples, texel: usize, triangle: u32, barycentrics: Vec3A| {
+        let start = triangle as usize * 3;
+        let face = [indices[start], indices[start + 1], indices[start + 2]].map(|v| v as usize);
+        samples.positions[texel] =
+            interpolate_barycentric(face.map(|v| positions[v].into()), barycentrics);
+        samples.normals[texel] =
+            interpolate_barycentric(face.map(|v| normals[v].into()), barycentrics)
+                .normalize_or_zero();
+        samples.triangles[texel] = Some(triangle);
+    };
+
+    let texel_center = |x: usize, y: usize| {
+        (Vec2::new(x as f32, y as f32) + 0.5) / Vec2::new(width as f32, height as f32)
+    };
+
+    for (triangle, face) in indices.chunks_exact(3).enumerate() {
+        let [uv0, uv1, uv2] = [face[0], face[1], face[2]].map(|v| uvs[v as usize]);
+
+        // Only check texels within the bounding box of the triangle.
+        let min = uv0.min(uv1).min(uv2).clamp(Vec2::ZERO, Vec2::ONE) * size.as_vec2();
+        let max = uv0.max(uv1).max(uv2).clamp(Vec2::ZERO, Vec2::ONE) * size.as_vec2();
+        let x_range = (min.x.floor() as usize)..(max.x.ceil() as usize).min(width);
+        let y_range = (min.y.floor() as usize)..(max.y.ceil() as usize).min(height);
+
+        for y in y_range {
+            for x in x_range.clone() {
+                // Accept either winding order.
+                if let Some(barycentrics) =
+                    calculate_barycentrics_2d(texel_center(x, y), uv0, uv1, uv2)
+                {
+                    if barycentrics.min_element() >= 0.0 {
+                        sample(&mut samples, y * width + x, triangle as u32, barycentrics);
+                    }
+                }
+            }
+        }
+    }
+
+    // Each dilation step only uses texels covered by previous steps.
+    let neighbors = [
+        (-1, 0),
+        (1, 0),
+        (0, -1),
+        (0, 1),
+        (-1, -1),
+        (1, -1),
+        (-1, 1),
+        (1, 1),
+    ];
+    for _ in 0..dilation {
+        let covered = samples.triangles.clone();
+        for y in 0..height {
+            for x in 0..width {
+                if covered[y * width + x].is_some() {
+                    continue;
+                }
+
+                let neighbor_triangle = neighbors.iter().find_map(|(dx, dy)| {
+                    let nx = x.checked_add_signed(*dx).filter(|nx| *nx < width)?;
+                    let ny = y.checked_add_signed(*dy).filter(|ny| *ny < height)?;
+                    covered[ny * width + nx]
+                });
+                if let Some(triangle) = neighbor_triangle {
+                    let start = triangle as usize * 3;
+                    let [uv0, uv1, uv2] = [start, start + 1, start + 2]
+                        .map(|i| uvs[indices[i] as usize].extend(0.0).into());
+                    let center: Vec3A = texel_center(x, y).extend(0.0).into();
+                    let (_, barycentrics) = closest_point_on_triangle(center, uv0, uv1, uv2);
+                    sample(&mut samples, y * width + x, triangle, barycentrics);
+                }
+            }
+        }
+    }
+
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::shapes::generate_plane;
+
+    #[test]
+    fn rasterize_empty() {
+        let texels = rasterize_texels::<Vec3A, Vec3A>(&[], &[], &[], &[], UVec2::new(2, 2), 1);
+        assert_eq!(vec![None; 4], texels.triangles);
+
+        let texels = rasterize_texels::<Vec3A, Vec3A>(&[], &[], &[], &[], UVec2::ZERO, 1);
+        assert!(texels.positions.is_empty());
+    }
+
+    #[test]
+    fn rasterize_plane() {
+        let plane = generate_plane(Vec2::ONE, UVec2::new(3, 5));
+        let size = UVec2::new(16, 8);
+        let texels = rasterize_texels(
+            &plane.positions,
+            &plane.normals,
+            &plane.uvs,
+            &plane.indices,
+            size,
+            0,
+        );
+
+        // The plane has UVs covering the entire texture.
+        assert!(texels.triangles.iter().all(|t| t.is_some()));
+        for y in 0..8 {
+            for x in 0..16 {
+                let uv = (Vec2::new(x as f32, y as f32) + 0.5) / size.as_vec2();
+                let expected = Vec3A::new(uv.x - 0.5, 0.0, uv.y - 0.5);
+                assert!(texels.positions[y * 16 + x].abs_diff_eq(expected, 0.0001));
+                assert_eq!(Vec3A::Y, texels.normals[y * 16 + x]);
+            }
+        }
+    }
+
+    #[test]
+    fn rasterize_dilation() {
+        // A small triangle in the center of the texture with clockwise UVs.
+        let positions = [Vec3A::ZERO, Vec3A::Y, Vec3A::X];
+        let normals = [Vec3A::Z; 3];
+        let uvs = [
+            Vec2::new(0.4, 0.4),
+            Vec2::new(0.4, 0.6),
+            Vec2::new(0.6, 0.4),
+        ];
+        let size = UVec2::new(10, 10);
+        let count = |dilation| {
+            let texels = rasterize_texels(&positions, &normals, &uvs, &[0, 1, 2], size, dilation);
+            for (p, t) in texels.positions.iter().zip(&texels.triangles) {
+                if t.is_some() {
+                    // Positions are on the triangle.
+                    assert!(p.min_element() >= 0.0 && p.x + p.y <= 1.0 + 0.0001);
+                }
+            }
+            texels.triangles.iter().filter(|t| t.is_some()).count()
+        };
+
+        assert_eq!(3, count(0));
+        assert!(count(1) > count(0));
+        assert!(count(2) > count(1));
+        assert_eq!(100, count(10));
+    }
+}
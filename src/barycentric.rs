@@ -0,0 +1,177 @@
+//! Functions for calculating barycentric coordinates and interpolating vertex attributes.
+//!
+//! The barycentric coordinates `(u, v, w)` of a point are the weights of the three triangle vertices `a`, `b`, and `c`
+//! such that the point is `a * u + b * v + c * w` and `u + v + w == 1.0`.
+//! Queries like [Bvh::raycast](crate::bvh::Bvh::raycast) return barycentric coordinates in the same form.
+
+use std::ops::{Add, Mul};
+
+use glam::{Vec2, Vec3A};
+
+/// Calculates the barycentric coordinates of `point` projected onto the plane of the triangle `abc`.
+/// The coordinates are not clamped, so points outside the triangle have at least one negative coordinate.
+/// Returns `None` if the triangle is degenerate and has zero area.
+/// # Examples
+/**
+```rust
+use geometry_tools::barycentric::calculate_barycentrics;
+use glam::Vec3A;
+
+let barycentrics = calculate_barycentrics(Vec3A::new(0.25, 0.5, 1.0), Vec3A::ZERO, Vec3A::X, Vec3A::Y);
+assert_eq!(Some(Vec3A::new(0.25, 0.25, 0.5)), barycentrics);
+
+assert_eq!(None, calculate_barycentrics(Vec3A::ZERO, Vec3A::ZERO, Vec3A::X, Vec3A::X * 2.0));
+```
+ */
+pub fn calculate_barycentrics(point: Vec3A, a: Vec3A, b: Vec3A, c: Vec3A) -> Option<Vec3A> {
+    // See "Real-Time Collision Detection" by Ericson.
+    let v0 = b - a;
+    let v1 = c - a;
+    let v2 = point - a;
+    let d00 = v0.dot(v0);
+    let d01 = v0.dot(v1);
+    let d11 = v1.dot(v1);
+    let d20 = v2.dot(v0);
+    let d21 = v2.dot(v1);
+    barycentrics_from_dots(d00, d01, d11, d20, d21)
+}
+
+/// Calculates the barycentric coordinates of `point` for the 2D triangle `abc` like [calculate_barycentrics].
+/// This is useful for finding the surface point for a texel using the UV coordinates of a triangle.
+/// # Examples
+/**
+```rust
+use geometry_tools::barycentric::calculate_barycentrics_2d;
+use glam::{Vec2, Vec3A};
+
+let barycentrics = calculate_barycentrics_2d(Vec2::new(1.0, 0.0), Vec2::ZERO, Vec2::X, Vec2::Y);
+assert_eq!(Some(Vec3A::new(0.0, 1.0, 0.0)), barycentrics);
+```
+ */
+pub fn calculate_barycentrics_2d(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> Option<Vec3A> {
+    let v0 = b - a;
+    let v1 = c - a;
+    let v2 = point - a;
+    barycentrics_from_dots(v0.dot(v0), v0.dot(v1), v1.dot(v1), v2.dot(v0), v2.dot(v1))
+}
+
+fn barycentrics_from_dots(d00: f32, d01: f32, d11: f32, d20: f32, d21: f32) -> Option<Vec3A> {
+    let denominator = d00 * d11 - d01 * d01;
+    if denominator == 0.0 || !denominator.is_finite() {
+        return None;
+    }
+    let v = (d11 * d20 - d01 * d21) / denominator;
+    let w = (d00 * d21 - d01 * d20) / denominator;
+    Some(Vec3A::new(1.0 - v - w, v, w))
+}
+
+/// Interpolates the three triangle vertex `values` using `barycentrics`.
+/// This works for any attribute that supports scaling and addition like [f32], [Vec2], [Vec3A], or [glam::Vec4].
+/// Interpolated normals and tangents should be normalized before use.
+/// # Examples
+/**
+```rust
+use geometry_tools::barycentric::interpolate_barycentric;
+use glam::{Vec2, Vec3A};
+
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+let uv = interpolate_barycentric(uvs, Vec3A::new(0.5, 0.25, 0.25));
+assert_eq!(Vec2::new(0.25, 0.25), uv);
+```
+ */
+pub fn interpolate_barycentric<T>(values: [T; 3], barycentrics: Vec3A) -> T
+where
+    T: Mul<f32, Output = T> + Add<Output = T>,
+{
+    let [a, b, c] = values;
+    a * barycentrics.x + b * barycentrics.y + c * barycentrics.z
+}
+
+/// Interpolates the per vertex `attribute` at `barycentrics` for `triangle` in the triangle list `indices`
+/// like [interpolate_barycentric].
+///
+/// # Panics
+/// Panics if `triangle` or any of its vertex indices are out of range.
+/// # Examples
+/**
+```rust
+use geometry_tools::barycentric::interpolate_triangle_attribute;
+use glam::{Vec3A, Vec4};
+
+let colors = [Vec4::ONE, Vec4::ZERO, Vec4::ZERO, Vec4::ONE];
+let indices = [0, 1, 2, 2, 1, 3];
+
+// Use the triangle and coordinates from a raycast or closest point query.
+let color = interpolate_triangle_attribute(&colors, &indices, 1, Vec3A::new(0.0, 0.5, 0.5));
+assert_eq!(Vec4::splat(0.5), color);
+```
+ */
+pub fn interpolate_triangle_attribute<T>(
+    attribute: &[T],
+    indices: &[u32],
+    triangle: u32,
+    barycentrics: Vec3A,
+) -> T
+where
+    T: Mul<f32, Output = T> + Add<Output = T> + Copy,
+{
+    let start = triangle as usize * 3;
+    let face = &indices[start..start + 3];
+    interpolate_barycentric(
+        [face[0], face[1], face[2]].map(|i| attribute[i as usize]),
+        barycentrics,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn barycentrics_vertices() {
+        let [a, b, c] = [
+            Vec3A::new(1.0, 2.0, 3.0),
+            Vec3A::new(4.0, 0.0, 1.0),
+            Vec3A::Z,
+        ];
+        assert_eq!(Some(Vec3A::X), calculate_barycentrics(a, a, b, c));
+        assert_eq!(Some(Vec3A::Y), calculate_barycentrics(b, a, b, c));
+        assert_eq!(Some(Vec3A::Z), calculate_barycentrics(c, a, b, c));
+    }
+
+    #[test]
+    fn barycentrics_outside_triangle() {
+        let barycentrics =
+            calculate_barycentrics(Vec3A::new(2.0, 0.0, 0.0), Vec3A::ZERO, Vec3A::X, Vec3A::Y);
+        assert_eq!(Some(Vec3A::new(-1.0, 2.0, 0.0)), barycentrics);
+    }
+
+    #[test]
+    fn barycentrics_round_trip() {
+        let [a, b, c] = [
+            Vec3A::new(1.0, 2.0, 3.0),
+            Vec3A::new(4.0, 0.0, 1.0),
+            Vec3A::Z,
+        ];
+        let expected = Vec3A::new(0.2, 0.3, 0.5);
+        let point = interpolate_barycentric([a, b, c], expected);
+        let barycentrics = calculate_barycentrics(point, a, b, c).unwrap();
+        assert!(barycentrics.abs_diff_eq(expected, 0.0001));
+    }
+
+    #[test]
+    fn barycentrics_2d_degenerate() {
+        assert_eq!(
+            None,
+            calculate_barycentrics_2d(Vec2::ZERO, Vec2::ONE, Vec2::ONE, Vec2::ONE)
+        );
+    }
+
+    #[test]
+    fn interpolate_scalar() {
+        assert_eq!(
+            2.0,
+            interpolate_barycentric([1.0, 2.0, 4.0], Vec3A::new(0.5, 0.25, 0.25))
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Functions for calculating vectors and bounding volumes for many meshes at once.
+//!
+//! Each function returns one result for each mesh in the same order as the input.
+//! When the `rayon` feature is enabled, the meshes are processed in parallel.
+//! Large meshes are still split across threads by the functions for a single mesh,
+//! so a few large meshes and many small meshes both use the available threads.
+
+use glam::{Vec3A, Vec4};
+
+use crate::bounding::{calculate_aabb_from_points, calculate_bounding_sphere_from_points};
+use crate::mesh::MeshRef;
+use crate::vectors::{calculate_smooth_normals, calculate_tangents, TangentBitangentError};
+
+/// Calculates smooth normals using [calculate_smooth_normals] for the positions and indices of each mesh.
+/// # Examples
+/**
+```rust
+use geometry_tools::batch::calculate_smooth_normals_batch;
+use geometry_tools::mesh::MeshRef;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let meshes = [
+    MeshRef { positions: &positions, indices: &[0, 1, 2], ..Default::default() },
+    MeshRef { positions: &positions, indices: &[0, 2, 1], ..Default::default() },
+];
+
+let normals = calculate_smooth_normals_batch(&meshes);
+assert_eq!(vec![vec![Vec3A::Z; 3], vec![Vec3A::NEG_Z; 3]], normals);
+```
+ */
+pub fn calculate_smooth_normals_batch(meshes: &[MeshRef]) -> Vec<Vec<Vec3A>> {
+    map_meshes(meshes, |mesh| {
+        calculate_smooth_normals(mesh.positions, mesh.indices)
+    })
+}
+
+/// Calculates tangents using [calculate_tangents] for the positions, normals, uvs, and indices of each mesh.
+/// Each mesh has its own result, so an error for one mesh does not affect the other meshes.
+pub fn calculate_tangents_batch(
+    meshes: &[MeshRef],
+) -> Vec<Result<Vec<Vec4>, TangentBitangentError>> {
+    map_meshes(meshes, |mesh| {
+        calculate_tangents(mesh.positions, mesh.normals, mesh.uvs, mesh.indices)
+    })
+}
+
+/// Calculates the axis-aligned bounding box using [calculate_aabb_from_points] for the positions of each mesh.
+pub fn calculate_aabb_batch(meshes: &[MeshRef]) -> Vec<(Vec3A, Vec3A)> {
+    map_meshes(meshes, |mesh| calculate_aabb_from_points(mesh.positions))
+}
+
+/// Calculates a bounding sphere using [calculate_bounding_sphere_from_points] for the positions of each mesh.
+pub fn calculate_bounding_sphere_batch(meshes: &[MeshRef]) -> Vec<Vec4> {
+    map_meshes(meshes, |mesh| {
+        calculate_bounding_sphere_from_points(mesh.positions)
+    })
+}
+
+#[cfg(feature = "rayon")]
+fn map_meshes<T, F>(meshes: &[MeshRef], f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&MeshRef) -> T + Sync + Send,
+{
+    use rayon::prelude::*;
+
+    // Schedule individual meshes since mesh sizes can vary a lot.
+    meshes.par_iter().with_max_len(1).map(f).collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn map_meshes<T, F>(meshes: &[MeshRef], f: F) -> Vec<T>
+where
+    F: Fn(&MeshRef) -> T,
+{
+    meshes.iter().map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec2;
+
+    use super::*;
+
+    #[test]
+    fn batch_empty() {
+        assert!(calculate_smooth_normals_batch(&[]).is_empty());
+        assert!(calculate_tangents_batch(&[]).is_empty());
+        assert!(calculate_aabb_batch(&[]).is_empty());
+        assert!(calculate_bounding_sphere_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn batch_matches_single_mesh() {
+        let positions: Vec<_> = (0..6)
+            .map(|i| Vec3A::new(i as f32, (i % 2) as f32, (i * i) as f32 * 0.1))
+            .collect();
+        let uvs: Vec<_> = positions.iter().map(|p| Vec2::new(p.x, p.y)).collect();
+        let normals = vec![Vec3A::Z; positions.len()];
+        let indices = [0, 1, 2, 2, 1, 3, 3, 4, 5];
+
+        let meshes: Vec<_> = (1..=3)
+            .map(|i| MeshRef {
+                positions: &positions[..i * 2],
+                normals: &normals[..i * 2],
+                uvs: &uvs[..i * 2],
+                indices: &indices[..(i - 1) * 3],
+                ..Default::default()
+            })
+            .collect();
+
+        for (i, mesh) in meshes.iter().enumerate() {
+            assert_eq!(
+                calculate_smooth_normals(mesh.positions, mesh.indices),
+                calculate_smooth_normals_batch(&meshes)[i]
+            );
+            assert_eq!(
+                calculate_tangents(mesh.positions, mesh.normals, mesh.uvs, mesh.indices).unwrap(),
+                *calculate_tangents_batch(&meshes)[i].as_ref().unwrap()
+            );
+            assert_eq!(
+                calculate_aabb_from_points(mesh.positions),
+                calculate_aabb_batch(&meshes)[i]
+            );
+            assert_eq!(
+                calculate_bounding_sphere_from_points(mesh.positions),
+                calculate_bounding_sphere_batch(&meshes)[i]
+            );
+        }
+    }
+
+    #[test]
+    fn tangents_batch_error_per_mesh() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let uvs = [Vec2::ZERO; 3];
+        let meshes = [
+            MeshRef {
+                positions: &positions,
+                normals: &positions,
+                uvs: &uvs,
+                indices: &[0, 1, 2],
+                ..Default::default()
+            },
+            MeshRef {
+                positions: &positions,
+                uvs: &uvs,
+                indices: &[0, 1, 2],
+                ..Default::default()
+            },
+        ];
+
+        let tangents = calculate_tangents_batch(&meshes);
+        assert!(tangents[0].is_ok());
+        assert!(matches!(
+            tangents[1],
+            Err(TangentBitangentError::AttributeCountMismatch { .. })
+        ));
+    }
+}
@@ -1,6 +1,11 @@
 //! Functions for calculating bounding spheres and axis-aligned bounding boxes.
 
-use glam::{Vec3A, Vec4};
+use glam::{DMat3, DVec3, DVec4, Mat3, Vec3A, Vec4};
+
+use crate::eigen::symmetric_eigen;
+use crate::scalar::{Scalar, Vector};
+use crate::sum::sum;
+use crate::vec3::{aabb_from_arrays, convert_to_vec3a, Float3};
 
 /// Calculates a bounding sphere of the form `(center, radius)` that contains all the specified points.
 /// The returned result may be larger than the optimal solution.
@@ -33,27 +38,137 @@ assert_eq!(Vec4::ZERO, bounding_sphere);
 pub fn calculate_bounding_sphere_from_points<P>(points: &[P]) -> Vec4
 where
     P: Into<Vec3A> + Copy,
+{
+    let (center, radius) = bounding_sphere_from_points::<Vec3A, _>(points);
+    center.extend(radius)
+}
+
+/// Calculates a bounding sphere of the form `(center, radius)` that contains all the specified points
+/// using double precision like [calculate_bounding_sphere_from_points].
+/// # Examples
+/**
+```rust
+use geometry_tools::bounding::calculate_bounding_sphere_from_points_f64;
+use glam::{DVec3, DVec4};
+
+let points = [DVec3::new(1e9, -1.0, 0.0), DVec3::new(1e9, 1.0, 0.0)];
+assert_eq!(
+    DVec4::new(1e9, 0.0, 0.0, 1.0),
+    calculate_bounding_sphere_from_points_f64(&points)
+);
+```
+ */
+pub fn calculate_bounding_sphere_from_points_f64<P>(points: &[P]) -> DVec4
+where
+    P: Into<DVec3> + Copy,
+{
+    let (center, radius) = bounding_sphere_from_points::<DVec3, _>(points);
+    center.extend(radius)
+}
+
+/// Calculates a bounding sphere like [calculate_bounding_sphere_from_points]
+/// for tightly packed points such as [glam::Vec3] or `[f32; 3]`.
+/// The points are converted in batches instead of one at a time.
+/// # Examples
+/**
+```rust
+use geometry_tools::bounding::calculate_bounding_sphere_from_points_vec3;
+use glam::Vec4;
+
+let points = [[0.0, -1.0, 0.0], [0.0, 1.0, 0.0]];
+assert_eq!(
+    Vec4::new(0.0, 0.0, 0.0, 1.0),
+    calculate_bounding_sphere_from_points_vec3(&points)
+);
+```
+ */
+pub fn calculate_bounding_sphere_from_points_vec3<P: Float3>(points: &[P]) -> Vec4 {
+    calculate_bounding_sphere_from_points(&convert_to_vec3a(points))
+}
+
+pub(crate) fn bounding_sphere_from_points<V, P>(points: &[P]) -> (V, V::Scalar)
+where
+    V: Vector,
+    P: Into<V> + Copy,
 {
     if points.is_empty() {
-        return Vec4::ZERO;
+        return (V::ZERO, V::Scalar::ZERO);
     }
 
+    #[cfg(feature = "rayon")]
+    let converted: Vec<V> = points.iter().copied().map(Into::into).collect();
+    #[cfg(feature = "rayon")]
+    let points = converted.as_slice();
+
     // It's possible to optimize the center iteratively at the cost of performance.
     // Use the simple approach of averaging the points as the center.
-    let center: Vec3A = points.iter().copied().map(Into::into).sum::<Vec3A>() / points.len() as f32;
+    let center: V = sum_points(points) / V::Scalar::from_usize(points.len());
 
     // Find the smallest radius that contains all points given a center.
-    let mut radius_squared = 0f32;
+    let radius_squared = max_distance_squared(points, center);
+    (center, radius_squared.sqrt())
+}
+
+#[cfg(not(any(feature = "rayon", feature = "deterministic")))]
+fn sum_points<V, P>(points: &[P]) -> V
+where
+    V: Vector,
+    P: Into<V> + Copy,
+{
+    points.iter().copied().map(Into::into).sum()
+}
+
+#[cfg(all(not(feature = "rayon"), feature = "deterministic"))]
+fn sum_points<V, P>(points: &[P]) -> V
+where
+    V: Vector,
+    P: Into<V> + Copy,
+{
+    // Sum the same chunks as the parallel implementation to get identical results.
+    sum(points
+        .chunks(crate::parallel::CHUNK_SIZE)
+        .map(|chunk| sum(chunk.iter().copied().map(Into::into))))
+}
+
+#[cfg(not(feature = "rayon"))]
+fn max_distance_squared<V, P>(points: &[P], center: V) -> V::Scalar
+where
+    V: Vector,
+    P: Into<V> + Copy,
+{
+    let mut radius_squared = V::Scalar::ZERO;
     for length_squared in points.iter().map(|p| {
-        let p: Vec3A = (*p).into();
+        let p: V = (*p).into();
         p.distance_squared(center)
     }) {
         if length_squared > radius_squared {
             radius_squared = length_squared;
         }
     }
+    radius_squared
+}
+
+#[cfg(feature = "rayon")]
+fn sum_points<V: Vector>(points: &[V]) -> V {
+    use rayon::prelude::*;
+
+    // Sum fixed size chunks in order to get the same result for any thread count.
+    let sums: Vec<V> = points
+        .par_chunks(crate::parallel::CHUNK_SIZE)
+        .map(|chunk| sum(chunk.iter().copied()))
+        .collect();
+    sum(sums)
+}
+
+#[cfg(feature = "rayon")]
+fn max_distance_squared<V: Vector>(points: &[V], center: V) -> V::Scalar {
+    use rayon::prelude::*;
 
-    center.extend(radius_squared.sqrt())
+    // The maximum doesn't depend on the order of the points.
+    points
+        .par_iter()
+        .map(|p| p.distance_squared(center))
+        .reduce(|| V::Scalar::ZERO, |a, b| if b > a { b } else { a })
 }
 
 /// Calculates a bounding sphere of the form `(center, radius)` that contains all the specified bounding spheres.
@@ -91,8 +206,7 @@ pub fn calculate_bounding_sphere_from_spheres(spheres: &[Vec4]) -> Vec4 {
     }
 
     // Use the simple approach of averaging the points as the center.
-    let center: Vec3A =
-        spheres.iter().copied().map(Vec3A::from_vec4).sum::<Vec3A>() / spheres.len() as f32;
+    let center: Vec3A = sum(spheres.iter().copied().map(Vec3A::from_vec4)) / spheres.len() as f32;
 
     // Find the smallest radius that contains all spheres given a center.
     // This is a simple extension of testing for sphere-sphere intersection.
@@ -135,11 +249,60 @@ assert_eq!((Vec3A::ZERO, Vec3A::ZERO), aabb);
 pub fn calculate_aabb_from_points<P>(points: &[P]) -> (Vec3A, Vec3A)
 where
     P: Into<Vec3A> + Copy,
+{
+    aabb_from_points(points)
+}
+
+/// Calculates an axis-aligned bounding box of the form `(min_xyz, max_xyz)` containing all the specified points
+/// using double precision like [calculate_aabb_from_points].
+/// # Examples
+/**
+```rust
+use geometry_tools::bounding::calculate_aabb_from_points_f64;
+use glam::DVec3;
+
+let (min, max) = calculate_aabb_from_points_f64(&[
+    DVec3::new(1e9 + 0.5, 2.0, 1.0),
+    DVec3::new(1e9, -1.0, 2.0),
+]);
+assert_eq!(DVec3::new(1e9, -1.0, 1.0), min);
+assert_eq!(DVec3::new(1e9 + 0.5, 2.0, 2.0), max);
+```
+ */
+pub fn calculate_aabb_from_points_f64<P>(points: &[P]) -> (DVec3, DVec3)
+where
+    P: Into<DVec3> + Copy,
+{
+    aabb_from_points(points)
+}
+
+/// Calculates an axis-aligned bounding box like [calculate_aabb_from_points]
+/// for tightly packed points such as [glam::Vec3] or `[f32; 3]`.
+/// The components of 4 points are compared at a time without converting each point.
+/// # Examples
+/**
+```rust
+use geometry_tools::bounding::calculate_aabb_from_points_vec3;
+use glam::{Vec3, Vec3A};
+
+let (min, max) = calculate_aabb_from_points_vec3(&[Vec3::new(-1.0, 2.0, 0.0), Vec3::ONE]);
+assert_eq!(Vec3A::new(-1.0, 1.0, 0.0), min);
+assert_eq!(Vec3A::new(1.0, 2.0, 1.0), max);
+```
+ */
+pub fn calculate_aabb_from_points_vec3<P: Float3>(points: &[P]) -> (Vec3A, Vec3A) {
+    aabb_from_arrays(P::as_arrays(points))
+}
+
+pub(crate) fn aabb_from_points<V, P>(points: &[P]) -> (V, V)
+where
+    V: Vector,
+    P: Into<V> + Copy,
 {
     match points.first().copied() {
         Some(p) => {
-            let mut min_xyz: Vec3A = p.into();
-            let mut max_xyz: Vec3A = p.into();
+            let mut min_xyz: V = p.into();
+            let mut max_xyz: V = p.into();
 
             for point in points {
                 min_xyz = min_xyz.min((*point).into());
@@ -148,7 +311,299 @@ where
 
             (min_xyz, max_xyz)
         }
-        None => (Vec3A::ZERO, Vec3A::ZERO),
+        None => (V::ZERO, V::ZERO),
+    }
+}
+
+/// The bounding sphere and normal cone of a cluster of triangles used for culling.
+/// See [calculate_cluster_bounds].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClusterBounds {
+    /// The bounding sphere of the form `(center, radius)` containing all the cluster's vertices.
+    pub bounding_sphere: Vec4,
+    /// The apex of the normal cone used for perspective culling.
+    pub cone_apex: Vec3A,
+    /// The normalized average direction of the cluster's face normals.
+    pub cone_axis: Vec3A,
+    /// The sine of the cone's half angle or `1.0` if the cluster can never be culled.
+    pub cone_cutoff: f32,
+}
+
+impl ClusterBounds {
+    /// Returns `true` if every triangle in the cluster faces away from `camera_position`.
+    /// This is equivalent to `dot(normalize(cone_apex - camera_position), cone_axis) >= cone_cutoff`.
+    pub fn is_backfacing(&self, camera_position: Vec3A) -> bool {
+        (self.cone_apex - camera_position)
+            .normalize_or_zero()
+            .dot(self.cone_axis)
+            >= self.cone_cutoff
+    }
+}
+
+/// Calculates the bounding sphere and normal cone for the triangle list defined by `positions` and `indices`.
+/// The bounding sphere uses [calculate_bounding_sphere_from_points] for the vertices referenced by `indices`.
+/// Clusters with normals spread over too wide of an angle for culling to be effective have a `cone_cutoff` of `1.0`.
+/// # Examples
+/**
+```rust
+use geometry_tools::bounding::calculate_cluster_bounds;
+use glam::Vec3A;
+
+let positions = [
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(0.0, 1.0, 0.0),
+    Vec3A::new(1.0, 1.0, 0.0),
+];
+let bounds = calculate_cluster_bounds(&positions, &[0, 1, 2, 2, 1, 3]);
+assert_eq!(Vec3A::Z, bounds.cone_axis);
+
+// The triangles face towards +Z.
+assert!(bounds.is_backfacing(Vec3A::new(0.5, 0.5, -5.0)));
+assert!(!bounds.is_backfacing(Vec3A::new(0.5, 0.5, 5.0)));
+```
+ */
+pub fn calculate_cluster_bounds<P>(positions: &[P], indices: &[u32]) -> ClusterBounds
+where
+    P: Into<Vec3A> + Copy,
+{
+    let points: Vec<Vec3A> = indices
+        .iter()
+        .map(|i| positions[*i as usize].into())
+        .collect();
+    let bounding_sphere = calculate_bounding_sphere_from_points(&points);
+    let center = Vec3A::from_vec4(bounding_sphere);
+
+    // Degenerate triangles don't affect which direction the cluster faces.
+    let faces: Vec<(Vec3A, Vec3A)> = points
+        .chunks_exact(3)
+        .filter_map(|f| {
+            let normal = (f[1] - f[0]).cross(f[2] - f[0]).try_normalize()?;
+            Some((f[0], normal))
+        })
+        .collect();
+
+    let cone_axis = faces
+        .iter()
+        .map(|(_, n)| *n)
+        .sum::<Vec3A>()
+        .normalize_or_zero();
+
+    let min_dot = faces
+        .iter()
+        .map(|(_, n)| n.dot(cone_axis))
+        .reduce(f32::min)
+        .unwrap_or_default();
+
+    // Culling is rarely effective for cones wider than roughly 84 degrees.
+    if min_dot <= 0.1 {
+        return ClusterBounds {
+            bounding_sphere,
+            cone_apex: center,
+            cone_axis,
+            cone_cutoff: 1.0,
+        };
+    }
+
+    // Move the apex back far enough that every face plane is in front of it.
+    let max_t = faces
+        .iter()
+        .map(|(p, n)| (center - *p).dot(*n) / cone_axis.dot(*n))
+        .fold(0.0, f32::max);
+
+    ClusterBounds {
+        bounding_sphere,
+        cone_apex: center - cone_axis * max_t,
+        cone_axis,
+        cone_cutoff: (1.0 - min_dot * min_dot).sqrt(),
+    }
+}
+
+/// The principal component analysis (PCA) of a point set calculated by [calculate_principal_axes].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrincipalAxes {
+    /// The average of all the points.
+    pub mean: Vec3A,
+    /// The covariance matrix of the points relative to the mean.
+    pub covariance: Mat3,
+    /// The eigenvalues of the covariance matrix in descending order,
+    /// which are the variances of the points along each principal axis.
+    pub variances: Vec3A,
+    /// The normalized eigenvectors of the covariance matrix as the columns of a rotation matrix.
+    /// The first column is the direction with the largest variance.
+    pub axes: Mat3,
+}
+
+/// Calculates the principal axes of `points` from the eigenvectors of their covariance matrix.
+/// The axes are useful for fitting oriented bounding volumes or aligning assets to their dominant directions.
+///
+/// The sign of each axis is arbitrary, but the axes always form a right-handed coordinate system.
+/// If `points` is empty, the mean, covariance, and variances are zero, and the axes are the identity.
+/// # Examples
+/**
+```rust
+use geometry_tools::bounding::calculate_principal_axes;
+use glam::Vec3A;
+
+// Points spread mostly along the diagonal of the XY plane.
+let points = [
+    Vec3A::new(-2.0, -2.0, 0.0),
+    Vec3A::new(2.0, 2.0, 0.0),
+    Vec3A::new(-0.5, 0.5, 0.0),
+    Vec3A::new(0.5, -0.5, 0.0),
+];
+let pca = calculate_principal_axes(&points);
+assert_eq!(Vec3A::ZERO, pca.mean);
+
+let direction = Vec3A::new(1.0, 1.0, 0.0).normalize();
+assert!(Vec3A::from(pca.axes.x_axis).dot(direction).abs() > 0.999);
+assert!(pca.variances.x > pca.variances.y);
+assert_eq!(0.0, pca.variances.z);
+```
+ */
+pub fn calculate_principal_axes<P>(points: &[P]) -> PrincipalAxes
+where
+    P: Into<Vec3A> + Copy,
+{
+    if points.is_empty() {
+        return PrincipalAxes {
+            mean: Vec3A::ZERO,
+            covariance: Mat3::ZERO,
+            variances: Vec3A::ZERO,
+            axes: Mat3::IDENTITY,
+        };
+    }
+
+    // Use double precision and subtract the mean first to avoid cancellation for points far from the origin.
+    let count = points.len() as f64;
+    let mean = sum(points.iter().map(|p| (*p).into().as_dvec3())) / count;
+    // Sum the diagonal and off diagonal elements separately since the matrix is symmetric.
+    let deviations = || points.iter().map(|p| (*p).into().as_dvec3() - mean);
+    let [xx, yy, zz] = (sum(deviations().map(|d| d * d)) / count).to_array();
+    let [xy, xz, yz] =
+        (sum(deviations().map(|d| DVec3::new(d.x * d.y, d.x * d.z, d.y * d.z))) / count).to_array();
+    let covariance = DMat3::from_cols_array(&[xx, xy, xz, xy, yy, yz, xz, yz, zz]).as_mat3();
+
+    let (variances, axes) = symmetric_eigen(covariance);
+
+    PrincipalAxes {
+        mean: mean.as_vec3a(),
+        covariance,
+        // Rounding can produce tiny negative eigenvalues for flat or collinear points.
+        variances: variances.max(Vec3A::ZERO),
+        axes,
+    }
+}
+
+pub mod ffi {
+    use crate::ffi::{catch_status, output, slice, FfiStatus};
+
+    /// A wrapper for [calculate_bounding_sphere_from_points](crate::bounding::calculate_bounding_sphere_from_points).
+    /// The function writes the xyz values of the center to `center` and the radius to `radius`.
+    ///
+    /// # Safety
+    ///
+    /// `points` must have length `points_length` and use the same 16 byte layout as
+    /// [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+    /// `center` must point to three floats, and `radius` must point to a single float.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_calculate_bounding_sphere_from_points(
+        points: *const glam::Vec3A,
+        points_length: u32,
+        center: *mut [f32; 3],
+        radius: *mut f32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            let points = slice(points, points_length)?;
+            let center = output(center)?;
+            let radius = output(radius)?;
+
+            let sphere = super::calculate_bounding_sphere_from_points(points);
+            *center = sphere.truncate().to_array();
+            *radius = sphere.w;
+            Ok(())
+        })
+    }
+
+    /// A wrapper for [calculate_aabb_from_points](crate::bounding::calculate_aabb_from_points).
+    /// The function writes the xyz values of the minimum and maximum to `min` and `max`.
+    ///
+    /// # Safety
+    ///
+    /// `points` must have length `points_length` and use the same 16 byte layout as
+    /// [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+    /// `min` and `max` must each point to three floats.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_calculate_aabb_from_points(
+        points: *const glam::Vec3A,
+        points_length: u32,
+        min: *mut [f32; 3],
+        max: *mut [f32; 3],
+    ) -> FfiStatus {
+        catch_status(|| {
+            let points = slice(points, points_length)?;
+            let min = output(min)?;
+            let max = output(max)?;
+
+            let (aabb_min, aabb_max) = super::calculate_aabb_from_points(points);
+            *min = aabb_min.to_array();
+            *max = aabb_max.to_array();
+            Ok(())
+        })
+    }
+
+    /// A version of [calculate_bounding_sphere_from_points](crate::bounding::calculate_bounding_sphere_from_points) for tightly packed points
+    /// with three floats for each point.
+    ///
+    /// # Safety
+    ///
+    /// `points` must have length `points_length` with a required size of at least `points_length * 12` bytes.
+    /// `center` must point to three floats, and `radius` must point to a single float.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_calculate_bounding_sphere_from_points_vec3(
+        points: *const glam::Vec3,
+        points_length: u32,
+        center: *mut [f32; 3],
+        radius: *mut f32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            let points = slice(points, points_length)?;
+            let center = output(center)?;
+            let radius = output(radius)?;
+
+            let sphere = super::calculate_bounding_sphere_from_points_vec3(points);
+            *center = sphere.truncate().to_array();
+            *radius = sphere.w;
+            Ok(())
+        })
+    }
+
+    /// A version of [calculate_aabb_from_points](crate::bounding::calculate_aabb_from_points) for tightly packed points
+    /// with three floats for each point.
+    ///
+    /// # Safety
+    ///
+    /// `points` must have length `points_length` with a required size of at least `points_length * 12` bytes.
+    /// `min` and `max` must each point to three floats.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_calculate_aabb_from_points_vec3(
+        points: *const glam::Vec3,
+        points_length: u32,
+        min: *mut [f32; 3],
+        max: *mut [f32; 3],
+    ) -> FfiStatus {
+        catch_status(|| {
+            let points = slice(points, points_length)?;
+            let min = output(min)?;
+            let max = output(max)?;
+
+            let (aabb_min, aabb_max) = super::calculate_aabb_from_points_vec3(points);
+            *min = aabb_min.to_array();
+            *max = aabb_max.to_array();
+            Ok(())
+        })
     }
 }
 
@@ -296,4 +751,165 @@ mod tests {
         let bounding_sphere = calculate_bounding_sphere_from_spheres(&spheres);
         assert!(sphere_contains_spheres(&spheres, bounding_sphere));
     }
+
+    #[test]
+    fn cluster_empty() {
+        let bounds = calculate_cluster_bounds::<Vec3A>(&[], &[]);
+        assert_eq!(Vec4::ZERO, bounds.bounding_sphere);
+        assert_eq!(1.0, bounds.cone_cutoff);
+        assert!(!bounds.is_backfacing(Vec3A::ZERO));
+    }
+
+    #[test]
+    fn cluster_closed_mesh_never_culled() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+        let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+        let bounds = calculate_cluster_bounds(&positions, &indices);
+        assert_eq!(1.0, bounds.cone_cutoff);
+        assert!(!bounds.is_backfacing(Vec3A::splat(10.0)));
+        assert!(!bounds.is_backfacing(Vec3A::splat(-10.0)));
+    }
+
+    #[test]
+    fn cluster_bent_faces() {
+        // Two faces tilted 45 degrees from the z-axis in opposite directions.
+        let positions = [
+            Vec3A::new(-1.0, 0.0, 0.0),
+            Vec3A::new(0.0, 0.0, 1.0),
+            Vec3A::new(0.0, 1.0, 1.0),
+            Vec3A::new(1.0, 0.0, 0.0),
+            Vec3A::new(-1.0, 1.0, 0.0),
+            Vec3A::new(1.0, 1.0, 0.0),
+        ];
+        let indices = [0, 1, 2, 0, 2, 4, 1, 3, 5, 1, 5, 2];
+        let bounds = calculate_cluster_bounds(&positions, &indices);
+
+        assert!(sphere_contains_points(&positions, bounds.bounding_sphere));
+        assert!(bounds.cone_axis.abs_diff_eq(Vec3A::Z, 1e-6));
+        assert!((bounds.cone_cutoff - 0.5f32.sqrt()).abs() < 1e-6);
+
+        // Both faces point away from a camera behind the cluster.
+        assert!(bounds.is_backfacing(Vec3A::new(0.0, 0.5, -10.0)));
+        // One face is visible from a camera far to the side.
+        assert!(!bounds.is_backfacing(Vec3A::new(10.0, 0.5, 0.0)));
+    }
+
+    #[test]
+    fn aabb_f64_empty() {
+        assert_eq!(
+            (DVec3::ZERO, DVec3::ZERO),
+            calculate_aabb_from_points_f64::<DVec3>(&[])
+        );
+        assert_eq!(
+            DVec4::ZERO,
+            calculate_bounding_sphere_from_points_f64::<DVec3>(&[])
+        );
+    }
+
+    #[test]
+    fn bounding_sphere_f64_far_from_origin() {
+        // Single precision can't represent the radius relative to the center.
+        let points = [DVec3::new(1e9, 0.0, 0.0), DVec3::new(1e9 + 0.25, 0.0, 0.0)];
+        let sphere = calculate_bounding_sphere_from_points_f64(&points);
+        assert_eq!(DVec4::new(1e9 + 0.125, 0.0, 0.0, 0.125), sphere);
+
+        let points_f32 = points.map(|p| p.as_vec3a());
+        let sphere_f32 = calculate_bounding_sphere_from_points(&points_f32);
+        assert_eq!(0.0, sphere_f32.w);
+    }
+
+    #[test]
+    fn bounding_sphere_ffi() {
+        let points = [Vec3A::new(0.0, -1.0, 0.0), Vec3A::new(0.0, 1.0, 0.0)];
+        let mut center = [1.0; 3];
+        let mut radius = 0.0;
+        let status = unsafe {
+            ffi::geo_calculate_bounding_sphere_from_points(
+                points.as_ptr(),
+                points.len() as u32,
+                &mut center,
+                &mut radius,
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::Success, status);
+        assert_eq!([0.0; 3], center);
+        assert_eq!(1.0, radius);
+    }
+
+    #[test]
+    fn aabb_ffi_empty() {
+        let mut min = [1.0; 3];
+        let mut max = [1.0; 3];
+        let status =
+            unsafe { ffi::geo_calculate_aabb_from_points(std::ptr::null(), 0, &mut min, &mut max) };
+        assert_eq!(crate::ffi::FfiStatus::Success, status);
+        assert_eq!([0.0; 3], min);
+        assert_eq!([0.0; 3], max);
+    }
+
+    #[test]
+    fn aabb_ffi_null_output() {
+        let mut min = [1.0; 3];
+        let status = unsafe {
+            ffi::geo_calculate_aabb_from_points(std::ptr::null(), 0, &mut min, std::ptr::null_mut())
+        };
+        assert_eq!(crate::ffi::FfiStatus::NullPointer, status);
+        assert_eq!([1.0; 3], min);
+    }
+
+    #[test]
+    fn bounding_ffi_vec3() {
+        // Check that the 12 byte stride is used by reading the last point.
+        let points = [0.0f32, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+        let mut center = [1.0; 3];
+        let mut radius = 0.0;
+        let mut min = [1.0; 3];
+        let mut max = [1.0; 3];
+        unsafe {
+            ffi::geo_calculate_bounding_sphere_from_points_vec3(
+                points.as_ptr() as *const glam::Vec3,
+                3,
+                &mut center,
+                &mut radius,
+            );
+            ffi::geo_calculate_aabb_from_points_vec3(
+                points.as_ptr() as *const glam::Vec3,
+                3,
+                &mut min,
+                &mut max,
+            );
+        }
+        assert_eq!([0.0; 3], center);
+        assert_eq!(1.0, radius);
+        assert_eq!([0.0, -1.0, 0.0], min);
+        assert_eq!([0.0, 1.0, 0.0], max);
+    }
+
+    #[test]
+    fn principal_axes_empty() {
+        let pca = calculate_principal_axes::<Vec3A>(&[]);
+        assert_eq!(Vec3A::ZERO, pca.mean);
+        assert_eq!(Mat3::ZERO, pca.covariance);
+        assert_eq!(Mat3::IDENTITY, pca.axes);
+    }
+
+    #[test]
+    fn principal_axes_box_far_from_origin() {
+        // The corners of a box with different extents along each axis.
+        let offset = Vec3A::splat(1e5);
+        let points: Vec<_> = (0..8)
+            .map(|i| {
+                let corner =
+                    Vec3A::new((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32);
+                (corner * 2.0 - 1.0) * Vec3A::new(1.0, 3.0, 2.0) + offset
+            })
+            .collect();
+        let pca = calculate_principal_axes(&points);
+        assert_eq!(offset, pca.mean);
+        assert_eq!(Vec3A::new(9.0, 4.0, 1.0), pca.variances);
+        assert_eq!(1.0, pca.axes.x_axis.y.abs());
+        assert_eq!(1.0, pca.axes.y_axis.z.abs());
+        assert_eq!(1.0, pca.axes.z_axis.x.abs());
+        assert!((pca.axes.determinant() - 1.0).abs() < 1e-6);
+    }
 }
@@ -0,0 +1,182 @@
+//! Functions for calculating bounding circles, axis-aligned bounding boxes, and convex hulls of 2D points.
+//!
+//! These are the 2D equivalents of the functions in [crate::bounding]
+//! for data like sprites, UV islands, and top-down level layouts.
+
+use glam::{Vec2, Vec3};
+
+use crate::bounding::{aabb_from_points, bounding_sphere_from_points};
+
+/// Calculates an axis-aligned bounding box of the form `(min_xy, max_xy)` containing all the specified points.
+/// If `points` is empty, both `min_xy` and `max_xy` will be zero.
+/// # Examples
+/**
+```rust
+use geometry_tools::bounding2d::calculate_aabb_from_points;
+use glam::Vec2;
+
+let (min, max) = calculate_aabb_from_points(&[
+    Vec2::new(0.0, 2.0),
+    Vec2::new(-1.0, 1.0),
+    Vec2::new(2.0, -1.0),
+]);
+assert_eq!(Vec2::new(-1.0, -1.0), min);
+assert_eq!(Vec2::new(2.0, 2.0), max);
+```
+ */
+pub fn calculate_aabb_from_points<P>(points: &[P]) -> (Vec2, Vec2)
+where
+    P: Into<Vec2> + Copy,
+{
+    aabb_from_points(points)
+}
+
+/// Calculates a bounding circle of the form `(center, radius)` that contains all the specified points
+/// like [calculate_bounding_sphere_from_points](crate::bounding::calculate_bounding_sphere_from_points).
+/// The returned result may be larger than the optimal solution.
+/// If `points` is empty, the center and radius will both be zero.
+/// # Examples
+/**
+```rust
+use geometry_tools::bounding2d::calculate_bounding_circle_from_points;
+use glam::{Vec2, Vec3};
+
+let points = [Vec2::new(-1.0, 2.0), Vec2::new(1.0, 2.0)];
+assert_eq!(Vec3::new(0.0, 2.0, 1.0), calculate_bounding_circle_from_points(&points));
+```
+ */
+pub fn calculate_bounding_circle_from_points<P>(points: &[P]) -> Vec3
+where
+    P: Into<Vec2> + Copy,
+{
+    let (center, radius) = bounding_sphere_from_points::<Vec2, _>(points);
+    center.extend(radius)
+}
+
+/// Calculates the convex hull of `points` using Andrew's monotone chain algorithm.
+///
+/// The result is the indices of the hull vertices in counterclockwise order
+/// starting from the point with the smallest x coordinate.
+/// Points on the edges of the hull and duplicate points are not included.
+/// If all the points are collinear, the result contains only the two endpoints.
+/// # Examples
+/**
+```rust
+use geometry_tools::bounding2d::calculate_convex_hull;
+use glam::Vec2;
+
+let points = [
+    Vec2::new(0.0, 0.0),
+    Vec2::new(1.0, 1.0),
+    Vec2::new(2.0, 0.0),
+    Vec2::new(2.0, 2.0),
+    Vec2::new(0.0, 2.0),
+    Vec2::new(1.0, 0.0),
+];
+assert_eq!(vec![0, 2, 3, 4], calculate_convex_hull(&points));
+```
+ */
+pub fn calculate_convex_hull<P>(points: &[P]) -> Vec<u32>
+where
+    P: Into<Vec2> + Copy,
+{
+    let points: Vec<Vec2> = points.iter().copied().map(Into::into).collect();
+
+    let mut order: Vec<u32> = (0..points.len() as u32).collect();
+    order.sort_by(|a, b| {
+        let (a, b) = (points[*a as usize], points[*b as usize]);
+        a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+    });
+    order.dedup_by(|a, b| points[*a as usize] == points[*b as usize]);
+    if order.len() < 3 {
+        return order;
+    }
+
+    // Points that don't turn counterclockwise are removed from the hull.
+    let turn = |hull: &[u32], p: u32| {
+        let [a, b] = [hull[hull.len() - 2], hull[hull.len() - 1]].map(|i| points[i as usize]);
+        (b - a).perp_dot(points[p as usize] - a)
+    };
+
+    // Build the lower hull from left to right.
+    let mut hull: Vec<u32> = Vec::with_capacity(order.len() + 1);
+    for p in &order {
+        while hull.len() >= 2 && turn(&hull, *p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+
+    // Build the upper hull from right to left.
+    let lower_len = hull.len() + 1;
+    for p in order.iter().rev().skip(1) {
+        while hull.len() >= lower_len && turn(&hull, *p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+
+    // The last point is the same as the first point.
+    hull.pop();
+    hull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            (Vec2::ZERO, Vec2::ZERO),
+            calculate_aabb_from_points::<Vec2>(&[])
+        );
+        assert_eq!(
+            Vec3::ZERO,
+            calculate_bounding_circle_from_points::<Vec2>(&[])
+        );
+        assert!(calculate_convex_hull::<Vec2>(&[]).is_empty());
+    }
+
+    #[test]
+    fn bounding_circle_contains_points() {
+        let points: Vec<_> = (0..50)
+            .map(|i| Vec2::new((i as f32 * 0.7).sin() * 3.0, (i as f32 * 1.3).cos()))
+            .collect();
+        let circle = calculate_bounding_circle_from_points(&points);
+        for p in points {
+            assert!(p.distance(circle.truncate()) <= circle.z);
+        }
+    }
+
+    #[test]
+    fn convex_hull_duplicates() {
+        let points = [Vec2::ONE, Vec2::ONE, Vec2::ONE];
+        assert_eq!(vec![0], calculate_convex_hull(&points));
+    }
+
+    #[test]
+    fn convex_hull_collinear() {
+        let points = [Vec2::X, Vec2::ZERO, Vec2::X * 3.0, Vec2::X * 2.0];
+        assert_eq!(vec![1, 2], calculate_convex_hull(&points));
+    }
+
+    #[test]
+    fn convex_hull_circle() {
+        // Include the center and duplicates of each point on the circle.
+        let mut points = vec![Vec2::ZERO];
+        for i in 0..32 {
+            let (sin, cos) = (i as f32 / 32.0 * std::f32::consts::TAU).sin_cos();
+            points.push(Vec2::new(cos, sin));
+            points.push(Vec2::new(cos, sin));
+        }
+
+        let hull = calculate_convex_hull(&points);
+        assert_eq!(32, hull.len());
+        // Each turn is counterclockwise.
+        for i in 0..hull.len() {
+            let [a, b, c] = [i, i + 1, i + 2].map(|j| points[hull[j % hull.len()] as usize]);
+            assert!((b - a).perp_dot(c - a) > 0.0);
+        }
+    }
+}
@@ -0,0 +1,1317 @@
+//! A bounding volume hierarchy for accelerating spatial queries on triangle meshes.
+
+use glam::{Vec2, Vec3A};
+
+use crate::barycentric::interpolate_barycentric;
+use crate::progress::{Cancelled, Progress, ProgressSteps};
+use crate::query::{
+    capsule_triangle_penetration, closest_point_on_triangle, sweep_sphere_triangle, Capsule,
+    Penetration,
+};
+
+/// The maximum number of triangles in a leaf node.
+const MAX_LEAF_TRIANGLES: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BvhNode {
+    min: Vec3A,
+    max: Vec3A,
+    /// The index of the first child for interior nodes or the first triangle for leaf nodes.
+    start: u32,
+    /// The number of triangles for leaf nodes or `0` for interior nodes.
+    count: u32,
+}
+
+/// A bounding volume hierarchy (BVH) of axis-aligned bounding boxes for the triangles of a mesh.
+/// The BVH stores a copy of the mesh, so it can be reused for many queries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bvh {
+    positions: Vec<Vec3A>,
+    triangles: Vec<[u32; 3]>,
+    /// The triangle indices sorted so that each leaf node references a contiguous range.
+    triangle_order: Vec<u32>,
+    nodes: Vec<BvhNode>,
+}
+
+/// A ray starting at `origin` in the direction `direction` for [raycast_mesh].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vec3A,
+    /// The direction of the ray, which does not need to be normalized.
+    pub direction: Vec3A,
+    /// The maximum distance along the ray in multiples of `direction`.
+    /// Use [f32::INFINITY] for an unbounded ray.
+    pub max_distance: f32,
+}
+
+/// The closest intersection of a [Ray] with a triangle mesh.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    /// The distance along the ray in multiples of the ray's direction.
+    pub distance: f32,
+    /// The index of the intersected triangle.
+    pub triangle: u32,
+    /// The barycentric coordinates of the intersection for the three vertices of the triangle.
+    pub barycentrics: Vec3A,
+}
+
+/// The closest point on a triangle mesh to a query point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosestPoint {
+    /// The closest point on the surface of the mesh.
+    pub point: Vec3A,
+    /// The distance from the query point to [point](#structfield.point).
+    pub distance: f32,
+    /// The index of the triangle containing the closest point.
+    pub triangle: u32,
+    /// The barycentric coordinates of the closest point for the three vertices of the triangle.
+    pub barycentrics: Vec3A,
+}
+
+/// A surface sample at the closest intersection of a [Ray] with a triangle mesh returned by [pick].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pick {
+    /// The intersection point on the surface of the mesh.
+    pub point: Vec3A,
+    /// The distance along the ray in multiples of the ray's direction.
+    pub distance: f32,
+    /// The index of the intersected triangle.
+    pub triangle: u32,
+    /// The barycentric coordinates of the intersection for the three vertices of the triangle.
+    pub barycentrics: Vec3A,
+    /// The normalized interpolated vertex normal at [point](#structfield.point).
+    pub normal: Vec3A,
+    /// The interpolated texture coordinates at [point](#structfield.point).
+    pub uv: Vec2,
+}
+
+/// The overlap between a [Capsule] and a triangle of a mesh returned by [Bvh::capsule_contacts].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CapsuleContact {
+    /// The index of the overlapping triangle.
+    pub triangle: u32,
+    pub penetration: Penetration,
+}
+
+/// The first contact of a sphere moving along a [Ray] with a triangle mesh returned by [Bvh::sphere_cast].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SphereHit {
+    /// The distance along the ray in multiples of the ray's direction.
+    /// This is zero if the sphere initially overlaps the mesh.
+    pub distance: f32,
+    /// The index of the first triangle touched by the sphere.
+    pub triangle: u32,
+    /// The contact point on the surface of the mesh.
+    pub point: Vec3A,
+    /// The normalized direction from [point](#structfield.point) to the center of the sphere at the contact.
+    pub normal: Vec3A,
+}
+
+impl Bvh {
+    /// Builds a BVH for the triangle list defined by `positions` and `indices`
+    /// by recursively splitting the triangles at the median centroid along the longest axis.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::bvh::Bvh;
+    use glam::Vec3A;
+
+    let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+    let bvh = Bvh::new(&positions, &[0, 1, 2]);
+    assert_eq!(1, bvh.triangles().len());
+    ```
+     */
+    pub fn new<P>(positions: &[P], indices: &[u32]) -> Self
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        // Progress without a cancellation flag can't fail.
+        Self::new_with_progress(positions, indices, Progress::default()).unwrap()
+    }
+
+    /// Builds a BVH like [Bvh::new] while reporting progress as the fraction of triangles assigned to leaf nodes.
+    /// Returns [Cancelled] if the cancellation flag of `progress` is set before the build finishes.
+    pub fn new_with_progress<P>(
+        positions: &[P],
+        indices: &[u32],
+        progress: Progress,
+    ) -> Result<Self, Cancelled>
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+        let triangles: Vec<[u32; 3]> = indices
+            .chunks_exact(3)
+            .map(|face| [face[0], face[1], face[2]])
+            .collect();
+
+        let centroids: Vec<Vec3A> = triangles
+            .iter()
+            .map(|t| t.iter().map(|v| positions[*v as usize]).sum::<Vec3A>() / 3.0)
+            .collect();
+
+        let mut bvh = Self {
+            triangle_order: (0..triangles.len() as u32).collect(),
+            positions,
+            triangles,
+            nodes: Vec::new(),
+        };
+        let mut steps = ProgressSteps::new(progress, bvh.triangles.len())?;
+        if !bvh.triangles.is_empty() {
+            bvh.nodes.push(bvh.leaf(0, bvh.triangles.len()));
+            bvh.split(0, &centroids, &mut steps)?;
+        }
+        steps.advance(0)?;
+        Ok(bvh)
+    }
+
+    /// The vertex positions of the mesh.
+    pub fn positions(&self) -> &[Vec3A] {
+        &self.positions
+    }
+
+    /// The vertex indices for each triangle of the mesh.
+    pub fn triangles(&self) -> &[[u32; 3]] {
+        &self.triangles
+    }
+
+    pub(crate) fn triangle_positions(&self, triangle: u32) -> [Vec3A; 3] {
+        self.triangles[triangle as usize].map(|v| self.positions[v as usize])
+    }
+
+    fn leaf(&self, start: usize, count: usize) -> BvhNode {
+        let mut min = Vec3A::splat(f32::MAX);
+        let mut max = Vec3A::splat(f32::MIN);
+        for t in &self.triangle_order[start..start + count] {
+            for p in self.triangle_positions(*t) {
+                min = min.min(p);
+                max = max.max(p);
+            }
+        }
+        BvhNode {
+            min,
+            max,
+            start: start as u32,
+            count: count as u32,
+        }
+    }
+
+    fn split(
+        &mut self,
+        node_index: usize,
+        centroids: &[Vec3A],
+        steps: &mut ProgressSteps,
+    ) -> Result<(), Cancelled> {
+        let node = self.nodes[node_index];
+        let start = node.start as usize;
+        let count = node.count as usize;
+        if count <= MAX_LEAF_TRIANGLES {
+            return steps.advance(count);
+        }
+
+        let order = &mut self.triangle_order[start..start + count];
+        let (min, max) = order.iter().fold(
+            (Vec3A::splat(f32::MAX), Vec3A::splat(f32::MIN)),
+            |(min, max), t| {
+                let c = centroids[*t as usize];
+                (min.min(c), max.max(c))
+            },
+        );
+        let extent = max - min;
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+
+        let mid = count / 2;
+        order.select_nth_unstable_by(mid, |a, b| {
+            centroids[*a as usize][axis].total_cmp(&centroids[*b as usize][axis])
+        });
+
+        // Children are stored next to each other.
+        let left = self.nodes.len();
+        let left_node = self.leaf(start, mid);
+        let right_node = self.leaf(start + mid, count - mid);
+        self.nodes.push(left_node);
+        self.nodes.push(right_node);
+        self.nodes[node_index].start = left as u32;
+        self.nodes[node_index].count = 0;
+
+        self.split(left, centroids, steps)?;
+        self.split(left + 1, centroids, steps)
+    }
+
+    /// Finds the closest point on the mesh to `point` within `max_distance`.
+    /// Use [f32::INFINITY] to search the entire mesh.
+    /// The barycentric coordinates can be used to interpolate vertex attributes at the closest point.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::bvh::Bvh;
+    use glam::Vec3A;
+
+    let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+    let bvh = Bvh::new(&positions, &[0, 1, 2]);
+
+    let closest = bvh.closest_point(Vec3A::new(0.5, 0.25, 2.0), f32::INFINITY).unwrap();
+    assert_eq!(Vec3A::new(0.5, 0.25, 0.0), closest.point);
+    assert_eq!(2.0, closest.distance);
+    assert_eq!(0, closest.triangle);
+    assert_eq!(Vec3A::new(0.25, 0.5, 0.25), closest.barycentrics);
+
+    assert_eq!(None, bvh.closest_point(Vec3A::new(0.5, 0.25, 2.0), 1.0));
+    ```
+     */
+    pub fn closest_point(&self, point: Vec3A, max_distance: f32) -> Option<ClosestPoint> {
+        let mut closest: Option<ClosestPoint> = None;
+        let mut best_distance_squared = max_distance * max_distance;
+
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            if aabb_distance_squared(point, node.min, node.max) > best_distance_squared {
+                continue;
+            }
+
+            if node.count > 0 {
+                let start = node.start as usize;
+                for t in &self.triangle_order[start..start + node.count as usize] {
+                    let [a, b, c] = self.triangle_positions(*t);
+                    let (p, barycentrics) = closest_point_on_triangle(point, a, b, c);
+                    let distance_squared = p.distance_squared(point);
+                    if distance_squared <= best_distance_squared {
+                        best_distance_squared = distance_squared;
+                        closest = Some(ClosestPoint {
+                            point: p,
+                            distance: distance_squared.sqrt(),
+                            triangle: *t,
+                            barycentrics,
+                        });
+                    }
+                }
+            } else {
+                // Visit the closer child first to reduce the search radius sooner.
+                let left = node.start as usize;
+                let right = left + 1;
+                let left_distance =
+                    aabb_distance_squared(point, self.nodes[left].min, self.nodes[left].max);
+                let right_distance =
+                    aabb_distance_squared(point, self.nodes[right].min, self.nodes[right].max);
+                if left_distance < right_distance {
+                    stack.push(right);
+                    stack.push(left);
+                } else {
+                    stack.push(left);
+                    stack.push(right);
+                }
+            }
+        }
+
+        closest
+    }
+
+    /// Finds the closest intersection of `ray` with the mesh.
+    /// Rays intersect triangles from both sides.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::bvh::{Bvh, Ray};
+    use glam::Vec3A;
+
+    let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+    let bvh = Bvh::new(&positions, &[0, 1, 2]);
+
+    let ray = Ray {
+        origin: Vec3A::new(0.25, 0.25, 2.0),
+        direction: Vec3A::NEG_Z,
+        max_distance: f32::INFINITY,
+    };
+    let hit = bvh.raycast(ray).unwrap();
+    assert_eq!(2.0, hit.distance);
+    assert_eq!(0, hit.triangle);
+    assert_eq!(Vec3A::new(0.5, 0.25, 0.25), hit.barycentrics);
+    ```
+     */
+    pub fn raycast(&self, ray: Ray) -> Option<Hit> {
+        let inverse_direction = ray.direction.recip();
+
+        let mut closest: Option<Hit> = None;
+        let mut max_distance = ray.max_distance;
+
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            if ray_aabb_distance(
+                ray.origin,
+                inverse_direction,
+                node.min,
+                node.max,
+                max_distance,
+            )
+            .is_none()
+            {
+                continue;
+            }
+
+            if node.count > 0 {
+                let start = node.start as usize;
+                for t in &self.triangle_order[start..start + node.count as usize] {
+                    let [a, b, c] = self.triangle_positions(*t);
+                    if let Some((distance, barycentrics)) =
+                        ray_triangle_intersection(ray.origin, ray.direction, a, b, c)
+                    {
+                        if distance <= max_distance {
+                            max_distance = distance;
+                            closest = Some(Hit {
+                                distance,
+                                triangle: *t,
+                                barycentrics,
+                            });
+                        }
+                    }
+                }
+            } else {
+                // Visit the closer child first to find hits sooner.
+                let left = node.start as usize;
+                let right = left + 1;
+                let distance = |i: usize| {
+                    ray_aabb_distance(
+                        ray.origin,
+                        inverse_direction,
+                        self.nodes[i].min,
+                        self.nodes[i].max,
+                        max_distance,
+                    )
+                };
+                match (distance(left), distance(right)) {
+                    (Some(l), Some(r)) => {
+                        if l < r {
+                            stack.push(right);
+                            stack.push(left);
+                        } else {
+                            stack.push(left);
+                            stack.push(right);
+                        }
+                    }
+                    (Some(_), None) => stack.push(left),
+                    (None, Some(_)) => stack.push(right),
+                    (None, None) => (),
+                }
+            }
+        }
+
+        closest
+    }
+
+    /// Finds the first contact of a sphere with `radius` moving along `ray` with the mesh.
+    /// The sphere starts centered at the ray's origin and moves at most the ray's maximum distance.
+    /// This is useful for camera collision and validating collision proxies.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::bvh::{Bvh, Ray};
+    use glam::Vec3A;
+
+    let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+    let bvh = Bvh::new(&positions, &[0, 1, 2]);
+
+    let ray = Ray {
+        origin: Vec3A::new(0.25, 0.25, 3.0),
+        direction: Vec3A::NEG_Z,
+        max_distance: f32::INFINITY,
+    };
+    let hit = bvh.sphere_cast(ray, 0.5).unwrap();
+    assert_eq!(2.5, hit.distance);
+    assert_eq!(0, hit.triangle);
+    assert_eq!(Vec3A::new(0.25, 0.25, 0.0), hit.point);
+    assert_eq!(Vec3A::Z, hit.normal);
+
+    assert_eq!(None, bvh.sphere_cast(Ray { max_distance: 2.0, ..ray }, 0.5));
+    ```
+     */
+    pub fn sphere_cast(&self, ray: Ray, radius: f32) -> Option<SphereHit> {
+        let inverse_direction = ray.direction.recip();
+
+        let mut closest: Option<SphereHit> = None;
+        let mut max_distance = ray.max_distance;
+
+        // Expanding the bounds by the radius allows testing the ray instead of the sphere.
+        let distance = |node: &BvhNode, max_distance: f32| {
+            ray_aabb_distance(
+                ray.origin,
+                inverse_direction,
+                node.min - radius,
+                node.max + radius,
+                max_distance,
+            )
+        };
+
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            if distance(node, max_distance).is_none() {
+                continue;
+            }
+
+            if node.count > 0 {
+                let start = node.start as usize;
+                for t in &self.triangle_order[start..start + node.count as usize] {
+                    let [a, b, c] = self.triangle_positions(*t);
+                    if let Some(contact) =
+                        sweep_sphere_triangle(ray.origin, radius, ray.direction, a, b, c)
+                    {
+                        if contact.distance <= max_distance {
+                            max_distance = contact.distance;
+                            closest = Some(SphereHit {
+                                distance: contact.distance,
+                                triangle: *t,
+                                point: contact.point,
+                                normal: contact.normal,
+                            });
+                        }
+                    }
+                }
+            } else {
+                // Visit the closer child first to find hits sooner.
+                let left = node.start as usize;
+                let right = left + 1;
+                match (
+                    distance(&self.nodes[left], max_distance),
+                    distance(&self.nodes[right], max_distance),
+                ) {
+                    (Some(l), Some(r)) => {
+                        if l < r {
+                            stack.push(right);
+                            stack.push(left);
+                        } else {
+                            stack.push(left);
+                            stack.push(right);
+                        }
+                    }
+                    (Some(_), None) => stack.push(left),
+                    (None, Some(_)) => stack.push(right),
+                    (None, None) => (),
+                }
+            }
+        }
+
+        closest
+    }
+
+    /// Returns `true` if `point` is inside the closed mesh.
+    /// This counts the number of ray intersections, so the winding order of the triangles does not matter.
+    /// A point is inside if an odd number of intersections is found for the majority of several ray directions,
+    /// which avoids incorrect results for rays passing exactly through edges or vertices.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::bvh::Bvh;
+    use glam::Vec3A;
+
+    // A tetrahedron.
+    let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+    let indices = [0, 2, 1, 0, 1, 3, 0, 3, 2, 1, 2, 3];
+    let bvh = Bvh::new(&positions, &indices);
+
+    assert!(bvh.contains_point(Vec3A::splat(0.1)));
+    assert!(!bvh.contains_point(Vec3A::splat(1.0)));
+    ```
+     */
+    pub fn contains_point(&self, point: Vec3A) -> bool {
+        // Use unusual directions to avoid passing exactly through edges or vertices.
+        let directions = [
+            Vec3A::new(0.5773, 0.5774, 0.5775),
+            Vec3A::new(-0.6123, 0.3371, -0.7151),
+            Vec3A::new(0.2107, -0.9233, 0.3211),
+        ];
+        let inside_count = directions
+            .iter()
+            .filter(|d| self.count_ray_intersections(point, **d) % 2 == 1)
+            .count();
+        inside_count >= 2
+    }
+
+    /// Returns `true` if `capsule` overlaps any triangle of the mesh.
+    /// This is faster than checking if [Bvh::capsule_contacts] is empty since the search stops at the first overlap.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::bvh::Bvh;
+    use geometry_tools::query::Capsule;
+    use glam::Vec3A;
+
+    let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+    let bvh = Bvh::new(&positions, &[0, 1, 2]);
+
+    let capsule = Capsule {
+        start: Vec3A::new(0.25, 0.25, 0.5),
+        end: Vec3A::new(0.25, 0.25, 2.0),
+        radius: 0.5,
+    };
+    assert!(bvh.intersects_capsule(capsule));
+    assert!(!bvh.intersects_capsule(Capsule { radius: 0.25, ..capsule }));
+    ```
+     */
+    pub fn intersects_capsule(&self, capsule: Capsule) -> bool {
+        self.query_aabb(capsule_aabb(capsule)).any(|t| {
+            let [a, b, c] = self.triangle_positions(t);
+            capsule_triangle_penetration(capsule, a, b, c).is_some()
+        })
+    }
+
+    /// Finds all triangles of the mesh that overlap `capsule` sorted by triangle index.
+    /// Each contact's penetration can be used to push the capsule out of the mesh.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::bvh::Bvh;
+    use geometry_tools::query::Capsule;
+    use glam::Vec3A;
+
+    // The second triangle is too far away to overlap.
+    let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::splat(5.0), Vec3A::splat(6.0), Vec3A::X * 5.0];
+    let bvh = Bvh::new(&positions, &[0, 1, 2, 3, 4, 5]);
+
+    let capsule = Capsule {
+        start: Vec3A::new(0.25, 0.25, 0.5),
+        end: Vec3A::new(0.25, 0.25, 2.0),
+        radius: 1.0,
+    };
+    let contacts = bvh.capsule_contacts(capsule);
+    assert_eq!(1, contacts.len());
+    assert_eq!(0, contacts[0].triangle);
+    assert_eq!(Vec3A::Z, contacts[0].penetration.normal);
+    assert_eq!(0.5, contacts[0].penetration.depth);
+    ```
+     */
+    pub fn capsule_contacts(&self, capsule: Capsule) -> Vec<CapsuleContact> {
+        let mut contacts: Vec<_> = self
+            .query_aabb(capsule_aabb(capsule))
+            .filter_map(|t| {
+                let [a, b, c] = self.triangle_positions(t);
+                let penetration = capsule_triangle_penetration(capsule, a, b, c)?;
+                Some(CapsuleContact {
+                    triangle: t,
+                    penetration,
+                })
+            })
+            .collect();
+        contacts.sort_unstable_by_key(|c| c.triangle);
+        contacts
+    }
+
+    /// Finds the triangles whose bounding boxes overlap the axis-aligned bounding box `aabb` of the form `(min_xyz, max_xyz)`.
+    /// The triangles are candidates that may not intersect the box itself.
+    /// This is useful for gathering the triangles in a region for decals, streaming chunks, or partial rebaking.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::bvh::Bvh;
+    use glam::Vec3A;
+
+    let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::splat(5.0), Vec3A::splat(6.0), Vec3A::X * 5.0];
+    let bvh = Bvh::new(&positions, &[0, 1, 2, 3, 4, 5]);
+
+    let triangles: Vec<_> = bvh.query_aabb((Vec3A::splat(-1.0), Vec3A::splat(0.5))).collect();
+    assert_eq!(vec![0], triangles);
+    ```
+     */
+    pub fn query_aabb(&self, aabb: (Vec3A, Vec3A)) -> impl Iterator<Item = u32> + '_ {
+        let (min, max) = aabb;
+        let overlaps = move |node_min: Vec3A, node_max: Vec3A| {
+            !(node_min.cmpgt(max).any() || node_max.cmplt(min).any())
+        };
+
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        let mut leaf: &[u32] = &[];
+        std::iter::from_fn(move || loop {
+            if let Some((t, remaining)) = leaf.split_first() {
+                leaf = remaining;
+                let [a, b, c] = self.triangle_positions(*t);
+                if overlaps(a.min(b).min(c), a.max(b).max(c)) {
+                    return Some(*t);
+                }
+                continue;
+            }
+
+            let node = &self.nodes[stack.pop()?];
+            if overlaps(node.min, node.max) {
+                if node.count > 0 {
+                    let start = node.start as usize;
+                    leaf = &self.triangle_order[start..start + node.count as usize];
+                } else {
+                    stack.push(node.start as usize + 1);
+                    stack.push(node.start as usize);
+                }
+            }
+        })
+    }
+
+    /// Counts the triangles intersected by the ray starting at `origin` with direction `direction`.
+    pub(crate) fn count_ray_intersections(&self, origin: Vec3A, direction: Vec3A) -> usize {
+        let inverse_direction = direction.recip();
+
+        let mut count = 0;
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            if ray_aabb_distance(origin, inverse_direction, node.min, node.max, f32::MAX).is_none()
+            {
+                continue;
+            }
+
+            if node.count > 0 {
+                let start = node.start as usize;
+                for t in &self.triangle_order[start..start + node.count as usize] {
+                    let [a, b, c] = self.triangle_positions(*t);
+                    if ray_triangle_intersection(origin, direction, a, b, c).is_some() {
+                        count += 1;
+                    }
+                }
+            } else {
+                stack.push(node.start as usize);
+                stack.push(node.start as usize + 1);
+            }
+        }
+        count
+    }
+}
+
+/// Finds the closest intersection with the mesh in `bvh` for each ray in `rays`.
+/// This is equivalent to calling [Bvh::raycast] for each ray.
+/// # Examples
+/**
+```rust
+use geometry_tools::bvh::{raycast_mesh, Bvh, Ray};
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let bvh = Bvh::new(&positions, &[0, 1, 2]);
+
+let rays = [
+    Ray {
+        origin: Vec3A::new(0.25, 0.25, 1.0),
+        direction: Vec3A::NEG_Z,
+        max_distance: f32::INFINITY,
+    },
+    Ray {
+        origin: Vec3A::new(0.25, 0.25, 1.0),
+        direction: Vec3A::Z,
+        max_distance: f32::INFINITY,
+    },
+];
+let hits = raycast_mesh(&bvh, &rays);
+assert_eq!(Some(1.0), hits[0].map(|h| h.distance));
+assert_eq!(None, hits[1]);
+```
+ */
+pub fn raycast_mesh(bvh: &Bvh, rays: &[Ray]) -> Vec<Option<Hit>> {
+    rays.iter().map(|ray| bvh.raycast(*ray)).collect()
+}
+
+/// Finds the closest point on the mesh in `bvh` within `max_distance` for each point in `points`.
+/// This is equivalent to calling [Bvh::closest_point] for each point.
+/// # Examples
+/**
+```rust
+use geometry_tools::bvh::{closest_points, Bvh};
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let bvh = Bvh::new(&positions, &[0, 1, 2]);
+
+let points = [Vec3A::new(-1.0, 0.0, 0.0), Vec3A::new(0.0, 0.0, 10.0)];
+let closest = closest_points(&bvh, &points, 5.0);
+assert_eq!(Some(Vec3A::ZERO), closest[0].map(|c| c.point));
+assert_eq!(None, closest[1]);
+```
+ */
+pub fn closest_points<P>(bvh: &Bvh, points: &[P], max_distance: f32) -> Vec<Option<ClosestPoint>>
+where
+    P: Into<Vec3A> + Copy,
+{
+    points
+        .iter()
+        .map(|p| bvh.closest_point((*p).into(), max_distance))
+        .collect()
+}
+
+/// Checks if each point in `points` is inside the closed mesh in `bvh`.
+/// This is equivalent to calling [Bvh::contains_point] for each point.
+/// # Examples
+/**
+```rust
+use geometry_tools::bvh::{contains_points, Bvh};
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+let indices = [0, 2, 1, 0, 1, 3, 0, 3, 2, 1, 2, 3];
+let bvh = Bvh::new(&positions, &indices);
+
+let points = [Vec3A::splat(0.1), Vec3A::splat(-0.1)];
+assert_eq!(vec![true, false], contains_points(&bvh, &points));
+```
+ */
+pub fn contains_points<P>(bvh: &Bvh, points: &[P]) -> Vec<bool>
+where
+    P: Into<Vec3A> + Copy,
+{
+    points
+        .iter()
+        .map(|p| bvh.contains_point((*p).into()))
+        .collect()
+}
+
+/// Finds the closest intersection of `ray` with the mesh in `bvh`
+/// and interpolates the vertex `normals` and `uvs` at the intersection.
+/// `positions` and `indices` should be the same mesh used to build `bvh`.
+/// This is useful for converting a mouse click in an editor to a surface sample.
+///
+/// # Panics
+/// Panics if the vertex attributes do not have an element for each vertex index of the intersected triangle.
+/// # Examples
+/**
+```rust
+use geometry_tools::bvh::{pick, Bvh, Ray};
+use glam::{Vec2, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let normals = [Vec3A::Z, Vec3A::Z, Vec3A::Z];
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+let indices = [0, 1, 2];
+let bvh = Bvh::new(&positions, &indices);
+
+let ray = Ray {
+    origin: Vec3A::new(0.5, 0.25, 1.0),
+    direction: Vec3A::NEG_Z,
+    max_distance: f32::INFINITY,
+};
+let pick = pick(&bvh, ray, &positions, &normals, &uvs, &indices).unwrap();
+assert_eq!(Vec3A::new(0.5, 0.25, 0.0), pick.point);
+assert_eq!(1.0, pick.distance);
+assert_eq!(Vec3A::Z, pick.normal);
+assert_eq!(Vec2::new(0.5, 0.25), pick.uv);
+```
+ */
+pub fn pick<P, N>(
+    bvh: &Bvh,
+    ray: Ray,
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[u32],
+) -> Option<Pick>
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    let hit = bvh.raycast(ray)?;
+    let start = hit.triangle as usize * 3;
+    let face = [indices[start], indices[start + 1], indices[start + 2]].map(|i| i as usize);
+
+    let point = interpolate_barycentric(face.map(|i| positions[i].into()), hit.barycentrics);
+    let normal = interpolate_barycentric(face.map(|i| normals[i].into()), hit.barycentrics);
+    let uv = interpolate_barycentric(face.map(|i| uvs[i]), hit.barycentrics);
+    Some(Pick {
+        point,
+        distance: hit.distance,
+        triangle: hit.triangle,
+        barycentrics: hit.barycentrics,
+        normal: normal.normalize_or_zero(),
+        uv,
+    })
+}
+
+fn capsule_aabb(capsule: Capsule) -> (Vec3A, Vec3A) {
+    (
+        capsule.start.min(capsule.end) - capsule.radius,
+        capsule.start.max(capsule.end) + capsule.radius,
+    )
+}
+
+pub(crate) fn aabb_distance_squared(point: Vec3A, min: Vec3A, max: Vec3A) -> f32 {
+    let d = (min - point).max(point - max).max(Vec3A::ZERO);
+    d.length_squared()
+}
+
+/// Returns the distance along the ray to the box if the ray hits the box before `max_distance`.
+pub(crate) fn ray_aabb_distance(
+    origin: Vec3A,
+    inverse_direction: Vec3A,
+    min: Vec3A,
+    max: Vec3A,
+    max_distance: f32,
+) -> Option<f32> {
+    let t0 = (min - origin) * inverse_direction;
+    let t1 = (max - origin) * inverse_direction;
+    let t_min = t0.min(t1).max_element().max(0.0);
+    let t_max = t0.max(t1).min_element().min(max_distance);
+    (t_min <= t_max).then_some(t_min)
+}
+
+/// Returns the distance along the ray and the barycentric coordinates of the intersection.
+/// See "Fast, Minimum Storage Ray/Triangle Intersection" by Möller and Trumbore.
+pub(crate) fn ray_triangle_intersection(
+    origin: Vec3A,
+    direction: Vec3A,
+    a: Vec3A,
+    b: Vec3A,
+    c: Vec3A,
+) -> Option<(f32, Vec3A)> {
+    let ab = b - a;
+    let ac = c - a;
+    let p = direction.cross(ac);
+    let det = ab.dot(p);
+    if det.abs() < f32::EPSILON * ab.length() * ac.length() {
+        // The ray is parallel to the triangle or the triangle is degenerate.
+        return None;
+    }
+
+    let inverse_det = 1.0 / det;
+    let s = origin - a;
+    let u = s.dot(p) * inverse_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross(ab);
+    let v = direction.dot(q) * inverse_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = ac.dot(q) * inverse_det;
+    (t >= 0.0).then_some((t, Vec3A::new(1.0 - u - v, u, v)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    const EPSILON: f32 = 0.0001;
+
+    fn grid(size: u32) -> (Vec<Vec3A>, Vec<u32>) {
+        let positions = (0..(size + 1) * (size + 1))
+            .map(|i| Vec3A::new((i % (size + 1)) as f32, (i / (size + 1)) as f32, 0.0))
+            .collect();
+        let indices = (0..size * size)
+            .flat_map(|i| {
+                let v = i / size * (size + 1) + i % size;
+                [v, v + 1, v + size + 2, v + size + 2, v + size + 1, v]
+            })
+            .collect();
+        (positions, indices)
+    }
+
+    #[test]
+    fn empty_bvh() {
+        let bvh = Bvh::new::<Vec3A>(&[], &[]);
+        assert!(bvh.triangles().is_empty());
+        assert_eq!(None, bvh.closest_point(Vec3A::ZERO, f32::INFINITY));
+        assert_eq!(0, bvh.count_ray_intersections(Vec3A::ZERO, Vec3A::X));
+    }
+
+    #[test]
+    fn bvh_leaves_contain_all_triangles() {
+        let (positions, indices) = grid(8);
+        let bvh = Bvh::new(&positions, &indices);
+        let mut triangles: Vec<_> = bvh
+            .nodes
+            .iter()
+            .filter(|n| n.count > 0)
+            .flat_map(|n| {
+                bvh.triangle_order[n.start as usize..(n.start + n.count) as usize].to_vec()
+            })
+            .collect();
+        triangles.sort();
+        assert_eq!((0..128).collect::<Vec<_>>(), triangles);
+    }
+
+    #[test]
+    fn bvh_progress() {
+        let (positions, indices) = grid(8);
+        let values = std::cell::RefCell::new(Vec::new());
+        let callback = |p: f32| values.borrow_mut().push(p);
+        let progress = Progress::new().with_callback(&callback);
+        let bvh = Bvh::new_with_progress(&positions, &indices, progress).unwrap();
+        assert_eq!(Bvh::new(&positions, &indices), bvh);
+
+        let values = values.into_inner();
+        assert_eq!(Some(&0.0), values.first());
+        assert_eq!(Some(&1.0), values.last());
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn bvh_cancel() {
+        let (positions, indices) = grid(8);
+        let cancel = std::sync::atomic::AtomicBool::new(true);
+        let progress = Progress::new().with_cancel(&cancel);
+        assert_eq!(
+            Err(Cancelled),
+            Bvh::new_with_progress(&positions, &indices, progress)
+        );
+    }
+
+    #[test]
+    fn closest_point_matches_brute_force() {
+        let (positions, indices) = grid(8);
+        let bvh = Bvh::new(&positions, &indices);
+        for point in [
+            Vec3A::new(2.3, 4.7, 1.0),
+            Vec3A::new(-1.0, -2.0, 0.5),
+            Vec3A::new(9.0, 3.5, -2.0),
+        ] {
+            let expected = indices
+                .chunks_exact(3)
+                .map(|t| {
+                    let [a, b, c] = [t[0], t[1], t[2]].map(|i| positions[i as usize]);
+                    closest_point_on_triangle(point, a, b, c).0.distance(point)
+                })
+                .fold(f32::MAX, f32::min);
+            let closest = bvh.closest_point(point, f32::INFINITY).unwrap();
+            assert_relative_eq!(expected, closest.distance, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn closest_point_max_distance() {
+        let (positions, indices) = grid(2);
+        let bvh = Bvh::new(&positions, &indices);
+        assert_eq!(None, bvh.closest_point(Vec3A::new(1.0, 1.0, 2.0), 1.5));
+    }
+
+    #[test]
+    fn closest_point_triangle_regions() {
+        let [a, b, c] = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let (p, uvw) = closest_point_on_triangle(Vec3A::new(0.25, 0.25, 1.0), a, b, c);
+        assert_eq!(Vec3A::new(0.25, 0.25, 0.0), p);
+        assert!(uvw.abs_diff_eq(Vec3A::new(0.5, 0.25, 0.25), EPSILON));
+
+        let (p, uvw) = closest_point_on_triangle(Vec3A::new(2.0, -1.0, 0.0), a, b, c);
+        assert_eq!(b, p);
+        assert_eq!(Vec3A::Y, uvw);
+
+        let (p, uvw) = closest_point_on_triangle(Vec3A::new(1.0, 1.0, 0.0), a, b, c);
+        assert_eq!(Vec3A::new(0.5, 0.5, 0.0), p);
+        assert_eq!(Vec3A::new(0.0, 0.5, 0.5), uvw);
+    }
+
+    #[test]
+    fn ray_intersections() {
+        let (positions, indices) = grid(4);
+        let bvh = Bvh::new(&positions, &indices);
+        let direction = Vec3A::new(0.01, 0.02, -1.0).normalize();
+        assert_eq!(
+            1,
+            bvh.count_ray_intersections(Vec3A::new(1.3, 2.4, 1.0), direction)
+        );
+        assert_eq!(
+            0,
+            bvh.count_ray_intersections(Vec3A::new(1.3, 2.4, -1.0), direction)
+        );
+    }
+
+    #[test]
+    fn raycast_empty() {
+        let bvh = Bvh::new::<Vec3A>(&[], &[]);
+        let ray = Ray {
+            origin: Vec3A::ZERO,
+            direction: Vec3A::X,
+            max_distance: f32::INFINITY,
+        };
+        assert_eq!(None, bvh.raycast(ray));
+        assert!(raycast_mesh(&bvh, &[]).is_empty());
+    }
+
+    #[test]
+    fn raycast_closest_hit() {
+        // Stack grids at different heights.
+        let (positions, indices) = grid(4);
+        let mut all_positions = positions.clone();
+        let mut all_indices = indices.clone();
+        for z in [1.0, 2.0] {
+            let offset = all_positions.len() as u32;
+            all_positions.extend(positions.iter().map(|p| *p + Vec3A::Z * z));
+            all_indices.extend(indices.iter().map(|i| i + offset));
+        }
+        let bvh = Bvh::new(&all_positions, &all_indices);
+
+        let down = Ray {
+            origin: Vec3A::new(1.3, 2.4, 5.0),
+            direction: Vec3A::NEG_Z * 2.0,
+            max_distance: f32::INFINITY,
+        };
+        let hit = bvh.raycast(down).unwrap();
+        assert_relative_eq!(1.5, hit.distance, epsilon = EPSILON);
+        assert!(hit.triangle >= 64);
+
+        let hit_position = hit
+            .barycentrics
+            .to_array()
+            .iter()
+            .zip(bvh.triangle_positions(hit.triangle))
+            .map(|(b, p)| p * *b)
+            .sum::<Vec3A>();
+        assert!(hit_position.abs_diff_eq(Vec3A::new(1.3, 2.4, 2.0), EPSILON));
+
+        let up = Ray {
+            origin: Vec3A::new(1.3, 2.4, 0.5),
+            direction: Vec3A::Z,
+            max_distance: f32::INFINITY,
+        };
+        assert_relative_eq!(0.5, bvh.raycast(up).unwrap().distance, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn raycast_max_distance() {
+        let (positions, indices) = grid(4);
+        let bvh = Bvh::new(&positions, &indices);
+        let ray = Ray {
+            origin: Vec3A::new(1.5, 1.5, 1.0),
+            direction: Vec3A::NEG_Z,
+            max_distance: 0.5,
+        };
+        assert_eq!(None, bvh.raycast(ray));
+    }
+
+    #[test]
+    fn raycast_mesh_matches_raycast() {
+        let (positions, indices) = grid(8);
+        let bvh = Bvh::new(&positions, &indices);
+        let rays: Vec<_> = (0..16)
+            .map(|i| Ray {
+                origin: Vec3A::new(i as f32 * 0.6, 3.3, 1.0),
+                direction: Vec3A::new(0.1, 0.2, -1.0),
+                max_distance: f32::INFINITY,
+            })
+            .collect();
+        let hits = raycast_mesh(&bvh, &rays);
+        assert_eq!(rays.len(), hits.len());
+        for (ray, hit) in rays.iter().zip(&hits) {
+            assert_eq!(bvh.raycast(*ray), *hit);
+        }
+        assert!(hits[0].is_some());
+        assert!(hits[15].is_none());
+    }
+
+    #[test]
+    fn contains_points_cube() {
+        let cube = crate::shapes::generate_cube(1.0);
+        let positions: Vec<_> = cube.positions.iter().map(|p| *p + 0.5).collect();
+        let indices = cube.indices;
+        let bvh = Bvh::new(&positions, &indices);
+
+        // Points aligned with the diagonals of the faces.
+        let points = [
+            Vec3A::splat(0.5),
+            Vec3A::new(0.5, 0.5, 1.5),
+            Vec3A::new(0.25, 0.25, 0.5),
+            Vec3A::new(-0.5, 0.5, 0.5),
+            Vec3A::new(0.1, 0.9, 0.5),
+        ];
+        assert_eq!(
+            vec![true, false, true, false, true],
+            contains_points(&bvh, &points)
+        );
+    }
+
+    #[test]
+    fn contains_points_empty() {
+        let bvh = Bvh::new::<Vec3A>(&[], &[]);
+        assert_eq!(vec![false], contains_points(&bvh, &[Vec3A::ZERO]));
+    }
+
+    #[test]
+    fn closest_points_barycentrics() {
+        let (positions, indices) = grid(4);
+        let bvh = Bvh::new(&positions, &indices);
+        let points = [Vec3A::new(1.2, 3.7, -0.5), Vec3A::new(5.0, 5.0, 0.0)];
+        for (point, closest) in points.iter().zip(closest_points(&bvh, &points, 10.0)) {
+            let closest = closest.unwrap();
+            let interpolated = closest
+                .barycentrics
+                .to_array()
+                .iter()
+                .zip(bvh.triangle_positions(closest.triangle))
+                .map(|(b, p)| p * *b)
+                .sum::<Vec3A>();
+            assert!(interpolated.abs_diff_eq(closest.point, EPSILON));
+            assert_relative_eq!(
+                point.distance(closest.point),
+                closest.distance,
+                epsilon = EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn pick_sphere() {
+        let sphere = crate::shapes::generate_uv_sphere(1.0, 32, 16);
+        let bvh = Bvh::new(&sphere.positions, &sphere.indices);
+        let ray = Ray {
+            origin: Vec3A::new(0.1, 0.2, 5.0),
+            direction: Vec3A::NEG_Z,
+            max_distance: f32::INFINITY,
+        };
+        let pick = pick(
+            &bvh,
+            ray,
+            &sphere.positions,
+            &sphere.normals,
+            &sphere.uvs,
+            &sphere.indices,
+        )
+        .unwrap();
+        assert_relative_eq!(
+            ray.origin.z - pick.distance,
+            pick.point.z,
+            epsilon = EPSILON
+        );
+        assert!(pick.normal.dot(pick.point.normalize()) > 0.99);
+        assert_relative_eq!(1.0, pick.normal.length(), epsilon = EPSILON);
+    }
+
+    #[test]
+    fn pick_miss() {
+        let (positions, indices) = grid(2);
+        let bvh = Bvh::new(&positions, &indices);
+        let ray = Ray {
+            origin: Vec3A::new(-1.0, -1.0, 1.0),
+            direction: Vec3A::NEG_Z,
+            max_distance: f32::INFINITY,
+        };
+        let normals = vec![Vec3A::Z; positions.len()];
+        let uvs = vec![Vec2::ZERO; positions.len()];
+        assert_eq!(None, pick(&bvh, ray, &positions, &normals, &uvs, &indices));
+    }
+
+    #[test]
+    fn capsule_contacts_grid() {
+        let (positions, indices) = grid(8);
+        let bvh = Bvh::new(&positions, &indices);
+
+        // A capsule standing on the grid overlaps the triangles around its base.
+        let capsule = Capsule {
+            start: Vec3A::new(4.25, 4.5, 0.4),
+            end: Vec3A::new(4.25, 4.5, 2.0),
+            radius: 0.5,
+        };
+        let contacts = bvh.capsule_contacts(capsule);
+        let expected: Vec<_> = (0..bvh.triangles().len() as u32)
+            .filter(|t| {
+                let [a, b, c] = bvh.triangle_positions(*t);
+                capsule_triangle_penetration(capsule, a, b, c).is_some()
+            })
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(
+            expected,
+            contacts.iter().map(|c| c.triangle).collect::<Vec<_>>()
+        );
+        // The deepest overlap is directly below the capsule.
+        let depth = contacts
+            .iter()
+            .map(|c| c.penetration.depth)
+            .fold(0.0, f32::max);
+        assert_relative_eq!(0.1, depth, epsilon = EPSILON);
+        assert!(bvh.intersects_capsule(capsule));
+    }
+
+    #[test]
+    fn capsule_contacts_empty() {
+        let bvh = Bvh::new::<Vec3A>(&[], &[]);
+        let capsule = Capsule {
+            start: Vec3A::ZERO,
+            end: Vec3A::X,
+            radius: 1.0,
+        };
+        assert!(bvh.capsule_contacts(capsule).is_empty());
+        assert!(!bvh.intersects_capsule(capsule));
+    }
+
+    #[test]
+    fn sphere_cast_grid() {
+        let (positions, indices) = grid(8);
+        let bvh = Bvh::new(&positions, &indices);
+        let ray = Ray {
+            origin: Vec3A::new(3.3, 4.6, 5.0),
+            direction: Vec3A::new(0.2, -0.1, -1.0),
+            max_distance: f32::INFINITY,
+        };
+        let hit = bvh.sphere_cast(ray, 0.5).unwrap();
+        assert_relative_eq!(
+            0.5,
+            (ray.origin + ray.direction * hit.distance).z,
+            epsilon = EPSILON
+        );
+        assert_eq!(Vec3A::Z, hit.normal);
+
+        // The BVH should find the same first contact as testing every triangle.
+        let expected = (0..bvh.triangles().len() as u32)
+            .filter_map(|t| {
+                let [a, b, c] = bvh.triangle_positions(t);
+                sweep_sphere_triangle(ray.origin, 0.5, ray.direction, a, b, c)
+            })
+            .map(|c| c.distance)
+            .fold(f32::INFINITY, f32::min);
+        assert_eq!(expected, hit.distance);
+    }
+
+    #[test]
+    fn sphere_cast_empty() {
+        let bvh = Bvh::new::<Vec3A>(&[], &[]);
+        let ray = Ray {
+            origin: Vec3A::ZERO,
+            direction: Vec3A::X,
+            max_distance: f32::INFINITY,
+        };
+        assert_eq!(None, bvh.sphere_cast(ray, 1.0));
+    }
+
+    #[test]
+    fn query_aabb_matches_brute_force() {
+        let (positions, indices) = grid(8);
+        let bvh = Bvh::new(&positions, &indices);
+        let (min, max) = (Vec3A::new(2.5, 1.5, -1.0), Vec3A::new(4.5, 3.0, 1.0));
+
+        let mut triangles: Vec<_> = bvh.query_aabb((min, max)).collect();
+        triangles.sort_unstable();
+        let expected: Vec<_> = (0..bvh.triangles().len() as u32)
+            .filter(|t| {
+                let [a, b, c] = bvh.triangle_positions(*t);
+                a.min(b).min(c).cmple(max).all() && a.max(b).max(c).cmpge(min).all()
+            })
+            .collect();
+        assert_eq!(expected, triangles);
+    }
+
+    #[test]
+    fn query_aabb_empty() {
+        let bvh = Bvh::new::<Vec3A>(&[], &[]);
+        assert_eq!(
+            None,
+            bvh.query_aabb((Vec3A::splat(-1.0), Vec3A::ONE)).next()
+        );
+    }
+}
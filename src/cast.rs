@@ -0,0 +1,176 @@
+//! Functions for reinterpreting raw buffers as vector slices without copying.
+//!
+//! This module requires the `bytemuck` feature.
+
+use bytemuck::{AnyBitPattern, NoUninit, PodCastError};
+use glam::{Vec2, Vec3, Vec3A, Vec4};
+use thiserror::Error;
+
+/// Errors that can occur while reinterpreting a buffer.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CastError {
+    #[error(
+        "The buffer is not aligned to {} bytes required by the output type.",
+        alignment
+    )]
+    Alignment { alignment: usize },
+    #[error(
+        "A buffer size of {} bytes is not supported. Expected a multiple of {} bytes.",
+        byte_count,
+        element_size
+    )]
+    Size {
+        byte_count: usize,
+        element_size: usize,
+    },
+}
+
+/// Reinterprets `values` as [Vec3A] without copying.
+/// The fourth component of each element is ignored by functions that use [Vec3A].
+/// This requires `values` to be aligned to 16 bytes.
+/// # Examples
+/**
+```rust
+use geometry_tools::cast::cast_vec3a;
+use glam::Vec4;
+
+let values = vec![Vec4::new(1.0, 2.0, 3.0, 0.0)];
+let values: &[[f32; 4]] = bytemuck::cast_slice(&values);
+
+let positions = cast_vec3a(values).unwrap();
+assert_eq!(glam::vec3a(1.0, 2.0, 3.0), positions[0]);
+```
+ */
+pub fn cast_vec3a(values: &[[f32; 4]]) -> Result<&[Vec3A], CastError> {
+    cast(values)
+}
+
+/// Reinterprets `values` as [Vec4] without copying.
+/// This requires `values` to be aligned to the alignment of [Vec4].
+pub fn cast_vec4(values: &[[f32; 4]]) -> Result<&[Vec4], CastError> {
+    cast(values)
+}
+
+/// Reinterprets `values` as [Vec2] without copying.
+pub fn cast_vec2(values: &[[f32; 2]]) -> &[Vec2] {
+    bytemuck::cast_slice(values)
+}
+
+/// Reinterprets `bytes` as [Vec3A] with 16 bytes per element without copying.
+/// # Examples
+/**
+```rust
+use geometry_tools::cast::{vec3a_from_bytes, CastError};
+use glam::{Vec3A, Vec4};
+
+let values = vec![Vec4::new(1.0, 2.0, 3.0, 0.0); 2];
+let bytes: &[u8] = bytemuck::cast_slice(&values);
+
+assert_eq!(2, vec3a_from_bytes(bytes).unwrap().len());
+assert_eq!(
+    Err(CastError::Size { byte_count: 31, element_size: 16 }),
+    vec3a_from_bytes(&bytes[..31])
+);
+```
+ */
+pub fn vec3a_from_bytes(bytes: &[u8]) -> Result<&[Vec3A], CastError> {
+    cast(bytes)
+}
+
+/// Reinterprets `bytes` as [Vec4] with 16 bytes per element without copying.
+pub fn vec4_from_bytes(bytes: &[u8]) -> Result<&[Vec4], CastError> {
+    cast(bytes)
+}
+
+/// Reinterprets `bytes` as tightly packed [Vec3] with 12 bytes per element without copying.
+/// This is the layout of most position buffers in files.
+pub fn vec3_from_bytes(bytes: &[u8]) -> Result<&[Vec3], CastError> {
+    cast(bytes)
+}
+
+/// Reinterprets `bytes` as [Vec2] with 8 bytes per element without copying.
+pub fn vec2_from_bytes(bytes: &[u8]) -> Result<&[Vec2], CastError> {
+    cast(bytes)
+}
+
+/// Reinterprets `bytes` as `u32` vertex indices without copying.
+pub fn indices_from_bytes(bytes: &[u8]) -> Result<&[u32], CastError> {
+    cast(bytes)
+}
+
+fn cast<A: NoUninit, B: AnyBitPattern>(values: &[A]) -> Result<&[B], CastError> {
+    // Empty slices may have a dangling pointer with a smaller alignment.
+    if values.is_empty() {
+        return Ok(&[]);
+    }
+    bytemuck::try_cast_slice(values).map_err(|e| match e {
+        PodCastError::TargetAlignmentGreaterAndInputNotAligned => CastError::Alignment {
+            alignment: std::mem::align_of::<B>(),
+        },
+        _ => CastError::Size {
+            byte_count: std::mem::size_of_val(values),
+            element_size: std::mem::size_of::<B>(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cast_empty() {
+        assert!(cast_vec3a(&[]).unwrap().is_empty());
+        assert!(cast_vec4(&[]).unwrap().is_empty());
+        assert!(cast_vec2(&[]).is_empty());
+        assert!(vec3_from_bytes(&[]).unwrap().is_empty());
+        assert!(indices_from_bytes(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn cast_aligned() {
+        let values = vec![Vec4::new(1.0, 2.0, 3.0, 4.0), Vec4::new(5.0, 6.0, 7.0, 8.0)];
+        let arrays: &[[f32; 4]] = bytemuck::cast_slice(&values);
+        assert_eq!(values, cast_vec4(arrays).unwrap());
+        assert_eq!(
+            &[Vec3A::new(1.0, 2.0, 3.0), Vec3A::new(5.0, 6.0, 7.0)],
+            cast_vec3a(arrays).unwrap()
+        );
+
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(values, vec4_from_bytes(bytes).unwrap());
+        assert_eq!(
+            &[Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)],
+            &vec2_from_bytes(bytes).unwrap()[..2]
+        );
+        assert_eq!(
+            Vec3::new(4.0, 5.0, 6.0),
+            vec3_from_bytes(&bytes[..24]).unwrap()[1]
+        );
+    }
+
+    #[test]
+    fn cast_misaligned() {
+        let values = vec![Vec4::ZERO; 2];
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(
+            Err(CastError::Alignment { alignment: 16 }),
+            vec3a_from_bytes(&bytes[4..20])
+        );
+        assert_eq!(1, vec3_from_bytes(&bytes[4..16]).unwrap().len());
+    }
+
+    #[test]
+    fn cast_indices_size() {
+        let indices = vec![0u32, 1, 2];
+        let bytes: &[u8] = bytemuck::cast_slice(&indices);
+        assert_eq!(indices, indices_from_bytes(bytes).unwrap());
+        assert_eq!(
+            Err(CastError::Size {
+                byte_count: 5,
+                element_size: 4
+            }),
+            indices_from_bytes(&bytes[..5])
+        );
+    }
+}
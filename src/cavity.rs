@@ -0,0 +1,192 @@
+//! Functions for generating cavity and edge masks for stylized shading.
+
+use glam::{Vec3A, Vec4};
+
+use crate::curvature::{calculate_mean_curvature, weld_positions};
+use crate::progress::{Cancelled, Progress};
+use crate::topology::edge_key;
+
+/// Per vertex masks calculated by [calculate_cavity].
+/// Each value ranges from `0.0` to `1.0`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Cavity {
+    /// The concavity of each vertex for darkening creases and crevices.
+    pub cavity: Vec<f32>,
+    /// The convexity of each vertex for highlighting worn edges.
+    pub edges: Vec<f32>,
+    /// The fraction of the hemisphere above each vertex occluded by its neighboring vertices.
+    pub occlusion: Vec<f32>,
+}
+
+impl Cavity {
+    /// Packs the masks into vertex colors with cavity in red, edges in green, and occlusion in blue.
+    /// The alpha channel is `1.0`.
+    pub fn to_colors(&self) -> Vec<Vec4> {
+        self.cavity
+            .iter()
+            .zip(&self.edges)
+            .zip(&self.occlusion)
+            .map(|((c, e), o)| Vec4::new(*c, *e, *o, 1.0))
+            .collect()
+    }
+}
+
+/// Calculates cavity, edge, and local occlusion masks for each vertex.
+///
+/// The cavity and edge masks use the curvature from [calculate_mean_curvature]
+/// multiplied by `curvature_scale` and clamped to the range `0.0` to `1.0`.
+/// Larger values of `curvature_scale` highlight more subtle details.
+/// The occlusion mask uses the angles from each vertex normal to the neighboring vertices,
+/// so only the immediate neighborhood contributes to the occlusion.
+/// # Examples
+/**
+```rust
+use geometry_tools::cavity::calculate_cavity;
+use glam::Vec3A;
+
+// An octahedron with a vertex pushed inwards.
+let positions = [
+    Vec3A::X,
+    Vec3A::NEG_X,
+    Vec3A::Y,
+    Vec3A::NEG_Y,
+    Vec3A::Z * -0.5,
+    Vec3A::NEG_Z,
+];
+let indices = [0, 2, 4, 2, 1, 4, 1, 3, 4, 3, 0, 4, 2, 0, 5, 1, 2, 5, 3, 1, 5, 0, 3, 5];
+
+let cavity = calculate_cavity(&positions, &indices, 1.0);
+assert!(cavity.cavity[4] > 0.0);
+assert!(cavity.occlusion[4] > 0.0);
+assert_eq!(0.0, cavity.cavity[5]);
+assert!(cavity.edges[5] > 0.0);
+
+let colors = cavity.to_colors();
+```
+ */
+pub fn calculate_cavity<P>(positions: &[P], indices: &[u32], curvature_scale: f32) -> Cavity
+where
+    P: Into<Vec3A> + Copy,
+{
+    // Progress without a cancellation flag can't fail.
+    calculate_cavity_with_progress(positions, indices, curvature_scale, Progress::default())
+        .unwrap()
+}
+
+/// Calculates the masks like [calculate_cavity] while reporting progress
+/// after calculating the curvature and occlusion.
+/// Returns [Cancelled] if the cancellation flag of `progress` is set before the masks are calculated.
+pub fn calculate_cavity_with_progress<P>(
+    positions: &[P],
+    indices: &[u32],
+    curvature_scale: f32,
+    progress: Progress,
+) -> Result<Cavity, Cancelled>
+where
+    P: Into<Vec3A> + Copy,
+{
+    progress.report(0.0)?;
+    let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+    let curvature = calculate_mean_curvature(&positions, indices);
+    progress.report(0.5)?;
+
+    let cavity = curvature
+        .iter()
+        .map(|c| (-c * curvature_scale).clamp(0.0, 1.0))
+        .collect();
+    let edges = curvature
+        .iter()
+        .map(|c| (c * curvature_scale).clamp(0.0, 1.0))
+        .collect();
+
+    let occlusion = local_occlusion(&positions, indices);
+    progress.report(1.0)?;
+    Ok(Cavity {
+        cavity,
+        edges,
+        occlusion,
+    })
+}
+
+fn local_occlusion(positions: &[Vec3A], indices: &[u32]) -> Vec<f32> {
+    let welded = weld_positions(positions);
+
+    let mut normals = vec![Vec3A::ZERO; positions.len()];
+    let mut edges = Vec::new();
+    for face in indices.chunks_exact(3) {
+        let [i0, i1, i2] = [face[0], face[1], face[2]].map(|i| welded[i as usize]);
+        let [v0, v1, v2] = [i0, i1, i2].map(|i| positions[i as usize]);
+        let normal = (v1 - v0).cross(v2 - v0);
+        for (a, b) in [(i0, i1), (i1, i2), (i2, i0)] {
+            normals[a as usize] += normal;
+            if a != b {
+                edges.push(edge_key(a, b));
+            }
+        }
+    }
+    edges.sort_unstable();
+    edges.dedup();
+
+    // Neighbors above the tangent plane occlude part of the hemisphere.
+    let mut occlusion = vec![0.0f32; positions.len()];
+    let mut neighbor_counts = vec![0usize; positions.len()];
+    for [a, b] in edges {
+        for (v, n) in [(a, b), (b, a)] {
+            let normal = normals[v as usize].normalize_or_zero();
+            let direction = (positions[n as usize] - positions[v as usize]).normalize_or_zero();
+            occlusion[v as usize] += normal.dot(direction).max(0.0);
+            neighbor_counts[v as usize] += 1;
+        }
+    }
+
+    welded
+        .iter()
+        .map(|w| {
+            let count = neighbor_counts[*w as usize];
+            if count > 0 {
+                occlusion[*w as usize] / count as f32
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cavity_empty() {
+        let cavity = calculate_cavity::<Vec3A>(&[], &[], 1.0);
+        assert_eq!(Cavity::default(), cavity);
+        assert!(cavity.to_colors().is_empty());
+    }
+
+    #[test]
+    fn cavity_flat() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::NEG_X, Vec3A::NEG_Y];
+        let indices = [0, 1, 2, 0, 2, 3, 0, 3, 4, 0, 4, 1];
+        let cavity = calculate_cavity(&positions, &indices, 1.0);
+        assert_eq!(vec![0.0; 5], cavity.cavity);
+        assert_eq!(vec![0.0; 5], cavity.edges);
+        assert_eq!(vec![0.0; 5], cavity.occlusion);
+        assert_eq!(Vec4::new(0.0, 0.0, 0.0, 1.0), cavity.to_colors()[0]);
+    }
+
+    #[test]
+    fn cavity_pit() {
+        // A fan with the center vertex pushed down.
+        let positions = [
+            Vec3A::new(0.0, 0.0, -1.0),
+            Vec3A::X,
+            Vec3A::Y,
+            Vec3A::NEG_X,
+            Vec3A::NEG_Y,
+        ];
+        let indices = [0, 1, 2, 0, 2, 3, 0, 3, 4, 0, 4, 1];
+        let cavity = calculate_cavity(&positions, &indices, 10.0);
+        assert_eq!(0.0, cavity.edges[0]);
+        assert!((cavity.occlusion[0] - 0.5f32.sqrt()).abs() < 0.0001);
+    }
+}
@@ -0,0 +1,258 @@
+//! Functions for comparing meshes with tolerances for regression testing.
+
+use glam::{Vec2, Vec3A, Vec4};
+
+use crate::mesh::MeshRef;
+
+/// The maximum allowed distance between corresponding values for each attribute in [compare_meshes].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompareTolerances {
+    pub position: f32,
+    pub normal: f32,
+    pub tangent: f32,
+    pub uv: f32,
+    pub color: f32,
+}
+
+impl Default for CompareTolerances {
+    fn default() -> Self {
+        Self {
+            position: 1e-5,
+            normal: 1e-4,
+            tangent: 1e-4,
+            uv: 1e-5,
+            color: 1e-3,
+        }
+    }
+}
+
+/// The differences between the values of an attribute in two meshes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttributeDifference {
+    /// The number of values in each mesh.
+    pub counts: [usize; 2],
+    /// The largest distance between corresponding values.
+    pub max_error: f32,
+    /// The average distance between corresponding values.
+    pub mean_error: f32,
+    /// The number of corresponding values with a distance larger than the tolerance.
+    pub exceeding_count: usize,
+}
+
+impl AttributeDifference {
+    /// Returns `true` if both meshes have the same number of values
+    /// and all values are within the tolerance.
+    pub fn is_within_tolerance(&self) -> bool {
+        self.counts[0] == self.counts[1] && self.exceeding_count == 0
+    }
+}
+
+/// The differences between two meshes calculated by [compare_meshes].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshDiff {
+    /// The number of complete triangles in each mesh.
+    pub triangle_counts: [usize; 2],
+    pub positions: AttributeDifference,
+    /// The difference in normals or `None` if neither mesh has normals.
+    pub normals: Option<AttributeDifference>,
+    /// The difference in tangents or `None` if neither mesh has tangents.
+    pub tangents: Option<AttributeDifference>,
+    /// The difference in UVs or `None` if neither mesh has UVs.
+    pub uvs: Option<AttributeDifference>,
+    /// The difference in colors or `None` if neither mesh has colors.
+    pub colors: Option<AttributeDifference>,
+    /// The triangles present in both meshes with different vertex indices.
+    pub differing_triangles: Vec<u32>,
+}
+
+impl MeshDiff {
+    /// Returns `true` if the meshes have the same topology and all attributes are within the tolerances.
+    pub fn is_within_tolerance(&self) -> bool {
+        self.triangle_counts[0] == self.triangle_counts[1]
+            && self.differing_triangles.is_empty()
+            && self.positions.is_within_tolerance()
+            && [self.normals, self.tangents, self.uvs, self.colors]
+                .iter()
+                .flatten()
+                .all(AttributeDifference::is_within_tolerance)
+    }
+}
+
+/// Compares the vertex attributes and indices of `a` and `b`.
+/// Vertices and triangles are compared by index, so the meshes should have the same vertex and triangle order.
+/// Only the values present in both meshes are compared when the counts differ.
+/// # Examples
+/**
+```rust
+use geometry_tools::compare::{compare_meshes, CompareTolerances};
+use geometry_tools::mesh::MeshRef;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let expected = MeshRef {
+    positions: &positions,
+    indices: &[0, 1, 2],
+    ..Default::default()
+};
+
+let exported_positions = [Vec3A::ZERO, Vec3A::X, Vec3A::new(0.0, 1.000001, 0.0)];
+let exported = MeshRef {
+    positions: &exported_positions,
+    ..expected
+};
+
+let diff = compare_meshes(&expected, &exported, &CompareTolerances::default());
+assert!(diff.is_within_tolerance());
+assert!(diff.positions.max_error > 0.0);
+```
+ */
+pub fn compare_meshes(a: &MeshRef, b: &MeshRef, tolerances: &CompareTolerances) -> MeshDiff {
+    let differing_triangles = a
+        .indices
+        .chunks_exact(3)
+        .zip(b.indices.chunks_exact(3))
+        .enumerate()
+        .filter(|(_, (a, b))| a != b)
+        .map(|(i, _)| i as u32)
+        .collect();
+
+    MeshDiff {
+        triangle_counts: [a.indices.len() / 3, b.indices.len() / 3],
+        positions: compare_values(a.positions, b.positions, tolerances.position, |a, b| {
+            a.distance(*b)
+        }),
+        normals: compare_optional(a.normals, b.normals, tolerances.normal, Vec3A::distance),
+        tangents: compare_optional(a.tangents, b.tangents, tolerances.tangent, Vec4::distance),
+        uvs: compare_optional(a.uvs, b.uvs, tolerances.uv, Vec2::distance),
+        colors: compare_optional(a.colors, b.colors, tolerances.color, Vec4::distance),
+        differing_triangles,
+    }
+}
+
+fn compare_optional<T, F>(
+    a: &[T],
+    b: &[T],
+    tolerance: f32,
+    distance: F,
+) -> Option<AttributeDifference>
+where
+    T: Copy,
+    F: Fn(T, T) -> f32,
+{
+    if a.is_empty() && b.is_empty() {
+        None
+    } else {
+        Some(compare_values(a, b, tolerance, |a, b| distance(*a, *b)))
+    }
+}
+
+fn compare_values<T, F>(a: &[T], b: &[T], tolerance: f32, distance: F) -> AttributeDifference
+where
+    F: Fn(&T, &T) -> f32,
+{
+    let mut difference = AttributeDifference {
+        counts: [a.len(), b.len()],
+        max_error: 0.0,
+        mean_error: 0.0,
+        exceeding_count: 0,
+    };
+
+    let mut total_error = 0.0;
+    let mut count = 0;
+    for (a, b) in a.iter().zip(b) {
+        let error = distance(a, b);
+        // NaN should always count as a difference.
+        if error > tolerance || error.is_nan() {
+            difference.exceeding_count += 1;
+        }
+        difference.max_error = difference.max_error.max(error);
+        total_error += error as f64;
+        count += 1;
+    }
+    if count > 0 {
+        difference.mean_error = (total_error / count as f64) as f32;
+    }
+    difference
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_empty() {
+        let diff = compare_meshes(
+            &MeshRef::default(),
+            &MeshRef::default(),
+            &CompareTolerances::default(),
+        );
+        assert!(diff.is_within_tolerance());
+        assert_eq!(None, diff.normals);
+    }
+
+    #[test]
+    fn compare_attribute_errors() {
+        let normals_a = [Vec3A::Z, Vec3A::Z];
+        let normals_b = [Vec3A::Z, Vec3A::new(0.0, 0.1, 1.0)];
+        let a = MeshRef {
+            positions: &[Vec3A::ZERO, Vec3A::X],
+            normals: &normals_a,
+            ..Default::default()
+        };
+        let b = MeshRef {
+            normals: &normals_b,
+            ..a
+        };
+
+        let diff = compare_meshes(&a, &b, &CompareTolerances::default());
+        let normals = diff.normals.unwrap();
+        assert_eq!(1, normals.exceeding_count);
+        assert!((normals.max_error - 0.1).abs() < 1e-6);
+        assert!((normals.mean_error - 0.05).abs() < 1e-6);
+        assert!(!diff.is_within_tolerance());
+    }
+
+    #[test]
+    fn compare_missing_attribute() {
+        let uvs = [Vec2::ZERO];
+        let a = MeshRef {
+            positions: &[Vec3A::ZERO],
+            uvs: &uvs,
+            ..Default::default()
+        };
+        let b = MeshRef { uvs: &[], ..a };
+
+        let diff = compare_meshes(&a, &b, &CompareTolerances::default());
+        assert_eq!([1, 0], diff.uvs.unwrap().counts);
+        assert!(!diff.is_within_tolerance());
+    }
+
+    #[test]
+    fn compare_indices() {
+        let positions = [Vec3A::ZERO; 4];
+        let a = MeshRef {
+            positions: &positions,
+            indices: &[0, 1, 2, 0, 2, 3],
+            ..Default::default()
+        };
+        let b = MeshRef {
+            indices: &[0, 1, 2, 0, 3, 2, 1, 2, 3],
+            ..a
+        };
+
+        let diff = compare_meshes(&a, &b, &CompareTolerances::default());
+        assert_eq!([2, 3], diff.triangle_counts);
+        assert_eq!(vec![1], diff.differing_triangles);
+        assert!(!diff.is_within_tolerance());
+    }
+
+    #[test]
+    fn compare_nan() {
+        let a = MeshRef {
+            positions: &[Vec3A::NAN],
+            ..Default::default()
+        };
+        let diff = compare_meshes(&a, &a, &CompareTolerances::default());
+        assert_eq!(1, diff.positions.exceeding_count);
+    }
+}
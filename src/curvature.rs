@@ -0,0 +1,205 @@
+//! Functions for estimating the curvature of triangle meshes.
+
+use std::collections::HashMap;
+
+use glam::Vec3A;
+
+/// Calculates the mean curvature at each vertex using the cotangent Laplacian and mixed Voronoi areas.
+/// The curvature is positive for convex regions and negative for concave regions
+/// for meshes with counterclockwise winding, so a sphere of radius `r` has a curvature of `1.0 / r`.
+///
+/// Vertices with the same position are treated as the same vertex,
+/// so UV seams and hard edges do not affect the result.
+/// Vertices on open borders or not used by any triangles have a curvature of `0.0`.
+///
+/// See "Discrete Differential-Geometry Operators for Triangulated 2-Manifolds" by Meyer et al.
+/// # Examples
+/**
+```rust
+use geometry_tools::curvature::calculate_mean_curvature;
+use glam::Vec3A;
+
+// An octahedron with a vertex pushed inwards.
+let positions = [
+    Vec3A::X,
+    Vec3A::NEG_X,
+    Vec3A::Y,
+    Vec3A::NEG_Y,
+    Vec3A::Z * -0.5,
+    Vec3A::NEG_Z,
+];
+let indices = [0, 2, 4, 2, 1, 4, 1, 3, 4, 3, 0, 4, 2, 0, 5, 1, 2, 5, 3, 1, 5, 0, 3, 5];
+
+let curvature = calculate_mean_curvature(&positions, &indices);
+assert!(curvature[4] < 0.0);
+assert!(curvature[5] > 0.0);
+```
+ */
+pub fn calculate_mean_curvature<P>(positions: &[P], indices: &[u32]) -> Vec<f32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+    let welded = weld_positions(&positions);
+
+    let mut laplacian = vec![Vec3A::ZERO; positions.len()];
+    let mut normals = vec![Vec3A::ZERO; positions.len()];
+    let mut areas = vec![0.0f32; positions.len()];
+    let mut edge_counts: HashMap<[u32; 2], usize> = HashMap::new();
+
+    for face in indices.chunks_exact(3) {
+        let [i0, i1, i2] = [face[0], face[1], face[2]].map(|i| welded[i as usize]);
+        if i0 == i1 || i1 == i2 || i2 == i0 {
+            continue;
+        }
+
+        let [v0, v1, v2] = [i0, i1, i2].map(|i| positions[i as usize]);
+        let normal = (v1 - v0).cross(v2 - v0);
+        let area = normal.length() / 2.0;
+        if area == 0.0 {
+            continue;
+        }
+
+        let is_obtuse =
+            [(v0, v1, v2), (v1, v2, v0), (v2, v0, v1)].map(|(a, b, c)| (b - a).dot(c - a) < 0.0);
+
+        for (corner, (i, j, k)) in [(i0, i1, i2), (i1, i2, i0), (i2, i0, i1)]
+            .into_iter()
+            .enumerate()
+        {
+            // The cotangent of the angle at k weights the edge from i to j.
+            let (pi, pj, pk) = (
+                positions[i as usize],
+                positions[j as usize],
+                positions[k as usize],
+            );
+            let cot_k = cotangent(pi - pk, pj - pk);
+            laplacian[i as usize] += (pj - pi) * cot_k;
+            laplacian[j as usize] += (pi - pj) * cot_k;
+
+            normals[i as usize] += normal;
+            areas[i as usize] += if is_obtuse.iter().any(|o| *o) {
+                // Voronoi regions extend outside obtuse triangles.
+                if is_obtuse[corner] {
+                    area / 2.0
+                } else {
+                    area / 4.0
+                }
+            } else {
+                let cot_j = cotangent(pi - pj, pk - pj);
+                (pi.distance_squared(pj) * cot_k + pi.distance_squared(pk) * cot_j) / 8.0
+            };
+
+            *edge_counts.entry([i.min(j), i.max(j)]).or_default() += 1;
+        }
+    }
+
+    let mut is_boundary = vec![false; positions.len()];
+    for ([a, b], count) in edge_counts {
+        if count == 1 {
+            is_boundary[a as usize] = true;
+            is_boundary[b as usize] = true;
+        }
+    }
+
+    welded
+        .iter()
+        .map(|w| {
+            let w = *w as usize;
+            if is_boundary[w] || areas[w] == 0.0 {
+                0.0
+            } else {
+                // The Laplacian points inwards for convex regions.
+                let mean_curvature_normal = laplacian[w] / (4.0 * areas[w]);
+                -mean_curvature_normal.dot(normals[w].normalize_or_zero())
+            }
+        })
+        .collect()
+}
+
+fn cotangent(a: Vec3A, b: Vec3A) -> f32 {
+    a.dot(b) / a.cross(b).length()
+}
+
+pub(crate) fn weld_positions(positions: &[Vec3A]) -> Vec<u32> {
+    let mut first_vertex = HashMap::new();
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            *first_vertex
+                .entry(p.to_array().map(f32::to_bits))
+                .or_insert(i as u32)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::subdivide::subdivide_midpoint;
+
+    fn sphere(radius: f32) -> (Vec<Vec3A>, Vec<u32>) {
+        let positions = [
+            Vec3A::X,
+            Vec3A::NEG_X,
+            Vec3A::Y,
+            Vec3A::NEG_Y,
+            Vec3A::Z,
+            Vec3A::NEG_Z,
+        ];
+        let indices = [
+            0, 2, 4, 2, 1, 4, 1, 3, 4, 3, 0, 4, 2, 0, 5, 1, 2, 5, 3, 1, 5, 0, 3, 5,
+        ];
+        let mesh = subdivide_midpoint::<_, Vec3A>(&positions, &[], &[], &indices, 4);
+        let positions = mesh
+            .positions
+            .iter()
+            .map(|p| p.normalize() * radius)
+            .collect();
+        (positions, mesh.indices)
+    }
+
+    #[test]
+    fn curvature_empty() {
+        assert!(calculate_mean_curvature::<Vec3A>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn curvature_sphere() {
+        let (positions, indices) = sphere(2.0);
+        let curvature = calculate_mean_curvature(&positions, &indices);
+        for c in curvature {
+            assert!((c - 0.5).abs() < 0.01, "{c}");
+        }
+    }
+
+    #[test]
+    fn curvature_inverted_sphere() {
+        let (positions, indices) = sphere(1.0);
+        let indices: Vec<_> = indices
+            .chunks_exact(3)
+            .flat_map(|f| [f[0], f[2], f[1]])
+            .collect();
+        let curvature = calculate_mean_curvature(&positions, &indices);
+        for c in curvature {
+            assert!((c + 1.0).abs() < 0.1, "{c}");
+        }
+    }
+
+    #[test]
+    fn curvature_flat_and_boundary() {
+        let positions = [
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+            Vec3A::NEG_X,
+            Vec3A::NEG_Y,
+            Vec3A::Z,
+        ];
+        let indices = [0, 1, 2, 0, 2, 3, 0, 3, 4, 0, 4, 1];
+        let curvature = calculate_mean_curvature(&positions, &indices);
+        assert_eq!(vec![0.0; 6], curvature);
+    }
+}
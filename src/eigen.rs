@@ -0,0 +1,111 @@
+use glam::{DMat3, DVec3, Mat3, Vec3A};
+
+/// Calculates the eigenvalues and eigenvectors of the symmetric matrix `m` using the Jacobi eigenvalue algorithm.
+/// The eigenvalues are sorted in descending order,
+/// and the corresponding normalized eigenvectors are the columns of a right-handed rotation matrix.
+// Indexing is clearer than iterators when updating two columns at once.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn symmetric_eigen(m: Mat3) -> (Vec3A, Mat3) {
+    // Use double precision since the rotations accumulate rounding errors.
+    let mut a = m.as_dmat3().to_cols_array_2d();
+    let mut v = DMat3::IDENTITY.to_cols_array_2d();
+
+    for _ in 0..32 {
+        // Find the largest off diagonal element.
+        let (p, q) = [(0, 1), (0, 2), (1, 2)]
+            .into_iter()
+            .max_by(|(i0, j0), (i1, j1)| a[*j0][*i0].abs().total_cmp(&a[*j1][*i1].abs()))
+            .unwrap();
+
+        let apq = a[q][p];
+        if apq.abs() <= 1e-15 * (a[p][p].abs() + a[q][q].abs()).max(f64::MIN_POSITIVE) {
+            break;
+        }
+
+        // Calculate the rotation that zeroes out the element at (p, q).
+        let theta = (a[q][q] - a[p][p]) / (2.0 * apq);
+        let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+        let t = if theta == 0.0 { 1.0 } else { t };
+        let c = 1.0 / (t * t + 1.0).sqrt();
+        let s = t * c;
+
+        for k in 0..3 {
+            let akp = a[p][k];
+            let akq = a[q][k];
+            a[p][k] = c * akp - s * akq;
+            a[q][k] = s * akp + c * akq;
+        }
+        for k in 0..3 {
+            let apk = a[k][p];
+            let aqk = a[k][q];
+            a[k][p] = c * apk - s * aqk;
+            a[k][q] = s * apk + c * aqk;
+        }
+        for row in 0..3 {
+            let vp = v[p][row];
+            let vq = v[q][row];
+            v[p][row] = c * vp - s * vq;
+            v[q][row] = s * vp + c * vq;
+        }
+    }
+
+    let mut pairs: Vec<(f64, DVec3)> = (0..3)
+        .map(|i| (a[i][i], DVec3::from_array(v[i]).normalize()))
+        .collect();
+    pairs.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let values = Vec3A::new(pairs[0].0 as f32, pairs[1].0 as f32, pairs[2].0 as f32);
+    let x = pairs[0].1;
+    let y = pairs[1].1;
+    // Flip the last axis if necessary to avoid reflections.
+    let z = if x.cross(y).dot(pairs[2].1) < 0.0 {
+        -pairs[2].1
+    } else {
+        pairs[2].1
+    };
+
+    (values, DMat3::from_cols(x, y, z).as_mat3())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn eigen_diagonal() {
+        let (values, vectors) = symmetric_eigen(Mat3::from_diagonal(Vec3::new(1.0, 3.0, 2.0)));
+        assert!(values.abs_diff_eq(Vec3A::new(3.0, 2.0, 1.0), EPSILON));
+        assert!(vectors.x_axis.abs().abs_diff_eq(Vec3::Y, EPSILON));
+        assert!(vectors.y_axis.abs().abs_diff_eq(Vec3::Z, EPSILON));
+        assert!(vectors.z_axis.abs().abs_diff_eq(Vec3::X, EPSILON));
+        assert!((vectors.determinant() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn eigen_symmetric() {
+        let m = Mat3::from_cols(
+            Vec3::new(2.0, 1.0, 0.0),
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(0.0, 0.0, 5.0),
+        );
+        let (values, vectors) = symmetric_eigen(m);
+        assert!(values.abs_diff_eq(Vec3A::new(5.0, 3.0, 1.0), EPSILON));
+
+        // Check that m * v = lambda * v for each pair.
+        for i in 0..3 {
+            let v = vectors.col(i);
+            assert!((m * v).abs_diff_eq(v * values[i], EPSILON));
+        }
+        assert!((vectors.determinant() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn eigen_zero() {
+        let (values, vectors) = symmetric_eigen(Mat3::ZERO);
+        assert_eq!(Vec3A::ZERO, values);
+        assert_eq!(Mat3::IDENTITY, vectors);
+    }
+}
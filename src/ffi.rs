@@ -1,2 +1,234 @@
 //! Combined ffi functions from all modules.
+//!
+//! All exported functions use a `geo_` prefix.
+//! The C header is at `include/geometry_tools.h`.
+//! Enable the `ffi-header` feature to generate the header using cbindgen in the build output directory
+//! and test that it matches the header in `include`.
+//!
+//! All functions return an [FfiStatus] and validate pointers, lengths, and indices before writing any outputs.
+//! Panics are caught and reported as [FfiStatus::Panic] instead of unwinding into the caller.
+pub use crate::attributes::ffi::*;
+pub use crate::bounding::ffi::*;
+pub use crate::simplify::ffi::*;
 pub use crate::vectors::normal::ffi::*;
+pub use crate::weld::ffi::*;
+pub use mesh::*;
+
+mod mesh;
+
+use std::ffi::c_void;
+use std::panic::AssertUnwindSafe;
+
+use crate::validation::{check_triangle_indices as check_indices, IndexError};
+use crate::vectors::TangentBitangentError;
+
+/// The version of the C API returned by [geo_abi_version].
+/// This is incremented for any change to the signature of existing functions or types.
+pub const GEO_ABI_VERSION: u32 = 1;
+
+/// Returns the version of the C API.
+/// Callers should check that this matches the version the bindings were generated for.
+#[no_mangle]
+pub extern "C" fn geo_abi_version() -> u32 {
+    GEO_ABI_VERSION
+}
+
+/// The result of an FFI function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FfiStatus {
+    /// The function completed successfully.
+    Success = 0,
+    /// A pointer was null for a non empty buffer or required output.
+    NullPointer = 1,
+    /// Buffers that should have the same length had different lengths.
+    LengthMismatch = 2,
+    /// An index was outside the range of the vertex buffers.
+    IndexOutOfRange = 3,
+    /// The index count was not a multiple of 3.
+    InvalidIndexCount = 4,
+    /// An unexpected panic occurred and was caught before reaching the caller.
+    Panic = 5,
+    /// An attribute descriptor did not fit in its buffer or had an invalid stride.
+    InvalidAttribute = 6,
+    /// The operation was cancelled by the caller.
+    Cancelled = 7,
+    /// A pointer did not have the required alignment for its type like 16 bytes for [glam::Vec3A].
+    Misaligned = 8,
+}
+
+/// A progress callback for long running functions
+/// called with a progress value from `0.0` to `1.0` and the caller's `user_data` pointer.
+/// The callback is called on the same thread as the function.
+pub type GeoProgressCallback = Option<unsafe extern "C" fn(progress: f32, user_data: *mut c_void)>;
+
+/// Runs `f` and converts the result and any panics to a status code.
+pub(crate) fn catch_status<F>(f: F) -> FfiStatus
+where
+    F: FnOnce() -> Result<(), FfiStatus>,
+{
+    // The caller's buffers are not observed again after a panic, so unwind safety is not a concern.
+    match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => FfiStatus::Success,
+        Ok(Err(status)) => status,
+        Err(_) => FfiStatus::Panic,
+    }
+}
+
+/// Creates a slice from a pointer that may be null if `length` is zero.
+pub(crate) unsafe fn slice<'a, T>(data: *const T, length: u32) -> Result<&'a [T], FfiStatus> {
+    // Null pointers are not valid for slices even with a length of zero.
+    if length == 0 {
+        Ok(&[])
+    } else if data.is_null() {
+        Err(FfiStatus::NullPointer)
+    } else if !data.is_aligned() {
+        Err(FfiStatus::Misaligned)
+    } else {
+        Ok(std::slice::from_raw_parts(data, length as usize))
+    }
+}
+
+/// Creates a mutable slice from a pointer that may be null if `length` is zero.
+pub(crate) unsafe fn slice_mut<'a, T>(data: *mut T, length: u32) -> Result<&'a mut [T], FfiStatus> {
+    if length == 0 {
+        Ok(&mut [])
+    } else if data.is_null() {
+        Err(FfiStatus::NullPointer)
+    } else if !data.is_aligned() {
+        Err(FfiStatus::Misaligned)
+    } else {
+        Ok(std::slice::from_raw_parts_mut(data, length as usize))
+    }
+}
+
+/// Returns a mutable reference for a required output pointer.
+pub(crate) unsafe fn output<'a, T>(data: *mut T) -> Result<&'a mut T, FfiStatus> {
+    if !data.is_null() && !data.is_aligned() {
+        return Err(FfiStatus::Misaligned);
+    }
+    data.as_mut().ok_or(FfiStatus::NullPointer)
+}
+
+/// Checks that `indices` are triangle indices that are all less than `vertex_count`.
+pub(crate) fn check_triangle_indices<I>(indices: &[I], vertex_count: usize) -> Result<(), FfiStatus>
+where
+    I: Into<u32> + Copy,
+{
+    check_indices(indices, vertex_count).map_err(index_status)
+}
+
+/// Converts an index validation error to the matching status code.
+fn index_status(error: IndexError) -> FfiStatus {
+    match error {
+        IndexError::InvalidIndexCount { .. } => FfiStatus::InvalidIndexCount,
+        IndexError::IndexOutOfRange { .. } => FfiStatus::IndexOutOfRange,
+    }
+}
+
+/// Converts a tangent calculation error to the matching status code.
+pub(crate) fn tangent_status(error: TangentBitangentError) -> FfiStatus {
+    match error {
+        TangentBitangentError::AttributeCountMismatch { .. }
+        | TangentBitangentError::OutputCountMismatch { .. }
+        | TangentBitangentError::AdjacencyVertexCountMismatch { .. }
+        | TangentBitangentError::InvalidFlatLength(_) => FfiStatus::LengthMismatch,
+        TangentBitangentError::InvalidIndexCont { .. } => FfiStatus::InvalidIndexCount,
+        TangentBitangentError::InvalidIndices(e) => index_status(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use glam::{Vec2, Vec3A};
+
+    #[test]
+    fn abi_version() {
+        assert_eq!(GEO_ABI_VERSION, geo_abi_version());
+    }
+
+    #[test]
+    fn catch_status_panic() {
+        assert_eq!(FfiStatus::Panic, catch_status(|| panic!("test")));
+    }
+
+    #[test]
+    fn slice_null() {
+        unsafe {
+            assert_eq!(Ok(&[][..]), slice::<u32>(std::ptr::null(), 0));
+            assert_eq!(
+                Err(FfiStatus::NullPointer),
+                slice::<u32>(std::ptr::null(), 1)
+            );
+        }
+    }
+
+    #[test]
+    fn slice_misaligned() {
+        let mut data = [Vec3A::ZERO; 2];
+        unsafe {
+            // Offsetting by a single float breaks the 16 byte alignment of Vec3A.
+            let misaligned = data.as_mut_ptr().cast::<f32>().add(1).cast::<Vec3A>();
+            assert_eq!(Err(FfiStatus::Misaligned), slice(misaligned, 1));
+            assert_eq!(
+                Err(FfiStatus::Misaligned),
+                slice_mut(misaligned, 1).map(|s| s.len())
+            );
+            assert_eq!(Err(FfiStatus::Misaligned), output(misaligned).map(|v| *v));
+            assert_eq!(Ok(&[][..]), slice(misaligned, 0));
+            assert_eq!(Ok(&data[..]), slice(data.as_ptr(), 2));
+        }
+    }
+
+    #[test]
+    fn triangle_indices() {
+        assert_eq!(Ok(()), check_triangle_indices(&[0u32, 1, 2], 3));
+        assert_eq!(
+            Err(FfiStatus::InvalidIndexCount),
+            check_triangle_indices(&[0u32, 1], 3)
+        );
+        assert_eq!(
+            Err(FfiStatus::IndexOutOfRange),
+            check_triangle_indices(&[0u16, 1, 3], 3)
+        );
+    }
+
+    #[cfg(feature = "ffi-header")]
+    #[test]
+    fn header_up_to_date() {
+        let generated = concat!(env!("OUT_DIR"), "/geometry_tools.h");
+        assert!(
+            include_str!("../include/geometry_tools.h")
+                == include_str!(concat!(env!("OUT_DIR"), "/geometry_tools.h")),
+            "include/geometry_tools.h should match the generated header at {generated}"
+        );
+    }
+
+    #[test]
+    fn tangent_errors() {
+        let tangents = |indices: &[u32]| {
+            crate::vectors::calculate_tangents_strict(
+                &[Vec3A::ZERO; 3],
+                &[Vec3A::Z; 3],
+                &[Vec2::ZERO; 3],
+                indices,
+            )
+            .map_err(tangent_status)
+        };
+        assert_eq!(Err(FfiStatus::InvalidIndexCount), tangents(&[0, 1]));
+        assert_eq!(Err(FfiStatus::IndexOutOfRange), tangents(&[0, 1, 3]));
+
+        let result = crate::vectors::calculate_tangents(
+            &[Vec3A::ZERO; 3],
+            &[Vec3A::Z; 2],
+            &[Vec2::ZERO; 3],
+            &[0u32, 1, 2],
+        );
+        assert_eq!(
+            Err(FfiStatus::LengthMismatch),
+            result.map_err(tangent_status)
+        );
+    }
+}
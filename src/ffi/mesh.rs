@@ -0,0 +1,314 @@
+use glam::{Vec2, Vec3A, Vec4};
+
+use crate::topology::MeshAdjacency;
+use crate::vectors::{calculate_smooth_normals_with_adjacency, calculate_tangents_with_adjacency};
+
+use super::{
+    catch_status, check_triangle_indices, output, slice, slice_mut, tangent_status, FfiStatus,
+};
+
+/// An opaque handle that owns positions, indices, and cached adjacency
+/// for repeated calculations without passing the full mesh for each call.
+/// Create with [geo_mesh_create] and free with [geo_mesh_destroy].
+pub struct GeoMesh {
+    positions: Vec<Vec3A>,
+    adjacency: MeshAdjacency,
+}
+
+/// Creates a mesh handle by copying `positions` and `indices` and writes the handle to `mesh`.
+/// The handle must be freed with [geo_mesh_destroy].
+///
+/// # Safety
+///
+/// `positions` and `indices` must have lengths `positions_length` and `indices_length`.
+/// `positions` uses the same 16 byte layout as [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+/// `mesh` must point to a pointer that can be written.
+#[no_mangle]
+pub unsafe extern "C" fn geo_mesh_create(
+    positions: *const Vec3A,
+    positions_length: u32,
+    indices: *const u32,
+    indices_length: u32,
+    mesh: *mut *mut GeoMesh,
+) -> FfiStatus {
+    catch_status(|| {
+        let positions = slice(positions, positions_length)?;
+        let indices = slice(indices, indices_length)?;
+        let mesh = output(mesh)?;
+        check_triangle_indices(indices, positions.len())?;
+
+        *mesh = Box::into_raw(Box::new(GeoMesh {
+            positions: positions.to_vec(),
+            adjacency: MeshAdjacency::new(positions.len(), indices),
+        }));
+        Ok(())
+    })
+}
+
+/// Frees a mesh handle created by [geo_mesh_create].
+/// Null pointers are ignored.
+///
+/// # Safety
+///
+/// `mesh` must be null or a handle from [geo_mesh_create] that has not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn geo_mesh_destroy(mesh: *mut GeoMesh) {
+    if !mesh.is_null() {
+        drop(Box::from_raw(mesh));
+    }
+}
+
+/// Replaces the positions of `mesh` while keeping the indices and cached adjacency.
+/// Returns [FfiStatus::LengthMismatch] if the vertex count changes.
+///
+/// # Safety
+///
+/// `mesh` must be a valid handle, and `positions` must have length `positions_length`.
+#[no_mangle]
+pub unsafe extern "C" fn geo_mesh_set_positions(
+    mesh: *mut GeoMesh,
+    positions: *const Vec3A,
+    positions_length: u32,
+) -> FfiStatus {
+    catch_status(|| {
+        let mesh = output(mesh)?;
+        let positions = slice(positions, positions_length)?;
+        if positions.len() != mesh.positions.len() {
+            return Err(FfiStatus::LengthMismatch);
+        }
+        mesh.positions.copy_from_slice(positions);
+        Ok(())
+    })
+}
+
+/// Calculates smooth normals like [calculate_smooth_normals](crate::vectors::calculate_smooth_normals)
+/// and writes them to `normals`.
+/// Returns [FfiStatus::LengthMismatch] if `normals_length` is not the vertex count of `mesh`.
+///
+/// # Safety
+///
+/// `mesh` must be a valid handle, and `normals` must have length `normals_length`.
+#[no_mangle]
+pub unsafe extern "C" fn geo_mesh_calculate_smooth_normals(
+    mesh: *const GeoMesh,
+    normals: *mut Vec3A,
+    normals_length: u32,
+) -> FfiStatus {
+    catch_status(|| {
+        let mesh = mesh.as_ref().ok_or(FfiStatus::NullPointer)?;
+        let normals = slice_mut(normals, normals_length)?;
+        if normals.len() != mesh.positions.len() {
+            return Err(FfiStatus::LengthMismatch);
+        }
+        normals.copy_from_slice(&calculate_smooth_normals_with_adjacency(
+            &mesh.positions,
+            &mesh.adjacency,
+        ));
+        Ok(())
+    })
+}
+
+/// Calculates tangents like [calculate_tangents](crate::vectors::calculate_tangents)
+/// and writes them to `tangents`.
+/// Returns [FfiStatus::LengthMismatch] if `length` is not the vertex count of `mesh`.
+///
+/// # Safety
+///
+/// `mesh` must be a valid handle.
+/// `normals`, `uvs`, and `tangents` must all have length `length`.
+#[no_mangle]
+pub unsafe extern "C" fn geo_mesh_calculate_tangents(
+    mesh: *const GeoMesh,
+    normals: *const Vec3A,
+    uvs: *const Vec2,
+    tangents: *mut Vec4,
+    length: u32,
+) -> FfiStatus {
+    catch_status(|| {
+        let mesh = mesh.as_ref().ok_or(FfiStatus::NullPointer)?;
+        if length as usize != mesh.positions.len() {
+            return Err(FfiStatus::LengthMismatch);
+        }
+        let normals = slice(normals, length)?;
+        let uvs = slice(uvs, length)?;
+        let tangents = slice_mut(tangents, length)?;
+
+        let result =
+            calculate_tangents_with_adjacency(&mesh.positions, normals, uvs, &mesh.adjacency)
+                .map_err(tangent_status)?;
+        tangents.copy_from_slice(&result);
+        Ok(())
+    })
+}
+
+/// Calculates the axis-aligned bounding box of the positions of `mesh`.
+///
+/// # Safety
+///
+/// `mesh` must be a valid handle. `min` and `max` must each point to three floats.
+#[no_mangle]
+pub unsafe extern "C" fn geo_mesh_calculate_aabb(
+    mesh: *const GeoMesh,
+    min: *mut [f32; 3],
+    max: *mut [f32; 3],
+) -> FfiStatus {
+    catch_status(|| {
+        let mesh = mesh.as_ref().ok_or(FfiStatus::NullPointer)?;
+        let min = output(min)?;
+        let max = output(max)?;
+
+        let (aabb_min, aabb_max) = crate::bounding::calculate_aabb_from_points(&mesh.positions);
+        *min = aabb_min.to_array();
+        *max = aabb_max.to_array();
+        Ok(())
+    })
+}
+
+/// Calculates a bounding sphere of the positions of `mesh`.
+///
+/// # Safety
+///
+/// `mesh` must be a valid handle.
+/// `center` must point to three floats, and `radius` must point to a single float.
+#[no_mangle]
+pub unsafe extern "C" fn geo_mesh_calculate_bounding_sphere(
+    mesh: *const GeoMesh,
+    center: *mut [f32; 3],
+    radius: *mut f32,
+) -> FfiStatus {
+    catch_status(|| {
+        let mesh = mesh.as_ref().ok_or(FfiStatus::NullPointer)?;
+        let center = output(center)?;
+        let radius = output(radius)?;
+
+        let sphere = crate::bounding::calculate_bounding_sphere_from_points(&mesh.positions);
+        *center = sphere.truncate().to_array();
+        *radius = sphere.w;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::calculate_smooth_normals;
+
+    unsafe fn create(positions: &[Vec3A], indices: &[u32]) -> *mut GeoMesh {
+        let mut mesh = std::ptr::null_mut();
+        let status = geo_mesh_create(
+            positions.as_ptr(),
+            positions.len() as u32,
+            indices.as_ptr(),
+            indices.len() as u32,
+            &mut mesh,
+        );
+        assert_eq!(FfiStatus::Success, status);
+        mesh
+    }
+
+    #[test]
+    fn mesh_handle_normals_after_edit() {
+        let mut positions = vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::ONE];
+        let indices = [0, 1, 2, 2, 1, 3];
+        let mut normals = vec![Vec3A::ZERO; 4];
+        unsafe {
+            let mesh = create(&positions, &indices);
+
+            positions[3] = Vec3A::new(1.0, 1.0, 1.0);
+            assert_eq!(
+                FfiStatus::Success,
+                geo_mesh_set_positions(mesh, positions.as_ptr(), 4)
+            );
+            assert_eq!(
+                FfiStatus::Success,
+                geo_mesh_calculate_smooth_normals(mesh, normals.as_mut_ptr(), 4)
+            );
+            geo_mesh_destroy(mesh);
+        }
+        assert_eq!(calculate_smooth_normals(&positions, &indices), normals);
+    }
+
+    #[test]
+    fn mesh_handle_tangents_bounds() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let normals = [Vec3A::Z; 3];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+        let mut tangents = [Vec4::ZERO; 3];
+        let (mut min, mut max) = ([1.0; 3], [1.0; 3]);
+        let (mut center, mut radius) = ([1.0; 3], 0.0);
+        unsafe {
+            let mesh = create(&positions, &[0, 1, 2]);
+            assert_eq!(
+                FfiStatus::Success,
+                geo_mesh_calculate_tangents(
+                    mesh,
+                    normals.as_ptr(),
+                    uvs.as_ptr(),
+                    tangents.as_mut_ptr(),
+                    3
+                )
+            );
+            assert_eq!(
+                FfiStatus::Success,
+                geo_mesh_calculate_aabb(mesh, &mut min, &mut max)
+            );
+            assert_eq!(
+                FfiStatus::Success,
+                geo_mesh_calculate_bounding_sphere(mesh, &mut center, &mut radius)
+            );
+            geo_mesh_destroy(mesh);
+        }
+        assert_eq!([Vec4::new(1.0, 0.0, 0.0, 1.0); 3], tangents);
+        assert_eq!(([0.0; 3], [1.0, 1.0, 0.0]), (min, max));
+        let sphere = crate::bounding::calculate_bounding_sphere_from_points(&positions);
+        assert_eq!(sphere, Vec3A::from(center).extend(radius));
+    }
+
+    #[test]
+    fn mesh_handle_tangents_no_faces() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let normals = [Vec3A::Z; 3];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+        let mut tangents = [Vec4::ZERO; 3];
+        unsafe {
+            let mesh = create(&positions, &[]);
+            assert_eq!(
+                FfiStatus::Success,
+                geo_mesh_calculate_tangents(
+                    mesh,
+                    normals.as_ptr(),
+                    uvs.as_ptr(),
+                    tangents.as_mut_ptr(),
+                    3
+                )
+            );
+            geo_mesh_destroy(mesh);
+        }
+        let expected =
+            crate::vectors::calculate_tangents::<_, _, u32>(&positions, &normals, &uvs, &[])
+                .unwrap();
+        assert_eq!(expected, tangents);
+    }
+
+    #[test]
+    fn mesh_handle_errors() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let indices = [0, 1, 3];
+        let mut mesh = std::ptr::null_mut();
+        unsafe {
+            assert_eq!(
+                FfiStatus::IndexOutOfRange,
+                geo_mesh_create(positions.as_ptr(), 3, indices.as_ptr(), 3, &mut mesh)
+            );
+            assert!(mesh.is_null());
+
+            let mesh = create(&positions, &[0, 1, 2]);
+            assert_eq!(
+                FfiStatus::LengthMismatch,
+                geo_mesh_set_positions(mesh, positions.as_ptr(), 2)
+            );
+            geo_mesh_destroy(mesh);
+            geo_mesh_destroy(std::ptr::null_mut());
+        }
+    }
+}
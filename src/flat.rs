@@ -0,0 +1,202 @@
+//! Functions for flat arrays of floats like those from file formats and scripting languages.
+//!
+//! Positions and normals are `[x, y, z, x, y, z, ...]` and texture coordinates are `[u, v, u, v, ...]`.
+//! The arrays are reinterpreted without copying after checking the length,
+//! and the positions are converted in batches like the functions ending in `_vec3`.
+
+use glam::{Vec2, Vec3, Vec3A, Vec4};
+use thiserror::Error;
+
+use crate::bounding::{
+    calculate_aabb_from_points_vec3, calculate_bounding_sphere_from_points_vec3,
+};
+use crate::vectors::{
+    calculate_smooth_normals_vec3, calculate_tangents_vec3, TangentBitangentError,
+};
+
+/// The error returned when the length of a flat array is not a multiple of the number of components.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("The flat array length {length} is not a multiple of {components}.")]
+pub struct FlatLengthError {
+    pub length: usize,
+    pub components: usize,
+}
+
+/// Reinterprets `values` as xyz triples without copying.
+/// # Examples
+/**
+```rust
+use geometry_tools::flat::as_vec3;
+use glam::Vec3;
+
+assert_eq!(Ok(&[Vec3::X, Vec3::Y][..]), as_vec3(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0]));
+assert!(as_vec3(&[1.0, 0.0]).is_err());
+```
+ */
+pub fn as_vec3(values: &[f32]) -> Result<&[Vec3], FlatLengthError> {
+    check_length(values, 3)?;
+    // Safety: Vec3 is repr(C) with three f32 fields, so it has the same size and alignment as [f32; 3].
+    Ok(unsafe { std::slice::from_raw_parts(values.as_ptr() as *const Vec3, values.len() / 3) })
+}
+
+/// Reinterprets `values` as uv pairs without copying.
+/// # Examples
+/**
+```rust
+use geometry_tools::flat::as_vec2;
+use glam::Vec2;
+
+assert_eq!(Ok(&[Vec2::X, Vec2::Y][..]), as_vec2(&[1.0, 0.0, 0.0, 1.0]));
+assert!(as_vec2(&[1.0, 0.0, 0.0]).is_err());
+```
+ */
+pub fn as_vec2(values: &[f32]) -> Result<&[Vec2], FlatLengthError> {
+    check_length(values, 2)?;
+    // Safety: Vec2 is repr(C) with two f32 fields, so it has the same size and alignment as [f32; 2].
+    Ok(unsafe { std::slice::from_raw_parts(values.as_ptr() as *const Vec2, values.len() / 2) })
+}
+
+fn check_length(values: &[f32], components: usize) -> Result<(), FlatLengthError> {
+    if values.len().is_multiple_of(components) {
+        Ok(())
+    } else {
+        Err(FlatLengthError {
+            length: values.len(),
+            components,
+        })
+    }
+}
+
+/// Calculates smooth per-vertex normals like [calculate_smooth_normals](crate::vectors::calculate_smooth_normals)
+/// for flat xyz `positions`.
+/// # Examples
+/**
+```rust
+use geometry_tools::flat::calculate_smooth_normals;
+use glam::Vec3A;
+
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+let positions = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+let normals = calculate_smooth_normals(&positions, &[0, 1, 2])?;
+assert_eq!(vec![Vec3A::Z; 3], normals);
+# Ok(())
+# }
+```
+ */
+pub fn calculate_smooth_normals(
+    positions: &[f32],
+    indices: &[u32],
+) -> Result<Vec<Vec3A>, FlatLengthError> {
+    Ok(calculate_smooth_normals_vec3(as_vec3(positions)?, indices))
+}
+
+/// Calculates smooth per-vertex tangents like [calculate_tangents](crate::vectors::calculate_tangents)
+/// for flat xyz `positions` and `normals` and flat uv `uvs`.
+/// Invalid array lengths return [TangentBitangentError::InvalidFlatLength].
+/// # Examples
+/**
+```rust
+use geometry_tools::flat::calculate_tangents;
+use glam::Vec4;
+
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+let positions = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+let normals = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0];
+let uvs = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0];
+let tangents = calculate_tangents(&positions, &normals, &uvs, &[0, 1, 2])?;
+assert_eq!(vec![Vec4::new(1.0, 0.0, 0.0, 1.0); 3], tangents);
+# Ok(())
+# }
+```
+ */
+pub fn calculate_tangents(
+    positions: &[f32],
+    normals: &[f32],
+    uvs: &[f32],
+    indices: &[u32],
+) -> Result<Vec<Vec4>, TangentBitangentError> {
+    calculate_tangents_vec3(
+        as_vec3(positions)?,
+        as_vec3(normals)?,
+        as_vec2(uvs)?,
+        indices,
+    )
+}
+
+/// Calculates an axis-aligned bounding box like [calculate_aabb_from_points](crate::bounding::calculate_aabb_from_points)
+/// for flat xyz `points`.
+/// # Examples
+/**
+```rust
+use geometry_tools::flat::calculate_aabb_from_points;
+use glam::Vec3A;
+
+let (min, max) = calculate_aabb_from_points(&[-1.0, 2.0, 0.0, 1.0, 1.0, 1.0]).unwrap();
+assert_eq!(Vec3A::new(-1.0, 1.0, 0.0), min);
+assert_eq!(Vec3A::new(1.0, 2.0, 1.0), max);
+```
+ */
+pub fn calculate_aabb_from_points(points: &[f32]) -> Result<(Vec3A, Vec3A), FlatLengthError> {
+    Ok(calculate_aabb_from_points_vec3(as_vec3(points)?))
+}
+
+/// Calculates a bounding sphere like [calculate_bounding_sphere_from_points](crate::bounding::calculate_bounding_sphere_from_points)
+/// for flat xyz `points`.
+/// # Examples
+/**
+```rust
+use geometry_tools::flat::calculate_bounding_sphere_from_points;
+use glam::Vec4;
+
+let sphere = calculate_bounding_sphere_from_points(&[0.0, -1.0, 0.0, 0.0, 1.0, 0.0]).unwrap();
+assert_eq!(Vec4::new(0.0, 0.0, 0.0, 1.0), sphere);
+```
+ */
+pub fn calculate_bounding_sphere_from_points(points: &[f32]) -> Result<Vec4, FlatLengthError> {
+    Ok(calculate_bounding_sphere_from_points_vec3(as_vec3(points)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Ok(&[][..]), as_vec3(&[]));
+        assert_eq!(Ok(&[][..]), as_vec2(&[]));
+        assert_eq!(Ok(Vec::new()), calculate_smooth_normals(&[], &[]));
+    }
+
+    #[test]
+    fn invalid_length() {
+        assert_eq!(
+            Err(FlatLengthError {
+                length: 4,
+                components: 3
+            }),
+            calculate_aabb_from_points(&[0.0; 4])
+        );
+    }
+
+    #[test]
+    fn tangents_invalid_uv_length() {
+        let result = calculate_tangents(&[0.0; 9], &[0.0; 9], &[0.0; 5], &[0, 1, 2]);
+        assert!(matches!(
+            result,
+            Err(TangentBitangentError::InvalidFlatLength(FlatLengthError {
+                length: 5,
+                components: 2
+            }))
+        ));
+    }
+
+    #[test]
+    fn smooth_normals_match_vec3a() {
+        let sphere = crate::shapes::generate_uv_sphere(1.0, 8, 4);
+        let flat: Vec<f32> = sphere.positions.iter().flat_map(|p| p.to_array()).collect();
+        assert_eq!(
+            crate::vectors::calculate_smooth_normals(&sphere.positions, &sphere.indices),
+            calculate_smooth_normals(&flat, &sphere.indices).unwrap()
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Functions for decoding and encoding vertex attributes stored as half precision floats.
+//!
+//! Values use the IEEE 754 binary16 format stored as the raw `u16` bits.
+
+use glam::{Vec2, Vec3A, Vec4};
+
+/// Converts the binary16 value with bits `bits` to `f32` without any loss of precision.
+/// # Examples
+/**
+```rust
+use geometry_tools::float16::f16_to_f32;
+
+assert_eq!(1.0, f16_to_f32(0x3c00));
+assert_eq!(-2.0, f16_to_f32(0xc000));
+assert_eq!(65504.0, f16_to_f32(0x7bff));
+assert_eq!(f32::INFINITY, f16_to_f32(0x7c00));
+```
+ */
+pub fn f16_to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exponent = ((bits >> 10) & 0x1f) as u32;
+    let mantissa = (bits & 0x3ff) as u32;
+
+    match exponent {
+        // Subnormal values have no implicit leading one.
+        0 => f32::from_bits(sign | (mantissa as f32 * 2f32.powi(-24)).to_bits()),
+        // Infinity or NaN.
+        0x1f => f32::from_bits(sign | 0x7f800000 | (mantissa << 13)),
+        _ => f32::from_bits(sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)),
+    }
+}
+
+/// Converts `value` to the bits of the nearest binary16 value using round to nearest even.
+/// Values too large to represent are converted to infinity.
+/// # Examples
+/**
+```rust
+use geometry_tools::float16::f32_to_f16;
+
+assert_eq!(0x3c00, f32_to_f16(1.0));
+assert_eq!(0x3555, f32_to_f16(1.0 / 3.0));
+assert_eq!(0x7c00, f32_to_f16(1e6));
+```
+ */
+pub fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7fffff;
+
+    if exponent == 0xff {
+        // Preserve NaN by keeping a mantissa bit set.
+        let nan = if mantissa != 0 { 0x200 } else { 0 };
+        return sign | 0x7c00 | nan;
+    }
+
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    if half_exponent <= 0 {
+        // The result is subnormal or rounds to zero.
+        if half_exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x800000;
+        let shift = (14 - half_exponent) as u32;
+        let half_mantissa = mantissa >> shift;
+        return sign | round_to_nearest_even(half_mantissa, mantissa, shift) as u16;
+    }
+
+    // Rounding may carry into the exponent, which correctly produces infinity on overflow.
+    let half = ((half_exponent as u32) << 10) | (mantissa >> 13);
+    sign | round_to_nearest_even(half, mantissa, 13) as u16
+}
+
+fn round_to_nearest_even(truncated: u32, value: u32, shift: u32) -> u32 {
+    let remainder = value & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    if remainder > halfway || (remainder == halfway && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+/// Decodes binary16 UVs or other 2 component values.
+pub fn decode_f16_vec2(values: &[[u16; 2]]) -> Vec<Vec2> {
+    values
+        .iter()
+        .map(|v| Vec2::from_array(v.map(f16_to_f32)))
+        .collect()
+}
+
+/// Decodes binary16 positions, normals, or other 3 component values.
+/// # Examples
+/**
+```rust
+use geometry_tools::float16::{decode_f16_vec3a, encode_f16_vec3};
+use glam::Vec3A;
+
+let positions = [Vec3A::new(0.5, -1.0, 2.0)];
+let encoded = encode_f16_vec3(&positions);
+assert_eq!(vec![[0x3800, 0xbc00, 0x4000]], encoded);
+assert_eq!(positions.to_vec(), decode_f16_vec3a(&encoded));
+```
+ */
+pub fn decode_f16_vec3a(values: &[[u16; 3]]) -> Vec<Vec3A> {
+    values
+        .iter()
+        .map(|v| Vec3A::from_array(v.map(f16_to_f32)))
+        .collect()
+}
+
+/// Decodes binary16 tangents, colors, or other 4 component values.
+/// Positions padded to 4 components can be decoded with this function
+/// and used as the input to functions expecting [Vec3A].
+pub fn decode_f16_vec4(values: &[[u16; 4]]) -> Vec<Vec4> {
+    values
+        .iter()
+        .map(|v| Vec4::from_array(v.map(f16_to_f32)))
+        .collect()
+}
+
+/// Encodes 2 component values as binary16 using [f32_to_f16].
+pub fn encode_f16_vec2(values: &[Vec2]) -> Vec<[u16; 2]> {
+    values
+        .iter()
+        .map(|v| v.to_array().map(f32_to_f16))
+        .collect()
+}
+
+/// Encodes 3 component values as binary16 using [f32_to_f16].
+pub fn encode_f16_vec3<P>(values: &[P]) -> Vec<[u16; 3]>
+where
+    P: Into<Vec3A> + Copy,
+{
+    values
+        .iter()
+        .map(|v| (*v).into().to_array().map(f32_to_f16))
+        .collect()
+}
+
+/// Encodes 4 component values as binary16 using [f32_to_f16].
+pub fn encode_f16_vec4(values: &[Vec4]) -> Vec<[u16; 4]> {
+    values
+        .iter()
+        .map(|v| v.to_array().map(f32_to_f16))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_all_values() {
+        for bits in 0..=u16::MAX {
+            let value = f16_to_f32(bits);
+            if value.is_nan() {
+                assert!(f16_to_f32(f32_to_f16(value)).is_nan());
+            } else {
+                assert_eq!(bits, f32_to_f16(value), "{bits:#06x}");
+            }
+        }
+    }
+
+    #[test]
+    fn subnormals() {
+        assert_eq!(2f32.powi(-24), f16_to_f32(0x0001));
+        assert_eq!(-2f32.powi(-24), f16_to_f32(0x8001));
+        assert_eq!(0x0001, f32_to_f16(2f32.powi(-24) * 0.75));
+        // Ties round to even.
+        assert_eq!(0x0000, f32_to_f16(2f32.powi(-25)));
+        assert_eq!(0x0002, f32_to_f16(2f32.powi(-24) * 1.5));
+        assert_eq!(0x8000, f32_to_f16(-1e-10));
+    }
+
+    #[test]
+    fn rounding_and_overflow() {
+        // The spacing between values near 1.0 is 2^-10.
+        assert_eq!(0x3c00, f32_to_f16(1.0 + 2f32.powi(-11)));
+        assert_eq!(0x3c02, f32_to_f16(1.0 + 3.0 * 2f32.powi(-11)));
+        assert_eq!(0x7bff, f32_to_f16(65519.0));
+        assert_eq!(0x7c00, f32_to_f16(65520.0));
+        assert_eq!(0xfc00, f32_to_f16(f32::NEG_INFINITY));
+    }
+
+    #[test]
+    fn encode_decode_vectors() {
+        let uvs = [Vec2::new(0.25, 1.0)];
+        assert_eq!(uvs.to_vec(), decode_f16_vec2(&encode_f16_vec2(&uvs)));
+
+        let tangents = [Vec4::new(1.0, 0.0, 0.0, -1.0)];
+        assert_eq!(
+            vec![[0x3c00, 0x0000, 0x0000, 0xbc00]],
+            encode_f16_vec4(&tangents)
+        );
+        assert_eq!(
+            tangents.to_vec(),
+            decode_f16_vec4(&encode_f16_vec4(&tangents))
+        );
+
+        assert!(decode_f16_vec3a(&[]).is_empty());
+    }
+}
@@ -0,0 +1,253 @@
+//! Functions for calculating distances along the surface of triangle meshes.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use glam::{Vec2, Vec3A};
+
+use crate::curvature::weld_positions;
+use crate::topology::MeshAdjacency;
+
+/// The algorithm used by [calculate_geodesic_distances].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeodesicMethod {
+    /// Shortest paths along mesh edges.
+    /// This is fast but overestimates distances for paths that cross triangles.
+    Dijkstra,
+    /// Shortest paths along mesh edges refined by propagating
+    /// the distances across triangles using the fast marching method.
+    /// This is more accurate for paths that cross triangles.
+    FastMarching,
+}
+
+/// Calculates the distance along the surface from the nearest vertex in `seeds` for each vertex.
+/// Vertices that cannot be reached from any seed have a distance of [f32::INFINITY].
+///
+/// Vertices with the same position are treated as the same vertex,
+/// so distances propagate across UV seams and hard edges.
+/// # Examples
+/**
+```rust
+use geometry_tools::geodesic::{calculate_geodesic_distances, GeodesicMethod};
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::new(1.0, 1.0, 0.0)];
+let indices = [0, 1, 2, 2, 1, 3];
+
+let distances = calculate_geodesic_distances(&positions, &indices, &[0], GeodesicMethod::Dijkstra);
+assert_eq!(vec![0.0, 1.0, 1.0, 2.0], distances);
+
+let distances =
+    calculate_geodesic_distances(&positions, &indices, &[0], GeodesicMethod::FastMarching);
+assert!((distances[3] - 2.0f32.sqrt()).abs() < 0.0001);
+```
+ */
+pub fn calculate_geodesic_distances<P>(
+    positions: &[P],
+    indices: &[u32],
+    seeds: &[u32],
+    method: GeodesicMethod,
+) -> Vec<f32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+    let welded = weld_positions(&positions);
+
+    let triangles: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|f| [f[0], f[1], f[2]].map(|i| welded[i as usize]))
+        .filter(|[v0, v1, v2]| v0 != v1 && v1 != v2 && v2 != v0)
+        .collect();
+
+    let adjacency = MeshAdjacency::from_faces(positions.len(), triangles);
+
+    let mut distances = vec![f32::INFINITY; positions.len()];
+    let mut is_final = vec![false; positions.len()];
+    let mut queue = BinaryHeap::new();
+    for seed in seeds {
+        let seed = welded[*seed as usize];
+        distances[seed as usize] = 0.0;
+        queue.push(QueueEntry {
+            distance: 0.0,
+            vertex: seed,
+        });
+    }
+
+    while let Some(QueueEntry { distance, vertex }) = queue.pop() {
+        if is_final[vertex as usize] {
+            continue;
+        }
+        is_final[vertex as usize] = true;
+
+        for t in adjacency.vertex_faces(vertex as usize) {
+            let triangle = adjacency.faces()[*t as usize];
+            let corner = triangle.iter().position(|v| *v == vertex).unwrap();
+            let next = triangle[(corner + 1) % 3];
+            let previous = triangle[(corner + 2) % 3];
+
+            for (target, other) in [(next, previous), (previous, next)] {
+                if is_final[target as usize] {
+                    continue;
+                }
+
+                let mut new_distance =
+                    distance + positions[vertex as usize].distance(positions[target as usize]);
+                if method == GeodesicMethod::FastMarching && is_final[other as usize] {
+                    let update = triangle_update(
+                        positions[vertex as usize],
+                        positions[other as usize],
+                        positions[target as usize],
+                        distance,
+                        distances[other as usize],
+                    );
+                    new_distance = new_distance.min(update);
+                }
+
+                if new_distance < distances[target as usize] {
+                    distances[target as usize] = new_distance;
+                    queue.push(QueueEntry {
+                        distance: new_distance,
+                        vertex: target,
+                    });
+                }
+            }
+        }
+    }
+
+    welded.iter().map(|w| distances[*w as usize]).collect()
+}
+
+#[derive(PartialEq)]
+struct QueueEntry {
+    distance: f32,
+    vertex: u32,
+}
+
+impl Eq for QueueEntry {}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reverse the order to pop the smallest distance first.
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then(other.vertex.cmp(&self.vertex))
+    }
+}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Calculates the distance at `c` from a planar wavefront passing through `a` and `b`.
+/// Returns [f32::INFINITY] if the wavefront does not reach `c` through the edge from `a` to `b`.
+fn triangle_update(a: Vec3A, b: Vec3A, c: Vec3A, distance_a: f32, distance_b: f32) -> f32 {
+    // Unfold the triangle into 2D with a at the origin and b on the x-axis.
+    let edge_length = a.distance(b);
+    if edge_length == 0.0 {
+        return f32::INFINITY;
+    }
+    let x_axis = (b - a) / edge_length;
+    let c_x = (c - a).dot(x_axis);
+    let c_y = (c - a - x_axis * c_x).length();
+    let c = Vec2::new(c_x, c_y);
+
+    // Find the virtual source on the opposite side of the edge from c.
+    let source_x = (distance_a * distance_a - distance_b * distance_b + edge_length * edge_length)
+        / (2.0 * edge_length);
+    let source_y_squared = distance_a * distance_a - source_x * source_x;
+    if source_y_squared < 0.0 {
+        return f32::INFINITY;
+    }
+    let source = Vec2::new(source_x, -source_y_squared.sqrt());
+
+    // The path from the source to c must cross the edge.
+    let t = -source.y / (c.y - source.y);
+    let crossing = source.x + t * (c.x - source.x);
+    if (0.0..=edge_length).contains(&crossing) {
+        source.distance(c)
+    } else {
+        f32::INFINITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::heightfield::heightfield_to_mesh;
+
+    #[test]
+    fn geodesic_empty() {
+        assert!(
+            calculate_geodesic_distances::<Vec3A>(&[], &[], &[], GeodesicMethod::Dijkstra)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn geodesic_no_seeds() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        assert_eq!(
+            vec![f32::INFINITY; 3],
+            calculate_geodesic_distances(&positions, &[0, 1, 2], &[], GeodesicMethod::Dijkstra)
+        );
+    }
+
+    #[test]
+    fn geodesic_unreachable_and_welded() {
+        // Two triangles sharing an edge through duplicate vertices and a separate triangle.
+        let positions = [
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+            Vec3A::X,
+            Vec3A::Y,
+            Vec3A::ONE,
+            Vec3A::splat(5.0),
+            Vec3A::splat(6.0),
+            Vec3A::new(5.0, 6.0, 5.0),
+        ];
+        let indices = [0, 1, 2, 4, 3, 5, 6, 7, 8];
+        let distances =
+            calculate_geodesic_distances(&positions, &indices, &[0], GeodesicMethod::FastMarching);
+        assert_eq!(1.0, distances[3]);
+        assert_eq!(1.0, distances[4]);
+        assert!(distances[5].is_finite());
+        assert_eq!(vec![f32::INFINITY; 3], distances[6..]);
+    }
+
+    #[test]
+    fn geodesic_plane() {
+        let mesh = heightfield_to_mesh(&[0.0; 11 * 11], 11, 11, Vec2::ONE);
+        let seed = 5 * 11 + 5;
+
+        let dijkstra = calculate_geodesic_distances(
+            &mesh.positions,
+            &mesh.indices,
+            &[seed],
+            GeodesicMethod::Dijkstra,
+        );
+        let fast_marching = calculate_geodesic_distances(
+            &mesh.positions,
+            &mesh.indices,
+            &[seed],
+            GeodesicMethod::FastMarching,
+        );
+
+        let center = mesh.positions[seed as usize];
+        let mut dijkstra_error = 0.0f32;
+        let mut fast_marching_error = 0.0f32;
+        for ((p, d), f) in mesh.positions.iter().zip(dijkstra).zip(fast_marching) {
+            let expected = p.distance(center);
+            assert!(d >= expected - 0.0001);
+            assert!(f <= d + 0.0001);
+            dijkstra_error = dijkstra_error.max(d - expected);
+            fast_marching_error = fast_marching_error.max((f - expected).abs());
+        }
+        assert!(fast_marching_error < 0.5 * dijkstra_error);
+    }
+}
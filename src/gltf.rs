@@ -0,0 +1,173 @@
+//! Functions for calculating missing geometry data for mesh primitives from the gltf crate.
+//!
+//! This module requires the `gltf` feature.
+
+use glam::{Vec2, Vec3A};
+
+use crate::bounding::calculate_aabb_from_points;
+use crate::vectors::{calculate_smooth_normals, calculate_tangents, TangentBitangentError};
+
+/// The vertex data of a triangle list primitive read by [read_primitive].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PrimitiveGeometry {
+    pub positions: Vec<Vec3A>,
+    /// The `NORMAL` attribute or an empty list if the primitive has no normals.
+    pub normals: Vec<Vec3A>,
+    /// The `TEXCOORD_0` attribute or an empty list if the primitive has no UVs.
+    pub uvs: Vec<Vec2>,
+    /// The triangle list indices, which are generated for non indexed primitives.
+    pub indices: Vec<u32>,
+}
+
+/// Reads the positions, normals, UVs, and indices of `primitive`.
+/// `get_buffer_data` should return the data for each buffer like for `gltf::Primitive::reader`.
+///
+/// Returns `None` if the primitive is not a triangle list or has no float positions.
+pub fn read_primitive<'a, 's, F>(
+    primitive: &'a ::gltf::Primitive<'a>,
+    get_buffer_data: F,
+) -> Option<PrimitiveGeometry>
+where
+    F: Clone + Fn(::gltf::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    if primitive.mode() != ::gltf::mesh::Mode::Triangles {
+        return None;
+    }
+
+    let reader = primitive.reader(get_buffer_data);
+    let positions: Vec<Vec3A> = reader.read_positions()?.map(Vec3A::from_array).collect();
+    let normals = reader
+        .read_normals()
+        .map(|n| n.map(Vec3A::from_array).collect())
+        .unwrap_or_default();
+    let uvs = reader
+        .read_tex_coords(0)
+        .map(|uvs| uvs.into_f32().map(Vec2::from_array).collect())
+        .unwrap_or_default();
+    let indices = reader
+        .read_indices()
+        .map(|indices| indices.into_u32().collect())
+        .unwrap_or_else(|| (0..positions.len() as u32).collect());
+
+    Some(PrimitiveGeometry {
+        positions,
+        normals,
+        uvs,
+        indices,
+    })
+}
+
+impl PrimitiveGeometry {
+    /// Calculates smooth normals for the `NORMAL` attribute using [calculate_smooth_normals].
+    pub fn calculate_normals(&self) -> Vec<[f32; 3]> {
+        calculate_smooth_normals(&self.positions, &self.indices)
+            .into_iter()
+            .map(|n| n.to_array())
+            .collect()
+    }
+
+    /// Calculates tangents for the `TANGENT` attribute using [calculate_tangents].
+    /// The bitangent sign in the fourth component follows the glTF convention.
+    /// Smooth normals are calculated if the primitive has no normals.
+    pub fn calculate_tangents(&self) -> Result<Vec<[f32; 4]>, TangentBitangentError> {
+        let normals = if self.normals.is_empty() {
+            calculate_smooth_normals(&self.positions, &self.indices)
+        } else {
+            self.normals.clone()
+        };
+        let tangents = calculate_tangents(&self.positions, &normals, &self.uvs, &self.indices)?;
+        Ok(tangents.into_iter().map(|t| t.to_array()).collect())
+    }
+
+    /// Calculates the `(min, max)` values for the `POSITION` accessor, which glTF requires.
+    pub fn calculate_bounds(&self) -> ([f32; 3], [f32; 3]) {
+        let (min, max) = calculate_aabb_from_points(&self.positions);
+        (min.to_array(), max.to_array())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(indices: bool, mode: u32) -> (::gltf::Gltf, Vec<u8>) {
+        // A single quad with positions, UVs, and u16 indices.
+        let positions = [
+            [0.0f32, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ];
+        let uvs = [[0.0f32, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]];
+        let index_data = [0u16, 1, 2, 2, 3, 0];
+
+        let mut bytes = Vec::new();
+        bytes.extend(positions.iter().flatten().flat_map(|f| f.to_le_bytes()));
+        bytes.extend(uvs.iter().flatten().flat_map(|f| f.to_le_bytes()));
+        bytes.extend(index_data.iter().flat_map(|i| i.to_le_bytes()));
+
+        let indices = if indices { r#","indices":2"# } else { "" };
+        let json = format!(
+            r#"{{
+                "asset": {{"version": "2.0"}},
+                "buffers": [{{"byteLength": {}}}],
+                "bufferViews": [
+                    {{"buffer": 0, "byteOffset": 0, "byteLength": 48}},
+                    {{"buffer": 0, "byteOffset": 48, "byteLength": 32}},
+                    {{"buffer": 0, "byteOffset": 80, "byteLength": 12}}
+                ],
+                "accessors": [
+                    {{"bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3",
+                      "min": [0, 0, 0], "max": [1, 1, 0]}},
+                    {{"bufferView": 1, "componentType": 5126, "count": 4, "type": "VEC2"}},
+                    {{"bufferView": 2, "componentType": 5123, "count": 6, "type": "SCALAR"}}
+                ],
+                "meshes": [{{"primitives": [{{
+                    "attributes": {{"POSITION": 0, "TEXCOORD_0": 1}},
+                    "mode": {}{}
+                }}]}}]
+            }}"#,
+            bytes.len(),
+            mode,
+            indices
+        );
+        (::gltf::Gltf::from_slice(json.as_bytes()).unwrap(), bytes)
+    }
+
+    #[test]
+    fn read_indexed_primitive() {
+        let (gltf, bytes) = document(true, 4);
+        let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+        let geometry = read_primitive(&primitive, |_| Some(&bytes)).unwrap();
+
+        assert_eq!(4, geometry.positions.len());
+        assert!(geometry.normals.is_empty());
+        assert_eq!(Vec2::new(0.0, 1.0), geometry.uvs[0]);
+        assert_eq!(vec![0, 1, 2, 2, 3, 0], geometry.indices);
+
+        assert_eq!(vec![[0.0, 0.0, 1.0]; 4], geometry.calculate_normals());
+        assert_eq!(([0.0; 3], [1.0, 1.0, 0.0]), geometry.calculate_bounds());
+
+        let tangents = geometry.calculate_tangents().unwrap();
+        assert_eq!(4, tangents.len());
+        for [x, y, z, _] in tangents {
+            assert!((x - 1.0).abs() < 0.0001 && y.abs() < 0.0001 && z.abs() < 0.0001);
+        }
+    }
+
+    #[test]
+    fn read_non_indexed_primitive() {
+        let (gltf, bytes) = document(false, 4);
+        let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+        let geometry = read_primitive(&primitive, |_| Some(&bytes)).unwrap();
+        assert_eq!(vec![0, 1, 2, 3], geometry.indices);
+    }
+
+    #[test]
+    fn read_unsupported_mode() {
+        // Triangle strips are not supported.
+        let (gltf, bytes) = document(true, 5);
+        let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+        assert_eq!(None, read_primitive(&primitive, |_| Some(&bytes)));
+    }
+}
@@ -0,0 +1,520 @@
+//! Compute shader implementations of smooth normals and tangents using [wgpu](https://crates.io/crates/wgpu).
+//!
+//! This module requires the `gpu` feature.
+//! Each function falls back to the CPU implementation if no adapter is available,
+//! the mesh is too small to benefit from the GPU, or the GPU calculation fails.
+//! The GPU results match the CPU results up to small floating point differences.
+
+use glam::{Vec2, Vec3A, Vec4};
+use wgpu::util::DeviceExt;
+
+use crate::topology::MeshAdjacency;
+use crate::vectors::{calculate_smooth_normals, calculate_tangents, TangentBitangentError};
+
+/// The default value for [Gpu::with_min_face_count].
+pub const DEFAULT_MIN_FACE_COUNT: usize = 100_000;
+
+const WORKGROUP_SIZE: u32 = 256;
+
+/// Calculates smooth normals and tangents on the GPU with automatic fallback to the CPU.
+/// # Examples
+/**
+```rust no_run
+use geometry_tools::gpu::Gpu;
+use glam::{Vec2, Vec3A, Vec4};
+
+let gpu = Gpu::new();
+
+let positions = vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let uvs = vec![Vec2::ZERO, Vec2::X, Vec2::Y];
+let indices = vec![0, 1, 2];
+
+let normals = gpu.calculate_smooth_normals(&positions, &indices);
+assert_eq!(vec![Vec3A::Z; 3], normals);
+
+let tangents = gpu.calculate_tangents(&positions, &normals, &uvs, &indices).unwrap();
+assert_eq!(vec![Vec4::new(1.0, 0.0, 0.0, 1.0); 3], tangents);
+```
+ */
+pub struct Gpu {
+    context: Option<GpuContext>,
+    min_face_count: usize,
+}
+
+struct GpuContext {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    face_normals: wgpu::ComputePipeline,
+    vertex_normals: wgpu::ComputePipeline,
+    face_tangents: wgpu::ComputePipeline,
+    vertex_tangents: wgpu::ComputePipeline,
+}
+
+impl Default for Gpu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Gpu {
+    /// Creates a device using the default high performance adapter.
+    /// All calculations use the CPU if no adapter or device is available.
+    pub fn new() -> Self {
+        Self {
+            context: GpuContext::new(),
+            min_face_count: DEFAULT_MIN_FACE_COUNT,
+        }
+    }
+
+    /// Creates an instance that always uses the CPU.
+    pub fn cpu() -> Self {
+        Self {
+            context: None,
+            min_face_count: DEFAULT_MIN_FACE_COUNT,
+        }
+    }
+
+    /// Returns `true` if calculations for large enough meshes will run on the GPU.
+    pub fn is_gpu(&self) -> bool {
+        self.context.is_some()
+    }
+
+    /// Sets the minimum number of faces for using the GPU.
+    /// Smaller meshes use the CPU since the cost of transferring the data outweighs the faster calculation.
+    pub fn with_min_face_count(mut self, min_face_count: usize) -> Self {
+        self.min_face_count = min_face_count;
+        self
+    }
+
+    /// Calculates smooth per-vertex normals like [calculate_smooth_normals].
+    ///
+    /// # Panics
+    /// Panics if any index is out of range for `positions`.
+    pub fn calculate_smooth_normals<P>(&self, positions: &[P], indices: &[u32]) -> Vec<Vec3A>
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        if positions.is_empty() || indices.is_empty() {
+            return Vec::new();
+        }
+
+        self.context(indices)
+            .and_then(|context| context.calculate_smooth_normals(positions, indices))
+            .unwrap_or_else(|| calculate_smooth_normals(positions, indices))
+    }
+
+    /// Calculates smooth per-vertex tangents like [calculate_tangents].
+    ///
+    /// # Panics
+    /// Panics if any index is out of range for `positions`.
+    pub fn calculate_tangents<P, N>(
+        &self,
+        positions: &[P],
+        normals: &[N],
+        uvs: &[Vec2],
+        indices: &[u32],
+    ) -> Result<Vec<Vec4>, TangentBitangentError>
+    where
+        P: Into<Vec3A> + Copy,
+        N: Into<Vec3A> + Copy,
+    {
+        // Check the inputs on the CPU to return the same errors.
+        let is_valid = positions.len() == normals.len()
+            && normals.len() == uvs.len()
+            && indices.len().is_multiple_of(3)
+            && !positions.is_empty()
+            && !indices.is_empty();
+        if !is_valid {
+            return calculate_tangents(positions, normals, uvs, indices);
+        }
+
+        match self
+            .context(indices)
+            .and_then(|context| context.calculate_tangents(positions, normals, uvs, indices))
+        {
+            Some(tangents) => Ok(tangents),
+            None => calculate_tangents(positions, normals, uvs, indices),
+        }
+    }
+
+    fn context(&self, indices: &[u32]) -> Option<&GpuContext> {
+        self.context
+            .as_ref()
+            .filter(|_| indices.len() / 3 >= self.min_face_count)
+    }
+}
+
+impl GpuContext {
+    fn new() -> Option<Self> {
+        let instance = wgpu::Instance::default();
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))?;
+
+        // Large meshes need the highest supported buffer sizes.
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("geometry_tools"),
+                required_features: wgpu::Features::empty(),
+                required_limits: adapter.limits(),
+                memory_hints: wgpu::MemoryHints::Performance,
+            },
+            None,
+        ))
+        .ok()?;
+
+        let normals = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("normals"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("gpu/normals.wgsl").into()),
+        });
+        let tangents = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("tangents"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("gpu/tangents.wgsl").into()),
+        });
+
+        Some(Self {
+            face_normals: compute_pipeline(&device, &normals, "calculate_face_normals"),
+            vertex_normals: compute_pipeline(&device, &normals, "calculate_vertex_normals"),
+            face_tangents: compute_pipeline(&device, &tangents, "calculate_face_tangents"),
+            vertex_tangents: compute_pipeline(&device, &tangents, "calculate_vertex_tangents"),
+            device,
+            queue,
+        })
+    }
+
+    fn calculate_smooth_normals<P>(&self, positions: &[P], indices: &[u32]) -> Option<Vec<Vec3A>>
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        let indices = &indices[..indices.len() / 3 * 3];
+        let face_count = indices.len() / 3;
+        if face_count == 0 {
+            return None;
+        }
+        let adjacency = MeshAdjacency::new(positions.len(), indices);
+        let (offsets, vertex_faces) = adjacency.vertex_face_offsets();
+
+        let sizes = [
+            positions.len() * 16,
+            face_count * 16,
+            indices.len() * 4,
+            vertex_faces.len() * 4,
+        ];
+        if !self.supports(&sizes, positions.len().max(face_count)) {
+            return None;
+        }
+
+        let positions = self.storage_buffer(&vec4_arrays(positions));
+        let indices = self.storage_buffer(indices);
+        let face_normals = self.output_buffer(face_count * 16);
+        let offsets = self.storage_buffer(offsets);
+        let vertex_faces = self.storage_buffer(vertex_faces);
+        let normals = self.output_buffer(sizes[0]);
+
+        let output = self.run(
+            &[
+                Dispatch {
+                    pipeline: &self.face_normals,
+                    buffers: &[(0, &positions), (1, &indices), (2, &face_normals)],
+                    count: face_count,
+                },
+                Dispatch {
+                    pipeline: &self.vertex_normals,
+                    buffers: &[
+                        (3, &face_normals),
+                        (4, &offsets),
+                        (5, &vertex_faces),
+                        (6, &normals),
+                    ],
+                    count: sizes[0] / 16,
+                },
+            ],
+            &normals,
+        )?;
+        Some(
+            output
+                .into_iter()
+                .map(|n| Vec3A::from_vec4(n.into()))
+                .collect(),
+        )
+    }
+
+    fn calculate_tangents<P, N>(
+        &self,
+        positions: &[P],
+        normals: &[N],
+        uvs: &[Vec2],
+        indices: &[u32],
+    ) -> Option<Vec<Vec4>>
+    where
+        P: Into<Vec3A> + Copy,
+        N: Into<Vec3A> + Copy,
+    {
+        let face_count = indices.len() / 3;
+        let adjacency = MeshAdjacency::new(positions.len(), indices);
+        let (offsets, vertex_faces) = adjacency.vertex_face_offsets();
+
+        let sizes = [
+            positions.len() * 16,
+            face_count * 16,
+            indices.len() * 4,
+            vertex_faces.len() * 4,
+        ];
+        if !self.supports(&sizes, positions.len().max(face_count)) {
+            return None;
+        }
+
+        let uvs: Vec<[f32; 2]> = uvs.iter().map(|uv| uv.to_array()).collect();
+
+        let positions = self.storage_buffer(&vec4_arrays(positions));
+        let uvs = self.storage_buffer(&uvs);
+        let indices = self.storage_buffer(indices);
+        let face_tangents = self.output_buffer(face_count * 16);
+        let face_bitangents = self.output_buffer(face_count * 16);
+        let offsets = self.storage_buffer(offsets);
+        let vertex_faces = self.storage_buffer(vertex_faces);
+        let normals = self.storage_buffer(&vec4_arrays(normals));
+        let tangents = self.output_buffer(sizes[0]);
+
+        let output = self.run(
+            &[
+                Dispatch {
+                    pipeline: &self.face_tangents,
+                    buffers: &[
+                        (0, &positions),
+                        (1, &uvs),
+                        (2, &indices),
+                        (3, &face_tangents),
+                        (4, &face_bitangents),
+                    ],
+                    count: face_count,
+                },
+                Dispatch {
+                    pipeline: &self.vertex_tangents,
+                    buffers: &[
+                        (5, &face_tangents),
+                        (6, &face_bitangents),
+                        (7, &offsets),
+                        (8, &vertex_faces),
+                        (9, &normals),
+                        (10, &tangents),
+                    ],
+                    count: sizes[0] / 16,
+                },
+            ],
+            &tangents,
+        )?;
+        Some(output.into_iter().map(Vec4::from).collect())
+    }
+
+    fn supports(&self, buffer_sizes: &[usize], invocation_count: usize) -> bool {
+        let limits = self.device.limits();
+        let max_size = (limits.max_storage_buffer_binding_size as u64).min(limits.max_buffer_size);
+        let max_workgroups = limits.max_compute_workgroups_per_dimension as usize;
+        let workgroups = invocation_count.div_ceil(WORKGROUP_SIZE as usize);
+
+        buffer_sizes.iter().all(|size| *size as u64 <= max_size)
+            && workgroups <= max_workgroups * max_workgroups
+    }
+
+    fn storage_buffer<T: bytemuck::Pod>(&self, data: &[T]) -> wgpu::Buffer {
+        self.device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents: bytemuck::cast_slice(data),
+                usage: wgpu::BufferUsages::STORAGE,
+            })
+    }
+
+    fn output_buffer(&self, size: usize) -> wgpu::Buffer {
+        self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: size as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Runs the dispatches in order and reads back `output`.
+    /// Returns `None` if any GPU operation fails.
+    fn run(&self, dispatches: &[Dispatch], output: &wgpu::Buffer) -> Option<Vec<[f32; 4]>> {
+        self.device.push_error_scope(wgpu::ErrorFilter::OutOfMemory);
+        self.device.push_error_scope(wgpu::ErrorFilter::Validation);
+
+        let max_workgroups = self.device.limits().max_compute_workgroups_per_dimension;
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        for dispatch in dispatches {
+            let entries: Vec<_> = dispatch
+                .buffers
+                .iter()
+                .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                    binding: *binding,
+                    resource: buffer.as_entire_binding(),
+                })
+                .collect();
+            let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &dispatch.pipeline.get_bind_group_layout(0),
+                entries: &entries,
+            });
+
+            // Use the y dimension for invocations beyond the workgroup limit.
+            let workgroups = (dispatch.count as u32).div_ceil(WORKGROUP_SIZE);
+            let x = workgroups.min(max_workgroups);
+            let y = workgroups.div_ceil(x);
+
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(dispatch.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(x, y, 1);
+        }
+
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: output.size(),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        encoder.copy_buffer_to_buffer(output, 0, &staging, 0, output.size());
+        self.queue.submit([encoder.finish()]);
+
+        let validation_error = pollster::block_on(self.device.pop_error_scope());
+        let memory_error = pollster::block_on(self.device.pop_error_scope());
+        if validation_error.is_some() || memory_error.is_some() {
+            return None;
+        }
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let slice = staging.slice(..);
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver.recv().ok()?.ok()?;
+
+        // Mapped memory is not guaranteed to be aligned for f32.
+        let data = slice
+            .get_mapped_range()
+            .chunks_exact(16)
+            .map(|bytes| {
+                let mut value = [0.0f32; 4];
+                bytemuck::bytes_of_mut(&mut value).copy_from_slice(bytes);
+                value
+            })
+            .collect();
+        staging.unmap();
+        Some(data)
+    }
+}
+
+struct Dispatch<'a> {
+    pipeline: &'a wgpu::ComputePipeline,
+    buffers: &'a [(u32, &'a wgpu::Buffer)],
+    count: usize,
+}
+
+fn compute_pipeline(
+    device: &wgpu::Device,
+    module: &wgpu::ShaderModule,
+    entry_point: &str,
+) -> wgpu::ComputePipeline {
+    device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some(entry_point),
+        layout: None,
+        module,
+        entry_point,
+        compilation_options: Default::default(),
+        cache: None,
+    })
+}
+
+fn vec4_arrays<P>(points: &[P]) -> Vec<[f32; 4]>
+where
+    P: Into<Vec3A> + Copy,
+{
+    points
+        .iter()
+        .map(|p| (*p).into().extend(0.0).to_array())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    fn assert_normals_eq(expected: &[Vec3A], actual: &[Vec3A]) {
+        assert_eq!(expected.len(), actual.len());
+        for (e, a) in expected.iter().zip(actual) {
+            assert_relative_eq!(e.x, a.x, epsilon = 0.0001);
+            assert_relative_eq!(e.y, a.y, epsilon = 0.0001);
+            assert_relative_eq!(e.z, a.z, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn cpu_fallback() {
+        let mesh = crate::shapes::generate_uv_sphere(1.0, 16, 8);
+        let gpu = Gpu::cpu();
+        assert!(!gpu.is_gpu());
+
+        let normals = gpu.calculate_smooth_normals(&mesh.positions, &mesh.indices);
+        assert_eq!(
+            calculate_smooth_normals(&mesh.positions, &mesh.indices),
+            normals
+        );
+        assert_eq!(
+            calculate_tangents(&mesh.positions, &normals, &mesh.uvs, &mesh.indices).unwrap(),
+            gpu.calculate_tangents(&mesh.positions, &normals, &mesh.uvs, &mesh.indices)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn tangents_invalid_input() {
+        let gpu = Gpu::cpu();
+        let result = gpu.calculate_tangents(
+            &[Vec3A::ZERO; 3],
+            &[Vec3A::Z; 2],
+            &[Vec2::ZERO; 3],
+            &[0, 1, 2],
+        );
+        assert!(matches!(
+            result,
+            Err(TangentBitangentError::AttributeCountMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn gpu_matches_cpu() {
+        // Skip the test if there is no supported adapter.
+        let gpu = Gpu::new().with_min_face_count(0);
+        if !gpu.is_gpu() {
+            return;
+        }
+
+        let mesh = crate::shapes::generate_uv_sphere(1.0, 64, 32);
+        let normals = gpu.calculate_smooth_normals(&mesh.positions, &mesh.indices);
+        assert_normals_eq(
+            &calculate_smooth_normals(&mesh.positions, &mesh.indices),
+            &normals,
+        );
+
+        let expected =
+            calculate_tangents(&mesh.positions, &normals, &mesh.uvs, &mesh.indices).unwrap();
+        let tangents = gpu
+            .calculate_tangents(&mesh.positions, &normals, &mesh.uvs, &mesh.indices)
+            .unwrap();
+        for (e, a) in expected.iter().zip(&tangents) {
+            assert_relative_eq!(e.x, a.x, epsilon = 0.0001);
+            assert_relative_eq!(e.y, a.y, epsilon = 0.0001);
+            assert_relative_eq!(e.z, a.z, epsilon = 0.0001);
+            assert_eq!(e.w, a.w);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+// Smooth normals are calculated in two passes to avoid floating point atomics.
+// The first pass calculates a normal for each face.
+// The second pass sums the face normals for each vertex in increasing order of face index.
+
+@group(0) @binding(0) var<storage, read> positions: array<vec4<f32>>;
+@group(0) @binding(1) var<storage, read> indices: array<u32>;
+@group(0) @binding(2) var<storage, read_write> face_normals: array<vec4<f32>>;
+
+@group(0) @binding(3) var<storage, read> face_normals_in: array<vec4<f32>>;
+@group(0) @binding(4) var<storage, read> vertex_face_offsets: array<u32>;
+@group(0) @binding(5) var<storage, read> vertex_faces: array<u32>;
+@group(0) @binding(6) var<storage, read_write> normals: array<vec4<f32>>;
+
+const WORKGROUP_SIZE: u32 = 256u;
+const F32_MAX: f32 = 3.40282347e38;
+
+// Dispatches with more than 65535 workgroups use the y dimension.
+fn invocation_index(id: vec3<u32>, workgroups: vec3<u32>) -> u32 {
+    return id.x + id.y * workgroups.x * WORKGROUP_SIZE;
+}
+
+// Matches glam's normalize_or_zero.
+fn normalize_or_zero(v: vec3<f32>) -> vec3<f32> {
+    let rcp = 1.0 / length(v);
+    if rcp > 0.0 && rcp <= F32_MAX {
+        return v * rcp;
+    }
+    return vec3<f32>(0.0);
+}
+
+@compute @workgroup_size(256)
+fn calculate_face_normals(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) workgroups: vec3<u32>,
+) {
+    let f = invocation_index(id, workgroups);
+    if f >= arrayLength(&face_normals) {
+        return;
+    }
+
+    let v0 = positions[indices[f * 3u]].xyz;
+    let v1 = positions[indices[f * 3u + 1u]].xyz;
+    let v2 = positions[indices[f * 3u + 2u]].xyz;
+    face_normals[f] = vec4<f32>(cross(v1 - v0, v2 - v0), 0.0);
+}
+
+@compute @workgroup_size(256)
+fn calculate_vertex_normals(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) workgroups: vec3<u32>,
+) {
+    let v = invocation_index(id, workgroups);
+    if v >= arrayLength(&normals) {
+        return;
+    }
+
+    var normal = vec3<f32>(0.0);
+    for (var i = vertex_face_offsets[v]; i < vertex_face_offsets[v + 1u]; i++) {
+        normal += face_normals_in[vertex_faces[i]].xyz;
+    }
+    normals[v] = vec4<f32>(normalize_or_zero(normal), 0.0);
+}
@@ -0,0 +1,115 @@
+// Tangents are calculated in two passes like smooth normals.
+// The first pass calculates a tangent and bitangent for each face.
+// The second pass sums the face vectors for each vertex in increasing order of face index.
+
+@group(0) @binding(0) var<storage, read> positions: array<vec4<f32>>;
+@group(0) @binding(1) var<storage, read> uvs: array<vec2<f32>>;
+@group(0) @binding(2) var<storage, read> indices: array<u32>;
+@group(0) @binding(3) var<storage, read_write> face_tangents: array<vec4<f32>>;
+@group(0) @binding(4) var<storage, read_write> face_bitangents: array<vec4<f32>>;
+
+@group(0) @binding(5) var<storage, read> face_tangents_in: array<vec4<f32>>;
+@group(0) @binding(6) var<storage, read> face_bitangents_in: array<vec4<f32>>;
+@group(0) @binding(7) var<storage, read> vertex_face_offsets: array<u32>;
+@group(0) @binding(8) var<storage, read> vertex_faces: array<u32>;
+@group(0) @binding(9) var<storage, read> normals: array<vec4<f32>>;
+@group(0) @binding(10) var<storage, read_write> tangents: array<vec4<f32>>;
+
+const WORKGROUP_SIZE: u32 = 256u;
+const F32_MAX: f32 = 3.40282347e38;
+const DEFAULT_TANGENT: vec3<f32> = vec3<f32>(1.0, 0.0, 0.0);
+const DEFAULT_BITANGENT: vec3<f32> = vec3<f32>(0.0, 1.0, 0.0);
+
+// Dispatches with more than 65535 workgroups use the y dimension.
+fn invocation_index(id: vec3<u32>, workgroups: vec3<u32>) -> u32 {
+    return id.x + id.y * workgroups.x * WORKGROUP_SIZE;
+}
+
+// Matches glam's normalize_or_zero.
+fn normalize_or_zero(v: vec3<f32>) -> vec3<f32> {
+    let rcp = 1.0 / length(v);
+    if rcp > 0.0 && rcp <= F32_MAX {
+        return v * rcp;
+    }
+    return vec3<f32>(0.0);
+}
+
+fn or_default(v: vec3<f32>, default_value: vec3<f32>) -> vec3<f32> {
+    if dot(v, v) == 0.0 {
+        return default_value;
+    }
+    return v;
+}
+
+@compute @workgroup_size(256)
+fn calculate_face_tangents(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) workgroups: vec3<u32>,
+) {
+    let f = invocation_index(id, workgroups);
+    if f >= arrayLength(&face_tangents) {
+        return;
+    }
+
+    let i0 = indices[f * 3u];
+    let i1 = indices[f * 3u + 1u];
+    let i2 = indices[f * 3u + 2u];
+
+    let pos_a = positions[i1].xyz - positions[i0].xyz;
+    let pos_b = positions[i2].xyz - positions[i0].xyz;
+    let uv_a = uvs[i1] - uvs[i0];
+    let uv_b = uvs[i2] - uvs[i0];
+
+    // Fix +/- infinity from division by zero.
+    let div = uv_a.x * uv_b.y - uv_b.x * uv_a.y;
+    var r = 1.0;
+    if div != 0.0 {
+        r = 1.0 / div;
+    }
+
+    let tangent = (pos_a * uv_b.y - pos_b * uv_a.y) * r;
+    let bitangent = (pos_b * uv_a.x - pos_a * uv_b.x) * r;
+    face_tangents[f] = vec4<f32>(or_default(tangent, DEFAULT_TANGENT), 0.0);
+    face_bitangents[f] = vec4<f32>(or_default(bitangent, DEFAULT_BITANGENT), 0.0);
+}
+
+@compute @workgroup_size(256)
+fn calculate_vertex_tangents(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) workgroups: vec3<u32>,
+) {
+    let v = invocation_index(id, workgroups);
+    if v >= arrayLength(&tangents) {
+        return;
+    }
+
+    var tangent = vec3<f32>(0.0);
+    var bitangent = vec3<f32>(0.0);
+    for (var i = vertex_face_offsets[v]; i < vertex_face_offsets[v + 1u]; i++) {
+        let f = vertex_faces[i];
+        tangent += face_tangents_in[f].xyz;
+        bitangent += face_bitangents_in[f].xyz;
+    }
+
+    // Even if the vectors are not zero, they may still sum to zero.
+    tangent = or_default(tangent, DEFAULT_TANGENT);
+    bitangent = or_default(bitangent, DEFAULT_BITANGENT);
+
+    // Account for mirrored normal maps.
+    // The default bitangent may be parallel to the normal vector.
+    let normal = normals[v].xyz;
+    let b_cross_n = cross(bitangent, normal);
+    if dot(b_cross_n, b_cross_n) != 0.0 {
+        bitangent = normalize(bitangent - normal * dot(normal, bitangent));
+    }
+
+    tangent = normalize_or_zero(tangent);
+    bitangent = normalize_or_zero(bitangent);
+
+    // 0.0 should still return 1.0 to avoid generating black bitangents.
+    var w = -1.0;
+    if dot(cross(tangent, bitangent), normal) >= 0.0 {
+        w = 1.0;
+    }
+    tangents[v] = vec4<f32>(tangent, w);
+}
@@ -0,0 +1,170 @@
+//! Functions for calculating stable hashes of mesh data for caching.
+
+use crate::mesh::MeshRef;
+
+/// Calculates a 64-bit hash of the attributes and indices of `mesh`
+/// that is the same across platforms, compiler versions, and program runs.
+///
+/// If `tolerance` is `Some`, each component is rounded to the nearest multiple of `tolerance` before hashing,
+/// so small differences like floating point noise from reexporting do not change the hash.
+/// Values near the midpoint between two multiples may still hash differently.
+///
+/// Positive and negative zero hash the same, and all `NaN` values hash the same.
+/// # Examples
+/**
+```rust
+use geometry_tools::hash::hash_mesh;
+use geometry_tools::mesh::MeshRef;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let mesh = MeshRef {
+    positions: &positions,
+    indices: &[0, 1, 2],
+    ..Default::default()
+};
+
+let moved = [Vec3A::ZERO, Vec3A::X, Vec3A::new(0.0, 1.00001, 0.0)];
+let moved_mesh = MeshRef {
+    positions: &moved,
+    ..mesh
+};
+assert_ne!(hash_mesh(&mesh, None), hash_mesh(&moved_mesh, None));
+assert_eq!(hash_mesh(&mesh, Some(0.001)), hash_mesh(&moved_mesh, Some(0.001)));
+```
+ */
+pub fn hash_mesh(mesh: &MeshRef, tolerance: Option<f32>) -> u64 {
+    let mut hasher = Fnv1a::new();
+
+    // Include each length to distinguish missing attributes and different splits of the same values.
+    let mut write_floats = |values: &mut dyn Iterator<Item = f32>, len: usize| {
+        hasher.write_u64(len as u64);
+        for value in values {
+            hasher.write_u64(float_key(value, tolerance));
+        }
+    };
+    write_floats(
+        &mut mesh.positions.iter().flat_map(|v| v.to_array()),
+        mesh.positions.len(),
+    );
+    write_floats(
+        &mut mesh.normals.iter().flat_map(|v| v.to_array()),
+        mesh.normals.len(),
+    );
+    write_floats(
+        &mut mesh.tangents.iter().flat_map(|v| v.to_array()),
+        mesh.tangents.len(),
+    );
+    write_floats(
+        &mut mesh.uvs.iter().flat_map(|v| v.to_array()),
+        mesh.uvs.len(),
+    );
+    write_floats(
+        &mut mesh.colors.iter().flat_map(|v| v.to_array()),
+        mesh.colors.len(),
+    );
+
+    hasher.write_u64(mesh.indices.len() as u64);
+    for index in mesh.indices {
+        hasher.write_u64(*index as u64);
+    }
+
+    hasher.finish()
+}
+
+fn float_key(value: f32, tolerance: Option<f32>) -> u64 {
+    if value.is_nan() {
+        return u64::MAX;
+    }
+    match tolerance {
+        Some(tolerance) if tolerance > 0.0 => {
+            let rounded = (value as f64 / tolerance as f64).round();
+            // Adding zero converts -0.0 to 0.0.
+            (rounded + 0.0).to_bits()
+        }
+        _ => ((value + 0.0) as f64).to_bits(),
+    }
+}
+
+/// The 64-bit FNV-1a hash, which has a fixed and documented output unlike the standard library hashers.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        for byte in value.to_le_bytes() {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use glam::{Vec2, Vec3A};
+
+    #[test]
+    fn hash_empty_is_stable() {
+        // Changing this value invalidates all existing caches.
+        assert_eq!(0xa09d945a1cd8d6e5, hash_mesh(&MeshRef::default(), None));
+    }
+
+    #[test]
+    fn hash_attributes_are_distinguished() {
+        let values = [Vec3A::X];
+        let positions = MeshRef {
+            positions: &values,
+            ..Default::default()
+        };
+        let normals = MeshRef {
+            normals: &values,
+            ..Default::default()
+        };
+        assert_ne!(hash_mesh(&positions, None), hash_mesh(&normals, None));
+
+        let uvs = [Vec2::ZERO];
+        let with_uvs = MeshRef {
+            uvs: &uvs,
+            ..positions
+        };
+        assert_ne!(hash_mesh(&positions, None), hash_mesh(&with_uvs, None));
+    }
+
+    #[test]
+    fn hash_indices() {
+        let a = MeshRef {
+            indices: &[0, 1, 2],
+            ..Default::default()
+        };
+        let b = MeshRef {
+            indices: &[0, 2, 1],
+            ..Default::default()
+        };
+        assert_ne!(hash_mesh(&a, None), hash_mesh(&b, None));
+    }
+
+    #[test]
+    fn hash_signed_zero_and_nan() {
+        let a = [Vec3A::new(0.0, f32::NAN, 1.0)];
+        let b = [Vec3A::new(-0.0, -f32::NAN, 1.0)];
+        let hash = |positions| {
+            hash_mesh(
+                &MeshRef {
+                    positions,
+                    ..Default::default()
+                },
+                None,
+            )
+        };
+        assert_eq!(hash(&a), hash(&b));
+    }
+}
@@ -0,0 +1,151 @@
+//! Functions for generating terrain meshes from heightfields.
+
+use glam::{Vec2, Vec3A};
+
+use crate::mesh::Mesh;
+
+/// Generates a grid mesh with Y up from the `width` by `depth` samples in `heights` with x varying fastest.
+/// Adjacent samples are `spacing.x` apart along the x-axis and `spacing.y` apart along the z-axis,
+/// and the mesh starts at the origin.
+///
+/// The normals are calculated from the slope of the heightfield using central differences
+/// or one sided differences on the borders.
+/// The texture coordinates range from `0.0` to `1.0` across the heightfield.
+/// The triangles use counterclockwise winding when viewed from above.
+/// # Examples
+/**
+```rust
+use geometry_tools::heightfield::heightfield_to_mesh;
+use glam::{Vec2, Vec3A};
+
+let heights = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+let mesh = heightfield_to_mesh(&heights, 3, 2, Vec2::new(0.5, 1.0));
+
+assert_eq!(6, mesh.positions.len());
+assert_eq!(Vec3A::new(1.0, 1.0, 1.0), mesh.positions[5]);
+assert_eq!(Vec2::ONE, mesh.uvs[5]);
+assert_eq!(2 * 2 * 3, mesh.indices.len());
+```
+ */
+pub fn heightfield_to_mesh(heights: &[f32], width: usize, depth: usize, spacing: Vec2) -> Mesh {
+    if width == 0 || depth == 0 {
+        return Mesh::default();
+    }
+
+    let height = |x: usize, z: usize| heights[z * width + x];
+
+    let mut positions = Vec::with_capacity(width * depth);
+    let mut normals = Vec::with_capacity(width * depth);
+    let mut uvs = Vec::with_capacity(width * depth);
+    for z in 0..depth {
+        for x in 0..width {
+            positions.push(Vec3A::new(
+                x as f32 * spacing.x,
+                height(x, z),
+                z as f32 * spacing.y,
+            ));
+
+            // Use central differences where possible.
+            let (x0, x1) = (x.saturating_sub(1), (x + 1).min(width - 1));
+            let (z0, z1) = (z.saturating_sub(1), (z + 1).min(depth - 1));
+            let dx = if x1 > x0 {
+                (height(x1, z) - height(x0, z)) / ((x1 - x0) as f32 * spacing.x)
+            } else {
+                0.0
+            };
+            let dz = if z1 > z0 {
+                (height(x, z1) - height(x, z0)) / ((z1 - z0) as f32 * spacing.y)
+            } else {
+                0.0
+            };
+            normals.push(Vec3A::new(-dx, 1.0, -dz).normalize());
+
+            uvs.push(Vec2::new(
+                x as f32 / (width - 1).max(1) as f32,
+                z as f32 / (depth - 1).max(1) as f32,
+            ));
+        }
+    }
+
+    let mut indices = Vec::with_capacity((width - 1) * (depth - 1) * 6);
+    for z in 0..depth - 1 {
+        for x in 0..width - 1 {
+            let v00 = (z * width + x) as u32;
+            let v10 = v00 + 1;
+            let v01 = v00 + width as u32;
+            let v11 = v01 + 1;
+            indices.extend_from_slice(&[v00, v01, v10, v10, v01, v11]);
+        }
+    }
+
+    Mesh {
+        positions,
+        normals,
+        uvs,
+        indices,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn heightfield_empty() {
+        assert_eq!(Mesh::default(), heightfield_to_mesh(&[], 0, 0, Vec2::ONE));
+        assert_eq!(Mesh::default(), heightfield_to_mesh(&[], 4, 0, Vec2::ONE));
+    }
+
+    #[test]
+    fn heightfield_single_sample() {
+        let mesh = heightfield_to_mesh(&[2.0], 1, 1, Vec2::ONE);
+        assert_eq!(vec![Vec3A::new(0.0, 2.0, 0.0)], mesh.positions);
+        assert_eq!(vec![Vec3A::Y], mesh.normals);
+        assert_eq!(vec![Vec2::ZERO], mesh.uvs);
+        assert!(mesh.indices.is_empty());
+    }
+
+    #[test]
+    fn heightfield_slope_normals() {
+        // A plane rising along x with a slope of 1.
+        let heights: Vec<_> = (0..16).map(|i| (i % 4) as f32 * 2.0).collect();
+        let mesh = heightfield_to_mesh(&heights, 4, 4, Vec2::new(2.0, 1.0));
+        let expected = Vec3A::new(-1.0, 1.0, 0.0).normalize();
+        for normal in &mesh.normals {
+            assert!(normal.abs_diff_eq(expected, EPSILON));
+        }
+
+        // The analytic normals should match the face normals for a plane.
+        for face in mesh.indices.chunks_exact(3) {
+            let [v0, v1, v2] = [face[0], face[1], face[2]].map(|i| mesh.positions[i as usize]);
+            let face_normal = (v1 - v0).cross(v2 - v0).normalize();
+            assert!(face_normal.abs_diff_eq(expected, EPSILON));
+        }
+    }
+
+    #[test]
+    fn heightfield_smooth_normals() {
+        let heights = [0.0, 1.0, 4.0, 0.0, 1.0, 4.0];
+        let mesh = heightfield_to_mesh(&heights, 3, 2, Vec2::ONE);
+        assert_relative_eq!(
+            -1.0,
+            mesh.normals[0].x / mesh.normals[0].y,
+            epsilon = EPSILON
+        );
+        assert_relative_eq!(
+            -2.0,
+            mesh.normals[1].x / mesh.normals[1].y,
+            epsilon = EPSILON
+        );
+        assert_relative_eq!(
+            -3.0,
+            mesh.normals[2].x / mesh.normals[2].y,
+            epsilon = EPSILON
+        );
+    }
+}
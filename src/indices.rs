@@ -0,0 +1,298 @@
+//! Functions for converting and generating vertex index buffers.
+use std::collections::HashMap;
+
+use glam::Vec3A;
+
+use crate::topology::{edge_key, is_degenerate};
+
+/// A vertex index buffer using the smallest integer type that can store every index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexBuffer {
+    U8(Vec<u8>),
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl IndexBuffer {
+    /// The number of indices in the buffer.
+    pub fn len(&self) -> usize {
+        match self {
+            IndexBuffer::U8(indices) => indices.len(),
+            IndexBuffer::U16(indices) => indices.len(),
+            IndexBuffer::U32(indices) => indices.len(),
+        }
+    }
+
+    /// Returns `true` if the buffer contains no indices.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The size in bytes of a single index.
+    pub fn index_size(&self) -> usize {
+        match self {
+            IndexBuffer::U8(_) => 1,
+            IndexBuffer::U16(_) => 2,
+            IndexBuffer::U32(_) => 4,
+        }
+    }
+
+    /// Converts the indices back to `u32`.
+    pub fn to_u32(&self) -> Vec<u32> {
+        match self {
+            IndexBuffer::U8(indices) => indices.iter().map(|i| *i as u32).collect(),
+            IndexBuffer::U16(indices) => indices.iter().map(|i| *i as u32).collect(),
+            IndexBuffer::U32(indices) => indices.clone(),
+        }
+    }
+}
+
+/// Converts `indices` to the smallest index type that can represent the largest index.
+/// An empty buffer is converted to [IndexBuffer::U8].
+///
+/// Some graphics APIs reserve the maximum value of the index type for primitive restart.
+/// Use [narrow_indices_with_restart] if the maximum value should not be used by any index.
+/// # Examples
+/**
+```rust
+use geometry_tools::indices::{narrow_indices, IndexBuffer};
+
+assert_eq!(IndexBuffer::U8(vec![0, 1, 255]), narrow_indices(vec![0, 1, 255]));
+assert_eq!(IndexBuffer::U16(vec![0, 1, 256]), narrow_indices(vec![0, 1, 256]));
+assert_eq!(IndexBuffer::U32(vec![0, 1, 65536]), narrow_indices(vec![0, 1, 65536]));
+```
+ */
+pub fn narrow_indices(indices: Vec<u32>) -> IndexBuffer {
+    narrow(indices, 0)
+}
+
+/// Converts `indices` to the smallest index type that can represent the largest index
+/// without using the maximum value of that type, which is reserved for primitive restart.
+/// # Examples
+/**
+```rust
+use geometry_tools::indices::{narrow_indices_with_restart, IndexBuffer};
+
+assert_eq!(IndexBuffer::U8(vec![0, 1, 254]), narrow_indices_with_restart(vec![0, 1, 254]));
+assert_eq!(IndexBuffer::U16(vec![0, 1, 255]), narrow_indices_with_restart(vec![0, 1, 255]));
+```
+ */
+pub fn narrow_indices_with_restart(indices: Vec<u32>) -> IndexBuffer {
+    narrow(indices, 1)
+}
+
+fn narrow(indices: Vec<u32>, reserved: u32) -> IndexBuffer {
+    let max_index = indices.iter().copied().max().unwrap_or_default();
+
+    if max_index <= u8::MAX as u32 - reserved {
+        IndexBuffer::U8(indices.iter().map(|i| *i as u8).collect())
+    } else if max_index <= u16::MAX as u32 - reserved {
+        IndexBuffer::U16(indices.iter().map(|i| *i as u16).collect())
+    } else {
+        IndexBuffer::U32(indices)
+    }
+}
+
+/// Calculates line list indices for the unique edges of the triangle list `indices`.
+/// Each edge appears once regardless of how many faces contain it.
+/// The edges are ordered by their first occurrence in `indices`.
+/// Degenerate triangles with repeated vertex indices only contribute their non degenerate edges.
+/// # Examples
+/**
+```rust
+use geometry_tools::indices::calculate_wireframe_indices;
+
+// Two triangles sharing an edge have five unique edges.
+let lines = calculate_wireframe_indices(&[0, 1, 2, 2, 1, 3]);
+assert_eq!(vec![0, 1, 1, 2, 2, 0, 1, 3, 3, 2], lines);
+```
+ */
+pub fn calculate_wireframe_indices(indices: &[u32]) -> Vec<u32> {
+    unique_edges(indices)
+        .into_iter()
+        .flat_map(|edge| edge.vertices)
+        .collect()
+}
+
+/// Calculates line list indices for the unique feature edges of the triangle list `indices`.
+/// An edge is a feature edge if the angle between the normals of its adjacent faces is greater than `min_angle` in radians.
+/// Boundary edges and edges shared by more than two faces are always included.
+/// # Examples
+/**
+```rust
+use geometry_tools::indices::calculate_feature_edge_indices;
+use glam::Vec3A;
+
+// Two coplanar triangles only have boundary edges as feature edges.
+let positions = [
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(0.0, 1.0, 0.0),
+    Vec3A::new(1.0, 1.0, 0.0),
+];
+let lines = calculate_feature_edge_indices(&positions, &[0, 1, 2, 2, 1, 3], 30f32.to_radians());
+assert_eq!(vec![0, 1, 2, 0, 1, 3, 3, 2], lines);
+```
+ */
+pub fn calculate_feature_edge_indices<P>(
+    positions: &[P],
+    indices: &[u32],
+    min_angle: f32,
+) -> Vec<u32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let face_normals: Vec<Vec3A> = indices
+        .chunks(3)
+        .map(|face| match face {
+            [v0, v1, v2] => {
+                let v0: Vec3A = positions[*v0 as usize].into();
+                let v1: Vec3A = positions[*v1 as usize].into();
+                let v2: Vec3A = positions[*v2 as usize].into();
+                (v1 - v0).cross(v2 - v0).normalize_or_zero()
+            }
+            _ => Vec3A::ZERO,
+        })
+        .collect();
+
+    let min_cos = min_angle.cos();
+
+    unique_edges(indices)
+        .into_iter()
+        .filter(|edge| match edge.faces[..] {
+            [f0, f1] => {
+                let n0 = face_normals[f0 as usize];
+                let n1 = face_normals[f1 as usize];
+                // Degenerate faces don't have a well defined angle.
+                n0 != Vec3A::ZERO && n1 != Vec3A::ZERO && n0.dot(n1) < min_cos
+            }
+            _ => true,
+        })
+        .flat_map(|edge| edge.vertices)
+        .collect()
+}
+
+struct Edge {
+    vertices: [u32; 2],
+    faces: Vec<u32>,
+}
+
+fn unique_edges(indices: &[u32]) -> Vec<Edge> {
+    let mut edges: Vec<Edge> = Vec::new();
+    let mut edge_indices = HashMap::new();
+
+    for (face_index, face) in indices.chunks(3).enumerate() {
+        if let [v0, v1, v2] = face {
+            for (a, b) in [(*v0, *v1), (*v1, *v2), (*v2, *v0)] {
+                if a == b {
+                    continue;
+                }
+
+                let edge_index = *edge_indices.entry(edge_key(a, b)).or_insert_with(|| {
+                    edges.push(Edge {
+                        vertices: [a, b],
+                        faces: Vec::new(),
+                    });
+                    edges.len() - 1
+                });
+
+                // Degenerate faces shouldn't affect the angle between faces.
+                if !is_degenerate(*v0, *v1, *v2) {
+                    edges[edge_index].faces.push(face_index as u32);
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn narrow_empty() {
+        let indices = narrow_indices(Vec::new());
+        assert_eq!(IndexBuffer::U8(Vec::new()), indices);
+        assert!(indices.is_empty());
+    }
+
+    #[test]
+    fn narrow_u8_max() {
+        assert_eq!(
+            IndexBuffer::U8(vec![255, 0]),
+            narrow_indices(vec![u8::MAX as u32, 0])
+        );
+    }
+
+    #[test]
+    fn narrow_u16_max() {
+        let indices = narrow_indices(vec![u16::MAX as u32, 0]);
+        assert_eq!(IndexBuffer::U16(vec![u16::MAX, 0]), indices);
+        assert_eq!(2, indices.index_size());
+    }
+
+    #[test]
+    fn narrow_u32() {
+        let indices = narrow_indices(vec![0, u32::MAX]);
+        assert_eq!(IndexBuffer::U32(vec![0, u32::MAX]), indices);
+        assert_eq!(4, indices.index_size());
+    }
+
+    #[test]
+    fn narrow_with_restart_u16_max() {
+        assert_eq!(
+            IndexBuffer::U32(vec![u16::MAX as u32]),
+            narrow_indices_with_restart(vec![u16::MAX as u32])
+        );
+    }
+
+    #[test]
+    fn round_trip_u32() {
+        let indices = vec![0, 300, 2];
+        assert_eq!(indices, narrow_indices(indices.clone()).to_u32());
+    }
+
+    #[test]
+    fn wireframe_empty() {
+        assert!(calculate_wireframe_indices(&[]).is_empty());
+    }
+
+    #[test]
+    fn wireframe_tetrahedron() {
+        let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+        let lines = calculate_wireframe_indices(&indices);
+        assert_eq!(12, lines.len());
+    }
+
+    #[test]
+    fn wireframe_degenerate_triangle() {
+        assert_eq!(
+            vec![0, 1, 1, 2, 2, 0],
+            calculate_wireframe_indices(&[0, 1, 2, 0, 0, 1])
+        );
+    }
+
+    #[test]
+    fn feature_edges_cube_corner() {
+        // Two perpendicular triangles sharing the edge from 0 to 1.
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+        let indices = [0, 1, 2, 1, 0, 3];
+
+        let lines = calculate_feature_edge_indices(&positions, &indices, 45f32.to_radians());
+        assert_eq!(10, lines.len());
+
+        let lines = calculate_feature_edge_indices(&positions, &indices, 100f32.to_radians());
+        assert_eq!(8, lines.len());
+        assert!(!lines.chunks(2).any(|l| l == [0, 1] || l == [1, 0]));
+    }
+
+    #[test]
+    fn feature_edges_non_manifold() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z, -Vec3A::Y];
+        let indices = [0, 1, 2, 1, 0, 3, 0, 1, 4];
+        let lines = calculate_feature_edge_indices(&positions, &indices, std::f32::consts::PI);
+        assert!(lines.chunks(2).any(|l| l == [0, 1]));
+    }
+}
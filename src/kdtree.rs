@@ -0,0 +1,438 @@
+//! A k-d tree for accelerating nearest neighbor queries on point sets.
+
+use glam::Vec3A;
+
+use crate::bvh::aabb_distance_squared;
+
+/// The maximum number of points in a leaf node.
+const MAX_LEAF_POINTS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct KdNode {
+    min: Vec3A,
+    max: Vec3A,
+    /// The index of the first child for interior nodes or the first point for leaf nodes.
+    start: u32,
+    /// The number of points for leaf nodes or `0` for interior nodes.
+    count: u32,
+}
+
+/// A k-d tree with axis-aligned bounding boxes for each node.
+/// The tree stores a copy of the points, so it can be reused for many queries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KdTree {
+    points: Vec<Vec3A>,
+    /// The point indices sorted so that each leaf node references a contiguous range.
+    point_order: Vec<u32>,
+    nodes: Vec<KdNode>,
+}
+
+/// A point found by a query on a [KdTree].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Neighbor {
+    /// The index of the point in the points used to build the tree.
+    pub index: u32,
+    /// The distance from the query point to the point at [index](#structfield.index).
+    pub distance: f32,
+}
+
+impl KdTree {
+    /// Builds a k-d tree for `points` by recursively splitting the points at the median along the longest axis.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::kdtree::KdTree;
+    use glam::Vec3A;
+
+    let points = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+    let tree = KdTree::new(&points);
+    assert_eq!(3, tree.points().len());
+    ```
+     */
+    pub fn new<P>(points: &[P]) -> Self
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        let mut tree = Self {
+            points: points.iter().copied().map(Into::into).collect(),
+            point_order: (0..points.len() as u32).collect(),
+            nodes: Vec::new(),
+        };
+        if !tree.points.is_empty() {
+            tree.nodes.push(tree.leaf(0, tree.points.len()));
+            tree.split(0);
+        }
+        tree
+    }
+
+    /// The points used to build the tree.
+    pub fn points(&self) -> &[Vec3A] {
+        &self.points
+    }
+
+    fn leaf(&self, start: usize, count: usize) -> KdNode {
+        let (min, max) = self.point_order[start..start + count].iter().fold(
+            (Vec3A::splat(f32::MAX), Vec3A::splat(f32::MIN)),
+            |(min, max), i| {
+                let p = self.points[*i as usize];
+                (min.min(p), max.max(p))
+            },
+        );
+        KdNode {
+            min,
+            max,
+            start: start as u32,
+            count: count as u32,
+        }
+    }
+
+    fn split(&mut self, node_index: usize) {
+        let node = self.nodes[node_index];
+        let start = node.start as usize;
+        let count = node.count as usize;
+        if count <= MAX_LEAF_POINTS {
+            return;
+        }
+
+        let extent = node.max - node.min;
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+
+        let mid = count / 2;
+        let points = &self.points;
+        self.point_order[start..start + count].select_nth_unstable_by(mid, |a, b| {
+            points[*a as usize][axis].total_cmp(&points[*b as usize][axis])
+        });
+
+        // Children are stored next to each other.
+        let left = self.nodes.len();
+        let left_node = self.leaf(start, mid);
+        let right_node = self.leaf(start + mid, count - mid);
+        self.nodes.push(left_node);
+        self.nodes.push(right_node);
+        self.nodes[node_index].start = left as u32;
+        self.nodes[node_index].count = 0;
+
+        self.split(left);
+        self.split(left + 1);
+    }
+
+    /// Finds the closest point to `point` within `max_distance`.
+    /// Use [f32::INFINITY] to search all the points.
+    /// If multiple points are equally close, the point with the lowest index is returned.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::kdtree::KdTree;
+    use glam::Vec3A;
+
+    let tree = KdTree::new(&[Vec3A::ZERO, Vec3A::X, Vec3A::Y]);
+
+    let nearest = tree.nearest(Vec3A::new(2.0, 0.0, 0.0), f32::INFINITY).unwrap();
+    assert_eq!(1, nearest.index);
+    assert_eq!(1.0, nearest.distance);
+
+    assert_eq!(None, tree.nearest(Vec3A::new(2.0, 0.0, 0.0), 0.5));
+    ```
+     */
+    pub fn nearest(&self, point: Vec3A, max_distance: f32) -> Option<Neighbor> {
+        self.k_nearest(point, 1, max_distance).pop()
+    }
+
+    /// Finds the `k` closest points to `point` within `max_distance` sorted from closest to farthest.
+    /// Use [f32::INFINITY] to search all the points.
+    /// Points at the same distance are sorted by index.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::kdtree::KdTree;
+    use glam::Vec3A;
+
+    let points = [Vec3A::ZERO, Vec3A::X, Vec3A::X * 2.0, Vec3A::X * 3.0];
+    let tree = KdTree::new(&points);
+
+    let neighbors = tree.k_nearest(Vec3A::X * 2.1, 2, f32::INFINITY);
+    let indices: Vec<_> = neighbors.iter().map(|n| n.index).collect();
+    assert_eq!(vec![2, 3], indices);
+    ```
+     */
+    pub fn k_nearest(&self, point: Vec3A, k: usize, max_distance: f32) -> Vec<Neighbor> {
+        // Store squared distances until the end to avoid unnecessary square roots.
+        let max_distance_squared = max_distance * max_distance;
+        let mut nearest: Vec<Neighbor> = Vec::with_capacity(k.min(self.points.len()));
+        if k == 0 {
+            return nearest;
+        }
+
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_index) = stack.pop() {
+            let bound = if nearest.len() == k {
+                nearest[k - 1].distance
+            } else {
+                max_distance_squared
+            };
+            let node = &self.nodes[node_index];
+            if aabb_distance_squared(point, node.min, node.max) > bound {
+                continue;
+            }
+
+            if node.count > 0 {
+                let start = node.start as usize;
+                for i in &self.point_order[start..start + node.count as usize] {
+                    let candidate = Neighbor {
+                        index: *i,
+                        distance: self.points[*i as usize].distance_squared(point),
+                    };
+                    if candidate.distance > max_distance_squared {
+                        continue;
+                    }
+                    let position = nearest.partition_point(|n| is_closer(n, &candidate));
+                    if position < k {
+                        nearest.truncate(k - 1);
+                        nearest.insert(position, candidate);
+                    }
+                }
+            } else {
+                self.push_children(&mut stack, node, point);
+            }
+        }
+
+        for n in &mut nearest {
+            n.distance = n.distance.sqrt();
+        }
+        nearest
+    }
+
+    /// Finds all points within `radius` of `point` sorted from closest to farthest.
+    /// Points at the same distance are sorted by index.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::kdtree::KdTree;
+    use glam::Vec3A;
+
+    let points = [Vec3A::ZERO, Vec3A::X, Vec3A::X * 2.0, Vec3A::X * 3.0];
+    let tree = KdTree::new(&points);
+
+    let neighbors = tree.within_radius(Vec3A::X * 1.9, 1.0);
+    let indices: Vec<_> = neighbors.iter().map(|n| n.index).collect();
+    assert_eq!(vec![2, 1], indices);
+    ```
+     */
+    pub fn within_radius(&self, point: Vec3A, radius: f32) -> Vec<Neighbor> {
+        let radius_squared = radius * radius;
+        let mut neighbors = Vec::new();
+
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            if aabb_distance_squared(point, node.min, node.max) > radius_squared {
+                continue;
+            }
+
+            if node.count > 0 {
+                let start = node.start as usize;
+                for i in &self.point_order[start..start + node.count as usize] {
+                    let distance_squared = self.points[*i as usize].distance_squared(point);
+                    if distance_squared <= radius_squared {
+                        neighbors.push(Neighbor {
+                            index: *i,
+                            distance: distance_squared,
+                        });
+                    }
+                }
+            } else {
+                let left = node.start as usize;
+                stack.push(left);
+                stack.push(left + 1);
+            }
+        }
+
+        neighbors.sort_unstable_by(|a, b| {
+            a.distance
+                .total_cmp(&b.distance)
+                .then(a.index.cmp(&b.index))
+        });
+        for n in &mut neighbors {
+            n.distance = n.distance.sqrt();
+        }
+        neighbors
+    }
+
+    fn push_children(&self, stack: &mut Vec<usize>, node: &KdNode, point: Vec3A) {
+        // Visit the closer child first to reduce the search radius sooner.
+        let left = node.start as usize;
+        let right = left + 1;
+        let left_distance =
+            aabb_distance_squared(point, self.nodes[left].min, self.nodes[left].max);
+        let right_distance =
+            aabb_distance_squared(point, self.nodes[right].min, self.nodes[right].max);
+        if left_distance < right_distance {
+            stack.push(right);
+            stack.push(left);
+        } else {
+            stack.push(left);
+            stack.push(right);
+        }
+    }
+}
+
+fn is_closer(a: &Neighbor, b: &Neighbor) -> bool {
+    (a.distance, a.index) < (b.distance, b.index)
+}
+
+/// Finds the `k` closest points in `tree` within `max_distance` for each point in `points`.
+/// This is equivalent to calling [KdTree::k_nearest] for each point.
+/// # Examples
+/**
+```rust
+use geometry_tools::kdtree::{k_nearest_neighbors, KdTree};
+use glam::Vec3A;
+
+let points = [Vec3A::ZERO, Vec3A::X, Vec3A::X * 2.0];
+let tree = KdTree::new(&points);
+
+// The closest point to each point is the point itself.
+let neighbors = k_nearest_neighbors(&tree, &points, 2, f32::INFINITY);
+assert_eq!(vec![0, 1], neighbors[0].iter().map(|n| n.index).collect::<Vec<_>>());
+assert_eq!(vec![2, 1], neighbors[2].iter().map(|n| n.index).collect::<Vec<_>>());
+```
+ */
+pub fn k_nearest_neighbors<P>(
+    tree: &KdTree,
+    points: &[P],
+    k: usize,
+    max_distance: f32,
+) -> Vec<Vec<Neighbor>>
+where
+    P: Into<Vec3A> + Copy,
+{
+    points
+        .iter()
+        .map(|p| tree.k_nearest((*p).into(), k, max_distance))
+        .collect()
+}
+
+/// Finds all the points in `tree` within `radius` for each point in `points`.
+/// This is equivalent to calling [KdTree::within_radius] for each point.
+/// # Examples
+/**
+```rust
+use geometry_tools::kdtree::{neighbors_within_radius, KdTree};
+use glam::Vec3A;
+
+let points = [Vec3A::ZERO, Vec3A::X, Vec3A::X * 3.0];
+let tree = KdTree::new(&points);
+
+let neighbors = neighbors_within_radius(&tree, &points, 1.5);
+assert_eq!(2, neighbors[0].len());
+assert_eq!(1, neighbors[2].len());
+```
+ */
+pub fn neighbors_within_radius<P>(tree: &KdTree, points: &[P], radius: f32) -> Vec<Vec<Neighbor>>
+where
+    P: Into<Vec3A> + Copy,
+{
+    points
+        .iter()
+        .map(|p| tree.within_radius((*p).into(), radius))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(count: usize) -> Vec<Vec3A> {
+        (0..count)
+            .map(|i| {
+                let i = i as f32;
+                Vec3A::new((i * 0.37).sin() * 5.0, (i * 0.91).cos() * 3.0, i * 0.01)
+            })
+            .collect()
+    }
+
+    fn brute_force(points: &[Vec3A], point: Vec3A, k: usize, max_distance: f32) -> Vec<u32> {
+        let mut neighbors: Vec<_> = (0..points.len() as u32)
+            .filter(|i| points[*i as usize].distance(point) <= max_distance)
+            .collect();
+        neighbors.sort_by(|a, b| {
+            points[*a as usize]
+                .distance_squared(point)
+                .total_cmp(&points[*b as usize].distance_squared(point))
+                .then(a.cmp(b))
+        });
+        neighbors.truncate(k);
+        neighbors
+    }
+
+    #[test]
+    fn empty() {
+        let tree = KdTree::new::<Vec3A>(&[]);
+        assert_eq!(None, tree.nearest(Vec3A::ZERO, f32::INFINITY));
+        assert!(tree.k_nearest(Vec3A::ZERO, 4, f32::INFINITY).is_empty());
+        assert!(tree.within_radius(Vec3A::ZERO, 1.0).is_empty());
+    }
+
+    #[test]
+    fn k_nearest_zero() {
+        let tree = KdTree::new(&points(10));
+        assert!(tree.k_nearest(Vec3A::ZERO, 0, f32::INFINITY).is_empty());
+    }
+
+    #[test]
+    fn k_nearest_matches_brute_force() {
+        let points = points(500);
+        let tree = KdTree::new(&points);
+        for query in [Vec3A::ZERO, Vec3A::new(4.0, -2.0, 1.0), Vec3A::splat(20.0)] {
+            for (k, max_distance) in [(1, f32::INFINITY), (7, f32::INFINITY), (600, 2.0)] {
+                let expected = brute_force(&points, query, k, max_distance);
+                let indices: Vec<_> = tree
+                    .k_nearest(query, k, max_distance)
+                    .iter()
+                    .map(|n| n.index)
+                    .collect();
+                assert_eq!(expected, indices);
+            }
+        }
+    }
+
+    #[test]
+    fn within_radius_matches_brute_force() {
+        let points = points(500);
+        let tree = KdTree::new(&points);
+        let query = Vec3A::new(1.0, 1.0, 2.0);
+        let expected = brute_force(&points, query, usize::MAX, 1.5);
+        let neighbors = tree.within_radius(query, 1.5);
+        assert_eq!(
+            expected,
+            neighbors.iter().map(|n| n.index).collect::<Vec<_>>()
+        );
+        for n in neighbors {
+            assert_eq!(points[n.index as usize].distance(query), n.distance);
+        }
+    }
+
+    #[test]
+    fn nearest_duplicate_points() {
+        let tree = KdTree::new(&[Vec3A::ONE; 20]);
+        assert_eq!(
+            Some(Neighbor {
+                index: 0,
+                distance: 0.0
+            }),
+            tree.nearest(Vec3A::ONE, f32::INFINITY)
+        );
+    }
+}
@@ -3,9 +3,79 @@
 //! Most functions support any type that can be converted into [glam::Vec3A].
 //! This allows [glam::Vec3A] and [glam::Vec4] to have identical performance.
 //! Using [glam::Vec3] will have slightly reduced performance due to conversions to aligned types.
+//! Functions ending in `_vec3` convert [glam::Vec3] and `[f32; 3]` in batches to avoid most of this overhead.
+//! For very large point sets, [soa::Vec3Soa] stores the components in separate arrays
+//! to calculate bounds and centroids using the full width of SIMD registers.
+//!
+//! Enable the `mint` feature to also accept [mint](https://crates.io/crates/mint) types
+//! and use the functions in `mint` that avoid glam types in their signatures.
+//!
+//! Enable the `gpu` feature to calculate normals and tangents for very large meshes
+//! with compute shaders using the types in `gpu`.
+//!
+//! Enable the `deterministic` feature for bit-identical sums independent of the thread count
+//! using compensated summation in a fixed order.
 
 pub use glam;
 
+pub mod attributes;
+pub mod bake;
+pub mod barycentric;
+pub mod batch;
 pub mod bounding;
+pub mod bounding2d;
+pub mod bvh;
+#[cfg(feature = "bytemuck")]
+pub mod cast;
+pub mod cavity;
+pub mod compare;
+pub mod curvature;
+mod eigen;
 pub mod ffi;
+pub mod flat;
+pub mod float16;
+pub mod geodesic;
+#[cfg(feature = "gltf")]
+pub mod gltf;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+pub mod hash;
+pub mod heightfield;
+pub mod indices;
+pub mod kdtree;
+pub mod mass;
+pub mod mesh;
+pub mod meshlet;
+#[cfg(feature = "mint")]
+pub mod mint;
+pub mod morph;
+pub mod optimize;
+mod parallel;
+pub mod point_cloud;
+pub mod progress;
+pub mod quantize;
+pub mod query;
+pub mod remap;
+mod scalar;
+pub mod sdf;
+pub mod shadow;
+pub mod shapes;
+pub mod simplify;
+pub mod skinning;
+pub mod smooth;
+pub mod soa;
+pub mod statistics;
+pub mod streaming;
+pub mod subdivide;
+mod sum;
+pub mod topology;
+pub mod transfer;
+pub mod transform;
+pub mod triangles;
+pub mod triangulation;
+pub mod uv;
+pub mod validation;
+pub mod vec3;
 pub mod vectors;
+pub mod vertex;
+pub mod weld;
@@ -0,0 +1,492 @@
+//! Functions for calculating volume and mass properties of triangle meshes.
+
+use glam::{DVec3, Mat3, Vec3A};
+
+use crate::bounding::{aabb_from_points, calculate_aabb_from_points};
+use crate::eigen::symmetric_eigen;
+use crate::scalar::{Scalar, Vector3};
+use crate::sum::Accumulator;
+use crate::topology::check_watertight;
+
+/// The result of [calculate_volume].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Volume {
+    /// The enclosed volume, which is negative if the faces are wound inside out.
+    pub signed_volume: f32,
+    /// `true` if the mesh is closed with consistent winding.
+    /// The volume is only meaningful for watertight meshes.
+    pub is_watertight: bool,
+}
+
+/// Calculates the volume enclosed by the triangle list defined by `positions` and `indices`
+/// by summing the signed volumes of the tetrahedra formed by each face and a common point.
+/// Faces should use counterclockwise winding when viewed from outside the mesh.
+///
+/// The result is only meaningful for closed meshes, so the watertightness from
+/// [check_watertight](crate::topology::check_watertight) is also returned.
+/// # Examples
+/**
+```rust
+use geometry_tools::mass::calculate_volume;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+
+let volume = calculate_volume(&positions, &indices);
+assert!((volume.signed_volume - 1.0 / 6.0).abs() < 1e-6);
+assert!(volume.is_watertight);
+```
+ */
+pub fn calculate_volume<P>(positions: &[P], indices: &[u32]) -> Volume
+where
+    P: Into<Vec3A> + Copy,
+{
+    // Using a point near the mesh reduces precision loss for meshes far from the origin.
+    let (min, max) = calculate_aabb_from_points(positions);
+    let origin = (min + max) / 2.0;
+
+    let signed_volume = indices
+        .chunks_exact(3)
+        .map(|face| {
+            let v0 = positions[face[0] as usize].into() - origin;
+            let v1 = positions[face[1] as usize].into() - origin;
+            let v2 = positions[face[2] as usize].into() - origin;
+            v0.dot(v1.cross(v2))
+        })
+        .sum::<f32>()
+        / 6.0;
+
+    Volume {
+        signed_volume,
+        is_watertight: check_watertight(indices).is_watertight(),
+    }
+}
+
+/// Calculates the center of mass of the surface defined by `positions` and `indices`
+/// assuming a thin shell of uniform density.
+/// This is the average of the face centroids weighted by face area.
+/// If the total area is zero, the result is zero.
+/// # Examples
+/**
+```rust
+use geometry_tools::mass::calculate_surface_center_of_mass;
+use glam::Vec3A;
+
+// The vertex average is biased towards the densely tessellated side.
+let positions = [
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(2.0, 0.0, 0.0),
+    Vec3A::new(2.0, 2.0, 0.0),
+    Vec3A::new(0.0, 2.0, 0.0),
+];
+let indices = [0, 1, 4, 1, 2, 3, 1, 3, 4];
+
+let center = calculate_surface_center_of_mass(&positions, &indices);
+assert!(center.abs_diff_eq(Vec3A::new(1.0, 1.0, 0.0), 1e-6));
+```
+ */
+pub fn calculate_surface_center_of_mass<P>(positions: &[P], indices: &[u32]) -> Vec3A
+where
+    P: Into<Vec3A> + Copy,
+{
+    surface_center_of_mass(positions, indices)
+}
+
+/// Calculates the center of mass of the surface defined by `positions` and `indices`
+/// using double precision like [calculate_surface_center_of_mass].
+pub fn calculate_surface_center_of_mass_f64<P>(positions: &[P], indices: &[u32]) -> DVec3
+where
+    P: Into<DVec3> + Copy,
+{
+    surface_center_of_mass(positions, indices)
+}
+
+fn surface_center_of_mass<V, P>(positions: &[P], indices: &[u32]) -> V
+where
+    V: Vector3,
+    P: Into<V> + Copy,
+{
+    let three = V::Scalar::from_usize(3);
+
+    let mut total_area = Accumulator::new(V::Scalar::ZERO);
+    let mut weighted_sum = Accumulator::new(V::ZERO);
+    for face in indices.chunks_exact(3) {
+        let v0: V = positions[face[0] as usize].into();
+        let v1: V = positions[face[1] as usize].into();
+        let v2: V = positions[face[2] as usize].into();
+
+        // The factor of 1/2 cancels out when dividing by the total area.
+        let area = (v1 - v0).cross(v2 - v0).length();
+        total_area.add(area);
+        weighted_sum.add((v0 + v1 + v2) / three * area);
+    }
+
+    let total_area = total_area.sum();
+    if total_area > V::Scalar::ZERO {
+        weighted_sum.sum() / total_area
+    } else {
+        V::ZERO
+    }
+}
+
+/// Calculates the center of mass of the solid enclosed by `positions` and `indices`
+/// assuming a uniform density.
+/// This is the average of the tetrahedron centroids weighted by signed volume
+/// like in [calculate_volume], so the mesh should be closed.
+/// If the total volume is zero, the result is zero.
+/// # Examples
+/**
+```rust
+use geometry_tools::mass::calculate_solid_center_of_mass;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+
+let center = calculate_solid_center_of_mass(&positions, &indices);
+assert!(center.abs_diff_eq(Vec3A::splat(0.25), 1e-6));
+```
+ */
+pub fn calculate_solid_center_of_mass<P>(positions: &[P], indices: &[u32]) -> Vec3A
+where
+    P: Into<Vec3A> + Copy,
+{
+    solid_center_of_mass(positions, indices)
+}
+
+/// Calculates the center of mass of the solid enclosed by `positions` and `indices`
+/// using double precision like [calculate_solid_center_of_mass].
+/// # Examples
+/**
+```rust
+use geometry_tools::mass::calculate_solid_center_of_mass_f64;
+use glam::DVec3;
+
+let offset = DVec3::splat(1e9);
+let positions = [DVec3::ZERO, DVec3::X, DVec3::Y, DVec3::Z].map(|p| p + offset);
+let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+
+let center = calculate_solid_center_of_mass_f64(&positions, &indices);
+assert!(center.abs_diff_eq(DVec3::splat(0.25) + offset, 1e-6));
+```
+ */
+pub fn calculate_solid_center_of_mass_f64<P>(positions: &[P], indices: &[u32]) -> DVec3
+where
+    P: Into<DVec3> + Copy,
+{
+    solid_center_of_mass(positions, indices)
+}
+
+fn solid_center_of_mass<V, P>(positions: &[P], indices: &[u32]) -> V
+where
+    V: Vector3,
+    P: Into<V> + Copy,
+{
+    let (min, max) = aabb_from_points::<V, _>(positions);
+    let origin = (min + max) / V::Scalar::from_usize(2);
+    let four = V::Scalar::from_usize(4);
+
+    let mut total_volume = Accumulator::new(V::Scalar::ZERO);
+    let mut weighted_sum = Accumulator::new(V::ZERO);
+    for face in indices.chunks_exact(3) {
+        let v0 = positions[face[0] as usize].into() - origin;
+        let v1 = positions[face[1] as usize].into() - origin;
+        let v2 = positions[face[2] as usize].into() - origin;
+
+        // The factor of 1/6 cancels out when dividing by the total volume.
+        let volume = v0.dot(v1.cross(v2));
+        total_volume.add(volume);
+        weighted_sum.add((v0 + v1 + v2) / four * volume);
+    }
+
+    let total_volume = total_volume.sum();
+    if total_volume != V::Scalar::ZERO {
+        weighted_sum.sum() / total_volume + origin
+    } else {
+        V::ZERO
+    }
+}
+
+/// The mass properties of a solid calculated by [calculate_inertia_tensor].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InertiaTensor {
+    /// The total mass of the solid.
+    pub mass: f32,
+    /// The center of mass of the solid.
+    pub center_of_mass: Vec3A,
+    /// The inertia tensor relative to the center of mass.
+    pub tensor: Mat3,
+    /// The moments of inertia about each principal axis in descending order.
+    pub principal_moments: Vec3A,
+    /// The principal axes as the columns of a rotation matrix.
+    /// The tensor is diagonal in this coordinate system.
+    pub principal_axes: Mat3,
+}
+
+/// Calculates the inertia tensor for the solid enclosed by `positions` and `indices` with uniform `density`.
+/// The mesh should be closed with counterclockwise winding when viewed from outside the mesh.
+/// The tensor is calculated from the covariance of the tetrahedra formed by each face and a common point.
+/// # Examples
+/**
+```rust
+use geometry_tools::mass::calculate_inertia_tensor;
+use glam::{DVec3, Mat3, Vec3A};
+
+// A cube with side length 2 centered at the origin.
+let positions: Vec<_> = (0..8)
+    .map(|i| Vec3A::new((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32) * 2.0 - 1.0)
+    .collect();
+let indices = [
+    0, 2, 1, 1, 2, 3, 4, 5, 6, 5, 7, 6, 0, 1, 4, 1, 5, 4,
+    2, 6, 3, 3, 6, 7, 0, 4, 2, 2, 4, 6, 1, 3, 5, 3, 7, 5,
+];
+
+let inertia = calculate_inertia_tensor(&positions, &indices, 0.5);
+assert!((inertia.mass - 4.0).abs() < 1e-5);
+
+// The moment of inertia for a cube is mass * side^2 / 6.
+let expected = Mat3::from_diagonal(glam::Vec3::splat(4.0 * 4.0 / 6.0));
+assert!(inertia.tensor.abs_diff_eq(expected, 1e-5));
+```
+ */
+pub fn calculate_inertia_tensor<P>(positions: &[P], indices: &[u32], density: f32) -> InertiaTensor
+where
+    P: Into<Vec3A> + Copy,
+{
+    let (min, max) = calculate_aabb_from_points(positions);
+    let origin = (min + max) / 2.0;
+
+    // The covariance of the canonical tetrahedron with vertices at the origin and each unit axis.
+    let canonical = Mat3::from_cols_array(&[2.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 2.0]) / 120.0;
+
+    // Mat3 defaults to the identity, so sum the covariance one column at a time.
+    let mut covariance = [Accumulator::new(Vec3A::ZERO); 3];
+    let mut volume = Accumulator::new(0.0);
+    let mut weighted_centroid = Accumulator::new(Vec3A::ZERO);
+    for face in indices.chunks_exact(3) {
+        let v0 = positions[face[0] as usize].into() - origin;
+        let v1 = positions[face[1] as usize].into() - origin;
+        let v2 = positions[face[2] as usize].into() - origin;
+
+        // Transform the canonical tetrahedron to the tetrahedron for this face.
+        let a = Mat3::from_cols(v0.into(), v1.into(), v2.into());
+        let det = a.determinant();
+        let face_covariance = a * canonical * a.transpose() * det;
+        covariance[0].add(face_covariance.x_axis.into());
+        covariance[1].add(face_covariance.y_axis.into());
+        covariance[2].add(face_covariance.z_axis.into());
+
+        volume.add(det / 6.0);
+        weighted_centroid.add((v0 + v1 + v2) / 4.0 * (det / 6.0));
+    }
+
+    let covariance = Mat3::from_cols(
+        covariance[0].sum().into(),
+        covariance[1].sum().into(),
+        covariance[2].sum().into(),
+    );
+    let volume = volume.sum();
+    let weighted_centroid = weighted_centroid.sum();
+
+    let mass = volume * density;
+    let center = if volume != 0.0 {
+        weighted_centroid / volume
+    } else {
+        Vec3A::ZERO
+    };
+
+    // Move the covariance to the center of mass using the parallel axis theorem.
+    let covariance = covariance * density - outer_product(center, center) * mass;
+    let tensor = Mat3::from_diagonal(glam::Vec3::splat(
+        covariance.x_axis.x + covariance.y_axis.y + covariance.z_axis.z,
+    )) - covariance;
+
+    let (principal_moments, principal_axes) = symmetric_eigen(tensor);
+
+    InertiaTensor {
+        mass,
+        center_of_mass: if volume != 0.0 {
+            center + origin
+        } else {
+            Vec3A::ZERO
+        },
+        tensor,
+        principal_moments,
+        principal_axes,
+    }
+}
+
+fn outer_product(a: Vec3A, b: Vec3A) -> Mat3 {
+    Mat3::from_cols((a * b.x).into(), (a * b.y).into(), (a * b.z).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::remap::{remap_indices, scatter_attribute};
+    use crate::shapes::generate_cube;
+    use crate::weld::weld_positions;
+
+    const EPSILON: f32 = 0.0001;
+
+    /// A cube from [generate_cube] with shared vertices, so it is also watertight.
+    fn welded_cube(size: f32) -> (Vec<Vec3A>, Vec<u32>) {
+        let cube = generate_cube(size);
+        let welded = weld_positions(&cube.positions, 0.0);
+        let positions = scatter_attribute(&cube.positions, &welded.remap, welded.vertex_count);
+        (positions, remap_indices(&cube.indices, &welded.remap))
+    }
+
+    fn translated_cube(center: Vec3A, size: f32) -> (Vec<Vec3A>, Vec<u32>) {
+        let cube = generate_cube(size);
+        let positions = cube.positions.iter().map(|p| *p + center).collect();
+        (positions, cube.indices)
+    }
+
+    #[test]
+    fn volume_empty() {
+        let volume = calculate_volume::<Vec3A>(&[], &[]);
+        assert_eq!(0.0, volume.signed_volume);
+    }
+
+    #[test]
+    fn volume_cube() {
+        let (positions, indices) = welded_cube(2.0);
+        let volume = calculate_volume(&positions, &indices);
+        assert_relative_eq!(8.0, volume.signed_volume, epsilon = EPSILON);
+        assert!(volume.is_watertight);
+    }
+
+    #[test]
+    fn volume_cube_far_from_origin() {
+        let (positions, indices) = translated_cube(Vec3A::splat(10001.0), 2.0);
+        let volume = calculate_volume(&positions, &indices);
+        assert_relative_eq!(8.0, volume.signed_volume, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn volume_inside_out_cube() {
+        let (positions, mut indices) = welded_cube(1.0);
+        for face in indices.chunks_exact_mut(3) {
+            face.swap(1, 2);
+        }
+        let volume = calculate_volume(&positions, &indices);
+        assert_relative_eq!(-1.0, volume.signed_volume, epsilon = EPSILON);
+        assert!(volume.is_watertight);
+    }
+
+    #[test]
+    fn volume_open_mesh() {
+        let (positions, indices) = welded_cube(1.0);
+        let volume = calculate_volume(&positions, &indices[..30]);
+        assert!(!volume.is_watertight);
+    }
+
+    #[test]
+    fn surface_center_empty() {
+        assert_eq!(
+            Vec3A::ZERO,
+            calculate_surface_center_of_mass::<Vec3A>(&[], &[])
+        );
+    }
+
+    #[test]
+    fn surface_center_degenerate() {
+        let positions = [Vec3A::ONE; 3];
+        assert_eq!(
+            Vec3A::ZERO,
+            calculate_surface_center_of_mass(&positions, &[0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn surface_center_cube() {
+        let (positions, indices) = translated_cube(Vec3A::splat(4.0), 2.0);
+        let center = calculate_surface_center_of_mass(&positions, &indices);
+        assert!(center.abs_diff_eq(Vec3A::splat(4.0), EPSILON));
+    }
+
+    #[test]
+    fn solid_center_empty() {
+        assert_eq!(
+            Vec3A::ZERO,
+            calculate_solid_center_of_mass::<Vec3A>(&[], &[])
+        );
+    }
+
+    #[test]
+    fn solid_center_cube() {
+        let (positions, indices) = translated_cube(Vec3A::new(-4.0, 2.0, 3.0), 2.0);
+        let center = calculate_solid_center_of_mass(&positions, &indices);
+        assert!(center.abs_diff_eq(Vec3A::new(-4.0, 2.0, 3.0), EPSILON));
+    }
+
+    #[test]
+    fn solid_center_differs_from_surface_center() {
+        // An open box without a top has a lower surface center than a closed box.
+        let (positions, indices) = translated_cube(Vec3A::splat(0.5), 1.0);
+        let solid = calculate_solid_center_of_mass(&positions, &indices);
+        // The third face of the cube is the top face facing +Y.
+        let open_top: Vec<_> = indices[..12]
+            .iter()
+            .chain(&indices[18..])
+            .copied()
+            .collect();
+        let surface = calculate_surface_center_of_mass(&positions, &open_top);
+        assert!(solid.abs_diff_eq(Vec3A::splat(0.5), EPSILON));
+        assert!(surface.y < 0.5);
+    }
+
+    #[test]
+    fn inertia_empty() {
+        let inertia = calculate_inertia_tensor::<Vec3A>(&[], &[], 1.0);
+        assert_eq!(0.0, inertia.mass);
+        assert_eq!(Mat3::ZERO, inertia.tensor);
+    }
+
+    #[test]
+    fn inertia_cube_offset() {
+        // The tensor is relative to the center of mass, so translation has no effect.
+        let (positions, indices) = translated_cube(Vec3A::new(5.5, -1.5, 3.5), 1.0);
+        let inertia = calculate_inertia_tensor(&positions, &indices, 2.0);
+        assert_relative_eq!(2.0, inertia.mass, epsilon = EPSILON);
+        assert!(inertia
+            .center_of_mass
+            .abs_diff_eq(Vec3A::new(5.5, -1.5, 3.5), EPSILON));
+        let expected = Mat3::from_diagonal(glam::Vec3::splat(2.0 / 6.0));
+        assert!(inertia.tensor.abs_diff_eq(expected, EPSILON));
+    }
+
+    #[test]
+    fn inertia_box_principal_axes() {
+        // A box elongated along the x-axis and rotated 45 degrees around the z-axis.
+        let (positions, indices) = welded_cube(1.0);
+        let rotation = Mat3::from_rotation_z(45f32.to_radians());
+        let positions: Vec<Vec3A> = positions
+            .iter()
+            .map(|p| rotation * (*p * Vec3A::new(4.0, 1.0, 1.0)))
+            .collect();
+
+        let inertia = calculate_inertia_tensor(&positions, &indices, 1.0);
+        let mass = 4.0;
+        assert_relative_eq!(mass, inertia.mass, epsilon = EPSILON);
+
+        // I = m / 12 * (a^2 + b^2) for each axis of a box.
+        let long_axis = mass / 12.0 * (1.0 + 1.0);
+        let short_axis = mass / 12.0 * (16.0 + 1.0);
+        assert!(inertia
+            .principal_moments
+            .abs_diff_eq(Vec3A::new(short_axis, short_axis, long_axis), EPSILON));
+
+        // The smallest moment is about the long axis of the box.
+        let axis = inertia.principal_axes.z_axis;
+        let expected = rotation * glam::Vec3::X;
+        assert_relative_eq!(1.0, axis.dot(expected).abs(), epsilon = EPSILON);
+    }
+}
@@ -0,0 +1,579 @@
+//! Types for owned and borrowed meshes and functions for merging and splitting meshes.
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use glam::{Mat4, Vec2, Vec3A, Vec4};
+
+use crate::bounding::{calculate_aabb_from_points, calculate_bounding_sphere_from_points};
+use crate::remap::{remap_indices, scatter_attribute, REMAP_UNUSED};
+use crate::transform::transform_mesh;
+use crate::validation::{check_triangle_indices, IndexError};
+use crate::vectors::{calculate_smooth_normals, calculate_tangents, TangentBitangentError};
+
+/// Borrowed vertex attributes and triangle indices for a mesh.
+/// Empty attributes are treated as missing.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MeshRef<'a> {
+    pub positions: &'a [Vec3A],
+    pub normals: &'a [Vec3A],
+    pub tangents: &'a [Vec4],
+    pub uvs: &'a [Vec2],
+    pub colors: &'a [Vec4],
+    pub indices: &'a [u32],
+}
+
+/// Owned vertex attributes and triangle indices for a mesh.
+/// Empty attributes are treated as missing.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Mesh {
+    pub positions: Vec<Vec3A>,
+    pub normals: Vec<Vec3A>,
+    pub tangents: Vec<Vec4>,
+    pub uvs: Vec<Vec2>,
+    pub colors: Vec<Vec4>,
+    pub indices: Vec<u32>,
+    /// The ranges of vertices and indices for each source mesh if this mesh was merged from multiple meshes.
+    pub submeshes: Vec<Submesh>,
+}
+
+impl Mesh {
+    /// Borrows the vertex attributes and indices of this mesh.
+    pub fn as_mesh_ref(&self) -> MeshRef<'_> {
+        MeshRef {
+            positions: &self.positions,
+            normals: &self.normals,
+            tangents: &self.tangents,
+            uvs: &self.uvs,
+            colors: &self.colors,
+            indices: &self.indices,
+        }
+    }
+
+    /// The indices for the submesh at `index`.
+    pub fn submesh_indices(&self, index: usize) -> &[u32] {
+        let submesh = &self.submeshes[index];
+        &self.indices[submesh.index_offset..submesh.index_offset + submesh.index_count]
+    }
+
+    /// Checks that the indices contain only complete triangles that are in range for the positions
+    /// using [check_triangle_indices].
+    /// Call this before other methods to reject truncated index buffers instead of ignoring the trailing indices.
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::mesh::Mesh;
+    use geometry_tools::validation::IndexError;
+    use glam::Vec3A;
+
+    let mut mesh = Mesh {
+        positions: vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y],
+        indices: vec![0, 1, 2, 0],
+        ..Default::default()
+    };
+    assert_eq!(
+        Err(IndexError::InvalidIndexCount { index_count: 4 }),
+        mesh.check_indices().map(|m| m.compute_normals()).map(|_| ())
+    );
+    ```
+     */
+    pub fn check_indices(&mut self) -> Result<&mut Self, IndexError> {
+        check_triangle_indices(&self.indices, self.positions.len())?;
+        Ok(self)
+    }
+
+    /// Replaces the normals with smooth normals using [calculate_smooth_normals].
+    /// # Examples
+    /**
+    ```rust
+    use geometry_tools::mesh::Mesh;
+    use glam::{Vec2, Vec3A};
+
+    let mut mesh = Mesh {
+        positions: vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y],
+        uvs: vec![Vec2::ZERO, Vec2::X, Vec2::Y],
+        indices: vec![0, 1, 2],
+        ..Default::default()
+    };
+    mesh.compute_normals().compute_tangents().unwrap();
+
+    assert_eq!(vec![Vec3A::Z; 3], mesh.normals);
+    assert_eq!(3, mesh.tangents.len());
+    assert_eq!((Vec3A::ZERO, Vec3A::new(1.0, 1.0, 0.0)), mesh.aabb());
+    ```
+     */
+    pub fn compute_normals(&mut self) -> &mut Self {
+        self.normals = calculate_smooth_normals(&self.positions, &self.indices);
+        self
+    }
+
+    /// Replaces the tangents using [calculate_tangents].
+    /// Smooth normals are calculated first if the mesh has no normals.
+    pub fn compute_tangents(&mut self) -> Result<&mut Self, TangentBitangentError> {
+        if self.normals.is_empty() {
+            self.compute_normals();
+        }
+        self.tangents =
+            calculate_tangents(&self.positions, &self.normals, &self.uvs, &self.indices)?;
+        Ok(self)
+    }
+
+    /// Calculates the axis-aligned bounding box of the positions using [calculate_aabb_from_points].
+    pub fn aabb(&self) -> (Vec3A, Vec3A) {
+        calculate_aabb_from_points(&self.positions)
+    }
+
+    /// Calculates a bounding sphere of the positions using [calculate_bounding_sphere_from_points].
+    pub fn bounding_sphere(&self) -> Vec4 {
+        calculate_bounding_sphere_from_points(&self.positions)
+    }
+}
+
+/// The range of vertices and indices in a merged [Mesh] for a source mesh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Submesh {
+    pub vertex_offset: usize,
+    pub vertex_count: usize,
+    pub index_offset: usize,
+    pub index_count: usize,
+}
+
+/// Combines `meshes` into a single mesh by concatenating the vertex attributes and indices.
+/// The indices for each mesh are offset by the number of vertices in the previous meshes.
+///
+/// The vertex count for each mesh is the number of positions.
+/// If any mesh has an attribute, the merged mesh has that attribute for all vertices.
+/// Missing attributes are filled with zeros, and attributes with the wrong number of elements
+/// are truncated or filled with zeros to match the vertex count.
+/// # Examples
+/**
+```rust
+use geometry_tools::mesh::{merge_meshes, MeshRef};
+use glam::{Vec2, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+
+let a = MeshRef {
+    positions: &positions,
+    indices: &[0, 1, 2],
+    ..Default::default()
+};
+let b = MeshRef {
+    positions: &positions,
+    uvs: &uvs,
+    indices: &[2, 1, 0],
+    ..Default::default()
+};
+
+let mesh = merge_meshes(&[a, b]);
+assert_eq!(vec![0, 1, 2, 5, 4, 3], mesh.indices);
+assert_eq!(vec![Vec2::ZERO, Vec2::ZERO, Vec2::ZERO, Vec2::ZERO, Vec2::X, Vec2::Y], mesh.uvs);
+assert_eq!(&[5, 4, 3], mesh.submesh_indices(1));
+```
+ */
+pub fn merge_meshes(meshes: &[MeshRef]) -> Mesh {
+    let vertex_count: usize = meshes.iter().map(|m| m.positions.len()).sum();
+    let index_count: usize = meshes.iter().map(|m| m.indices.len()).sum();
+
+    let mut mesh = Mesh {
+        positions: Vec::with_capacity(vertex_count),
+        indices: Vec::with_capacity(index_count),
+        submeshes: Vec::with_capacity(meshes.len()),
+        ..Default::default()
+    };
+
+    let has_normals = meshes.iter().any(|m| !m.normals.is_empty());
+    let has_tangents = meshes.iter().any(|m| !m.tangents.is_empty());
+    let has_uvs = meshes.iter().any(|m| !m.uvs.is_empty());
+    let has_colors = meshes.iter().any(|m| !m.colors.is_empty());
+
+    for m in meshes {
+        let vertex_offset = mesh.positions.len();
+        let index_offset = mesh.indices.len();
+        let count = m.positions.len();
+
+        mesh.positions.extend_from_slice(m.positions);
+        if has_normals {
+            extend_attribute(&mut mesh.normals, m.normals, count);
+        }
+        if has_tangents {
+            extend_attribute(&mut mesh.tangents, m.tangents, count);
+        }
+        if has_uvs {
+            extend_attribute(&mut mesh.uvs, m.uvs, count);
+        }
+        if has_colors {
+            extend_attribute(&mut mesh.colors, m.colors, count);
+        }
+        mesh.indices
+            .extend(m.indices.iter().map(|i| i + vertex_offset as u32));
+
+        mesh.submeshes.push(Submesh {
+            vertex_offset,
+            vertex_count: count,
+            index_offset,
+            index_count: m.indices.len(),
+        });
+    }
+
+    mesh
+}
+
+/// Combines `instances` into a single mesh like [merge_meshes]
+/// after transforming each mesh by its instance transform using [transform_mesh].
+/// Returns the merged mesh and the combined axis-aligned bounding box of the form `(min_xyz, max_xyz)`.
+///
+/// Instances with a negative determinant like mirrored instances have their winding order and tangent w signs flipped.
+/// # Examples
+/**
+```rust
+use geometry_tools::mesh::{merge_mesh_instances, MeshRef};
+use glam::{Mat4, Vec3, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let mesh = MeshRef {
+    positions: &positions,
+    indices: &[0, 1, 2],
+    ..Default::default()
+};
+
+let instances = [
+    (mesh, Mat4::IDENTITY),
+    (mesh, Mat4::from_translation(Vec3::new(0.0, 0.0, 2.0))),
+];
+let (merged, (min, max)) = merge_mesh_instances(&instances);
+assert_eq!(6, merged.positions.len());
+assert_eq!(Vec3A::new(0.0, 1.0, 2.0), merged.positions[5]);
+assert_eq!(Vec3A::ZERO, min);
+assert_eq!(Vec3A::new(1.0, 1.0, 2.0), max);
+```
+ */
+pub fn merge_mesh_instances(instances: &[(MeshRef, Mat4)]) -> (Mesh, (Vec3A, Vec3A)) {
+    let meshes: Vec<_> = instances.iter().map(|(mesh, _)| *mesh).collect();
+    let mut mesh = merge_meshes(&meshes);
+
+    for (submesh, (_, transform)) in mesh.submeshes.iter().zip(instances) {
+        let vertices = submesh.vertex_offset..submesh.vertex_offset + submesh.vertex_count;
+        let indices = submesh.index_offset..submesh.index_offset + submesh.index_count;
+        transform_mesh(
+            *transform,
+            &mut mesh.positions[vertices.clone()],
+            attribute_range(&mut mesh.normals, vertices.clone()),
+            attribute_range(&mut mesh.tangents, vertices),
+            &mut mesh.indices[indices],
+        );
+    }
+
+    let bounds = calculate_aabb_from_points(&mesh.positions);
+    (mesh, bounds)
+}
+
+fn attribute_range<T>(values: &mut [T], range: std::ops::Range<usize>) -> &mut [T] {
+    // Missing attributes are empty for all submeshes.
+    if values.is_empty() {
+        values
+    } else {
+        &mut values[range]
+    }
+}
+
+/// A mesh created by [split_mesh_by_ranges] or [split_mesh_by_material].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitMesh {
+    /// The mesh with only the vertices used by its triangles.
+    pub mesh: Mesh,
+    /// The remap table from each original vertex to the vertex in [mesh](#structfield.mesh)
+    /// or [REMAP_UNUSED] for vertices not in this mesh.
+    pub remap: Vec<u32>,
+}
+
+/// Splits `mesh` into a separate mesh for each range of indices in `ranges`.
+/// Each range should start and end on a triangle boundary.
+/// The vertices for each output mesh are compacted to only include used vertices in their original order.
+/// # Examples
+/**
+```rust
+use geometry_tools::mesh::{split_mesh_by_ranges, MeshRef};
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+let mesh = MeshRef {
+    positions: &positions,
+    indices: &[0, 1, 2, 1, 3, 2],
+    ..Default::default()
+};
+
+let split = split_mesh_by_ranges(mesh, &[0..3, 3..6]);
+assert_eq!(vec![Vec3A::X, Vec3A::Y, Vec3A::Z], split[1].mesh.positions);
+assert_eq!(vec![0, 2, 1], split[1].mesh.indices);
+```
+ */
+pub fn split_mesh_by_ranges(mesh: MeshRef, ranges: &[Range<usize>]) -> Vec<SplitMesh> {
+    ranges
+        .iter()
+        .map(|range| split_mesh(mesh, &mesh.indices[range.clone()]))
+        .collect()
+}
+
+/// Splits `mesh` into a separate mesh for each unique value in `material_ids`,
+/// which contains the material for each triangle.
+/// Returns the material and mesh for each material in ascending order.
+/// The vertices for each output mesh are compacted to only include used vertices in their original order.
+/// # Examples
+/**
+```rust
+use geometry_tools::mesh::{split_mesh_by_material, MeshRef};
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+let mesh = MeshRef {
+    positions: &positions,
+    indices: &[0, 1, 2, 1, 3, 2, 0, 2, 3],
+    ..Default::default()
+};
+
+let split = split_mesh_by_material(mesh, &[5, 2, 5]);
+assert_eq!(2, split[0].0);
+assert_eq!(vec![0, 2, 1], split[0].1.mesh.indices);
+assert_eq!(5, split[1].0);
+assert_eq!(vec![0, 1, 2, 0, 2, 3], split[1].1.mesh.indices);
+```
+ */
+pub fn split_mesh_by_material(mesh: MeshRef, material_ids: &[u32]) -> Vec<(u32, SplitMesh)> {
+    let mut material_indices: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for (face, material) in mesh.indices.chunks_exact(3).zip(material_ids) {
+        material_indices
+            .entry(*material)
+            .or_default()
+            .extend_from_slice(face);
+    }
+
+    material_indices
+        .into_iter()
+        .map(|(material, indices)| (material, split_mesh(mesh, &indices)))
+        .collect()
+}
+
+fn split_mesh(mesh: MeshRef, indices: &[u32]) -> SplitMesh {
+    let mut remap = vec![REMAP_UNUSED; mesh.positions.len()];
+    for i in indices {
+        remap[*i as usize] = 0;
+    }
+    let mut count = 0;
+    for r in remap.iter_mut().filter(|r| **r != REMAP_UNUSED) {
+        *r = count;
+        count += 1;
+    }
+
+    let count = count as usize;
+    SplitMesh {
+        mesh: Mesh {
+            positions: scatter_attribute(mesh.positions, &remap, count),
+            normals: scatter_optional_attribute(mesh.normals, &remap, count),
+            tangents: scatter_optional_attribute(mesh.tangents, &remap, count),
+            uvs: scatter_optional_attribute(mesh.uvs, &remap, count),
+            colors: scatter_optional_attribute(mesh.colors, &remap, count),
+            indices: remap_indices(indices, &remap),
+            submeshes: Vec::new(),
+        },
+        remap,
+    }
+}
+
+fn scatter_optional_attribute<T: Copy + Default>(
+    values: &[T],
+    remap: &[u32],
+    count: usize,
+) -> Vec<T> {
+    if values.is_empty() {
+        Vec::new()
+    } else {
+        scatter_attribute(values, remap, count)
+    }
+}
+
+fn extend_attribute<T: Copy + Default>(output: &mut Vec<T>, values: &[T], count: usize) {
+    let start = output.len();
+    output.extend(values.iter().take(count));
+    output.resize(start + count, T::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_empty() {
+        let mut mesh = Mesh::default();
+        mesh.compute_normals().compute_tangents().unwrap();
+        assert_eq!(Mesh::default(), mesh);
+        assert_eq!(Vec4::ZERO, mesh.bounding_sphere());
+    }
+
+    #[test]
+    fn compute_tangents_keeps_normals() {
+        let mut mesh = Mesh {
+            positions: vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y],
+            normals: vec![Vec3A::NEG_Z; 3],
+            uvs: vec![Vec2::ZERO, Vec2::X, Vec2::Y],
+            indices: vec![0, 1, 2],
+            ..Default::default()
+        };
+        mesh.compute_tangents().unwrap();
+        assert_eq!(vec![Vec3A::NEG_Z; 3], mesh.normals);
+        assert_eq!(Vec4::new(1.0, 0.0, 0.0, -1.0), mesh.tangents[0]);
+    }
+
+    #[test]
+    fn merge_empty() {
+        assert_eq!(Mesh::default(), merge_meshes(&[]));
+    }
+
+    #[test]
+    fn merge_single_mesh() {
+        let mesh = Mesh {
+            positions: vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y],
+            normals: vec![Vec3A::Z; 3],
+            indices: vec![0, 1, 2],
+            ..Default::default()
+        };
+        let merged = merge_meshes(&[mesh.as_mesh_ref()]);
+        assert_eq!(mesh.positions, merged.positions);
+        assert_eq!(mesh.normals, merged.normals);
+        assert!(merged.tangents.is_empty());
+        assert!(merged.uvs.is_empty());
+        assert!(merged.colors.is_empty());
+        assert_eq!(mesh.indices, merged.indices);
+    }
+
+    #[test]
+    fn merge_attribute_count_mismatch() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let a = MeshRef {
+            positions: &positions,
+            colors: &[Vec4::ONE; 2],
+            ..Default::default()
+        };
+        let b = MeshRef {
+            positions: &positions[..1],
+            colors: &[Vec4::X; 4],
+            ..Default::default()
+        };
+        let merged = merge_meshes(&[a, b]);
+        assert_eq!(
+            vec![Vec4::ONE, Vec4::ONE, Vec4::ZERO, Vec4::X],
+            merged.colors
+        );
+    }
+
+    #[test]
+    fn merge_submeshes() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+        let a = MeshRef {
+            positions: &positions[..3],
+            indices: &[0, 1, 2],
+            ..Default::default()
+        };
+        let b = MeshRef {
+            positions: &positions,
+            indices: &[0, 1, 3, 1, 2, 3],
+            ..Default::default()
+        };
+        let merged = merge_meshes(&[a, b, a]);
+        assert_eq!(
+            vec![
+                Submesh {
+                    vertex_offset: 0,
+                    vertex_count: 3,
+                    index_offset: 0,
+                    index_count: 3
+                },
+                Submesh {
+                    vertex_offset: 3,
+                    vertex_count: 4,
+                    index_offset: 3,
+                    index_count: 6
+                },
+                Submesh {
+                    vertex_offset: 7,
+                    vertex_count: 3,
+                    index_offset: 9,
+                    index_count: 3
+                }
+            ],
+            merged.submeshes
+        );
+        assert_eq!(&[7, 8, 9], merged.submesh_indices(2));
+    }
+
+    #[test]
+    fn merge_instances_empty() {
+        let (mesh, bounds) = merge_mesh_instances(&[]);
+        assert_eq!(Mesh::default(), mesh);
+        assert_eq!((Vec3A::ZERO, Vec3A::ZERO), bounds);
+    }
+
+    #[test]
+    fn merge_instances_mirrored() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let normals = [Vec3A::Z; 3];
+        let tangents = [Vec4::new(1.0, 0.0, 0.0, 1.0); 3];
+        let mesh = MeshRef {
+            positions: &positions,
+            normals: &normals,
+            tangents: &tangents,
+            indices: &[0, 1, 2],
+            ..Default::default()
+        };
+        let mirror = Mat4::from_scale(glam::Vec3::new(-1.0, 1.0, 1.0));
+        let (merged, (min, max)) = merge_mesh_instances(&[(mesh, Mat4::IDENTITY), (mesh, mirror)]);
+        assert_eq!(vec![0, 1, 2, 3, 5, 4], merged.indices);
+        assert_eq!(vec![Vec3A::Z; 6], merged.normals);
+        assert_eq!(1.0, merged.tangents[0].w);
+        assert_eq!(-1.0, merged.tangents[3].w);
+        assert_eq!(Vec3A::new(-1.0, 0.0, 0.0), min);
+        assert_eq!(Vec3A::new(1.0, 1.0, 0.0), max);
+    }
+
+    #[test]
+    fn split_empty() {
+        assert!(split_mesh_by_ranges(MeshRef::default(), &[]).is_empty());
+        assert!(split_mesh_by_material(MeshRef::default(), &[]).is_empty());
+    }
+
+    #[test]
+    fn split_attributes_remap() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ONE];
+        let mesh = MeshRef {
+            positions: &positions,
+            uvs: &uvs,
+            indices: &[0, 1, 2, 3, 2, 1],
+            ..Default::default()
+        };
+        let split = split_mesh_by_material(mesh, &[1, 0]);
+        assert_eq!(0, split[0].0);
+        assert_eq!(vec![REMAP_UNUSED, 0, 1, 2], split[0].1.remap);
+        assert_eq!(vec![Vec2::X, Vec2::Y, Vec2::ONE], split[0].1.mesh.uvs);
+        assert!(split[0].1.mesh.normals.is_empty());
+    }
+
+    #[test]
+    fn split_merge_round_trip() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z, Vec3A::ONE];
+        let indices = [0, 1, 2, 2, 1, 3, 3, 1, 4];
+        let mesh = MeshRef {
+            positions: &positions,
+            indices: &indices,
+            ..Default::default()
+        };
+
+        let merged = merge_meshes(&[mesh, mesh]);
+        let split = split_mesh_by_ranges(merged.as_mesh_ref(), &[0..9, 9..18]);
+        for split in split {
+            assert_eq!(positions.to_vec(), split.mesh.positions);
+            assert_eq!(indices.to_vec(), split.mesh.indices);
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Functions for splitting triangle meshes into small clusters for mesh shader pipelines.
+
+use glam::Vec3A;
+
+use crate::bounding::{calculate_cluster_bounds, ClusterBounds};
+
+/// A cluster of triangles referencing a small set of vertices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Meshlet {
+    /// The start of this meshlet's vertex indices in [Meshlets::vertices].
+    pub vertex_offset: u32,
+    /// The number of unique vertices used by this meshlet.
+    pub vertex_count: u32,
+    /// The start of this meshlet's local triangle indices in [Meshlets::triangles].
+    pub triangle_offset: u32,
+    /// The number of triangles in this meshlet.
+    pub triangle_count: u32,
+}
+
+/// The meshlets and combined index buffers produced by [build_meshlets].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Meshlets {
+    pub meshlets: Vec<Meshlet>,
+    /// The original vertex indices for all meshlets.
+    pub vertices: Vec<u32>,
+    /// The triangle indices for all meshlets relative to the meshlet's vertices.
+    pub triangles: Vec<u8>,
+}
+
+impl Meshlets {
+    /// The original vertex indices used by `meshlet`.
+    pub fn meshlet_vertices(&self, meshlet: &Meshlet) -> &[u32] {
+        let start = meshlet.vertex_offset as usize;
+        &self.vertices[start..start + meshlet.vertex_count as usize]
+    }
+
+    /// The local triangle indices of `meshlet`.
+    pub fn meshlet_triangles(&self, meshlet: &Meshlet) -> &[u8] {
+        let start = meshlet.triangle_offset as usize;
+        &self.triangles[start..start + meshlet.triangle_count as usize * 3]
+    }
+
+    /// The triangle list for `meshlet` using the original vertex indices.
+    pub fn meshlet_indices(&self, meshlet: &Meshlet) -> Vec<u32> {
+        let vertices = self.meshlet_vertices(meshlet);
+        self.meshlet_triangles(meshlet)
+            .iter()
+            .map(|i| vertices[*i as usize])
+            .collect()
+    }
+
+    /// Calculates the culling bounds for each meshlet using [calculate_cluster_bounds].
+    pub fn calculate_bounds<P>(&self, positions: &[P]) -> Vec<ClusterBounds>
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        self.meshlets
+            .iter()
+            .map(|m| calculate_cluster_bounds(positions, &self.meshlet_indices(m)))
+            .collect()
+    }
+}
+
+/// The largest supported vertex count for a single meshlet since local indices use `u8`.
+pub const MAX_MESHLET_VERTICES: usize = 256;
+
+/// Splits the triangle list `indices` into meshlets with at most `max_vertices` unique vertices
+/// and `max_triangles` triangles.
+/// Each meshlet is grown by adding the adjacent triangle that introduces the fewest new vertices.
+/// Sorting triangles spatially with [sort_triangles_morton](crate::optimize::sort_triangles_morton)
+/// first can improve the results for disconnected meshes.
+///
+/// `max_vertices` is clamped to the range `3..=256`, and `max_triangles` is at least 1.
+/// Common values are 64 vertices and 124 or 126 triangles.
+/// # Examples
+/**
+```rust
+use geometry_tools::meshlet::build_meshlets;
+
+// A strip of quads.
+let indices = [0, 1, 2, 2, 1, 3, 2, 3, 4, 4, 3, 5];
+let meshlets = build_meshlets(&indices, 4, 64);
+
+assert_eq!(2, meshlets.meshlets.len());
+assert_eq!(vec![0, 1, 2, 2, 1, 3], meshlets.meshlet_indices(&meshlets.meshlets[0]));
+assert_eq!(vec![2, 3, 4, 4, 3, 5], meshlets.meshlet_indices(&meshlets.meshlets[1]));
+```
+ */
+pub fn build_meshlets(indices: &[u32], max_vertices: usize, max_triangles: usize) -> Meshlets {
+    let max_vertices = max_vertices.clamp(3, MAX_MESHLET_VERTICES);
+    let max_triangles = max_triangles.max(1);
+
+    let faces: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|f| [f[0], f[1], f[2]])
+        .collect();
+    let vertex_count = indices.iter().map(|i| *i as usize + 1).max().unwrap_or(0);
+    let vertex_faces = vertex_faces(&faces, vertex_count);
+
+    let mut result = Meshlets::default();
+    let mut used = vec![false; faces.len()];
+    let mut local_index = vec![u8::MAX; vertex_count];
+    let mut builder = MeshletBuilder::default();
+    let mut next_unused = 0;
+
+    loop {
+        // Prefer triangles that share the most vertices with the current meshlet.
+        let candidate = builder
+            .vertices
+            .iter()
+            .flat_map(|v| vertex_faces[*v as usize].iter().copied())
+            .filter(|f| !used[*f as usize])
+            .min_by_key(|f| (new_vertex_count(&faces[*f as usize], &local_index), *f));
+
+        let face = match candidate {
+            Some(face) => face,
+            None => {
+                while next_unused < faces.len() && used[next_unused] {
+                    next_unused += 1;
+                }
+                if next_unused == faces.len() {
+                    break;
+                }
+                next_unused as u32
+            }
+        };
+
+        let new_vertices = new_vertex_count(&faces[face as usize], &local_index);
+        if builder.vertices.len() + new_vertices > max_vertices
+            || builder.triangle_count == max_triangles
+        {
+            builder.finish(&mut result, &mut local_index);
+            continue;
+        }
+
+        builder.add(faces[face as usize], &mut local_index);
+        used[face as usize] = true;
+    }
+
+    builder.finish(&mut result, &mut local_index);
+    result
+}
+
+#[derive(Default)]
+struct MeshletBuilder {
+    vertices: Vec<u32>,
+    triangles: Vec<u8>,
+    triangle_count: usize,
+}
+
+impl MeshletBuilder {
+    fn add(&mut self, face: [u32; 3], local_index: &mut [u8]) {
+        for v in face {
+            // Use the vertex list to check membership since u8::MAX is a valid index.
+            if !self.contains(v, local_index) {
+                local_index[v as usize] = self.vertices.len() as u8;
+                self.vertices.push(v);
+            }
+            self.triangles.push(local_index[v as usize]);
+        }
+        self.triangle_count += 1;
+    }
+
+    fn contains(&self, v: u32, local_index: &[u8]) -> bool {
+        self.vertices.get(local_index[v as usize] as usize) == Some(&v)
+    }
+
+    fn finish(&mut self, result: &mut Meshlets, local_index: &mut [u8]) {
+        if self.triangle_count == 0 {
+            return;
+        }
+
+        result.meshlets.push(Meshlet {
+            vertex_offset: result.vertices.len() as u32,
+            vertex_count: self.vertices.len() as u32,
+            triangle_offset: result.triangles.len() as u32,
+            triangle_count: self.triangle_count as u32,
+        });
+        for v in &self.vertices {
+            local_index[*v as usize] = u8::MAX;
+        }
+        result.vertices.append(&mut self.vertices);
+        result.triangles.append(&mut self.triangles);
+        self.triangle_count = 0;
+    }
+}
+
+fn new_vertex_count(face: &[u32; 3], local_index: &[u8]) -> usize {
+    // Vertices not in the meshlet have already been reset to u8::MAX.
+    // A vertex with a local index of u8::MAX may still be in a full 256 vertex meshlet,
+    // which only overestimates the count and never exceeds the vertex limit.
+    let mut count = 0;
+    for (i, v) in face.iter().enumerate() {
+        if local_index[*v as usize] == u8::MAX && !face[..i].contains(v) {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn vertex_faces(faces: &[[u32; 3]], vertex_count: usize) -> Vec<Vec<u32>> {
+    let mut vertex_faces = vec![Vec::new(); vertex_count];
+    for (i, face) in faces.iter().enumerate() {
+        for v in face {
+            vertex_faces[*v as usize].push(i as u32);
+        }
+    }
+    vertex_faces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_indices(size: u32) -> Vec<u32> {
+        let mut indices = Vec::new();
+        for y in 0..size {
+            for x in 0..size {
+                let v0 = y * (size + 1) + x;
+                let v1 = v0 + 1;
+                let v2 = v0 + size + 1;
+                let v3 = v2 + 1;
+                indices.extend_from_slice(&[v0, v1, v2, v2, v1, v3]);
+            }
+        }
+        indices
+    }
+
+    fn check_meshlets(
+        meshlets: &Meshlets,
+        indices: &[u32],
+        max_vertices: usize,
+        max_triangles: usize,
+    ) {
+        let mut triangles = Vec::new();
+        for meshlet in &meshlets.meshlets {
+            assert!(meshlet.vertex_count as usize <= max_vertices);
+            assert!(meshlet.triangle_count as usize <= max_triangles);
+            assert!(meshlet.triangle_count > 0);
+            triangles.extend(
+                meshlets
+                    .meshlet_indices(meshlet)
+                    .chunks(3)
+                    .map(|t| t.to_vec()),
+            );
+        }
+
+        // Each triangle should appear exactly once.
+        let mut expected: Vec<_> = indices.chunks(3).map(|t| t.to_vec()).collect();
+        expected.sort();
+        triangles.sort();
+        assert_eq!(expected, triangles);
+    }
+
+    #[test]
+    fn empty_indices() {
+        assert_eq!(Meshlets::default(), build_meshlets(&[], 64, 124));
+    }
+
+    #[test]
+    fn single_meshlet() {
+        let indices = grid_indices(2);
+        let meshlets = build_meshlets(&indices, 64, 124);
+        assert_eq!(1, meshlets.meshlets.len());
+        assert_eq!(9, meshlets.vertices.len());
+        check_meshlets(&meshlets, &indices, 64, 124);
+    }
+
+    #[test]
+    fn triangle_limit() {
+        let indices = grid_indices(4);
+        let meshlets = build_meshlets(&indices, 64, 5);
+        assert_eq!(7, meshlets.meshlets.len());
+        check_meshlets(&meshlets, &indices, 64, 5);
+    }
+
+    #[test]
+    fn vertex_limit() {
+        let indices = grid_indices(16);
+        let meshlets = build_meshlets(&indices, 16, 124);
+        check_meshlets(&meshlets, &indices, 16, 124);
+    }
+
+    #[test]
+    fn max_vertex_count() {
+        let indices = grid_indices(32);
+        let meshlets = build_meshlets(&indices, 1000, 1000);
+        check_meshlets(&meshlets, &indices, 256, 1000);
+    }
+
+    #[test]
+    fn disconnected_triangles() {
+        let indices = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let meshlets = build_meshlets(&indices, 6, 124);
+        assert_eq!(2, meshlets.meshlets.len());
+        check_meshlets(&meshlets, &indices, 6, 124);
+    }
+
+    #[test]
+    fn meshlet_bounds() {
+        let indices = grid_indices(4);
+        let positions: Vec<_> = (0..25)
+            .map(|i| Vec3A::new((i % 5) as f32, (i / 5) as f32, 0.0))
+            .collect();
+        let meshlets = build_meshlets(&indices, 64, 8);
+        let bounds = meshlets.calculate_bounds(&positions);
+        assert_eq!(meshlets.meshlets.len(), bounds.len());
+        for b in bounds {
+            assert_eq!(Vec3A::Z, b.cone_axis);
+            assert!(b.is_backfacing(Vec3A::new(2.0, 2.0, -1.0)));
+        }
+    }
+
+    #[test]
+    fn degenerate_triangle() {
+        let indices = [0, 0, 1, 0, 1, 2];
+        let meshlets = build_meshlets(&indices, 3, 124);
+        assert_eq!(1, meshlets.meshlets.len());
+        check_meshlets(&meshlets, &indices, 3, 124);
+    }
+}
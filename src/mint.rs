@@ -0,0 +1,106 @@
+//! Versions of common functions using [mint](https://crates.io/crates/mint) types for inputs and outputs.
+//!
+//! This avoids exposing [glam] types in code that depends on a different version of glam.
+//! Functions with generic position inputs in other modules also accept
+//! [mint::Vector3] and `[f32; 3]` when this feature is enabled.
+//!
+//! This module requires the `mint` feature.
+
+use ::mint::{Vector2, Vector3, Vector4};
+use glam::Vec2;
+
+use crate::vectors::TangentBitangentError;
+
+/// Calculates smooth per vertex normals using [crate::vectors::calculate_smooth_normals].
+/// # Examples
+/**
+```rust
+use geometry_tools::mint::calculate_smooth_normals;
+use mint::Vector3;
+
+let positions: Vec<Vector3<f32>> = vec![
+    [0.0, 0.0, 0.0].into(),
+    [1.0, 0.0, 0.0].into(),
+    [0.0, 1.0, 0.0].into(),
+];
+let normals = calculate_smooth_normals(&positions, &[0, 1, 2]);
+assert_eq!(Vector3::from([0.0, 0.0, 1.0]), normals[0]);
+```
+ */
+pub fn calculate_smooth_normals(positions: &[Vector3<f32>], indices: &[u32]) -> Vec<Vector3<f32>> {
+    crate::vectors::calculate_smooth_normals(positions, indices)
+        .into_iter()
+        .map(Into::into)
+        .collect()
+}
+
+/// Calculates per vertex tangents using [crate::vectors::calculate_tangents].
+pub fn calculate_tangents(
+    positions: &[Vector3<f32>],
+    normals: &[Vector3<f32>],
+    uvs: &[Vector2<f32>],
+    indices: &[u32],
+) -> Result<Vec<Vector4<f32>>, TangentBitangentError> {
+    let uvs: Vec<Vec2> = uvs.iter().map(|uv| Vec2::from(*uv)).collect();
+    crate::vectors::calculate_tangents(positions, normals, &uvs, indices)
+        .map(|tangents| tangents.into_iter().map(Into::into).collect())
+}
+
+/// Calculates a bounding sphere as `[x, y, z, radius]`
+/// using [crate::bounding::calculate_bounding_sphere_from_points].
+pub fn calculate_bounding_sphere_from_points(points: &[Vector3<f32>]) -> Vector4<f32> {
+    crate::bounding::calculate_bounding_sphere_from_points(points).into()
+}
+
+/// Calculates the minimum and maximum of an axis-aligned bounding box
+/// using [crate::bounding::calculate_aabb_from_points].
+pub fn calculate_aabb_from_points(points: &[Vector3<f32>]) -> (Vector3<f32>, Vector3<f32>) {
+    let (min, max) = crate::bounding::calculate_aabb_from_points(points);
+    (min.into(), max.into())
+}
+
+/// Calculates the mean curvature at each vertex using [crate::curvature::calculate_mean_curvature].
+pub fn calculate_mean_curvature(positions: &[Vector3<f32>], indices: &[u32]) -> Vec<f32> {
+    crate::curvature::calculate_mean_curvature(positions, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Vec<Vector3<f32>> {
+        vec![
+            [0.0, 0.0, 0.0].into(),
+            [1.0, 0.0, 0.0].into(),
+            [0.0, 1.0, 0.0].into(),
+        ]
+    }
+
+    #[test]
+    fn tangents_triangle() {
+        let positions = triangle();
+        let normals = vec![Vector3::from([0.0, 0.0, 1.0]); 3];
+        let uvs: Vec<Vector2<f32>> = vec![[0.0, 0.0].into(), [1.0, 0.0].into(), [0.0, 1.0].into()];
+        let tangents = calculate_tangents(&positions, &normals, &uvs, &[0, 1, 2]).unwrap();
+        assert_eq!(vec![Vector4::from([1.0, 0.0, 0.0, 1.0]); 3], tangents);
+    }
+
+    #[test]
+    fn aabb_triangle() {
+        assert_eq!(
+            (
+                Vector3::from([0.0, 0.0, 0.0]),
+                Vector3::from([1.0, 1.0, 0.0])
+            ),
+            calculate_aabb_from_points(&triangle())
+        );
+    }
+
+    #[test]
+    fn bounding_sphere_empty() {
+        assert_eq!(
+            Vector4::from([0.0; 4]),
+            calculate_bounding_sphere_from_points(&[])
+        );
+    }
+}
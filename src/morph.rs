@@ -0,0 +1,129 @@
+//! Functions for applying and extracting morph targets (blend shapes).
+
+use glam::Vec3A;
+
+/// The offsets for the vertices changed by a morph target.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SparseMorphTarget {
+    /// The indices of the changed vertices in ascending order.
+    pub indices: Vec<u32>,
+    /// The offset from the base value for each vertex in `indices`.
+    pub deltas: Vec<Vec3A>,
+}
+
+/// Calculates the offsets from `base` to `target` for vertices that moved more than `tolerance`.
+/// `base` and `target` should have the same length.
+/// This works for any per vertex attribute like positions or normals.
+/// # Examples
+/**
+```rust
+use geometry_tools::morph::extract_morph_target;
+use glam::Vec3A;
+
+let base = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let target = [Vec3A::ZERO, Vec3A::X * 2.0, Vec3A::Y];
+
+let morph = extract_morph_target(&base, &target, 0.0);
+assert_eq!(vec![1], morph.indices);
+assert_eq!(vec![Vec3A::X], morph.deltas);
+```
+ */
+pub fn extract_morph_target<P>(base: &[P], target: &[P], tolerance: f32) -> SparseMorphTarget
+where
+    P: Into<Vec3A> + Copy,
+{
+    let (indices, deltas) = base
+        .iter()
+        .zip(target)
+        .enumerate()
+        .map(|(i, (b, t))| (i as u32, (*t).into() - (*b).into()))
+        .filter(|(_, delta)| delta.length() > tolerance)
+        .unzip();
+    SparseMorphTarget { indices, deltas }
+}
+
+/// Calculates the morphed values by adding the deltas of each morph target in `targets`
+/// scaled by the corresponding weight in `weights` to `base`.
+/// `targets` and `weights` should have the same length.
+///
+/// Normals and tangents can be recalculated for the morphed positions
+/// using functions like [calculate_smooth_normals](crate::vectors::calculate_smooth_normals).
+/// # Examples
+/**
+```rust
+use geometry_tools::morph::{apply_morph_targets, SparseMorphTarget};
+use glam::Vec3A;
+
+let base = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let smile = SparseMorphTarget {
+    indices: vec![1],
+    deltas: vec![Vec3A::Y],
+};
+let blink = SparseMorphTarget {
+    indices: vec![1, 2],
+    deltas: vec![Vec3A::Z, Vec3A::Z],
+};
+
+let morphed = apply_morph_targets(&base, &[smile, blink], &[0.5, 1.0]);
+assert_eq!(vec![Vec3A::ZERO, Vec3A::new(1.0, 0.5, 1.0), Vec3A::new(0.0, 1.0, 1.0)], morphed);
+```
+ */
+pub fn apply_morph_targets<P>(
+    base: &[P],
+    targets: &[SparseMorphTarget],
+    weights: &[f32],
+) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let mut values: Vec<Vec3A> = base.iter().copied().map(Into::into).collect();
+    for (target, weight) in targets.iter().zip(weights) {
+        if *weight == 0.0 {
+            continue;
+        }
+
+        for (i, delta) in target.indices.iter().zip(&target.deltas) {
+            values[*i as usize] += *delta * *weight;
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_empty() {
+        assert_eq!(
+            SparseMorphTarget::default(),
+            extract_morph_target::<Vec3A>(&[], &[], 0.0)
+        );
+    }
+
+    #[test]
+    fn extract_tolerance() {
+        let base = [Vec3A::ZERO, Vec3A::ZERO];
+        let target = [Vec3A::X * 0.001, Vec3A::X * 0.1];
+        let morph = extract_morph_target(&base, &target, 0.01);
+        assert_eq!(vec![1], morph.indices);
+    }
+
+    #[test]
+    fn apply_no_targets() {
+        let base = [Vec3A::X, Vec3A::Y];
+        assert_eq!(base.to_vec(), apply_morph_targets(&base, &[], &[]));
+    }
+
+    #[test]
+    fn extract_apply_round_trip() {
+        let base = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+        let target = [Vec3A::ZERO, Vec3A::ONE, Vec3A::Y, Vec3A::NEG_Z];
+        let morph = extract_morph_target(&base, &target, 0.0);
+        assert_eq!(vec![1, 3], morph.indices);
+        assert_eq!(
+            target.to_vec(),
+            apply_morph_targets(&base, &[morph], &[1.0])
+        );
+    }
+}
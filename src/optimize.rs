@@ -0,0 +1,292 @@
+//! Functions for reordering and analyzing index buffers for rendering and processing performance.
+
+use std::collections::HashMap;
+
+use glam::Vec3A;
+
+use crate::bounding::calculate_aabb_from_points;
+
+/// Reorders the triangles in `indices` by the Morton code (Z-order curve) of their centroids
+/// within the bounding box of `positions`.
+/// Triangles that are close in space will be close in the index buffer,
+/// which improves the locality of culling, acceleration structure construction, and compression.
+/// The vertex order within each triangle is preserved, and triangles with equal codes keep their original order.
+/// # Examples
+/**
+```rust
+use geometry_tools::optimize::sort_triangles_morton;
+use glam::Vec3A;
+
+let positions = [
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(0.0, 1.0, 0.0),
+    Vec3A::new(9.0, 9.0, 0.0),
+    Vec3A::new(10.0, 9.0, 0.0),
+    Vec3A::new(9.0, 10.0, 0.0),
+];
+
+let indices = sort_triangles_morton(&positions, &[3, 4, 5, 0, 1, 2]);
+assert_eq!(vec![0, 1, 2, 3, 4, 5], indices);
+```
+ */
+pub fn sort_triangles_morton<P>(positions: &[P], indices: &[u32]) -> Vec<u32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let (min, max) = calculate_aabb_from_points(positions);
+
+    let mut codes: Vec<(u32, [u32; 3])> = indices
+        .chunks_exact(3)
+        .map(|face| {
+            let centroid = face
+                .iter()
+                .map(|i| positions[*i as usize].into())
+                .sum::<Vec3A>()
+                / 3.0;
+            (morton_code(centroid, min, max), [face[0], face[1], face[2]])
+        })
+        .collect();
+
+    // Use a stable sort to make the result deterministic for equal codes.
+    codes.sort_by_key(|(code, _)| *code);
+
+    codes.into_iter().flat_map(|(_, face)| face).collect()
+}
+
+/// Calculates the 30-bit Morton code for `point` using 10 bits for each axis of the box `(min, max)`.
+pub(crate) fn morton_code(point: Vec3A, min: Vec3A, max: Vec3A) -> u32 {
+    // Avoid dividing by zero for flat or empty bounds.
+    let size = (max - min).max(Vec3A::splat(f32::MIN_POSITIVE));
+    let normalized = ((point - min) / size).clamp(Vec3A::ZERO, Vec3A::ONE);
+    let quantized = (normalized * 1023.0).round();
+
+    expand_bits(quantized.x as u32)
+        | (expand_bits(quantized.y as u32) << 1)
+        | (expand_bits(quantized.z as u32) << 2)
+}
+
+fn expand_bits(value: u32) -> u32 {
+    // Insert two zeros between each of the lower 10 bits.
+    let mut v = value & 0x3ff;
+    v = (v | (v << 16)) & 0x030000ff;
+    v = (v | (v << 8)) & 0x0300f00f;
+    v = (v | (v << 4)) & 0x030c30c3;
+    v = (v | (v << 2)) & 0x09249249;
+    v
+}
+
+/// Post-transform vertex cache statistics for an index buffer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VertexCacheStatistics {
+    /// The number of cache misses that require running the vertex shader.
+    pub vertices_transformed: usize,
+    /// The average cache miss ratio or transformed vertices per triangle.
+    /// The ideal value approaches 0.5 for large regular grids, and the worst value is 3.0.
+    pub acmr: f32,
+    /// The average transform to vertex ratio or transformed vertices per unique vertex.
+    /// The ideal value is 1.0.
+    pub atvr: f32,
+}
+
+/// Simulates a first in first out (FIFO) post-transform vertex cache with `cache_size` entries for the triangle list `indices`.
+/// Hits do not change the order of the cache, which models the fixed function caches of most older GPUs.
+/// # Examples
+/**
+```rust
+use geometry_tools::optimize::analyze_vertex_cache_fifo;
+
+// The shared edge only needs to be transformed once.
+let statistics = analyze_vertex_cache_fifo(&[0, 1, 2, 2, 1, 3], 16);
+assert_eq!(4, statistics.vertices_transformed);
+assert_eq!(2.0, statistics.acmr);
+assert_eq!(1.0, statistics.atvr);
+```
+ */
+pub fn analyze_vertex_cache_fifo(indices: &[u32], cache_size: usize) -> VertexCacheStatistics {
+    // Track when each vertex was added to check if it has been evicted.
+    let mut insert_times = HashMap::new();
+    let mut time = 0usize;
+    let vertices_transformed = count_misses(indices, |v| match insert_times.get(&v) {
+        Some(t) if time - t <= cache_size => false,
+        _ => {
+            insert_times.insert(v, time);
+            time += 1;
+            true
+        }
+    });
+
+    statistics(indices, vertices_transformed)
+}
+
+/// Simulates a least recently used (LRU) post-transform vertex cache with `cache_size` entries for the triangle list `indices`.
+/// Hits move the vertex to the front of the cache.
+/// # Examples
+/**
+```rust
+use geometry_tools::optimize::analyze_vertex_cache_lru;
+
+// Every vertex is a miss with a cache that is too small.
+let statistics = analyze_vertex_cache_lru(&[0, 1, 2, 3, 4, 0], 3);
+assert_eq!(6, statistics.vertices_transformed);
+assert_eq!(3.0, statistics.acmr);
+```
+ */
+pub fn analyze_vertex_cache_lru(indices: &[u32], cache_size: usize) -> VertexCacheStatistics {
+    // The most recently used vertex is at the end.
+    let mut cache: Vec<u32> = Vec::with_capacity(cache_size + 1);
+    let vertices_transformed = count_misses(indices, |v| {
+        let hit = match cache.iter().position(|c| *c == v) {
+            Some(i) => {
+                cache.remove(i);
+                true
+            }
+            None => false,
+        };
+        cache.push(v);
+        if cache.len() > cache_size {
+            cache.remove(0);
+        }
+        !hit
+    });
+
+    statistics(indices, vertices_transformed)
+}
+
+fn count_misses<F: FnMut(u32) -> bool>(indices: &[u32], mut is_miss: F) -> usize {
+    indices
+        .chunks_exact(3)
+        .flatten()
+        .filter(|v| is_miss(**v))
+        .count()
+}
+
+fn statistics(indices: &[u32], vertices_transformed: usize) -> VertexCacheStatistics {
+    let triangle_count = indices.len() / 3;
+    let mut unique_vertices: Vec<_> = indices[..triangle_count * 3].to_vec();
+    unique_vertices.sort_unstable();
+    unique_vertices.dedup();
+
+    VertexCacheStatistics {
+        vertices_transformed,
+        acmr: ratio(vertices_transformed, triangle_count),
+        atvr: ratio(vertices_transformed, unique_vertices.len()),
+    }
+}
+
+fn ratio(a: usize, b: usize) -> f32 {
+    if b == 0 {
+        0.0
+    } else {
+        a as f32 / b as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_empty() {
+        assert!(sort_triangles_morton::<Vec3A>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn sort_ignores_trailing_indices() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        assert_eq!(
+            vec![0, 1, 2],
+            sort_triangles_morton(&positions, &[0, 1, 2, 0])
+        );
+    }
+
+    #[test]
+    fn sort_preserves_winding_and_order_for_equal_codes() {
+        let positions = [Vec3A::ZERO; 3];
+        assert_eq!(
+            vec![2, 1, 0, 0, 1, 2],
+            sort_triangles_morton(&positions, &[2, 1, 0, 0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn sort_by_z_order() {
+        // Four triangles near the corners of a square in reverse Z-order.
+        let mut positions = Vec::new();
+        for corner in [
+            Vec3A::new(1.0, 1.0, 0.0),
+            Vec3A::new(0.0, 1.0, 0.0),
+            Vec3A::new(1.0, 0.0, 0.0),
+            Vec3A::new(0.0, 0.0, 0.0),
+        ] {
+            positions.extend([corner, corner + Vec3A::X * 0.1, corner + Vec3A::Y * 0.1]);
+        }
+        let indices: Vec<u32> = (0..12).collect();
+
+        let sorted = sort_triangles_morton(&positions, &indices);
+        assert_eq!(vec![9, 10, 11, 6, 7, 8, 3, 4, 5, 0, 1, 2], sorted);
+    }
+
+    #[test]
+    fn morton_code_corners() {
+        let min = Vec3A::ZERO;
+        let max = Vec3A::ONE;
+        assert_eq!(0, morton_code(Vec3A::ZERO, min, max));
+        assert_eq!(0x3fffffff, morton_code(Vec3A::ONE, min, max));
+        assert_eq!(0x09249249, morton_code(Vec3A::X, min, max));
+        assert_eq!(0x12492492, morton_code(Vec3A::Y, min, max));
+        assert_eq!(0x24924924, morton_code(Vec3A::Z, min, max));
+    }
+
+    #[test]
+    fn morton_code_flat_bounds() {
+        assert_eq!(0, morton_code(Vec3A::ONE, Vec3A::ONE, Vec3A::ONE));
+    }
+
+    #[test]
+    fn cache_empty() {
+        let statistics = analyze_vertex_cache_fifo(&[], 16);
+        assert_eq!(0, statistics.vertices_transformed);
+        assert_eq!(0.0, statistics.acmr);
+        assert_eq!(0.0, statistics.atvr);
+    }
+
+    #[test]
+    fn cache_fifo_hit_does_not_refresh() {
+        // Vertex 0 is evicted despite being used recently.
+        let indices = [0, 1, 2, 0, 3, 4, 0, 1, 2];
+        assert_eq!(
+            8,
+            analyze_vertex_cache_fifo(&indices, 3).vertices_transformed
+        );
+    }
+
+    #[test]
+    fn cache_lru_hit_refreshes() {
+        let indices = [0, 1, 2, 0, 3, 4, 0, 1, 2];
+        assert_eq!(
+            7,
+            analyze_vertex_cache_lru(&indices, 3).vertices_transformed
+        );
+
+        // Vertex 0 stays in the cache since it's used in every triangle.
+        let indices = [0, 1, 2, 0, 3, 4, 0, 5, 6, 0, 1, 2];
+        assert_eq!(
+            9,
+            analyze_vertex_cache_lru(&indices, 3).vertices_transformed
+        );
+        assert_eq!(
+            10,
+            analyze_vertex_cache_fifo(&indices, 3).vertices_transformed
+        );
+    }
+
+    #[test]
+    fn cache_repeated_triangle() {
+        let statistics = analyze_vertex_cache_lru(&[0, 1, 2, 0, 1, 2, 0, 1, 2, 0], 16);
+        assert_eq!(3, statistics.vertices_transformed);
+        assert_eq!(1.0, statistics.acmr);
+        assert_eq!(1.0, statistics.atvr);
+    }
+}
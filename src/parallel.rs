@@ -0,0 +1,65 @@
+//! Support for the optional `rayon` feature.
+//!
+//! Smooth normals, tangents, and bounding spheres are calculated in parallel when the feature is enabled.
+//! Results are combined in a fixed order, so the output does not depend on the number of threads.
+//! Smooth normals and tangents are identical to the single threaded results.
+//! Bounding sphere centers sum fixed size chunks instead of summing the points in order,
+//! so they may differ slightly from the single threaded results unless `deterministic` is also enabled.
+
+/// The number of elements summed by each task.
+/// A fixed size keeps the order of floating point operations independent of the thread count.
+#[cfg(any(feature = "rayon", feature = "deterministic"))]
+pub(crate) const CHUNK_SIZE: usize = 4096;
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use crate::sum::Accumulator;
+    use glam::Vec3A;
+
+    #[test]
+    fn smooth_normals_match_single_threaded() {
+        let mesh = crate::shapes::generate_uv_sphere(1.0, 64, 32);
+
+        // Accumulate in face order like the single threaded implementation.
+        let mut expected = vec![Accumulator::new(Vec3A::ZERO); mesh.positions.len()];
+        for f in mesh.indices.chunks_exact(3) {
+            let [v0, v1, v2] = [f[0], f[1], f[2]].map(|v| mesh.positions[v as usize]);
+            let normal = (v1 - v0).cross(v2 - v0);
+            for v in f {
+                expected[*v as usize].add(normal);
+            }
+        }
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|n| n.sum().normalize_or_zero())
+            .collect();
+
+        assert_eq!(
+            expected,
+            crate::vectors::calculate_smooth_normals(&mesh.positions, &mesh.indices)
+        );
+    }
+
+    #[test]
+    fn bounding_sphere_close_to_single_threaded() {
+        let mesh = crate::shapes::generate_uv_sphere(1.0, 256, 128);
+        let points: Vec<_> = mesh
+            .positions
+            .iter()
+            .map(|p| *p + Vec3A::new(100.0, -50.0, 25.0))
+            .collect();
+        assert!(points.len() > super::CHUNK_SIZE);
+
+        // Sum the points in order like the single threaded implementation.
+        let center = points.iter().sum::<Vec3A>() / points.len() as f32;
+        let radius = points
+            .iter()
+            .map(|p| p.distance(center))
+            .fold(0.0, f32::max);
+
+        // The rounding error of the in order sum grows with the number of points.
+        let sphere = crate::bounding::calculate_bounding_sphere_from_points(&points);
+        assert!(sphere.truncate().abs_diff_eq(center.into(), 1e-2));
+        assert!((sphere.w - radius).abs() < 1e-2);
+    }
+}
@@ -0,0 +1,275 @@
+//! Functions for processing point clouds without triangle indices.
+
+use std::collections::HashMap;
+
+use glam::{IVec3, Vec3A};
+
+use crate::bounding::calculate_aabb_from_points;
+use crate::kdtree::KdTree;
+use crate::sum::Accumulator;
+
+/// The point kept for each occupied voxel by [downsample_voxel_grid].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoxelPoint {
+    /// The average of all the points in the voxel.
+    Centroid,
+    /// The first point in the voxel in the order of the input points.
+    First,
+}
+
+/// The result of [downsample_voxel_grid].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownsampledPoints {
+    /// One point for each occupied voxel in the order the voxels first appear in the input points.
+    pub points: Vec<Vec3A>,
+    /// The remap table from each input point to the index of its voxel in [points](#structfield.points).
+    /// Use [scatter_attribute](crate::remap::scatter_attribute) to keep the attributes of the first point in each voxel.
+    pub remap: Vec<u32>,
+}
+
+/// Reduces the number of points by keeping a single point for each cube of side length `voxel_size`
+/// in a regular grid aligned to the bounds of `points`.
+/// This makes operations like bounding volume fitting and normal estimation practical
+/// for very dense scans while preserving the overall shape.
+///
+/// # Panics
+/// Panics if `voxel_size` is not positive and finite.
+/// # Examples
+/**
+```rust
+use geometry_tools::point_cloud::{downsample_voxel_grid, VoxelPoint};
+use glam::Vec3A;
+
+let points = [
+    Vec3A::new(0.1, 0.1, 0.1),
+    Vec3A::new(0.3, 0.1, 0.1),
+    Vec3A::new(1.5, 0.1, 0.1),
+];
+
+let downsampled = downsample_voxel_grid(&points, 1.0, VoxelPoint::Centroid);
+assert_eq!(vec![Vec3A::new(0.2, 0.1, 0.1), Vec3A::new(1.5, 0.1, 0.1)], downsampled.points);
+assert_eq!(vec![0, 0, 1], downsampled.remap);
+
+let downsampled = downsample_voxel_grid(&points, 1.0, VoxelPoint::First);
+assert_eq!(vec![points[0], points[2]], downsampled.points);
+```
+ */
+pub fn downsample_voxel_grid<P>(
+    points: &[P],
+    voxel_size: f32,
+    mode: VoxelPoint,
+) -> DownsampledPoints
+where
+    P: Into<Vec3A> + Copy,
+{
+    assert!(
+        voxel_size > 0.0 && voxel_size.is_finite(),
+        "voxel_size should be positive and finite"
+    );
+
+    // Use cell coordinates relative to the bounds to avoid overflow for points far from the origin.
+    let (min, _) = calculate_aabb_from_points(points);
+
+    let mut voxels = HashMap::new();
+    let mut sums: Vec<(Accumulator<Vec3A>, u32)> = Vec::new();
+    let remap = points
+        .iter()
+        .map(|p| {
+            let p: Vec3A = (*p).into();
+            let key: IVec3 = ((p - min) / voxel_size).floor().as_ivec3();
+            let index = *voxels.entry(key).or_insert_with(|| {
+                sums.push((Accumulator::new(Vec3A::ZERO), 0));
+                sums.len() as u32 - 1
+            });
+
+            let (sum, count) = &mut sums[index as usize];
+            if *count == 0 || mode == VoxelPoint::Centroid {
+                sum.add(p);
+            }
+            *count += 1;
+            index
+        })
+        .collect();
+
+    let points = sums
+        .iter()
+        .map(|(sum, count)| match mode {
+            VoxelPoint::Centroid => sum.sum() / *count as f32,
+            VoxelPoint::First => sum.sum(),
+        })
+        .collect();
+
+    DownsampledPoints { points, remap }
+}
+
+/// Smooths noisy per point `normals` like those estimated by fitting planes to neighborhoods of a scan
+/// by averaging the normals of the points within `radius` for the given number of `iterations`.
+///
+/// Neighbors are weighted by distance and by how similar their normals are.
+/// A `sharpness` of `0.0` weights all normals equally,
+/// and larger values like `10.0` reduce the influence of neighbors across sharp edges.
+/// Normals pointing in the opposite direction of the current point's normal are flipped before averaging,
+/// so the result keeps the orientation of the input normals even if neighboring normals are inconsistently oriented.
+///
+/// # Panics
+/// Panics if `points` and `normals` have different lengths.
+/// # Examples
+/**
+```rust
+use geometry_tools::point_cloud::smooth_point_normals;
+use glam::Vec3A;
+
+let points = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::ONE];
+let normals = [
+    Vec3A::new(0.1, 0.0, 1.0).normalize(),
+    Vec3A::new(-0.1, 0.0, 1.0).normalize(),
+    // Plane fits can point to either side of the surface.
+    Vec3A::new(0.0, 0.1, -1.0).normalize(),
+    Vec3A::new(0.0, -0.1, -1.0).normalize(),
+];
+
+let smoothed = smooth_point_normals(&points, &normals, 2.0, 1, 0.0);
+assert!(smoothed[0].z > normals[0].z);
+assert!(smoothed[2].z < normals[2].z);
+```
+ */
+pub fn smooth_point_normals<P, N>(
+    points: &[P],
+    normals: &[N],
+    radius: f32,
+    iterations: usize,
+    sharpness: f32,
+) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    assert_eq!(
+        points.len(),
+        normals.len(),
+        "points and normals should have the same length"
+    );
+
+    let tree = KdTree::new(points);
+    let neighbors: Vec<_> = tree
+        .points()
+        .iter()
+        .map(|p| tree.within_radius(*p, radius))
+        .collect();
+
+    // A Gaussian falloff that is close to zero at the edge of the radius.
+    let spatial_falloff = 2.0 / (radius * radius);
+
+    let mut normals: Vec<Vec3A> = normals
+        .iter()
+        .map(|n| (*n).into().normalize_or_zero())
+        .collect();
+    for _ in 0..iterations {
+        normals = neighbors
+            .iter()
+            .zip(&normals)
+            .map(|(neighbors, normal)| {
+                let mut sum = Vec3A::ZERO;
+                for neighbor in neighbors {
+                    let n = normals[neighbor.index as usize];
+                    let cos_angle = normal.dot(n);
+                    let weight = (-spatial_falloff * neighbor.distance * neighbor.distance
+                        - sharpness * (1.0 - cos_angle.abs()))
+                    .exp();
+                    // Flip neighboring normals to agree with the current orientation.
+                    sum += n * weight.copysign(cos_angle);
+                }
+                sum.try_normalize().unwrap_or(*normal)
+            })
+            .collect();
+    }
+    normals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn downsample_empty() {
+        let downsampled = downsample_voxel_grid::<Vec3A>(&[], 1.0, VoxelPoint::Centroid);
+        assert!(downsampled.points.is_empty());
+        assert!(downsampled.remap.is_empty());
+    }
+
+    #[test]
+    fn downsample_far_from_origin() {
+        let offset = Vec3A::splat(-1e6);
+        let points: Vec<_> = (0..64)
+            .map(|i| offset + Vec3A::new((i % 4) as f32, (i / 4 % 4) as f32, (i / 16) as f32))
+            .collect();
+        let downsampled = downsample_voxel_grid(&points, 2.0, VoxelPoint::First);
+        assert_eq!(8, downsampled.points.len());
+        for (i, p) in points.iter().enumerate() {
+            let kept = downsampled.points[downsampled.remap[i] as usize];
+            assert!((*p - kept).max_element() < 2.0);
+            assert!((kept - *p).max_element() < 2.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn downsample_zero_voxel_size() {
+        downsample_voxel_grid(&[Vec3A::ZERO], 0.0, VoxelPoint::First);
+    }
+
+    #[test]
+    fn smooth_point_normals_noisy_plane() {
+        let points: Vec<_> = (0..100)
+            .map(|i| Vec3A::new((i % 10) as f32, (i / 10) as f32, 0.0))
+            .collect();
+        let normals: Vec<_> = (0..100)
+            .map(|i| {
+                let noise = Vec3A::new((i as f32 * 1.7).sin(), (i as f32 * 2.3).cos(), 0.0);
+                let n = (Vec3A::Z + noise * 0.2).normalize();
+                if i % 3 == 0 {
+                    -n
+                } else {
+                    n
+                }
+            })
+            .collect();
+
+        let smoothed = smooth_point_normals(&points, &normals, 2.0, 3, 0.0);
+        for (before, after) in normals.iter().zip(&smoothed) {
+            assert!(after.z.abs() > 0.99);
+            assert!(after.z.abs() >= before.z.abs());
+            // The orientation of the input is preserved.
+            assert_eq!(before.z.signum(), after.z.signum());
+        }
+    }
+
+    #[test]
+    fn smooth_point_normals_sharp_edge() {
+        // Two perpendicular planes meeting at x = 0.
+        let mut points = Vec::new();
+        let mut normals = Vec::new();
+        for i in 0..5 {
+            for j in 0..5 {
+                points.push(Vec3A::new(i as f32 * 0.25, j as f32 * 0.25, 0.0));
+                normals.push(Vec3A::Z);
+                points.push(Vec3A::new(0.0, j as f32 * 0.25, i as f32 * 0.25 + 0.25));
+                normals.push(Vec3A::NEG_X);
+            }
+        }
+
+        let blurred = smooth_point_normals(&points, &normals, 0.5, 1, 0.0);
+        let sharp = smooth_point_normals(&points, &normals, 0.5, 1, 50.0);
+        assert!(sharp[0].z > blurred[0].z);
+        assert!(sharp[0].z > 0.999);
+    }
+
+    #[test]
+    fn smooth_point_normals_zero_iterations() {
+        let normals = [Vec3A::X * 2.0];
+        assert_eq!(
+            vec![Vec3A::X],
+            smooth_point_normals(&[Vec3A::ZERO], &normals, 1.0, 0, 0.0)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Progress reporting and cancellation for long running operations.
+//!
+//! Functions ending in `_with_progress` like [simplify_mesh_with_progress](crate::simplify::simplify_mesh_with_progress)
+//! accept a [Progress] for keeping user interfaces responsive while processing large meshes.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use thiserror::Error;
+
+/// An optional progress callback and cancellation flag for a long running operation.
+///
+/// The callback is called on the same thread as the function with values from `0.0` to `1.0`.
+/// The cancellation flag is checked each time progress is reported.
+/// # Examples
+/**
+```rust
+use std::sync::atomic::AtomicBool;
+
+use geometry_tools::progress::{Cancelled, Progress};
+use geometry_tools::simplify::simplify_mesh_with_progress;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let callback = |p: f32| println!("{:.0}%", p * 100.0);
+
+// Set the flag from another thread to cancel the operation.
+let cancel = AtomicBool::new(true);
+let progress = Progress::new().with_callback(&callback).with_cancel(&cancel);
+
+let result = simplify_mesh_with_progress(&positions, &[0, 1, 2], 0, 0.01, progress);
+assert_eq!(Err(Cancelled), result);
+```
+ */
+#[derive(Default, Clone, Copy)]
+pub struct Progress<'a> {
+    pub callback: Option<&'a dyn Fn(f32)>,
+    pub cancel: Option<&'a AtomicBool>,
+}
+
+/// The error returned when an operation is cancelled.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("The operation was cancelled.")]
+pub struct Cancelled;
+
+impl<'a> Progress<'a> {
+    /// Creates a [Progress] without a callback or cancellation flag.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the callback called with the progress from `0.0` to `1.0`.
+    pub fn with_callback(mut self, callback: &'a dyn Fn(f32)) -> Self {
+        self.callback = Some(callback);
+        self
+    }
+
+    /// Sets the flag that cancels the operation when it becomes `true`.
+    pub fn with_cancel(mut self, cancel: &'a AtomicBool) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Reports `progress` in the range `0.0` to `1.0` and checks for cancellation.
+    pub(crate) fn report(&self, progress: f32) -> Result<(), Cancelled> {
+        if self.cancel.is_some_and(|c| c.load(Ordering::Relaxed)) {
+            return Err(Cancelled);
+        }
+        if let Some(callback) = self.callback {
+            callback(progress.clamp(0.0, 1.0));
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Progress<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Progress")
+            .field("callback", &self.callback.is_some())
+            .field("cancel", &self.cancel)
+            .finish()
+    }
+}
+
+/// Reports progress for `total` units of work at most once for each percent
+/// to avoid the overhead of calling the callback for every unit.
+pub(crate) struct ProgressSteps<'a> {
+    progress: Progress<'a>,
+    total: usize,
+    completed: usize,
+    reported_percent: usize,
+}
+
+impl<'a> ProgressSteps<'a> {
+    pub fn new(progress: Progress<'a>, total: usize) -> Result<Self, Cancelled> {
+        progress.report(0.0)?;
+        Ok(Self {
+            progress,
+            total,
+            completed: 0,
+            reported_percent: 0,
+        })
+    }
+
+    /// Marks `count` additional units of work as completed.
+    pub fn advance(&mut self, count: usize) -> Result<(), Cancelled> {
+        self.completed += count;
+        let percent = (self.completed * 100)
+            .checked_div(self.total)
+            .unwrap_or(100);
+        if percent > self.reported_percent {
+            self.reported_percent = percent;
+            self.progress.report(percent as f32 / 100.0)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn progress_steps_once_per_percent() {
+        let values = RefCell::new(Vec::new());
+        let callback = |p: f32| values.borrow_mut().push(p);
+        let mut steps = ProgressSteps::new(Progress::new().with_callback(&callback), 1000).unwrap();
+        for _ in 0..1000 {
+            steps.advance(1).unwrap();
+        }
+
+        let values = values.into_inner();
+        assert_eq!(101, values.len());
+        assert_eq!(Some(&0.0), values.first());
+        assert_eq!(Some(&1.0), values.last());
+    }
+
+    #[test]
+    fn progress_cancelled() {
+        let cancel = AtomicBool::new(true);
+        assert_eq!(
+            Err(Cancelled),
+            Progress::new().with_cancel(&cancel).report(0.5)
+        );
+    }
+}
@@ -0,0 +1,512 @@
+//! Functions for snapping and quantizing vertex attributes with error reporting.
+
+use glam::{Mat4, Vec2, Vec3, Vec3A};
+
+use crate::bounding::calculate_aabb_from_points;
+
+/// The result of [snap_positions].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnappedPositions {
+    pub positions: Vec<Vec3A>,
+    /// The largest distance between an original position and its snapped position.
+    pub max_error: f32,
+    /// The axis-aligned bounding box of the form `(min_xyz, max_xyz)` for the snapped positions.
+    pub bounds: (Vec3A, Vec3A),
+}
+
+/// Rounds each component of `positions` to the nearest multiple of `grid_size`.
+/// # Examples
+/**
+```rust
+use geometry_tools::quantize::snap_positions;
+use glam::Vec3A;
+
+let snapped = snap_positions(&[Vec3A::new(0.3, 0.8, -0.2)], 0.5);
+assert_eq!(vec![Vec3A::new(0.5, 1.0, 0.0)], snapped.positions);
+assert_eq!((Vec3A::new(0.5, 1.0, 0.0), Vec3A::new(0.5, 1.0, 0.0)), snapped.bounds);
+```
+ */
+pub fn snap_positions<P>(positions: &[P], grid_size: f32) -> SnappedPositions
+where
+    P: Into<Vec3A> + Copy,
+{
+    let mut max_error = 0.0f32;
+    let snapped: Vec<Vec3A> = positions
+        .iter()
+        .map(|p| {
+            let p = (*p).into();
+            let snapped = (p / grid_size).round() * grid_size;
+            max_error = max_error.max(p.distance(snapped));
+            snapped
+        })
+        .collect();
+
+    SnappedPositions {
+        bounds: calculate_aabb_from_points(&snapped),
+        positions: snapped,
+        max_error,
+    }
+}
+
+/// Positions quantized to unsigned integers by [quantize_positions].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizedPositions {
+    /// The quantized components for each position.
+    pub values: Vec<[u16; 3]>,
+    /// The position for quantized values of `0`.
+    pub offset: Vec3A,
+    /// The distance between adjacent quantized values, which is the same for all axes.
+    pub scale: f32,
+    /// The largest distance between an original position and its dequantized position.
+    pub max_error: f32,
+}
+
+/// Quantizes `positions` to unsigned integers with `bits` bits per component
+/// covering the bounding box of the positions.
+/// The same scale is used for all axes to avoid distorting the mesh.
+/// `bits` is clamped to the range `1..=16`.
+///
+/// The positions can be reconstructed as `offset + value * scale` using [dequantize_positions].
+/// # Examples
+/**
+```rust
+use geometry_tools::quantize::{dequantize_positions, quantize_positions};
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::new(1.0, 0.5, 0.3)];
+let quantized = quantize_positions(&positions, 10);
+assert_eq!([1023, 512, 307], quantized.values[1]);
+assert!(quantized.max_error < 1.0 / 1023.0);
+
+let dequantized = dequantize_positions(&quantized);
+assert!(dequantized[1].abs_diff_eq(positions[1], quantized.max_error));
+```
+ */
+pub fn quantize_positions<P>(positions: &[P], bits: u32) -> QuantizedPositions
+where
+    P: Into<Vec3A> + Copy,
+{
+    let bits = bits.clamp(1, 16);
+    let max_value = ((1u32 << bits) - 1) as f32;
+
+    let (min, max) = calculate_aabb_from_points(positions);
+    let extent = (max - min).max_element();
+    let scale = if extent > 0.0 {
+        extent / max_value
+    } else {
+        1.0
+    };
+
+    let mut quantized = QuantizedPositions {
+        values: Vec::with_capacity(positions.len()),
+        offset: min,
+        scale,
+        max_error: 0.0,
+    };
+    for p in positions {
+        let p = (*p).into();
+        let value = ((p - min) / scale)
+            .round()
+            .clamp(Vec3A::ZERO, Vec3A::splat(max_value));
+        quantized.values.push(value.to_array().map(|v| v as u16));
+        quantized.max_error = quantized.max_error.max(p.distance(min + value * scale));
+    }
+    quantized
+}
+
+/// Reconstructs the positions from [quantize_positions].
+pub fn dequantize_positions(quantized: &QuantizedPositions) -> Vec<Vec3A> {
+    quantized
+        .values
+        .iter()
+        .map(|v| {
+            quantized.offset + Vec3A::new(v[0] as f32, v[1] as f32, v[2] as f32) * quantized.scale
+        })
+        .collect()
+}
+
+/// Positions quantized to signed normalized integers by [quantize_positions_snorm16].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snorm16Positions {
+    /// The quantized components for each position.
+    pub values: Vec<[i16; 3]>,
+    /// The center of the bounding box of the original positions.
+    pub offset: Vec3A,
+    /// Half the size of the bounding box of the original positions on each axis.
+    pub scale: Vec3A,
+    /// The largest distance between an original position and its dequantized position.
+    pub max_error: f32,
+}
+
+impl Snorm16Positions {
+    /// The transform from normalized values in the range `-1.0` to `1.0` to the original positions.
+    /// This can be combined with a mesh's transform when rendering
+    /// with a vertex format that normalizes the values like `snorm16x4`.
+    pub fn dequantization_transform(&self) -> Mat4 {
+        Mat4::from_translation(self.offset.into()) * Mat4::from_scale(Vec3::from(self.scale))
+    }
+}
+
+/// Quantizes `positions` to signed normalized 16-bit integers relative to the bounding box of the positions.
+/// Each axis is scaled independently so the bounding box covers the full `-32767..=32767` range.
+///
+/// The positions can be reconstructed as `offset + value / 32767 * scale`
+/// using [dequantize_positions_snorm16] or [Snorm16Positions::dequantization_transform].
+/// # Examples
+/**
+```rust
+use geometry_tools::quantize::{dequantize_positions_snorm16, quantize_positions_snorm16};
+use glam::Vec3A;
+
+let positions = [Vec3A::new(-2.0, 0.0, 1.0), Vec3A::new(2.0, 1.0, 1.0)];
+let quantized = quantize_positions_snorm16(&positions);
+assert_eq!(vec![[-32767, -32767, 0], [32767, 32767, 0]], quantized.values);
+assert_eq!(Vec3A::new(0.0, 0.5, 1.0), quantized.offset);
+assert_eq!(Vec3A::new(2.0, 0.5, 0.0), quantized.scale);
+
+let dequantized = dequantize_positions_snorm16(&quantized);
+assert_eq!(positions.to_vec(), dequantized);
+```
+ */
+pub fn quantize_positions_snorm16<P>(positions: &[P]) -> Snorm16Positions
+where
+    P: Into<Vec3A> + Copy,
+{
+    let (min, max) = calculate_aabb_from_points(positions);
+    let offset = (min + max) / 2.0;
+    let scale = (max - min) / 2.0;
+
+    // Avoid dividing by zero for flat axes, which always quantize to zero.
+    let inverse_scale = Vec3A::select(scale.cmpgt(Vec3A::ZERO), scale.recip(), Vec3A::ZERO);
+
+    let mut quantized = Snorm16Positions {
+        values: Vec::with_capacity(positions.len()),
+        offset,
+        scale,
+        max_error: 0.0,
+    };
+    for p in positions {
+        let p = (*p).into();
+        let value = ((p - offset) * inverse_scale * 32767.0)
+            .round()
+            .clamp(Vec3A::splat(-32767.0), Vec3A::splat(32767.0));
+        quantized.values.push(value.to_array().map(|v| v as i16));
+        quantized.max_error = quantized
+            .max_error
+            .max(p.distance(offset + value / 32767.0 * scale));
+    }
+    quantized
+}
+
+/// Reconstructs the positions from [quantize_positions_snorm16].
+pub fn dequantize_positions_snorm16(quantized: &Snorm16Positions) -> Vec<Vec3A> {
+    quantized
+        .values
+        .iter()
+        .map(|v| {
+            let value = Vec3A::new(v[0] as f32, v[1] as f32, v[2] as f32) / 32767.0;
+            quantized.offset + value * quantized.scale
+        })
+        .collect()
+}
+
+/// How [quantize_uvs_unorm16] and [quantize_uvs_snorm16] handle UVs outside the normalized range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UvRangeMode {
+    /// Clamp UVs to the normalized range.
+    Clamp,
+    /// Shift UVs by whole numbers into the normalized range, which preserves sampling with repeat wrapping.
+    /// Triangles that cross a whole number boundary will be distorted.
+    Wrap,
+    /// Scale and offset the UVs so their bounding box covers the normalized range.
+    /// The UVs must be reconstructed using the `offset` and `scale`.
+    Rebase,
+}
+
+/// UVs quantized to normalized integers by [quantize_uvs_unorm16] or [quantize_uvs_snorm16].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizedUvs<T> {
+    /// The quantized components for each UV.
+    pub values: Vec<[T; 2]>,
+    /// The UV for a normalized value of `0.0`.
+    pub offset: Vec2,
+    /// The UV scale for each normalized value.
+    pub scale: Vec2,
+    /// The largest distance between an original UV and its dequantized UV.
+    /// For [UvRangeMode::Wrap], this compares with the wrapped UVs.
+    pub max_error: f32,
+    /// The number of UVs outside the normalized range that were clamped or wrapped.
+    pub out_of_range_count: usize,
+}
+
+/// Quantizes `uvs` to unsigned normalized 16-bit integers in the range `0.0` to `1.0`.
+///
+/// The UVs can be reconstructed as `offset + value / 65535 * scale` using [dequantize_uvs_unorm16].
+/// The `offset` and `scale` are only needed for [UvRangeMode::Rebase].
+/// # Examples
+/**
+```rust
+use geometry_tools::quantize::{quantize_uvs_unorm16, UvRangeMode};
+use glam::Vec2;
+
+let uvs = [Vec2::new(0.0, 0.5), Vec2::new(1.25, 1.0)];
+
+let clamped = quantize_uvs_unorm16(&uvs, UvRangeMode::Clamp);
+assert_eq!(vec![[0, 32768], [65535, 65535]], clamped.values);
+assert_eq!(1, clamped.out_of_range_count);
+assert!((clamped.max_error - 0.25).abs() < 1e-6);
+
+let wrapped = quantize_uvs_unorm16(&uvs, UvRangeMode::Wrap);
+assert_eq!([16384, 65535], wrapped.values[1]);
+
+let rebased = quantize_uvs_unorm16(&uvs, UvRangeMode::Rebase);
+assert_eq!(vec![[0, 0], [65535, 65535]], rebased.values);
+assert_eq!(Vec2::new(1.25, 0.5), rebased.scale);
+```
+ */
+pub fn quantize_uvs_unorm16(uvs: &[Vec2], range: UvRangeMode) -> QuantizedUvs<u16> {
+    quantize_uvs(uvs, range, 0.0, 65535.0, |v| v as u16)
+}
+
+/// Reconstructs the UVs from [quantize_uvs_unorm16].
+pub fn dequantize_uvs_unorm16(quantized: &QuantizedUvs<u16>) -> Vec<Vec2> {
+    dequantize_uvs(quantized, 65535.0, |v| v as f32)
+}
+
+/// Quantizes `uvs` to signed normalized 16-bit integers in the range `-1.0` to `1.0`.
+///
+/// The UVs can be reconstructed as `offset + value / 32767 * scale` using [dequantize_uvs_snorm16].
+/// The `offset` and `scale` are only needed for [UvRangeMode::Rebase].
+pub fn quantize_uvs_snorm16(uvs: &[Vec2], range: UvRangeMode) -> QuantizedUvs<i16> {
+    quantize_uvs(uvs, range, -1.0, 32767.0, |v| v as i16)
+}
+
+/// Reconstructs the UVs from [quantize_uvs_snorm16].
+pub fn dequantize_uvs_snorm16(quantized: &QuantizedUvs<i16>) -> Vec<Vec2> {
+    dequantize_uvs(quantized, 32767.0, |v| v as f32)
+}
+
+fn quantize_uvs<T, F>(
+    uvs: &[Vec2],
+    range: UvRangeMode,
+    min_normalized: f32,
+    max_value: f32,
+    convert: F,
+) -> QuantizedUvs<T>
+where
+    F: Fn(f32) -> T,
+{
+    let (offset, scale) = match range {
+        UvRangeMode::Clamp | UvRangeMode::Wrap => (Vec2::ZERO, Vec2::ONE),
+        UvRangeMode::Rebase => {
+            let min = uvs.iter().copied().reduce(Vec2::min).unwrap_or_default();
+            let max = uvs.iter().copied().reduce(Vec2::max).unwrap_or_default();
+            // Map the bounds to the normalized range from min_normalized to 1.0.
+            let scale = (max - min) / (1.0 - min_normalized);
+            (min - scale * min_normalized, scale)
+        }
+    };
+    let inverse_scale = Vec2::select(scale.cmpgt(Vec2::ZERO), scale.recip(), Vec2::ZERO);
+    let range_size = 1.0 - min_normalized;
+
+    let mut quantized = QuantizedUvs {
+        values: Vec::with_capacity(uvs.len()),
+        offset,
+        scale,
+        max_error: 0.0,
+        out_of_range_count: 0,
+    };
+    for uv in uvs {
+        let normalized = (*uv - offset) * inverse_scale;
+        let out_of_range =
+            normalized.cmplt(Vec2::splat(min_normalized)) | normalized.cmpgt(Vec2::ONE);
+        if out_of_range.any() {
+            quantized.out_of_range_count += 1;
+        }
+
+        let (target, normalized) = if range == UvRangeMode::Wrap {
+            // Only wrap out of range components to preserve values of exactly 1.0.
+            let wrapped =
+                (normalized - min_normalized).rem_euclid(Vec2::splat(range_size)) + min_normalized;
+            let wrapped = Vec2::select(out_of_range, wrapped, normalized);
+            (wrapped, wrapped)
+        } else {
+            (*uv, normalized)
+        };
+
+        let value = (normalized.clamp(Vec2::splat(min_normalized), Vec2::ONE) * max_value).round();
+        quantized.values.push(value.to_array().map(&convert));
+        quantized.max_error = quantized
+            .max_error
+            .max(target.distance(offset + value / max_value * scale));
+    }
+    quantized
+}
+
+fn dequantize_uvs<T, F>(quantized: &QuantizedUvs<T>, max_value: f32, convert: F) -> Vec<Vec2>
+where
+    T: Copy,
+    F: Fn(T) -> f32,
+{
+    quantized
+        .values
+        .iter()
+        .map(|v| {
+            quantized.offset + Vec2::new(convert(v[0]), convert(v[1])) / max_value * quantized.scale
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn snap_empty() {
+        let snapped = snap_positions::<Vec3A>(&[], 1.0);
+        assert!(snapped.positions.is_empty());
+        assert_eq!(0.0, snapped.max_error);
+        assert_eq!((Vec3A::ZERO, Vec3A::ZERO), snapped.bounds);
+    }
+
+    #[test]
+    fn snap_max_error() {
+        let positions = [Vec3A::new(0.1, 0.0, 0.0), Vec3A::new(0.9, 1.2, 0.0)];
+        let snapped = snap_positions(&positions, 1.0);
+        assert_eq!(
+            vec![Vec3A::ZERO, Vec3A::new(1.0, 1.0, 0.0)],
+            snapped.positions
+        );
+        assert_relative_eq!(0.05f32.sqrt(), snapped.max_error, epsilon = EPSILON);
+        assert_eq!((Vec3A::ZERO, Vec3A::new(1.0, 1.0, 0.0)), snapped.bounds);
+    }
+
+    #[test]
+    fn quantize_empty() {
+        let quantized = quantize_positions::<Vec3A>(&[], 16);
+        assert!(quantized.values.is_empty());
+        assert!(dequantize_positions(&quantized).is_empty());
+    }
+
+    #[test]
+    fn quantize_single_point() {
+        let quantized = quantize_positions(&[Vec3A::ONE], 16);
+        assert_eq!(vec![[0, 0, 0]], quantized.values);
+        assert_eq!(vec![Vec3A::ONE], dequantize_positions(&quantized));
+    }
+
+    #[test]
+    fn quantize_bits_clamped() {
+        let positions = [Vec3A::ZERO, Vec3A::new(4.0, 1.0, 3.0)];
+        let quantized = quantize_positions(&positions, 32);
+        assert_eq!([65535, 16384, 49151], quantized.values[1]);
+
+        let quantized = quantize_positions(&positions, 0);
+        assert_eq!([1, 0, 1], quantized.values[1]);
+        assert_relative_eq!(2.0f32.sqrt(), quantized.max_error, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn quantize_error_decreases_with_bits() {
+        let positions: Vec<_> = (0..100)
+            .map(|i| {
+                Vec3A::new(
+                    (i as f32 * 0.37).sin(),
+                    (i as f32 * 0.11).cos(),
+                    i as f32 * 0.01,
+                )
+            })
+            .collect();
+        let low = quantize_positions(&positions, 8);
+        let high = quantize_positions(&positions, 12);
+        assert!(high.max_error < low.max_error);
+        assert!(low.max_error <= low.scale * 3.0f32.sqrt() / 2.0);
+    }
+
+    #[test]
+    fn snorm16_empty() {
+        let quantized = quantize_positions_snorm16::<Vec3A>(&[]);
+        assert!(quantized.values.is_empty());
+        assert_eq!(Vec3A::ZERO, quantized.offset);
+        assert_eq!(0.0, quantized.max_error);
+    }
+
+    #[test]
+    fn snorm16_max_error() {
+        let positions: Vec<_> = (0..100)
+            .map(|i| Vec3A::new((i as f32 * 0.37).sin() * 100.0, i as f32, 5.0))
+            .collect();
+        let quantized = quantize_positions_snorm16(&positions);
+
+        // Each component is off by at most half a step.
+        let step = quantized.scale / 32767.0;
+        assert!(quantized.max_error <= (step / 2.0).length() + EPSILON);
+
+        let dequantized = dequantize_positions_snorm16(&quantized);
+        for (expected, actual) in positions.iter().zip(&dequantized) {
+            assert!(expected.distance(*actual) <= quantized.max_error);
+        }
+    }
+
+    #[test]
+    fn snorm16_transform() {
+        let positions = [Vec3A::new(1.0, 2.0, 3.0), Vec3A::new(3.0, 6.0, 4.0)];
+        let quantized = quantize_positions_snorm16(&positions);
+        let transform = quantized.dequantization_transform();
+
+        for (p, v) in positions.iter().zip(&quantized.values) {
+            let normalized = Vec3::new(v[0] as f32, v[1] as f32, v[2] as f32) / 32767.0;
+            assert!(transform
+                .transform_point3(normalized)
+                .abs_diff_eq((*p).into(), EPSILON));
+        }
+    }
+
+    #[test]
+    fn uvs_empty() {
+        let quantized = quantize_uvs_unorm16(&[], UvRangeMode::Rebase);
+        assert!(quantized.values.is_empty());
+        assert_eq!(0.0, quantized.max_error);
+        assert!(dequantize_uvs_unorm16(&quantized).is_empty());
+    }
+
+    #[test]
+    fn uvs_snorm16_modes() {
+        let uvs = [Vec2::new(-1.0, 0.0), Vec2::new(1.5, 1.0)];
+
+        let clamped = quantize_uvs_snorm16(&uvs, UvRangeMode::Clamp);
+        assert_eq!(vec![[-32767, 0], [32767, 32767]], clamped.values);
+        assert_eq!(1, clamped.out_of_range_count);
+        assert_relative_eq!(0.5, clamped.max_error, epsilon = EPSILON);
+
+        // 1.5 wraps to -0.5 with a period of 2.0.
+        let wrapped = quantize_uvs_snorm16(&uvs, UvRangeMode::Wrap);
+        assert_eq!([-16384, 32767], wrapped.values[1]);
+        assert!(wrapped.max_error < 1.0 / 32767.0);
+
+        let rebased = quantize_uvs_snorm16(&uvs, UvRangeMode::Rebase);
+        assert_eq!(vec![[-32767, -32767], [32767, 32767]], rebased.values);
+        assert_eq!(0, rebased.out_of_range_count);
+        let dequantized = dequantize_uvs_snorm16(&rebased);
+        assert!(dequantized[0].abs_diff_eq(uvs[0], EPSILON));
+        assert!(dequantized[1].abs_diff_eq(uvs[1], EPSILON));
+    }
+
+    #[test]
+    fn uvs_unorm16_round_trip() {
+        let uvs: Vec<_> = (0..100)
+            .map(|i| Vec2::new(i as f32 / 99.0, (i as f32 * 0.3).sin() * 0.5 + 0.5))
+            .collect();
+        let quantized = quantize_uvs_unorm16(&uvs, UvRangeMode::Clamp);
+        assert_eq!(0, quantized.out_of_range_count);
+        assert!(quantized.max_error <= 0.5 / 65535.0 * 2.0f32.sqrt() + f32::EPSILON);
+
+        for (expected, actual) in uvs.iter().zip(dequantize_uvs_unorm16(&quantized)) {
+            assert!(expected.distance(actual) <= quantized.max_error);
+        }
+    }
+}
@@ -0,0 +1,672 @@
+//! Functions for finding the closest points and distances between points, segments, and triangles.
+//!
+//! Segments are defined by their two endpoints and include the endpoints.
+//! Triangles are defined by their three vertices and include their interior.
+//! A [Capsule] is the set of points within a radius of a segment.
+//! Sweeps move a sphere along a direction and find the first contact.
+
+use glam::Vec3A;
+
+use crate::barycentric::calculate_barycentrics;
+use crate::bvh::ray_triangle_intersection;
+
+/// Returns the closest point on the segment `ab` to `p`
+/// and the parameter `t` from `0.0` to `1.0` such that the point is `a + (b - a) * t`.
+/// # Examples
+/**
+```rust
+use geometry_tools::query::closest_point_on_segment;
+use glam::Vec3A;
+
+let (point, t) = closest_point_on_segment(Vec3A::new(0.25, 1.0, 0.0), Vec3A::ZERO, Vec3A::X);
+assert_eq!(Vec3A::new(0.25, 0.0, 0.0), point);
+assert_eq!(0.25, t);
+```
+ */
+pub fn closest_point_on_segment(p: Vec3A, a: Vec3A, b: Vec3A) -> (Vec3A, f32) {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared == 0.0 {
+        // The segment is a single point.
+        return (a, 0.0);
+    }
+    let t = ((p - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    (a + ab * t, t)
+}
+
+/// Calculates the distance from `p` to the closest point on the segment `ab`.
+/// # Examples
+/**
+```rust
+use geometry_tools::query::point_segment_distance;
+use glam::Vec3A;
+
+assert_eq!(1.0, point_segment_distance(Vec3A::new(0.5, 1.0, 0.0), Vec3A::ZERO, Vec3A::X));
+assert_eq!(2.0, point_segment_distance(Vec3A::new(3.0, 0.0, 0.0), Vec3A::ZERO, Vec3A::X));
+```
+ */
+pub fn point_segment_distance(p: Vec3A, a: Vec3A, b: Vec3A) -> f32 {
+    closest_point_on_segment(p, a, b).0.distance(p)
+}
+
+/// Returns the closest points on the segments `p1q1` and `p2q2` in the same order as the segments.
+/// If the segments are parallel and overlap, any pair of closest points may be returned.
+/// See "Real-Time Collision Detection" by Ericson.
+/// # Examples
+/**
+```rust
+use geometry_tools::query::closest_points_on_segments;
+use glam::Vec3A;
+
+let (c1, c2) = closest_points_on_segments(
+    Vec3A::new(-1.0, 0.0, 0.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(0.5, -1.0, 2.0),
+    Vec3A::new(0.5, 1.0, 2.0),
+);
+assert_eq!(Vec3A::new(0.5, 0.0, 0.0), c1);
+assert_eq!(Vec3A::new(0.5, 0.0, 2.0), c2);
+```
+ */
+pub fn closest_points_on_segments(p1: Vec3A, q1: Vec3A, p2: Vec3A, q2: Vec3A) -> (Vec3A, Vec3A) {
+    let d1 = q1 - p1;
+    let d2 = q2 - p2;
+    let r = p1 - p2;
+    let a = d1.length_squared();
+    let e = d2.length_squared();
+    let f = d2.dot(r);
+
+    if a == 0.0 && e == 0.0 {
+        // Both segments are points.
+        return (p1, p2);
+    }
+    if a == 0.0 {
+        // The first segment is a point.
+        return (p1, closest_point_on_segment(p1, p2, q2).0);
+    }
+
+    let c = d1.dot(r);
+    if e == 0.0 {
+        // The second segment is a point.
+        return (closest_point_on_segment(p2, p1, q1).0, p2);
+    }
+
+    let b = d1.dot(d2);
+    let denominator = a * e - b * b;
+
+    // Parallel segments can use any point on the first segment.
+    let mut s = if denominator > 0.0 {
+        ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    // Find the closest point on the second segment and recompute s if t is clamped.
+    let mut t = (b * s + f) / e;
+    if t < 0.0 {
+        t = 0.0;
+        s = (-c / a).clamp(0.0, 1.0);
+    } else if t > 1.0 {
+        t = 1.0;
+        s = ((b - c) / a).clamp(0.0, 1.0);
+    }
+
+    (p1 + d1 * s, p2 + d2 * t)
+}
+
+/// Calculates the shortest distance between the segments `p1q1` and `p2q2`.
+/// # Examples
+/**
+```rust
+use geometry_tools::query::segment_segment_distance;
+use glam::Vec3A;
+
+let distance = segment_segment_distance(Vec3A::ZERO, Vec3A::X, Vec3A::new(0.0, 1.0, 1.0), Vec3A::new(1.0, 1.0, 1.0));
+assert_eq!(2.0f32.sqrt(), distance);
+```
+ */
+pub fn segment_segment_distance(p1: Vec3A, q1: Vec3A, p2: Vec3A, q2: Vec3A) -> f32 {
+    let (c1, c2) = closest_points_on_segments(p1, q1, p2, q2);
+    c1.distance(c2)
+}
+
+/// Calculates the distance from `p` to the closest point on the triangle `abc`
+/// using [closest_point_on_triangle].
+pub fn point_triangle_distance(p: Vec3A, a: Vec3A, b: Vec3A, c: Vec3A) -> f32 {
+    closest_point_on_triangle(p, a, b, c).0.distance(p)
+}
+
+/// Returns the closest points on the segment `pq` and the triangle `abc` in that order.
+/// If the segment intersects the triangle, both points are the intersection point.
+/// # Examples
+/**
+```rust
+use geometry_tools::query::closest_points_segment_triangle;
+use glam::Vec3A;
+
+// A vertical segment above the triangle.
+let (on_segment, on_triangle) = closest_points_segment_triangle(
+    Vec3A::new(0.25, 0.25, 3.0),
+    Vec3A::new(0.25, 0.25, 1.0),
+    Vec3A::ZERO,
+    Vec3A::X,
+    Vec3A::Y,
+);
+assert_eq!(Vec3A::new(0.25, 0.25, 1.0), on_segment);
+assert_eq!(Vec3A::new(0.25, 0.25, 0.0), on_triangle);
+```
+ */
+pub fn closest_points_segment_triangle(
+    p: Vec3A,
+    q: Vec3A,
+    a: Vec3A,
+    b: Vec3A,
+    c: Vec3A,
+) -> (Vec3A, Vec3A) {
+    // The ray distance is in multiples of the segment length.
+    if let Some((t, _)) = ray_triangle_intersection(p, q - p, a, b, c) {
+        if t <= 1.0 {
+            let point = p + (q - p) * t;
+            return (point, point);
+        }
+    }
+
+    // Otherwise the closest points involve an endpoint of the segment or an edge of the triangle.
+    let candidates = [
+        (p, closest_point_on_triangle(p, a, b, c).0),
+        (q, closest_point_on_triangle(q, a, b, c).0),
+        closest_points_on_segments(p, q, a, b),
+        closest_points_on_segments(p, q, b, c),
+        closest_points_on_segments(p, q, c, a),
+    ];
+    candidates
+        .into_iter()
+        .min_by(|(s0, t0), (s1, t1)| {
+            s0.distance_squared(*t0)
+                .total_cmp(&s1.distance_squared(*t1))
+        })
+        .unwrap()
+}
+
+/// Calculates the shortest distance between the segment `pq` and the triangle `abc`.
+/// The distance is zero if the segment intersects the triangle.
+/// # Examples
+/**
+```rust
+use geometry_tools::query::segment_triangle_distance;
+use glam::Vec3A;
+
+let (a, b, c) = (Vec3A::ZERO, Vec3A::X, Vec3A::Y);
+assert_eq!(0.0, segment_triangle_distance(Vec3A::new(0.2, 0.2, -1.0), Vec3A::new(0.2, 0.2, 1.0), a, b, c));
+assert_eq!(1.0, segment_triangle_distance(Vec3A::new(-1.0, 0.0, 0.0), Vec3A::new(-2.0, 0.0, 0.0), a, b, c));
+```
+ */
+pub fn segment_triangle_distance(p: Vec3A, q: Vec3A, a: Vec3A, b: Vec3A, c: Vec3A) -> f32 {
+    let (s, t) = closest_points_segment_triangle(p, q, a, b, c);
+    s.distance(t)
+}
+
+/// A capsule defined by the segment from `start` to `end` and a `radius` around the segment.
+/// Capsules are commonly used as character controller collision shapes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule {
+    pub start: Vec3A,
+    pub end: Vec3A,
+    pub radius: f32,
+}
+
+/// The overlap between a [Capsule] and a triangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Penetration {
+    /// The closest point on the triangle to the capsule's segment.
+    pub point: Vec3A,
+    /// The normalized direction to move the capsule to resolve the overlap.
+    pub normal: Vec3A,
+    /// The distance to move the capsule along [normal](#structfield.normal) to resolve the overlap.
+    pub depth: f32,
+}
+
+/// Calculates the overlap between `capsule` and the triangle `abc`
+/// or `None` if the capsule does not overlap the triangle.
+///
+/// If the capsule's segment passes through the triangle, the capsule is pushed out along the face normal
+/// to the side of the triangle containing the center of the segment.
+/// The normal is zero if the segment intersects a degenerate triangle with zero area.
+/// # Examples
+/**
+```rust
+use geometry_tools::query::{capsule_triangle_penetration, Capsule};
+use glam::Vec3A;
+
+let capsule = Capsule {
+    start: Vec3A::new(0.25, 0.25, 0.5),
+    end: Vec3A::new(0.25, 0.25, 2.0),
+    radius: 1.0,
+};
+let penetration = capsule_triangle_penetration(capsule, Vec3A::ZERO, Vec3A::X, Vec3A::Y).unwrap();
+assert_eq!(Vec3A::new(0.25, 0.25, 0.0), penetration.point);
+assert_eq!(Vec3A::Z, penetration.normal);
+assert_eq!(0.5, penetration.depth);
+
+let capsule = Capsule { radius: 0.25, ..capsule };
+assert_eq!(None, capsule_triangle_penetration(capsule, Vec3A::ZERO, Vec3A::X, Vec3A::Y));
+```
+ */
+pub fn capsule_triangle_penetration(
+    capsule: Capsule,
+    a: Vec3A,
+    b: Vec3A,
+    c: Vec3A,
+) -> Option<Penetration> {
+    let Capsule { start, end, radius } = capsule;
+    let (on_segment, on_triangle) = closest_points_segment_triangle(start, end, a, b, c);
+    let distance = on_segment.distance(on_triangle);
+    if distance > radius {
+        return None;
+    }
+
+    if distance > 0.0 {
+        Some(Penetration {
+            point: on_triangle,
+            normal: (on_segment - on_triangle) / distance,
+            depth: radius - distance,
+        })
+    } else {
+        // Push the capsule out of the triangle's plane on the side of the segment center.
+        let mut normal = (b - a).cross(c - a).normalize_or_zero();
+        if normal.dot((start + end) * 0.5 - a) < 0.0 {
+            normal = -normal;
+        }
+        let deepest = normal.dot(start - a).min(normal.dot(end - a));
+        Some(Penetration {
+            point: on_triangle,
+            normal,
+            depth: radius - deepest,
+        })
+    }
+}
+
+/// The first contact of a sphere moving along a direction with a triangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SweepContact {
+    /// The distance moved before the contact in multiples of the direction.
+    /// This is zero if the sphere initially overlaps the triangle.
+    pub distance: f32,
+    /// The contact point on the triangle.
+    pub point: Vec3A,
+    /// The normalized direction from [point](#structfield.point) to the center of the sphere at the contact.
+    pub normal: Vec3A,
+}
+
+/// Finds the first contact of the sphere with `center` and `radius` moving along `direction` with the triangle `abc`.
+/// The direction does not need to be normalized.
+/// Returns `None` if the sphere never touches the triangle.
+/// # Examples
+/**
+```rust
+use geometry_tools::query::sweep_sphere_triangle;
+use glam::Vec3A;
+
+let center = Vec3A::new(0.25, 0.25, 3.0);
+let contact = sweep_sphere_triangle(center, 1.0, Vec3A::NEG_Z, Vec3A::ZERO, Vec3A::X, Vec3A::Y).unwrap();
+assert_eq!(2.0, contact.distance);
+assert_eq!(Vec3A::new(0.25, 0.25, 0.0), contact.point);
+assert_eq!(Vec3A::Z, contact.normal);
+
+assert_eq!(None, sweep_sphere_triangle(center, 1.0, Vec3A::Z, Vec3A::ZERO, Vec3A::X, Vec3A::Y));
+```
+ */
+pub fn sweep_sphere_triangle(
+    center: Vec3A,
+    radius: f32,
+    direction: Vec3A,
+    a: Vec3A,
+    b: Vec3A,
+    c: Vec3A,
+) -> Option<SweepContact> {
+    let face_normal = (b - a).cross(c - a).normalize_or_zero();
+
+    let (closest, _) = closest_point_on_triangle(center, a, b, c);
+    let distance = closest.distance(center);
+    if distance <= radius {
+        let normal = if distance > 0.0 {
+            (center - closest) / distance
+        } else if face_normal.dot(direction) > 0.0 {
+            -face_normal
+        } else {
+            face_normal
+        };
+        return Some(SweepContact {
+            distance: 0.0,
+            point: closest,
+            normal,
+        });
+    }
+
+    let mut first: Option<SweepContact> = None;
+    let mut update = |t: f32, point: Vec3A| {
+        if first.is_none_or(|f| t < f.distance) {
+            first = Some(SweepContact {
+                distance: t,
+                point,
+                normal: (center + direction * t - point).normalize_or_zero(),
+            });
+        }
+    };
+
+    // The interior of the face is hit when the sphere is radius away from the plane.
+    let normal = if face_normal.dot(center - a) < 0.0 {
+        -face_normal
+    } else {
+        face_normal
+    };
+    let speed = normal.dot(direction);
+    if speed < 0.0 {
+        let t = (radius - normal.dot(center - a)) / speed;
+        let point = center + direction * t - normal * radius;
+        let inside = calculate_barycentrics(point, a, b, c).is_some_and(|b| b.min_element() >= 0.0);
+        if t >= 0.0 && inside {
+            update(t, point);
+        }
+    }
+
+    // Otherwise the sphere hits one of the edges or vertices.
+    for (p, q) in [(a, b), (b, c), (c, a)] {
+        let edge = q - p;
+        let length_squared = edge.length_squared();
+        if length_squared > 0.0 {
+            // Intersect with an infinite cylinder around the edge.
+            let m = center - p;
+            let md = m - edge * (m.dot(edge) / length_squared);
+            let dd = direction - edge * (direction.dot(edge) / length_squared);
+            if let Some(t) =
+                smallest_root(dd.dot(dd), 2.0 * md.dot(dd), md.dot(md) - radius * radius)
+            {
+                let s = (m + direction * t).dot(edge) / length_squared;
+                if (0.0..=1.0).contains(&s) {
+                    update(t, p + edge * s);
+                }
+            }
+        }
+    }
+    for v in [a, b, c] {
+        let m = center - v;
+        let coefficients = (
+            direction.dot(direction),
+            2.0 * m.dot(direction),
+            m.dot(m) - radius * radius,
+        );
+        if let Some(t) = smallest_root(coefficients.0, coefficients.1, coefficients.2) {
+            update(t, v);
+        }
+    }
+
+    first
+}
+
+/// Returns the smallest non negative root of `a * t^2 + b * t + c` if the polynomial has real roots.
+fn smallest_root(a: f32, b: f32, c: f32) -> Option<f32> {
+    if a <= 0.0 {
+        return None;
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let t = (-b - discriminant.sqrt()) / (2.0 * a);
+    (t >= 0.0).then_some(t)
+}
+
+/// Returns the closest point on the triangle `abc` to `p` and its barycentric coordinates.
+/// Degenerate triangles return the closest point on one of the edges or vertices.
+/// See "Real-Time Collision Detection" by Ericson.
+/// # Examples
+/**
+```rust
+use geometry_tools::query::closest_point_on_triangle;
+use glam::Vec3A;
+
+let (point, barycentrics) =
+    closest_point_on_triangle(Vec3A::new(0.25, 0.5, 2.0), Vec3A::ZERO, Vec3A::X, Vec3A::Y);
+assert_eq!(Vec3A::new(0.25, 0.5, 0.0), point);
+assert_eq!(Vec3A::new(0.25, 0.25, 0.5), barycentrics);
+```
+ */
+pub fn closest_point_on_triangle(p: Vec3A, a: Vec3A, b: Vec3A, c: Vec3A) -> (Vec3A, Vec3A) {
+    let ab = b - a;
+    let ac = c - a;
+    let ap = p - a;
+    let d1 = ab.dot(ap);
+    let d2 = ac.dot(ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return (a, Vec3A::X);
+    }
+
+    let bp = p - b;
+    let d3 = ab.dot(bp);
+    let d4 = ac.dot(bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return (b, Vec3A::Y);
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return (a + ab * v, Vec3A::new(1.0 - v, v, 0.0));
+    }
+
+    let cp = p - c;
+    let d5 = ab.dot(cp);
+    let d6 = ac.dot(cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return (c, Vec3A::Z);
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return (a + ac * w, Vec3A::new(1.0 - w, 0.0, w));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return (b + (c - b) * w, Vec3A::new(0.0, 1.0 - w, w));
+    }
+
+    let sum = va + vb + vc;
+    if sum == 0.0 {
+        // The triangle is degenerate.
+        return (a, Vec3A::X);
+    }
+    let v = vb / sum;
+    let w = vc / sum;
+    (a + ab * v + ac * w, Vec3A::new(1.0 - v - w, v, w))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn segment_degenerate() {
+        let (point, t) = closest_point_on_segment(Vec3A::ONE, Vec3A::X, Vec3A::X);
+        assert_eq!((Vec3A::X, 0.0), (point, t));
+    }
+
+    #[test]
+    fn segments_parallel() {
+        let distance = segment_segment_distance(
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::new(0.5, 1.0, 0.0),
+            Vec3A::new(2.0, 1.0, 0.0),
+        );
+        assert_eq!(1.0, distance);
+    }
+
+    #[test]
+    fn segments_endpoint_closest() {
+        let (c1, c2) = closest_points_on_segments(
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::new(2.0, 1.0, 0.0),
+            Vec3A::new(3.0, 2.0, 0.0),
+        );
+        assert_eq!(Vec3A::X, c1);
+        assert_eq!(Vec3A::new(2.0, 1.0, 0.0), c2);
+    }
+
+    #[test]
+    fn segments_point_and_segment() {
+        let (c1, c2) = closest_points_on_segments(Vec3A::Y, Vec3A::Y, Vec3A::ZERO, Vec3A::X);
+        assert_eq!((Vec3A::Y, Vec3A::ZERO), (c1, c2));
+        let (c1, c2) = closest_points_on_segments(Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Y);
+        assert_eq!((Vec3A::ZERO, Vec3A::Y), (c1, c2));
+    }
+
+    #[test]
+    fn segment_triangle_edge_closest() {
+        // A segment crossing over the edge from a to b below the triangle's plane.
+        let (s, t) = closest_points_segment_triangle(
+            Vec3A::new(0.5, -1.0, -1.0),
+            Vec3A::new(0.5, 1.0, -1.0),
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+        );
+        assert!(s.abs_diff_eq(Vec3A::new(0.5, 0.0, -1.0), EPSILON));
+        assert!(t.abs_diff_eq(Vec3A::new(0.5, 0.0, 0.0), EPSILON));
+    }
+
+    #[test]
+    fn segment_triangle_coplanar_inside() {
+        let distance = segment_triangle_distance(
+            Vec3A::new(0.1, 0.1, 0.0),
+            Vec3A::new(0.2, 0.2, 0.0),
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+        );
+        assert!(distance < EPSILON);
+    }
+
+    #[test]
+    fn segment_triangle_matches_sampling() {
+        let [a, b, c] = [
+            Vec3A::ZERO,
+            Vec3A::new(2.0, 0.5, 0.0),
+            Vec3A::new(0.5, 2.0, 1.0),
+        ];
+        let (p, q) = (Vec3A::new(-1.0, 3.0, 2.0), Vec3A::new(3.0, -1.0, 1.5));
+        let expected = (0..=1000)
+            .map(|i| point_triangle_distance(p.lerp(q, i as f32 / 1000.0), a, b, c))
+            .fold(f32::INFINITY, f32::min);
+        assert!((expected - segment_triangle_distance(p, q, a, b, c)).abs() < 0.001);
+    }
+
+    #[test]
+    fn capsule_triangle_crossing() {
+        let capsule = Capsule {
+            start: Vec3A::new(0.25, 0.25, -0.5),
+            end: Vec3A::new(0.25, 0.25, 2.0),
+            radius: 0.1,
+        };
+        let penetration =
+            capsule_triangle_penetration(capsule, Vec3A::ZERO, Vec3A::X, Vec3A::Y).unwrap();
+        assert_eq!(Vec3A::Z, penetration.normal);
+        assert!((0.6 - penetration.depth).abs() < EPSILON);
+    }
+
+    #[test]
+    fn capsule_triangle_edge() {
+        // A capsule lying next to the edge from (0, 0, 0) to (1, 0, 0).
+        let capsule = Capsule {
+            start: Vec3A::new(-1.0, -0.5, 0.0),
+            end: Vec3A::new(2.0, -0.5, 0.0),
+            radius: 0.75,
+        };
+        let penetration =
+            capsule_triangle_penetration(capsule, Vec3A::ZERO, Vec3A::X, Vec3A::Y).unwrap();
+        assert_eq!(Vec3A::NEG_Y, penetration.normal);
+        assert!((0.25 - penetration.depth).abs() < EPSILON);
+    }
+
+    #[test]
+    fn capsule_triangle_touching() {
+        let capsule = Capsule {
+            start: Vec3A::new(0.25, 0.25, 1.0),
+            end: Vec3A::new(0.25, 0.25, 1.0),
+            radius: 1.0,
+        };
+        let penetration =
+            capsule_triangle_penetration(capsule, Vec3A::ZERO, Vec3A::X, Vec3A::Y).unwrap();
+        assert_eq!(0.0, penetration.depth);
+    }
+
+    #[test]
+    fn sweep_sphere_triangle_edge() {
+        // The sphere passes beside the triangle and hits the edge from (0, 0, 0) to (1, 0, 0).
+        let contact = sweep_sphere_triangle(
+            Vec3A::new(0.5, -0.5, 3.0),
+            1.0,
+            Vec3A::NEG_Z,
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+        )
+        .unwrap();
+        assert!((contact.distance - (3.0 - 0.75f32.sqrt())).abs() < EPSILON);
+        assert!(contact
+            .point
+            .abs_diff_eq(Vec3A::new(0.5, 0.0, 0.0), EPSILON));
+        assert!((contact.normal.length() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn sweep_sphere_triangle_vertex() {
+        let contact = sweep_sphere_triangle(
+            Vec3A::new(-3.0, -1.0, 0.0),
+            1.0,
+            Vec3A::X,
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+        )
+        .unwrap();
+        assert!((contact.distance - 3.0).abs() < EPSILON);
+        assert_eq!(Vec3A::ZERO, contact.point);
+    }
+
+    #[test]
+    fn sweep_sphere_triangle_initial_overlap() {
+        let contact = sweep_sphere_triangle(
+            Vec3A::new(0.25, 0.25, 0.5),
+            1.0,
+            Vec3A::Z,
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+        )
+        .unwrap();
+        assert_eq!(0.0, contact.distance);
+        assert_eq!(Vec3A::Z, contact.normal);
+    }
+
+    #[test]
+    fn sweep_sphere_triangle_parallel_miss() {
+        assert_eq!(
+            None,
+            sweep_sphere_triangle(
+                Vec3A::new(-5.0, 0.25, 2.0),
+                1.0,
+                Vec3A::X,
+                Vec3A::ZERO,
+                Vec3A::X,
+                Vec3A::Y,
+            )
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Functions for applying vertex remap tables to indices and vertex attributes.
+//!
+//! A remap table assigns each original vertex a new vertex index.
+//! Vertices that are not used in the output are assigned [REMAP_UNUSED].
+//! This is the direction produced when merging or removing vertices.
+//!
+//! A gather table instead lists the original vertex used for each new vertex.
+//! Use [invert_remap] to convert a remap table to a gather table.
+
+/// The remap table value for vertices that are not used in the output.
+pub const REMAP_UNUSED: u32 = u32::MAX;
+
+/// Calculates new values where each original value is moved to the output index in `remap`.
+/// If multiple original values map to the same output, the first original value is used.
+/// Outputs without a corresponding original value use the default value.
+/// `remap` should have the same length as `values`.
+///
+/// # Panics
+/// Panics if any value in `remap` other than [REMAP_UNUSED] is not less than `output_count`.
+/// # Examples
+/**
+```rust
+use geometry_tools::remap::{scatter_attribute, REMAP_UNUSED};
+use glam::Vec2;
+
+let uvs = [Vec2::X, Vec2::Y, Vec2::X, Vec2::ONE];
+
+// Vertex 2 is merged with vertex 0 and vertex 3 is removed.
+let remap = [0, 1, 0, REMAP_UNUSED];
+assert_eq!(vec![Vec2::X, Vec2::Y], scatter_attribute(&uvs, &remap, 2));
+```
+ */
+pub fn scatter_attribute<T>(values: &[T], remap: &[u32], output_count: usize) -> Vec<T>
+where
+    T: Copy + Default,
+{
+    let mut output = vec![T::default(); output_count];
+    let mut written = vec![false; output_count];
+    for (value, new_index) in values.iter().zip(remap) {
+        if *new_index != REMAP_UNUSED && !written[*new_index as usize] {
+            output[*new_index as usize] = *value;
+            written[*new_index as usize] = true;
+        }
+    }
+    output
+}
+
+/// Calculates new values by selecting the original value at each index in `gather`.
+/// # Examples
+/**
+```rust
+use geometry_tools::remap::gather_attribute;
+use glam::Vec3A;
+
+let positions = [Vec3A::X, Vec3A::Y, Vec3A::Z];
+assert_eq!(
+    vec![Vec3A::Z, Vec3A::X, Vec3A::X],
+    gather_attribute(&positions, &[2, 0, 0])
+);
+```
+ */
+pub fn gather_attribute<T>(values: &[T], gather: &[u32]) -> Vec<T>
+where
+    T: Copy,
+{
+    gather.iter().map(|i| values[*i as usize]).collect()
+}
+
+/// Applies the remap table `remap` to the vertex indices in `indices`.
+/// Indices referencing vertices marked as [REMAP_UNUSED] remain [REMAP_UNUSED].
+/// # Examples
+/**
+```rust
+use geometry_tools::remap::remap_indices;
+
+let remap = [0, 1, 0, 2];
+assert_eq!(vec![0, 1, 2, 0, 2, 1], remap_indices(&[0, 1, 3, 2, 3, 1], &remap));
+```
+ */
+pub fn remap_indices(indices: &[u32], remap: &[u32]) -> Vec<u32> {
+    indices.iter().map(|i| remap[*i as usize]).collect()
+}
+
+/// Converts the remap table `remap` into a gather table with `output_count` elements.
+/// Each output lists the first original vertex that maps to that output.
+/// Outputs without a corresponding original vertex are assigned [REMAP_UNUSED].
+/// # Examples
+/**
+```rust
+use geometry_tools::remap::{invert_remap, REMAP_UNUSED};
+
+assert_eq!(vec![0, 1], invert_remap(&[0, 1, 0, REMAP_UNUSED], 2));
+```
+ */
+pub fn invert_remap(remap: &[u32], output_count: usize) -> Vec<u32> {
+    let mut gather = vec![REMAP_UNUSED; output_count];
+    for (i, new_index) in remap.iter().enumerate() {
+        if *new_index != REMAP_UNUSED && gather[*new_index as usize] == REMAP_UNUSED {
+            gather[*new_index as usize] = i as u32;
+        }
+    }
+    gather
+}
+
+/// Calculates the number of output vertices for the remap table `remap`.
+/// This assumes every output index is used by at least one original vertex.
+/// # Examples
+/**
+```rust
+use geometry_tools::remap::{remap_output_count, REMAP_UNUSED};
+
+assert_eq!(3, remap_output_count(&[0, 2, 1, 2, REMAP_UNUSED]));
+```
+ */
+pub fn remap_output_count(remap: &[u32]) -> usize {
+    remap
+        .iter()
+        .filter(|i| **i != REMAP_UNUSED)
+        .map(|i| *i as usize + 1)
+        .max()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{Vec3A, Vec4};
+
+    use super::*;
+
+    #[test]
+    fn scatter_empty() {
+        assert!(scatter_attribute::<Vec3A>(&[], &[], 0).is_empty());
+    }
+
+    #[test]
+    fn scatter_identity() {
+        let values = [Vec4::X, Vec4::Y, Vec4::Z];
+        assert_eq!(values.to_vec(), scatter_attribute(&values, &[0, 1, 2], 3));
+    }
+
+    #[test]
+    fn scatter_missing_output_uses_default() {
+        assert_eq!(
+            vec![0.0, 2.0],
+            scatter_attribute(&[1.0f32, 2.0], &[REMAP_UNUSED, 1], 2)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn scatter_out_of_range() {
+        scatter_attribute(&[1, 2], &[0, 2], 2);
+    }
+
+    #[test]
+    fn scatter_first_value_wins() {
+        assert_eq!(
+            vec![[1u8; 4]],
+            scatter_attribute(&[[1u8; 4], [2u8; 4]], &[0, 0], 1)
+        );
+    }
+
+    #[test]
+    fn gather_then_scatter_round_trip() {
+        let values = [Vec3A::X, Vec3A::Y, Vec3A::X, Vec3A::Z];
+        let remap = [0, 1, 0, 2];
+        let count = remap_output_count(&remap);
+        let unique = scatter_attribute(&values, &remap, count);
+        let gather = invert_remap(&remap, count);
+        assert_eq!(unique, gather_attribute(&values, &gather));
+    }
+
+    #[test]
+    fn remap_unused_indices() {
+        assert_eq!(
+            vec![REMAP_UNUSED, 0],
+            remap_indices(&[1, 0], &[0, REMAP_UNUSED])
+        );
+    }
+
+    #[test]
+    fn output_count_empty() {
+        assert_eq!(0, remap_output_count(&[]));
+        assert_eq!(0, remap_output_count(&[REMAP_UNUSED]));
+    }
+}
@@ -0,0 +1,127 @@
+//! Internal traits for sharing algorithms between single and double precision vectors.
+
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Sub};
+
+use glam::{DVec3, Vec2, Vec3A};
+
+/// A floating point scalar type like `f32` or `f64`.
+pub(crate) trait Scalar:
+    Copy
+    + Default
+    + Send
+    + Sync
+    + PartialOrd
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Sum
+{
+    const ZERO: Self;
+
+    fn from_usize(value: usize) -> Self;
+
+    fn sqrt(self) -> Self;
+}
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+
+    fn from_usize(value: usize) -> Self {
+        value as f32
+    }
+
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+}
+
+impl Scalar for f64 {
+    const ZERO: Self = 0.0;
+
+    fn from_usize(value: usize) -> Self {
+        value as f64
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+}
+
+/// A vector type like [Vec2], [Vec3A], or [DVec3].
+pub(crate) trait Vector:
+    Copy
+    + Default
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + Mul<Self::Scalar, Output = Self>
+    + Div<Self::Scalar, Output = Self>
+    + Sum
+{
+    type Scalar: Scalar;
+
+    const ZERO: Self;
+
+    fn min(self, other: Self) -> Self;
+
+    fn max(self, other: Self) -> Self;
+
+    fn dot(self, other: Self) -> Self::Scalar;
+
+    fn length(self) -> Self::Scalar {
+        self.dot(self).sqrt()
+    }
+
+    fn distance_squared(self, other: Self) -> Self::Scalar {
+        let delta = self - other;
+        delta.dot(delta)
+    }
+}
+
+/// A 3D vector type like [Vec3A] or [DVec3].
+pub(crate) trait Vector3: Vector {
+    fn cross(self, other: Self) -> Self;
+}
+
+macro_rules! impl_vector {
+    ($vector:ty, $scalar:ty) => {
+        impl Vector for $vector {
+            type Scalar = $scalar;
+
+            const ZERO: Self = <$vector>::ZERO;
+
+            fn min(self, other: Self) -> Self {
+                <$vector>::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                <$vector>::max(self, other)
+            }
+
+            fn dot(self, other: Self) -> Self::Scalar {
+                <$vector>::dot(self, other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_vector3 {
+    ($vector:ty, $scalar:ty) => {
+        impl_vector!($vector, $scalar);
+
+        impl Vector3 for $vector {
+            fn cross(self, other: Self) -> Self {
+                <$vector>::cross(self, other)
+            }
+        }
+    };
+}
+
+impl_vector!(Vec2, f32);
+impl_vector3!(Vec3A, f32);
+impl_vector3!(DVec3, f64);
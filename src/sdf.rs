@@ -0,0 +1,306 @@
+//! Functions for generating signed distance fields from closed triangle meshes.
+
+use std::collections::HashMap;
+
+use glam::{UVec3, Vec3A};
+
+use crate::bvh::Bvh;
+use crate::progress::{Cancelled, Progress};
+use crate::topology::edge_key;
+
+/// A regular 3D grid of signed distances with negative values inside the mesh.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedDistanceField {
+    /// The position of the sample at index `(0, 0, 0)`.
+    pub origin: Vec3A,
+    /// The distance between adjacent samples along each axis.
+    pub cell_size: f32,
+    /// The number of samples along each axis.
+    pub dimensions: UVec3,
+    /// The signed distance for each sample with x varying fastest followed by y and then z.
+    pub distances: Vec<f32>,
+}
+
+impl SignedDistanceField {
+    /// The signed distance for the sample at `(x, y, z)`.
+    pub fn distance(&self, x: u32, y: u32, z: u32) -> f32 {
+        self.distances[self.index(x, y, z)]
+    }
+
+    /// The world space position for the sample at `(x, y, z)`.
+    pub fn position(&self, x: u32, y: u32, z: u32) -> Vec3A {
+        self.origin + Vec3A::new(x as f32, y as f32, z as f32) * self.cell_size
+    }
+
+    fn index(&self, x: u32, y: u32, z: u32) -> usize {
+        let d = self.dimensions;
+        (z as usize * d.y as usize + y as usize) * d.x as usize + x as usize
+    }
+}
+
+/// Calculates a signed distance field for the closed mesh in `bvh`
+/// with samples spaced `cell_size` apart covering the mesh bounds expanded by `padding`.
+///
+/// Distances are calculated using the closest point on the mesh.
+/// The sign uses the angle weighted pseudonormal of the closest triangle, edge, or vertex,
+/// which is correct for closed meshes with consistent counterclockwise winding.
+/// See "Signed Distance Computation Using the Angle Weighted Pseudonormal" by Bærentzen and Aanæs.
+///
+/// If `narrow_band` is `Some`, samples farther than this distance from the surface
+/// are clamped to plus or minus the narrow band distance, which is much faster for large grids.
+/// The sign for these samples is calculated by counting ray intersections.
+/// # Examples
+/**
+```rust
+use geometry_tools::bvh::Bvh;
+use geometry_tools::sdf::calculate_signed_distance_field;
+use glam::Vec3A;
+
+// A tetrahedron with counterclockwise winding.
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+let indices = [0, 2, 1, 0, 1, 3, 0, 3, 2, 1, 2, 3];
+let bvh = Bvh::new(&positions, &indices);
+
+let sdf = calculate_signed_distance_field(&bvh, 0.25, 0.5, None);
+assert_eq!(Vec3A::splat(-0.5), sdf.origin);
+
+// The sample at the origin is on the surface.
+assert_eq!(0.0, sdf.distance(2, 2, 2));
+
+// Inside samples are negative.
+assert!(sdf.distance(3, 3, 3) < 0.0);
+```
+ */
+pub fn calculate_signed_distance_field(
+    bvh: &Bvh,
+    cell_size: f32,
+    padding: f32,
+    narrow_band: Option<f32>,
+) -> SignedDistanceField {
+    // Progress without a cancellation flag can't fail.
+    calculate_signed_distance_field_with_progress(
+        bvh,
+        cell_size,
+        padding,
+        narrow_band,
+        Progress::default(),
+    )
+    .unwrap()
+}
+
+/// Calculates a signed distance field like [calculate_signed_distance_field]
+/// while reporting progress after each slice of samples along the z axis.
+/// Returns [Cancelled] if the cancellation flag of `progress` is set before all samples are calculated.
+pub fn calculate_signed_distance_field_with_progress(
+    bvh: &Bvh,
+    cell_size: f32,
+    padding: f32,
+    narrow_band: Option<f32>,
+    progress: Progress,
+) -> Result<SignedDistanceField, Cancelled> {
+    progress.report(0.0)?;
+
+    let (min, max) = crate::bounding::calculate_aabb_from_points(bvh.positions());
+    let origin = min - padding;
+    let dimensions = ((max + padding - origin) / cell_size).ceil().as_uvec3() + UVec3::ONE;
+
+    let mut sdf = SignedDistanceField {
+        origin,
+        cell_size,
+        dimensions,
+        distances: Vec::new(),
+    };
+    if bvh.triangles().is_empty() {
+        progress.report(1.0)?;
+        return Ok(sdf);
+    }
+
+    let pseudonormals = Pseudonormals::new(bvh);
+    let max_distance = narrow_band.unwrap_or(f32::INFINITY);
+    sdf.distances = Vec::with_capacity(dimensions.element_product() as usize);
+    for z in 0..dimensions.z {
+        for y in 0..dimensions.y {
+            for x in 0..dimensions.x {
+                let p = sdf.position(x, y, z);
+                let distance = match bvh.closest_point(p, max_distance) {
+                    Some(closest) => {
+                        let normal =
+                            pseudonormals.normal(bvh, closest.triangle, closest.barycentrics);
+                        if (p - closest.point).dot(normal) < 0.0 {
+                            -closest.distance
+                        } else {
+                            closest.distance
+                        }
+                    }
+                    None => {
+                        if bvh.contains_point(p) {
+                            -max_distance
+                        } else {
+                            max_distance
+                        }
+                    }
+                };
+                sdf.distances.push(distance);
+            }
+        }
+        progress.report((z + 1) as f32 / dimensions.z as f32)?;
+    }
+    Ok(sdf)
+}
+
+/// Angle weighted normals for the vertices and edges of a mesh.
+/// Vertices with the same position are treated as the same vertex.
+struct Pseudonormals {
+    welded: Vec<u32>,
+    vertex_normals: Vec<Vec3A>,
+    edge_normals: HashMap<[u32; 2], Vec3A>,
+}
+
+impl Pseudonormals {
+    fn new(bvh: &Bvh) -> Self {
+        let mut first_vertex = HashMap::new();
+        let welded: Vec<u32> = bvh
+            .positions()
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                *first_vertex
+                    .entry(p.to_array().map(f32::to_bits))
+                    .or_insert(i as u32)
+            })
+            .collect();
+
+        let mut vertex_normals = vec![Vec3A::ZERO; welded.len()];
+        let mut edge_normals: HashMap<[u32; 2], Vec3A> = HashMap::new();
+        for (i, t) in bvh.triangles().iter().enumerate() {
+            let [a, b, c] = bvh.triangle_positions(i as u32);
+            let normal = (b - a).cross(c - a).normalize_or_zero();
+            let [w0, w1, w2] = t.map(|v| welded[v as usize]);
+
+            for (v, p, p1, p2) in [(w0, a, b, c), (w1, b, c, a), (w2, c, a, b)] {
+                let angle = (p1 - p).angle_between(p2 - p);
+                if angle.is_finite() {
+                    vertex_normals[v as usize] += normal * angle;
+                }
+            }
+            for (v0, v1) in [(w0, w1), (w1, w2), (w2, w0)] {
+                *edge_normals.entry(edge_key(v0, v1)).or_default() += normal;
+            }
+        }
+
+        Self {
+            welded,
+            vertex_normals,
+            edge_normals,
+        }
+    }
+
+    fn normal(&self, bvh: &Bvh, triangle: u32, barycentrics: Vec3A) -> Vec3A {
+        let t = bvh.triangles()[triangle as usize].map(|v| self.welded[v as usize]);
+
+        // Determine if the closest point is on a vertex, edge, or the triangle face.
+        let epsilon = 1e-5;
+        let on_feature = barycentrics.to_array().map(|b| b > epsilon);
+        match on_feature {
+            [true, false, false] => self.vertex_normals[t[0] as usize],
+            [false, true, false] => self.vertex_normals[t[1] as usize],
+            [false, false, true] => self.vertex_normals[t[2] as usize],
+            [true, true, false] => self.edge_normals[&edge_key(t[0], t[1])],
+            [false, true, true] => self.edge_normals[&edge_key(t[1], t[2])],
+            [true, false, true] => self.edge_normals[&edge_key(t[2], t[0])],
+            _ => {
+                let [a, b, c] = bvh.triangle_positions(triangle);
+                (b - a).cross(c - a)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::shapes::generate_cube;
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn sdf_empty() {
+        let bvh = Bvh::new::<Vec3A>(&[], &[]);
+        let sdf = calculate_signed_distance_field(&bvh, 1.0, 0.0, None);
+        assert_eq!(UVec3::ONE, sdf.dimensions);
+        assert!(sdf.distances.is_empty());
+    }
+
+    #[test]
+    fn sdf_cube() {
+        let cube = generate_cube(2.0);
+        let (positions, indices) = (cube.positions, cube.indices);
+        let bvh = Bvh::new(&positions, &indices);
+        let sdf = calculate_signed_distance_field(&bvh, 0.5, 1.0, None);
+        assert_eq!(UVec3::splat(9), sdf.dimensions);
+        assert_eq!(729, sdf.distances.len());
+
+        for z in 0..9 {
+            for y in 0..9 {
+                for x in 0..9 {
+                    // The exact signed distance to a box.
+                    let p = sdf.position(x, y, z);
+                    let q = p.abs() - Vec3A::ONE;
+                    let expected = q.max(Vec3A::ZERO).length() + q.max_element().min(0.0);
+                    assert_relative_eq!(expected, sdf.distance(x, y, z), epsilon = EPSILON);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sdf_progress_cancel() {
+        let cube = generate_cube(2.0);
+        let (positions, indices) = (cube.positions, cube.indices);
+        let bvh = Bvh::new(&positions, &indices);
+
+        // Cancel after the first slice.
+        let cancel = std::sync::atomic::AtomicBool::new(false);
+        let values = std::cell::RefCell::new(Vec::new());
+        let callback = |p: f32| {
+            values.borrow_mut().push(p);
+            cancel.store(p > 0.0, std::sync::atomic::Ordering::Relaxed);
+        };
+        let progress = Progress::new()
+            .with_callback(&callback)
+            .with_cancel(&cancel);
+        assert_eq!(
+            Err(Cancelled),
+            calculate_signed_distance_field_with_progress(&bvh, 0.5, 1.0, None, progress)
+        );
+        assert_eq!(vec![0.0, 1.0 / 9.0], values.into_inner());
+    }
+
+    #[test]
+    fn sdf_narrow_band() {
+        let cube = generate_cube(2.0);
+        let (positions, indices) = (cube.positions, cube.indices);
+        let bvh = Bvh::new(&positions, &indices);
+        let sdf = calculate_signed_distance_field(&bvh, 0.25, 1.0, Some(0.3));
+        assert_eq!(-0.3, sdf.distance(8, 8, 8));
+        assert_eq!(0.3, sdf.distance(0, 0, 0));
+        assert_relative_eq!(0.25, sdf.distance(8, 8, 3), epsilon = EPSILON);
+        assert_relative_eq!(-0.25, sdf.distance(8, 8, 5), epsilon = EPSILON);
+    }
+
+    #[test]
+    fn sdf_seams() {
+        // Vertices with duplicate positions should still use the correct pseudonormals.
+        let cube = generate_cube(2.0);
+        let (positions, indices) = (cube.positions, cube.indices);
+        let positions: Vec<_> = indices.iter().map(|i| positions[*i as usize]).collect();
+        let indices: Vec<_> = (0..positions.len() as u32).collect();
+        let bvh = Bvh::new(&positions, &indices);
+        let sdf = calculate_signed_distance_field(&bvh, 0.5, 1.0, None);
+        assert_relative_eq!(3.0f32.sqrt(), sdf.distance(0, 0, 0), epsilon = EPSILON);
+        assert_relative_eq!(-1.0, sdf.distance(4, 4, 4), epsilon = EPSILON);
+    }
+}
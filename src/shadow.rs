@@ -0,0 +1,237 @@
+//! Functions for generating stencil shadow volumes.
+
+use std::collections::HashMap;
+
+use glam::Vec3A;
+
+use crate::topology::edge_key;
+
+/// The light casting shadows for [find_silhouette_edges] and [calculate_shadow_volume].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Light {
+    /// A light infinitely far away with light traveling in the specified direction.
+    Directional(Vec3A),
+    /// A light at the specified position.
+    Point(Vec3A),
+}
+
+impl Light {
+    /// The direction light travels when reaching `point`.
+    fn direction(&self, point: Vec3A) -> Vec3A {
+        match self {
+            Light::Directional(direction) => direction.normalize_or_zero(),
+            Light::Point(position) => (point - *position).normalize_or_zero(),
+        }
+    }
+}
+
+/// Finds the edges separating triangles facing towards `light` from triangles facing away from `light`.
+/// Open border edges of triangles facing towards the light are also included.
+/// Each edge is returned as `[a, b]` using the winding order of the triangle facing the light.
+///
+/// Vertices with the same position are treated as the same vertex,
+/// so UV seams and hard edges do not create extra silhouette edges.
+/// # Examples
+/**
+```rust
+use geometry_tools::shadow::{find_silhouette_edges, Light};
+use glam::Vec3A;
+
+// Two triangles folded along the edge from vertex 1 to vertex 2.
+// Only the first triangle faces the light.
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::new(0.2, 0.2, 1.0)];
+let indices = [0, 1, 2, 2, 1, 3];
+
+let edges = find_silhouette_edges(&positions, &indices, Light::Directional(Vec3A::NEG_Z));
+assert_eq!(vec![[0, 1], [1, 2], [2, 0]], edges);
+```
+ */
+pub fn find_silhouette_edges<P>(positions: &[P], indices: &[u32], light: Light) -> Vec<[u32; 2]>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+    silhouette_edges(&positions, indices, light)
+}
+
+/// Calculates a closed shadow volume for the closed mesh defined by `positions` and `indices`
+/// by extruding the silhouette edges away from `light` by `extrusion_distance`.
+/// Returns the positions and triangle indices of the shadow volume.
+///
+/// The volume includes the triangles facing the light as a front cap, the same triangles extruded as a back cap,
+/// and a quad for each edge from [find_silhouette_edges].
+/// The first half of the positions are the original positions, and the second half are the extruded positions.
+/// The volume uses counterclockwise winding when viewed from outside,
+/// so it can be rendered using either the depth pass or depth fail stencil shadow techniques.
+/// # Examples
+/**
+```rust
+use geometry_tools::shadow::{calculate_shadow_volume, Light};
+use glam::Vec3A;
+
+// A tetrahedron.
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+let indices = [0, 2, 1, 0, 1, 3, 0, 3, 2, 1, 2, 3];
+
+let light = Light::Point(Vec3A::new(0.0, 0.0, 5.0));
+let (volume_positions, volume_indices) = calculate_shadow_volume(&positions, &indices, light, 10.0);
+assert_eq!(8, volume_positions.len());
+```
+ */
+pub fn calculate_shadow_volume<P>(
+    positions: &[P],
+    indices: &[u32],
+    light: Light,
+    extrusion_distance: f32,
+) -> (Vec<Vec3A>, Vec<u32>)
+where
+    P: Into<Vec3A> + Copy,
+{
+    let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+    let count = positions.len() as u32;
+
+    let mut volume_positions = positions.clone();
+    volume_positions.extend(
+        positions
+            .iter()
+            .map(|p| *p + light.direction(*p) * extrusion_distance),
+    );
+
+    let mut volume_indices = Vec::new();
+    for face in indices.chunks_exact(3) {
+        if faces_light(&positions, face, light) {
+            // The back cap uses the opposite winding to face away from the volume.
+            volume_indices.extend_from_slice(face);
+            volume_indices.extend_from_slice(&[face[0] + count, face[2] + count, face[1] + count]);
+        }
+    }
+
+    for [a, b] in silhouette_edges(&positions, indices, light) {
+        volume_indices.extend_from_slice(&[b, a, a + count, b, a + count, b + count]);
+    }
+
+    (volume_positions, volume_indices)
+}
+
+fn faces_light(positions: &[Vec3A], face: &[u32], light: Light) -> bool {
+    let [v0, v1, v2] = [face[0], face[1], face[2]].map(|i| positions[i as usize]);
+    let normal = (v1 - v0).cross(v2 - v0);
+    let centroid = (v0 + v1 + v2) / 3.0;
+    normal.dot(light.direction(centroid)) < 0.0
+}
+
+fn silhouette_edges(positions: &[Vec3A], indices: &[u32], light: Light) -> Vec<[u32; 2]> {
+    let mut first_vertex = HashMap::new();
+    let welded: Vec<u32> = positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            *first_vertex
+                .entry(p.to_array().map(f32::to_bits))
+                .or_insert(i as u32)
+        })
+        .collect();
+
+    // Find the directed edges for lit and unlit faces for each edge.
+    let mut edge_faces: HashMap<[u32; 2], (Vec<[u32; 2]>, usize)> = HashMap::new();
+    let mut edge_order = Vec::new();
+    for face in indices.chunks_exact(3) {
+        let is_lit = faces_light(positions, face, light);
+        for (a, b) in [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])] {
+            let key = edge_key(welded[a as usize], welded[b as usize]);
+            let entry = edge_faces.entry(key).or_insert_with(|| {
+                edge_order.push(key);
+                (Vec::new(), 0)
+            });
+            if is_lit {
+                entry.0.push([a, b]);
+            } else {
+                entry.1 += 1;
+            }
+        }
+    }
+
+    // Use the order edges first appear to produce a deterministic result.
+    edge_order
+        .iter()
+        .filter_map(|key| match &edge_faces[key] {
+            (lit, unlit) if lit.len() == 1 && *unlit <= 1 => Some(lit[0]),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::mass::calculate_volume;
+    use crate::remap::{remap_indices, scatter_attribute};
+    use crate::shapes::generate_cube;
+    use crate::weld::weld_positions;
+    use approx::assert_relative_eq;
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn silhouette_empty() {
+        assert!(find_silhouette_edges::<Vec3A>(&[], &[], Light::Point(Vec3A::ZERO)).is_empty());
+    }
+
+    #[test]
+    fn silhouette_cube_top() {
+        let cube = generate_cube(1.0);
+        let (positions, indices) = (cube.positions, cube.indices);
+        let edges = find_silhouette_edges(&positions, &indices, Light::Directional(Vec3A::NEG_Z));
+
+        // The silhouette is the outline of the top face.
+        assert_eq!(4, edges.len());
+        for [a, b] in edges {
+            let (a, b) = (positions[a as usize], positions[b as usize]);
+            assert!(a.z == 0.5 && b.z == 0.5);
+            assert_eq!(1.0, a.distance(b));
+        }
+    }
+
+    #[test]
+    fn silhouette_seams() {
+        // Splitting each face into separate vertices should not change the silhouette.
+        let cube = generate_cube(1.0);
+        let (positions, indices) = (cube.positions, cube.indices);
+        let split_positions: Vec<_> = indices.iter().map(|i| positions[*i as usize]).collect();
+        let split_indices: Vec<_> = (0..split_positions.len() as u32).collect();
+        let light = Light::Point(Vec3A::new(3.0, 2.0, 4.0));
+        assert_eq!(
+            find_silhouette_edges(&positions, &indices, light).len(),
+            find_silhouette_edges(&split_positions, &split_indices, light).len()
+        );
+    }
+
+    #[test]
+    fn shadow_volume_directional() {
+        let cube = generate_cube(1.0);
+        let (positions, indices) = (cube.positions, cube.indices);
+        let (volume_positions, volume_indices) =
+            calculate_shadow_volume(&positions, &indices, Light::Directional(Vec3A::NEG_Z), 3.0);
+        assert!(volume_positions.contains(&Vec3A::new(0.5, 0.5, -2.5)));
+
+        let volume = calculate_volume(&volume_positions, &volume_indices);
+        assert!(volume.is_watertight);
+        assert_relative_eq!(3.0, volume.signed_volume, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn shadow_volume_point_light_closed() {
+        // Weld the vertices of each face, so the lit faces share edges.
+        let cube = generate_cube(1.0);
+        let welded = weld_positions(&cube.positions, 0.0);
+        let positions = scatter_attribute(&cube.positions, &welded.remap, welded.vertex_count);
+        let indices = remap_indices(&cube.indices, &welded.remap);
+        let light = Light::Point(Vec3A::new(3.0, 2.0, 4.0));
+        let (volume_positions, volume_indices) =
+            calculate_shadow_volume(&positions, &indices, light, 10.0);
+        let volume = calculate_volume(&volume_positions, &volume_indices);
+        assert!(volume.is_watertight);
+        assert!(volume.signed_volume > 0.0);
+    }
+}
@@ -0,0 +1,452 @@
+//! Functions for generating primitive meshes with reference normals, tangents, and UVs.
+//!
+//! The meshes are centered at the origin with Y up and use counterclockwise winding
+//! when viewed from outside. The normals and tangents are calculated analytically
+//! from the surface, which makes the meshes useful as test fixtures.
+//! The tangent sign follows the convention of [calculate_tangent_w].
+
+use std::f32::consts::{PI, TAU};
+
+use glam::{UVec2, Vec2, Vec3A};
+
+use crate::mesh::Mesh;
+use crate::subdivide::subdivide_midpoint;
+use crate::vectors::calculate_tangent_w;
+
+/// Generates an axis-aligned cube with side length `size`.
+/// Each face has separate vertices with UVs covering the entire `0.0` to `1.0` range.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_cube;
+
+let cube = generate_cube(2.0);
+assert_eq!(24, cube.positions.len());
+assert_eq!(36, cube.indices.len());
+```
+ */
+pub fn generate_cube(size: f32) -> Mesh {
+    let mut mesh = Mesh::default();
+    let faces = [
+        (Vec3A::X, Vec3A::NEG_Z),
+        (Vec3A::NEG_X, Vec3A::Z),
+        (Vec3A::Y, Vec3A::X),
+        (Vec3A::NEG_Y, Vec3A::X),
+        (Vec3A::Z, Vec3A::X),
+        (Vec3A::NEG_Z, Vec3A::NEG_X),
+    ];
+    for (normal, right) in faces {
+        let up = normal.cross(right);
+        let start = mesh.positions.len() as u32;
+        for (u, v) in [(0.0, 0.0), (0.0, 1.0), (1.0, 0.0), (1.0, 1.0)] {
+            let position = (normal + right * (u * 2.0 - 1.0) + up * (1.0 - v * 2.0)) * size / 2.0;
+            push_vertex(&mut mesh, position, normal, right, -up, Vec2::new(u, v));
+        }
+        push_quad(&mut mesh, start, start + 1, start + 2, start + 3);
+    }
+    mesh
+}
+
+/// Generates a plane in the XZ plane facing +Y with dimensions `size`.
+/// The plane is split into `subdivisions.x` by `subdivisions.y` quads.
+/// U increases along the x-axis and V increases along the z-axis.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_plane;
+use glam::{UVec2, Vec2};
+
+let plane = generate_plane(Vec2::new(2.0, 1.0), UVec2::new(2, 1));
+assert_eq!(6, plane.positions.len());
+assert_eq!(2 * 2 * 3, plane.indices.len());
+```
+ */
+pub fn generate_plane(size: Vec2, subdivisions: UVec2) -> Mesh {
+    let mut mesh = Mesh::default();
+    let (width, depth) = (subdivisions.x.max(1), subdivisions.y.max(1));
+    for z in 0..=depth {
+        for x in 0..=width {
+            let uv = Vec2::new(x as f32 / width as f32, z as f32 / depth as f32);
+            let position = Vec3A::new((uv.x - 0.5) * size.x, 0.0, (uv.y - 0.5) * size.y);
+            push_vertex(&mut mesh, position, Vec3A::Y, Vec3A::X, Vec3A::Z, uv);
+        }
+    }
+    push_grid(&mut mesh, width, depth, false);
+    mesh
+}
+
+/// Generates a sphere with `segments` divisions around the Y axis and `rings` divisions from pole to pole.
+/// U increases around the Y axis and V increases from `0.0` at the top to `1.0` at the bottom.
+/// The vertices along the UV seam and poles are duplicated.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_uv_sphere;
+
+let sphere = generate_uv_sphere(1.0, 16, 8);
+assert!(sphere.positions.iter().all(|p| (p.length() - 1.0).abs() < 1e-6));
+```
+ */
+pub fn generate_uv_sphere(radius: f32, segments: u32, rings: u32) -> Mesh {
+    let (segments, rings) = (segments.max(3), rings.max(2));
+
+    let mut mesh = Mesh::default();
+    for ring in 0..=rings {
+        let theta = ring as f32 / rings as f32 * PI;
+        for segment in 0..=segments {
+            let phi = segment as f32 / segments as f32 * TAU;
+            let (sin_phi, cos_phi) = phi.sin_cos();
+            let normal = Vec3A::new(theta.sin() * sin_phi, theta.cos(), theta.sin() * cos_phi);
+            let tangent = Vec3A::new(cos_phi, 0.0, -sin_phi);
+            let bitangent = Vec3A::new(theta.cos() * sin_phi, -theta.sin(), theta.cos() * cos_phi);
+            let uv = Vec2::new(segment as f32 / segments as f32, ring as f32 / rings as f32);
+            push_vertex(&mut mesh, normal * radius, normal, tangent, bitangent, uv);
+        }
+    }
+
+    let stride = segments + 1;
+    for ring in 0..rings {
+        for segment in 0..segments {
+            let top_left = ring * stride + segment;
+            let bottom_left = top_left + stride;
+            // Skip the degenerate triangles at the poles.
+            if ring != 0 {
+                mesh.indices
+                    .extend_from_slice(&[top_left, bottom_left, top_left + 1]);
+            }
+            if ring != rings - 1 {
+                mesh.indices
+                    .extend_from_slice(&[top_left + 1, bottom_left, bottom_left + 1]);
+            }
+        }
+    }
+    mesh
+}
+
+/// Generates a sphere by subdividing an icosahedron `subdivisions` times.
+/// The triangles are more uniform than [generate_uv_sphere], but the mesh has no UVs or tangents.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_icosphere;
+
+let sphere = generate_icosphere(1.0, 1);
+assert_eq!(20 * 4 * 3, sphere.indices.len());
+assert!(sphere.uvs.is_empty());
+```
+ */
+pub fn generate_icosphere(radius: f32, subdivisions: usize) -> Mesh {
+    let t = (1.0 + 5.0f32.sqrt()) / 2.0;
+    let positions = [
+        Vec3A::new(-1.0, t, 0.0),
+        Vec3A::new(1.0, t, 0.0),
+        Vec3A::new(-1.0, -t, 0.0),
+        Vec3A::new(1.0, -t, 0.0),
+        Vec3A::new(0.0, -1.0, t),
+        Vec3A::new(0.0, 1.0, t),
+        Vec3A::new(0.0, -1.0, -t),
+        Vec3A::new(0.0, 1.0, -t),
+        Vec3A::new(t, 0.0, -1.0),
+        Vec3A::new(t, 0.0, 1.0),
+        Vec3A::new(-t, 0.0, -1.0),
+        Vec3A::new(-t, 0.0, 1.0),
+    ];
+    let indices = [
+        0, 11, 5, 0, 5, 1, 0, 1, 7, 0, 7, 10, 0, 10, 11, 1, 5, 9, 5, 11, 4, 11, 10, 2, 10, 7, 6, 7,
+        1, 8, 3, 9, 4, 3, 4, 2, 3, 2, 6, 3, 6, 8, 3, 8, 9, 4, 9, 5, 2, 4, 11, 6, 2, 10, 8, 6, 7, 9,
+        8, 1,
+    ];
+
+    let subdivided = subdivide_midpoint::<_, Vec3A>(&positions, &[], &[], &indices, subdivisions);
+    let normals: Vec<Vec3A> = subdivided.positions.iter().map(|p| p.normalize()).collect();
+    Mesh {
+        positions: normals.iter().map(|n| *n * radius).collect(),
+        normals,
+        indices: subdivided.indices,
+        ..Default::default()
+    }
+}
+
+/// Generates an octahedron with a vertex on each positive and negative axis at distance `radius` from the origin.
+/// The six vertices are shared by all faces in the order +X, -X, +Y, -Y, +Z, -Z,
+/// which makes this the smallest closed mesh where every vertex has valence 4.
+/// The mesh has no UVs or tangents.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_octahedron;
+
+let octahedron = generate_octahedron(1.0);
+assert_eq!(6, octahedron.positions.len());
+assert_eq!(8 * 3, octahedron.indices.len());
+```
+ */
+pub fn generate_octahedron(radius: f32) -> Mesh {
+    let normals = vec![
+        Vec3A::X,
+        Vec3A::NEG_X,
+        Vec3A::Y,
+        Vec3A::NEG_Y,
+        Vec3A::Z,
+        Vec3A::NEG_Z,
+    ];
+    Mesh {
+        positions: normals.iter().map(|n| *n * radius).collect(),
+        normals,
+        indices: vec![
+            0, 2, 4, 2, 1, 4, 1, 3, 4, 3, 0, 4, 2, 0, 5, 1, 2, 5, 3, 1, 5, 0, 3, 5,
+        ],
+        ..Default::default()
+    }
+}
+
+/// Generates a closed cylinder along the Y axis with `segments` divisions around the Y axis.
+/// The sides have U increasing around the Y axis and V increasing from top to bottom.
+/// The caps have separate vertices with UVs mapping the circle into the `0.0` to `1.0` range.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_cylinder;
+
+let cylinder = generate_cylinder(0.5, 2.0, 8);
+assert_eq!((8 * 2 + 8 * 2) * 3, cylinder.indices.len());
+```
+ */
+pub fn generate_cylinder(radius: f32, height: f32, segments: u32) -> Mesh {
+    let segments = segments.max(3);
+    let half_height = height / 2.0;
+
+    let mut mesh = Mesh::default();
+    for (v, y) in [(0.0, half_height), (1.0, -half_height)] {
+        for segment in 0..=segments {
+            let phi = segment as f32 / segments as f32 * TAU;
+            let (sin_phi, cos_phi) = phi.sin_cos();
+            let normal = Vec3A::new(sin_phi, 0.0, cos_phi);
+            let tangent = Vec3A::new(cos_phi, 0.0, -sin_phi);
+            let uv = Vec2::new(segment as f32 / segments as f32, v);
+            let position = normal * radius + Vec3A::Y * y;
+            push_vertex(&mut mesh, position, normal, tangent, Vec3A::NEG_Y, uv);
+        }
+    }
+    push_grid(&mut mesh, segments, 1, false);
+
+    for (normal, y) in [(Vec3A::Y, half_height), (Vec3A::NEG_Y, -half_height)] {
+        let center = mesh.positions.len() as u32;
+        push_vertex(
+            &mut mesh,
+            Vec3A::Y * y,
+            normal,
+            Vec3A::X,
+            Vec3A::Z,
+            Vec2::splat(0.5),
+        );
+        for segment in 0..segments {
+            let phi = segment as f32 / segments as f32 * TAU;
+            let (sin_phi, cos_phi) = phi.sin_cos();
+            let position = Vec3A::new(sin_phi * radius, y, cos_phi * radius);
+            let uv = Vec2::new(sin_phi, cos_phi) * 0.5 + 0.5;
+            push_vertex(&mut mesh, position, normal, Vec3A::X, Vec3A::Z, uv);
+        }
+
+        for segment in 0..segments {
+            let a = center + 1 + segment;
+            let b = center + 1 + (segment + 1) % segments;
+            if normal.y > 0.0 {
+                mesh.indices.extend_from_slice(&[center, a, b]);
+            } else {
+                mesh.indices.extend_from_slice(&[center, b, a]);
+            }
+        }
+    }
+    mesh
+}
+
+/// Generates a torus around the Y axis with the given distance from the center to the tube
+/// and the radius of the tube.
+/// U increases around the Y axis and V increases around the tube.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_torus;
+
+let torus = generate_torus(1.0, 0.25, 16, 8);
+assert_eq!(17 * 9, torus.positions.len());
+assert_eq!(16 * 8 * 6, torus.indices.len());
+```
+ */
+pub fn generate_torus(
+    major_radius: f32,
+    minor_radius: f32,
+    major_segments: u32,
+    minor_segments: u32,
+) -> Mesh {
+    let (major_segments, minor_segments) = (major_segments.max(3), minor_segments.max(3));
+
+    let mut mesh = Mesh::default();
+    for minor in 0..=minor_segments {
+        let psi = minor as f32 / minor_segments as f32 * TAU;
+        let (sin_psi, cos_psi) = psi.sin_cos();
+        for major in 0..=major_segments {
+            let phi = major as f32 / major_segments as f32 * TAU;
+            let (sin_phi, cos_phi) = phi.sin_cos();
+
+            let ring_direction = Vec3A::new(sin_phi, 0.0, cos_phi);
+            let normal = ring_direction * cos_psi + Vec3A::Y * sin_psi;
+            let position = ring_direction * major_radius + normal * minor_radius;
+            let tangent = Vec3A::new(cos_phi, 0.0, -sin_phi);
+            let bitangent = Vec3A::Y * cos_psi - ring_direction * sin_psi;
+            let uv = Vec2::new(
+                major as f32 / major_segments as f32,
+                minor as f32 / minor_segments as f32,
+            );
+            push_vertex(&mut mesh, position, normal, tangent, bitangent, uv);
+        }
+    }
+    push_grid(&mut mesh, major_segments, minor_segments, true);
+    mesh
+}
+
+fn push_vertex(
+    mesh: &mut Mesh,
+    position: Vec3A,
+    normal: Vec3A,
+    tangent: Vec3A,
+    bitangent: Vec3A,
+    uv: Vec2,
+) {
+    mesh.positions.push(position);
+    mesh.normals.push(normal);
+    mesh.tangents
+        .push(tangent.extend(calculate_tangent_w(tangent, bitangent, normal)));
+    mesh.uvs.push(uv);
+}
+
+/// Adds two triangles for the quad with the given corners in UV space.
+fn push_quad(mesh: &mut Mesh, top_left: u32, bottom_left: u32, top_right: u32, bottom_right: u32) {
+    mesh.indices.extend_from_slice(&[
+        top_left,
+        bottom_left,
+        top_right,
+        top_right,
+        bottom_left,
+        bottom_right,
+    ]);
+}
+
+/// Adds quads for a grid of `(width + 1) * (height + 1)` vertices starting at index 0.
+/// `flip` reverses the winding order of the triangles.
+fn push_grid(mesh: &mut Mesh, width: u32, height: u32, flip: bool) {
+    let stride = width + 1;
+    for y in 0..height {
+        for x in 0..width {
+            let top_left = y * stride + x;
+            let bottom_left = top_left + stride;
+            if flip {
+                push_quad(mesh, top_left, top_left + 1, bottom_left, bottom_left + 1);
+            } else {
+                push_quad(mesh, top_left, bottom_left, top_left + 1, bottom_left + 1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::vectors::calculate_tangents;
+
+    const EPSILON: f32 = 0.0001;
+
+    fn check_mesh(mesh: &Mesh) {
+        for n in &mesh.normals {
+            assert!((n.length() - 1.0).abs() < EPSILON);
+        }
+
+        // Face normals should point in the same direction as the vertex normals.
+        for face in mesh.indices.chunks_exact(3) {
+            let [v0, v1, v2] = [face[0], face[1], face[2]].map(|i| mesh.positions[i as usize]);
+            let face_normal = (v1 - v0).cross(v2 - v0);
+            assert!(face_normal.length() > 0.0);
+            for i in face {
+                assert!(face_normal.dot(mesh.normals[*i as usize]) > 0.0);
+            }
+        }
+
+        for (t, n) in mesh.tangents.iter().zip(&mesh.normals) {
+            assert!(Vec3A::from_vec4(*t).dot(*n).abs() < EPSILON);
+        }
+    }
+
+    fn check_tangents(mesh: &Mesh) {
+        // The reference tangents should match the tangents calculated from the UVs.
+        let calculated =
+            calculate_tangents(&mesh.positions, &mesh.normals, &mesh.uvs, &mesh.indices).unwrap();
+        for (expected, actual) in mesh.tangents.iter().zip(calculated) {
+            assert!(Vec3A::from_vec4(*expected).dot(Vec3A::from_vec4(actual)) > 0.9);
+            assert_eq!(expected.w, actual.w);
+        }
+    }
+
+    #[test]
+    fn cube() {
+        let mesh = generate_cube(2.0);
+        check_mesh(&mesh);
+        check_tangents(&mesh);
+        assert!(mesh.positions.iter().all(|p| p.abs() == Vec3A::ONE));
+    }
+
+    #[test]
+    fn plane() {
+        let mesh = generate_plane(Vec2::new(4.0, 2.0), UVec2::new(4, 2));
+        check_mesh(&mesh);
+        check_tangents(&mesh);
+        assert_eq!(Vec3A::new(-2.0, 0.0, -1.0), mesh.positions[0]);
+        assert_eq!(Vec3A::new(2.0, 0.0, 1.0), mesh.positions[14]);
+    }
+
+    #[test]
+    fn uv_sphere() {
+        let mesh = generate_uv_sphere(2.0, 16, 8);
+        check_mesh(&mesh);
+        assert_eq!(17 * 9, mesh.positions.len());
+        assert_eq!((16 * 6 * 2 + 16 * 2) * 3, mesh.indices.len());
+        assert!(mesh
+            .positions
+            .iter()
+            .all(|p| (p.length() - 2.0).abs() < EPSILON));
+    }
+
+    #[test]
+    fn icosphere() {
+        let mesh = generate_icosphere(0.5, 2);
+        check_mesh(&mesh);
+        assert!(mesh.tangents.is_empty());
+        assert!(mesh
+            .positions
+            .iter()
+            .all(|p| (p.length() - 0.5).abs() < EPSILON));
+    }
+
+    #[test]
+    fn octahedron() {
+        let mesh = generate_octahedron(2.0);
+        check_mesh(&mesh);
+        assert!(mesh.tangents.is_empty());
+        assert!(crate::topology::check_watertight(&mesh.indices).is_watertight());
+    }
+
+    #[test]
+    fn cylinder() {
+        let mesh = generate_cylinder(1.0, 2.0, 12);
+        check_mesh(&mesh);
+        check_tangents(&mesh);
+    }
+
+    #[test]
+    fn torus() {
+        let mesh = generate_torus(1.0, 0.25, 16, 8);
+        check_mesh(&mesh);
+        check_tangents(&mesh);
+    }
+}
@@ -0,0 +1,676 @@
+//! Functions for reducing the triangle count of meshes for generating levels of detail.
+
+use std::collections::HashMap;
+
+use glam::{DVec3, Vec3A};
+
+use crate::progress::{Cancelled, Progress};
+use crate::remap::REMAP_UNUSED;
+use crate::topology::{edge_key, MeshAdjacency};
+
+/// The result of [simplify_mesh].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimplifiedMesh {
+    /// The triangle indices of the simplified mesh for the compacted vertices.
+    pub indices: Vec<u32>,
+    /// The remap table from each original vertex to its compacted vertex
+    /// or [REMAP_UNUSED] for removed vertices.
+    /// Use [scatter_attribute](crate::remap::scatter_attribute) to compact positions, normals, UVs, and other attributes.
+    pub remap: Vec<u32>,
+    /// The number of vertices used by the simplified mesh.
+    pub vertex_count: usize,
+    /// The largest approximate distance from the original surface of any collapsed vertex in world units.
+    pub error: f32,
+}
+
+/// Reduces the number of triangles for the triangle list defined by `positions` and `indices`
+/// until reaching `target_triangle_count` triangles or a collapse would exceed `max_error` in world units.
+///
+/// Edges are collapsed in order of increasing quadric error by moving one vertex onto the other,
+/// so the remaining vertices keep their original positions and attributes.
+/// Vertices on open borders, non-manifold edges, and attribute seams with duplicate positions are never moved
+/// to preserve the outline of the mesh and avoid stretching normals and UVs.
+/// Collapses that would flip the orientation of a triangle or create non-manifold geometry are also skipped,
+/// so the result may have more triangles than the target.
+/// # Examples
+/**
+```rust
+use geometry_tools::remap::scatter_attribute;
+use geometry_tools::simplify::simplify_mesh;
+use glam::{Vec2, Vec3A};
+
+// A flat 3x3 grid of quads with one interior vertex.
+let positions: Vec<_> = (0..16)
+    .map(|i| Vec3A::new((i % 4) as f32, (i / 4) as f32, 0.0))
+    .collect();
+let uvs: Vec<_> = positions.iter().map(|p| Vec2::new(p.x, p.y) / 3.0).collect();
+let indices: Vec<u32> = (0..9)
+    .flat_map(|i| {
+        let v = i / 3 * 4 + i % 3;
+        [v, v + 1, v + 5, v + 5, v + 4, v]
+    })
+    .collect();
+
+let simplified = simplify_mesh(&positions, &indices, 0, 0.01);
+assert!(simplified.indices.len() < indices.len());
+assert_eq!(0.0, simplified.error);
+
+// Apply the remap table to the vertex attributes.
+let new_positions = scatter_attribute(&positions, &simplified.remap, simplified.vertex_count);
+let new_uvs = scatter_attribute(&uvs, &simplified.remap, simplified.vertex_count);
+```
+ */
+pub fn simplify_mesh<P>(
+    positions: &[P],
+    indices: &[u32],
+    target_triangle_count: usize,
+    max_error: f32,
+) -> SimplifiedMesh
+where
+    P: Into<Vec3A> + Copy,
+{
+    // Progress without a cancellation flag can't fail.
+    simplify_mesh_with_progress(
+        positions,
+        indices,
+        target_triangle_count,
+        max_error,
+        Progress::default(),
+    )
+    .unwrap()
+}
+
+/// Simplifies a mesh like [simplify_mesh] while reporting progress
+/// as the fraction of triangles removed relative to `target_triangle_count`.
+/// Returns [Cancelled] if the cancellation flag of `progress` is set before the simplification finishes.
+pub fn simplify_mesh_with_progress<P>(
+    positions: &[P],
+    indices: &[u32],
+    target_triangle_count: usize,
+    max_error: f32,
+    progress: Progress,
+) -> Result<SimplifiedMesh, Cancelled>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+
+    let mut triangles: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|face| [face[0], face[1], face[2]])
+        .filter(|t| !is_degenerate(t))
+        .collect();
+
+    let mut quadrics = vec![Quadric::default(); positions.len()];
+    for t in &triangles {
+        let quadric = Quadric::from_triangle(t.map(|v| positions[v as usize]));
+        for v in t {
+            quadrics[*v as usize] += quadric;
+        }
+    }
+
+    let locked = locked_vertices(&positions, &triangles);
+
+    let mut collapse: Vec<u32> = (0..positions.len() as u32).collect();
+    let mut error = 0.0f32;
+    let max_error_squared = (max_error as f64) * (max_error as f64);
+
+    let initial_count = triangles.len();
+    let fraction_removed = |count: usize| {
+        (initial_count - count) as f32
+            / (initial_count - target_triangle_count.min(initial_count)).max(1) as f32
+    };
+
+    progress.report(0.0)?;
+    while triangles.len() > target_triangle_count {
+        let candidates = collapse_candidates(&positions, &triangles, &quadrics, &locked);
+        let adjacency = MeshAdjacency::new(positions.len(), triangles.as_flattened());
+
+        // Each collapse typically removes two triangles.
+        let max_collapses = (triangles.len() - target_triangle_count).div_ceil(2);
+
+        // Only collapse each region once per pass, so flip checks use up to date positions.
+        let mut touched = vec![false; positions.len()];
+        let mut collapse_count = 0;
+        for (cost, source, target) in candidates {
+            if cost > max_error_squared || collapse_count >= max_collapses {
+                break;
+            }
+            if touched[source as usize] || touched[target as usize] {
+                continue;
+            }
+            if !satisfies_link_condition(&adjacency, source, target)
+                || has_flipped_triangle(&positions, &adjacency, source, target)
+            {
+                continue;
+            }
+
+            for t in adjacency.vertex_faces(source as usize) {
+                for v in adjacency.faces()[*t as usize] {
+                    touched[v as usize] = true;
+                }
+            }
+
+            collapse[source as usize] = target;
+            let quadric = quadrics[source as usize];
+            quadrics[target as usize] += quadric;
+            error = error.max(cost.max(0.0).sqrt() as f32);
+            collapse_count += 1;
+        }
+
+        if collapse_count == 0 {
+            break;
+        }
+
+        triangles = triangles
+            .iter()
+            .map(|t| t.map(|v| collapse[v as usize]))
+            .filter(|t| !is_degenerate(t))
+            .collect();
+
+        progress.report(fraction_removed(triangles.len()))?;
+    }
+
+    // Compact the vertices while preserving their original order.
+    let mut used = vec![false; positions.len()];
+    for v in triangles.iter().flatten() {
+        used[*v as usize] = true;
+    }
+    let mut vertex_count = 0;
+    let remap = used
+        .iter()
+        .map(|used| {
+            if *used {
+                vertex_count += 1;
+                vertex_count as u32 - 1
+            } else {
+                REMAP_UNUSED
+            }
+        })
+        .collect::<Vec<_>>();
+
+    progress.report(1.0)?;
+    Ok(SimplifiedMesh {
+        indices: triangles
+            .iter()
+            .flatten()
+            .map(|v| remap[*v as usize])
+            .collect(),
+        remap,
+        vertex_count,
+        error,
+    })
+}
+
+/// A symmetric 4x4 matrix for the weighted sum of squared distances to a set of planes.
+/// See "Surface Simplification Using Quadric Error Metrics" by Garland and Heckbert.
+#[derive(Debug, Clone, Copy, Default)]
+struct Quadric {
+    // The upper triangle of the 3x3 matrix n * n^T.
+    a: [f64; 6],
+    b: DVec3,
+    c: f64,
+    weight: f64,
+}
+
+impl Quadric {
+    fn from_triangle([v0, v1, v2]: [Vec3A; 3]) -> Self {
+        let normal = (v1 - v0).cross(v2 - v0).as_dvec3();
+        let length = normal.length();
+        if length == 0.0 {
+            return Self::default();
+        }
+
+        // Weight each plane by triangle area.
+        let n = normal / length;
+        let d = -n.dot(v0.as_dvec3());
+        let weight = length / 2.0;
+        Self {
+            a: [
+                n.x * n.x,
+                n.x * n.y,
+                n.x * n.z,
+                n.y * n.y,
+                n.y * n.z,
+                n.z * n.z,
+            ]
+            .map(|a| a * weight),
+            b: n * d * weight,
+            c: d * d * weight,
+            weight,
+        }
+    }
+
+    /// The weighted average of the squared distances from `p` to each plane.
+    fn error(&self, p: Vec3A) -> f64 {
+        if self.weight == 0.0 {
+            return 0.0;
+        }
+
+        let p = p.as_dvec3();
+        let [a00, a01, a02, a11, a12, a22] = self.a;
+        let ap = DVec3::new(
+            a00 * p.x + a01 * p.y + a02 * p.z,
+            a01 * p.x + a11 * p.y + a12 * p.z,
+            a02 * p.x + a12 * p.y + a22 * p.z,
+        );
+        (p.dot(ap) + 2.0 * self.b.dot(p) + self.c) / self.weight
+    }
+}
+
+impl std::ops::Add for Quadric {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut a = self.a;
+        for (a, b) in a.iter_mut().zip(rhs.a) {
+            *a += b;
+        }
+        Self {
+            a,
+            b: self.b + rhs.b,
+            c: self.c + rhs.c,
+            weight: self.weight + rhs.weight,
+        }
+    }
+}
+
+impl std::ops::AddAssign for Quadric {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+fn is_degenerate(t: &[u32; 3]) -> bool {
+    t[0] == t[1] || t[1] == t[2] || t[2] == t[0]
+}
+
+fn locked_vertices(positions: &[Vec3A], triangles: &[[u32; 3]]) -> Vec<bool> {
+    let mut locked = vec![false; positions.len()];
+
+    // Border and non-manifold edges are not shared by exactly two triangles.
+    let mut edge_counts: HashMap<[u32; 2], usize> = HashMap::new();
+    for t in triangles {
+        for (a, b) in [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+            *edge_counts.entry([a.min(b), a.max(b)]).or_default() += 1;
+        }
+    }
+    for ([a, b], count) in edge_counts {
+        if count != 2 {
+            locked[a as usize] = true;
+            locked[b as usize] = true;
+        }
+    }
+
+    // Vertices split along attribute seams share the same position.
+    let mut position_counts: HashMap<[u32; 3], usize> = HashMap::new();
+    for p in positions {
+        *position_counts
+            .entry(p.to_array().map(f32::to_bits))
+            .or_default() += 1;
+    }
+    for (p, locked) in positions.iter().zip(locked.iter_mut()) {
+        if position_counts[&p.to_array().map(f32::to_bits)] > 1 {
+            *locked = true;
+        }
+    }
+
+    locked
+}
+
+fn collapse_candidates(
+    positions: &[Vec3A],
+    triangles: &[[u32; 3]],
+    quadrics: &[Quadric],
+    locked: &[bool],
+) -> Vec<(f64, u32, u32)> {
+    let mut edges: Vec<[u32; 2]> = triangles
+        .iter()
+        .flat_map(|t| [[t[0], t[1]], [t[1], t[2]], [t[2], t[0]]])
+        .map(|[a, b]| [a.min(b), a.max(b)])
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+
+    // Find the cheapest direction to collapse each edge as (cost, source, target).
+    let mut candidates: Vec<_> = edges
+        .iter()
+        .filter_map(|[a, b]| {
+            let quadric = quadrics[*a as usize] + quadrics[*b as usize];
+            let a_to_b =
+                (!locked[*a as usize]).then(|| (quadric.error(positions[*b as usize]), *a, *b));
+            let b_to_a =
+                (!locked[*b as usize]).then(|| (quadric.error(positions[*a as usize]), *b, *a));
+            match (a_to_b, b_to_a) {
+                (Some(ab), Some(ba)) => Some(if ba.0 < ab.0 { ba } else { ab }),
+                (ab, ba) => ab.or(ba),
+            }
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+    candidates
+}
+
+/// Returns `true` if collapsing `source` onto `target` preserves the topology of the surface.
+/// Every vertex adjacent to both vertices must form a triangle with the collapsed edge,
+/// and no edge may form a triangle with each vertex separately.
+/// Otherwise the collapse creates duplicate triangles or non-manifold edges
+/// like when collapsing an edge of a tetrahedron.
+fn satisfies_link_condition(adjacency: &MeshAdjacency, source: u32, target: u32) -> bool {
+    let opposite_edges = |vertex: u32, other: u32| {
+        adjacency
+            .vertex_faces(vertex as usize)
+            .iter()
+            .map(|t| adjacency.faces()[*t as usize])
+            .filter(move |t| !t.contains(&other))
+            .map(move |t| {
+                let corner = t.iter().position(|v| *v == vertex).unwrap();
+                edge_key(t[(corner + 1) % 3], t[(corner + 2) % 3])
+            })
+    };
+
+    let target_neighbors = adjacency.vertex_neighbors(target as usize);
+    let shared_vertices_opposite = adjacency
+        .vertex_neighbors(source as usize)
+        .iter()
+        .filter(|v| target_neighbors.binary_search(v).is_ok())
+        .all(|v| {
+            // Vertices in a triangle with both source and target are opposite the edge.
+            adjacency.vertex_faces(source as usize).iter().any(|t| {
+                let t = adjacency.faces()[*t as usize];
+                t.contains(&target) && t.contains(v)
+            })
+        });
+
+    let target_edges: Vec<[u32; 2]> = opposite_edges(target, source).collect();
+    let shared_edges = opposite_edges(source, target).any(|e| target_edges.contains(&e));
+
+    shared_vertices_opposite && !shared_edges
+}
+
+fn has_flipped_triangle(
+    positions: &[Vec3A],
+    adjacency: &MeshAdjacency,
+    source: u32,
+    target: u32,
+) -> bool {
+    adjacency.vertex_faces(source as usize).iter().any(|t| {
+        let t = adjacency.faces()[*t as usize];
+        if t.contains(&target) {
+            // This triangle is removed by the collapse.
+            return false;
+        }
+
+        let [v0, v1, v2] = t.map(|v| positions[v as usize]);
+        let [n0, n1, n2] = t.map(|v| positions[if v == source { target } else { v } as usize]);
+        let old_normal = (v1 - v0).cross(v2 - v0);
+        let new_normal = (n1 - n0).cross(n2 - n0);
+        old_normal.dot(new_normal) <= 0.0
+    })
+}
+
+pub mod ffi {
+    use std::ffi::c_void;
+    use std::sync::atomic::AtomicBool;
+
+    use crate::ffi::{
+        catch_status, check_triangle_indices, output, slice, slice_mut, FfiStatus,
+        GeoProgressCallback,
+    };
+    use crate::progress::Progress;
+
+    /// A wrapper for [simplify_mesh](crate::simplify::simplify_mesh).
+    /// The function writes the simplified indices to the first `index_count` elements of `out_indices`
+    /// and the remap table for each vertex to `out_remap`.
+    ///
+    /// `progress` is called with values from `0.0` to `1.0` and `user_data` if it is not null.
+    /// The function returns [FfiStatus::Cancelled] without writing any outputs
+    /// if `cancel` is not null and becomes `true` while the function runs.
+    ///
+    /// # Safety
+    ///
+    /// `positions` and `out_remap` must have length `positions_length`
+    /// with the same 16 byte layout as [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+    /// `indices` and `out_indices` must have length `indices_length`.
+    /// `index_count`, `vertex_count`, and `error` must point to writable values.
+    /// `cancel` must be null or point to a flag that remains valid until the function returns.
+    /// The flag may be set from another thread while the function runs.
+    #[no_mangle]
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe extern "C" fn geo_simplify_mesh(
+        positions: *const glam::Vec3A,
+        positions_length: u32,
+        indices: *const u32,
+        indices_length: u32,
+        target_triangle_count: u32,
+        max_error: f32,
+        out_indices: *mut u32,
+        out_remap: *mut u32,
+        index_count: *mut u32,
+        vertex_count: *mut u32,
+        error: *mut f32,
+        progress: GeoProgressCallback,
+        user_data: *mut c_void,
+        cancel: *const AtomicBool,
+    ) -> FfiStatus {
+        catch_status(|| {
+            let positions = slice(positions, positions_length)?;
+            let indices = slice(indices, indices_length)?;
+            let out_indices = slice_mut(out_indices, indices_length)?;
+            let out_remap = slice_mut(out_remap, positions_length)?;
+            let index_count = output(index_count)?;
+            let vertex_count = output(vertex_count)?;
+            let error = output(error)?;
+            check_triangle_indices(indices, positions.len())?;
+
+            let callback = progress.map(|f| move |p: f32| f(p, user_data));
+            let progress = Progress {
+                callback: callback.as_ref().map(|f| f as &dyn Fn(f32)),
+                cancel: cancel.as_ref(),
+            };
+            let simplified = super::simplify_mesh_with_progress(
+                positions,
+                indices,
+                target_triangle_count as usize,
+                max_error,
+                progress,
+            )
+            .map_err(|_| FfiStatus::Cancelled)?;
+
+            out_indices[..simplified.indices.len()].copy_from_slice(&simplified.indices);
+            out_remap.copy_from_slice(&simplified.remap);
+            *index_count = simplified.indices.len() as u32;
+            *vertex_count = simplified.vertex_count as u32;
+            *error = simplified.error;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::remap::scatter_attribute;
+
+    fn grid(size: u32, height: impl Fn(f32, f32) -> f32) -> (Vec<Vec3A>, Vec<u32>) {
+        let positions = (0..(size + 1) * (size + 1))
+            .map(|i| {
+                let x = (i % (size + 1)) as f32;
+                let y = (i / (size + 1)) as f32;
+                Vec3A::new(x, y, height(x, y))
+            })
+            .collect();
+        let indices = (0..size * size)
+            .flat_map(|i| {
+                let v = i / size * (size + 1) + i % size;
+                [v, v + 1, v + size + 2, v + size + 2, v + size + 1, v]
+            })
+            .collect();
+        (positions, indices)
+    }
+
+    #[test]
+    fn simplify_empty() {
+        let simplified = simplify_mesh::<Vec3A>(&[], &[], 0, 1.0);
+        assert!(simplified.indices.is_empty());
+        assert!(simplified.remap.is_empty());
+        assert_eq!(0, simplified.vertex_count);
+        assert_eq!(0.0, simplified.error);
+    }
+
+    #[test]
+    fn simplify_target_already_reached() {
+        let (positions, indices) = grid(2, |_, _| 0.0);
+        let simplified = simplify_mesh(&positions, &indices, 8, 1.0);
+        assert_eq!(indices, simplified.indices);
+        assert_eq!((0..9).collect::<Vec<_>>(), simplified.remap);
+    }
+
+    #[test]
+    fn simplify_flat_grid_preserves_orientation() {
+        let (positions, indices) = grid(8, |_, _| 0.0);
+        let simplified = simplify_mesh(&positions, &indices, 0, 0.001);
+        assert!(simplified.indices.len() / 3 < 64);
+
+        // The border vertices are locked.
+        assert_eq!(32, simplified.vertex_count);
+
+        let positions = scatter_attribute(&positions, &simplified.remap, simplified.vertex_count);
+        let area: f32 = simplified
+            .indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [v0, v1, v2] = [t[0], t[1], t[2]].map(|i| positions[i as usize]);
+                let normal = (v1 - v0).cross(v2 - v0);
+                assert!(normal.z > 0.0);
+                normal.length() / 2.0
+            })
+            .sum();
+        assert!((area - 64.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn simplify_max_error_prevents_collapses() {
+        // Collapsing any interior vertex on a curved surface introduces error.
+        let (positions, indices) = grid(4, |x, y| (x - 2.0).powi(2) + (y - 2.0).powi(2));
+        let simplified = simplify_mesh(&positions, &indices, 0, 0.0);
+        assert_eq!(indices, simplified.indices);
+        assert_eq!(0.0, simplified.error);
+
+        let simplified = simplify_mesh(&positions, &indices, 0, 10.0);
+        assert!(simplified.indices.len() < indices.len());
+        assert!(simplified.error > 0.0);
+    }
+
+    #[test]
+    fn simplify_attribute_seam_locked() {
+        // Split the center vertex of a flat grid into two vertices with the same position.
+        let (mut positions, mut indices) = grid(2, |_, _| 0.0);
+        positions.push(positions[4]);
+        for i in indices.iter_mut().skip(12) {
+            if *i == 4 {
+                *i = 9;
+            }
+        }
+        let simplified = simplify_mesh(&positions, &indices, 0, 1.0);
+        assert_eq!(indices, simplified.indices);
+    }
+
+    fn assert_closed_manifold(indices: &[u32]) {
+        let mut edge_counts: HashMap<[u32; 2], usize> = HashMap::new();
+        for t in indices.chunks_exact(3) {
+            for (a, b) in [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+                *edge_counts.entry([a.min(b), a.max(b)]).or_default() += 1;
+            }
+        }
+        assert!(edge_counts.values().all(|c| *c == 2));
+        assert!(crate::topology::find_non_manifold_geometry(indices).is_manifold());
+    }
+
+    #[test]
+    fn simplify_tetrahedron_link_condition() {
+        // Any collapse would create two copies of the same triangle.
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+        let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+        let simplified = simplify_mesh(&positions, &indices, 0, f32::INFINITY);
+        assert_eq!(indices.to_vec(), simplified.indices);
+    }
+
+    #[test]
+    fn simplify_octahedron_link_condition() {
+        let octahedron = crate::shapes::generate_octahedron(1.0);
+        let simplified =
+            simplify_mesh(&octahedron.positions, &octahedron.indices, 0, f32::INFINITY);
+
+        // A tetrahedron is the smallest closed mesh.
+        assert_eq!(12, simplified.indices.len());
+        assert_eq!(4, simplified.vertex_count);
+        assert_closed_manifold(&simplified.indices);
+    }
+
+    #[test]
+    fn simplify_progress_cancel() {
+        let (positions, indices) = grid(4, |_, _| 0.0);
+        let values = std::cell::RefCell::new(Vec::new());
+        let callback = |p: f32| values.borrow_mut().push(p);
+        let progress = Progress {
+            callback: Some(&callback),
+            cancel: None,
+        };
+        simplify_mesh_with_progress(&positions, &indices, 0, 0.01, progress).unwrap();
+        let values = values.into_inner();
+        assert_eq!(Some(&0.0), values.first());
+        assert_eq!(Some(&1.0), values.last());
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+
+        let cancel = std::sync::atomic::AtomicBool::new(true);
+        let progress = Progress {
+            callback: None,
+            cancel: Some(&cancel),
+        };
+        assert_eq!(
+            Err(Cancelled),
+            simplify_mesh_with_progress(&positions, &indices, 0, 0.01, progress)
+        );
+    }
+
+    #[test]
+    fn simplify_ffi() {
+        unsafe extern "C" fn count(_: f32, user_data: *mut std::ffi::c_void) {
+            *(user_data as *mut u32) += 1;
+        }
+
+        let (positions, indices) = grid(4, |_, _| 0.0);
+        let mut out_indices = vec![0; indices.len()];
+        let mut out_remap = vec![0; positions.len()];
+        let (mut index_count, mut vertex_count, mut error) = (0, 0, 1.0);
+        let mut calls = 0u32;
+        let status = unsafe {
+            ffi::geo_simplify_mesh(
+                positions.as_ptr(),
+                positions.len() as u32,
+                indices.as_ptr(),
+                indices.len() as u32,
+                0,
+                0.01,
+                out_indices.as_mut_ptr(),
+                out_remap.as_mut_ptr(),
+                &mut index_count,
+                &mut vertex_count,
+                &mut error,
+                Some(count),
+                &mut calls as *mut u32 as *mut std::ffi::c_void,
+                std::ptr::null(),
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::Success, status);
+
+        let expected = simplify_mesh(&positions, &indices, 0, 0.01);
+        assert_eq!(expected.indices, out_indices[..index_count as usize]);
+        assert_eq!(expected.remap, out_remap);
+        assert_eq!(expected.vertex_count, vertex_count as usize);
+        assert_eq!(0.0, error);
+        assert!(calls >= 2);
+    }
+}
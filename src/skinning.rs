@@ -0,0 +1,283 @@
+//! Functions for deforming meshes with skeletal animation.
+
+use glam::{Mat3A, Mat4, Vec3A, Vec4};
+
+/// Calculates the deformed positions and normals using linear blend skinning with up to 4 influences per vertex.
+/// Empty `normals` are treated as missing attributes and produce no deformed normals.
+///
+/// Each vertex is transformed by the sum of the matrices in `bone_matrices`
+/// for each index in `bone_indices` scaled by the corresponding weight in `bone_weights`.
+/// The weights for each vertex should sum to `1.0`.
+/// The bone matrices should already include the inverse bind matrices,
+/// so the identity matrix leaves the vertex unchanged.
+///
+/// Normals are transformed by the inverse transpose of the blended matrix and normalized.
+/// # Examples
+/**
+```rust
+use geometry_tools::skinning::skin_positions_normals;
+use glam::{Mat4, Vec3, Vec3A, Vec4};
+
+let positions = [Vec3A::X, Vec3A::Y];
+let normals = [Vec3A::Z, Vec3A::Z];
+let bone_indices = [[0u8, 0, 0, 0], [0, 1, 0, 0]];
+let bone_weights = [Vec4::new(1.0, 0.0, 0.0, 0.0), Vec4::new(0.5, 0.5, 0.0, 0.0)];
+let bone_matrices = [
+    Mat4::IDENTITY,
+    Mat4::from_translation(Vec3::new(0.0, 0.0, 2.0)),
+];
+
+let (positions, normals) = skin_positions_normals(
+    &positions,
+    &normals,
+    &bone_indices,
+    &bone_weights,
+    &bone_matrices,
+);
+assert_eq!(vec![Vec3A::X, Vec3A::new(0.0, 1.0, 1.0)], positions);
+assert_eq!(vec![Vec3A::Z, Vec3A::Z], normals);
+```
+ */
+pub fn skin_positions_normals<P, N, I>(
+    positions: &[P],
+    normals: &[N],
+    bone_indices: &[[I; 4]],
+    bone_weights: &[Vec4],
+    bone_matrices: &[Mat4],
+) -> (Vec<Vec3A>, Vec<Vec3A>)
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+    I: Into<u32> + Copy,
+{
+    let skin_transform = |i: usize| {
+        blend_matrices(
+            bone_indices[i].map(|b| b.into()),
+            bone_weights[i],
+            bone_matrices,
+        )
+    };
+
+    let skinned_positions = positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| skin_transform(i).transform_point3a((*p).into()))
+        .collect();
+
+    let skinned_normals = normals
+        .iter()
+        .enumerate()
+        .map(|(i, n)| {
+            let normal_transform = Mat3A::from_mat4(skin_transform(i)).inverse().transpose();
+            (normal_transform * (*n).into()).normalize_or_zero()
+        })
+        .collect();
+
+    (skinned_positions, skinned_normals)
+}
+
+/// The result of [limit_bone_influences].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LimitedBoneInfluences {
+    /// The bone indices with `max_influences` elements for each vertex.
+    pub bone_indices: Vec<u32>,
+    /// The normalized bone weights with `max_influences` elements for each vertex
+    /// sorted from largest to smallest.
+    /// Unused influences have a bone index and weight of `0`.
+    pub bone_weights: Vec<f32>,
+    /// The vertices where the removed influences exceeded the weight threshold.
+    pub lossy_vertices: Vec<u32>,
+}
+
+/// Limits each vertex to the `max_influences` bones with the largest weights and renormalizes the weights to sum to `1.0`.
+/// `bone_indices` and `bone_weights` contain `influence_count` elements for each vertex.
+/// Multiple influences for the same bone are combined before limiting.
+///
+/// Vertices where the removed weight is larger than `weight_threshold` as a fraction of the total weight
+/// are reported since they will deform noticeably differently.
+/// Vertices with a total weight of `0.0` are not renormalized.
+/// # Examples
+/**
+```rust
+use geometry_tools::skinning::limit_bone_influences;
+
+// A single vertex with 6 influences.
+let bone_indices = [0, 1, 2, 3, 4, 5];
+let bone_weights = [0.05, 0.3, 0.2, 0.2, 0.05, 0.2];
+
+let limited = limit_bone_influences(&bone_indices, &bone_weights, 6, 4, 0.05);
+assert_eq!(vec![1, 2, 3, 5], limited.bone_indices);
+assert!((limited.bone_weights.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+assert_eq!(vec![0], limited.lossy_vertices);
+```
+ */
+pub fn limit_bone_influences(
+    bone_indices: &[u32],
+    bone_weights: &[f32],
+    influence_count: usize,
+    max_influences: usize,
+    weight_threshold: f32,
+) -> LimitedBoneInfluences {
+    if influence_count == 0 {
+        return LimitedBoneInfluences::default();
+    }
+
+    let vertex_count = bone_indices.len().min(bone_weights.len()) / influence_count;
+    let mut limited_indices = vec![0; vertex_count * max_influences];
+    let mut limited_weights = vec![0.0; vertex_count * max_influences];
+    let mut lossy_vertices = Vec::new();
+
+    let mut influences = Vec::with_capacity(influence_count);
+    for (i, (indices, weights)) in bone_indices
+        .chunks_exact(influence_count)
+        .zip(bone_weights.chunks_exact(influence_count))
+        .enumerate()
+    {
+        // Combine influences for the same bone.
+        influences.clear();
+        for (bone, weight) in indices.iter().zip(weights) {
+            match influences.iter_mut().find(|(b, _)| b == bone) {
+                Some((_, w)) => *w += *weight,
+                None => influences.push((*bone, *weight)),
+            }
+        }
+
+        // The sort is stable to preserve the original order for equal weights.
+        influences.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let total: f32 = influences.iter().map(|(_, w)| w).sum();
+        let kept: f32 = influences.iter().take(max_influences).map(|(_, w)| w).sum();
+        if total > 0.0 && (total - kept) / total > weight_threshold {
+            lossy_vertices.push(i as u32);
+        }
+
+        let scale = if kept > 0.0 { 1.0 / kept } else { 1.0 };
+        for (j, (bone, weight)) in influences
+            .iter()
+            .filter(|(_, w)| *w != 0.0)
+            .take(max_influences)
+            .enumerate()
+        {
+            limited_indices[i * max_influences + j] = *bone;
+            limited_weights[i * max_influences + j] = weight * scale;
+        }
+    }
+
+    LimitedBoneInfluences {
+        bone_indices: limited_indices,
+        bone_weights: limited_weights,
+        lossy_vertices,
+    }
+}
+
+fn blend_matrices(indices: [u32; 4], weights: Vec4, bone_matrices: &[Mat4]) -> Mat4 {
+    indices
+        .iter()
+        .zip(weights.to_array())
+        .filter(|(_, weight)| *weight != 0.0)
+        .fold(Mat4::ZERO, |sum, (bone, weight)| {
+            sum + bone_matrices[*bone as usize] * weight
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use glam::{Quat, Vec3};
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn skin_empty() {
+        let (positions, normals) =
+            skin_positions_normals::<Vec3A, Vec3A, u16>(&[], &[], &[], &[], &[]);
+        assert!(positions.is_empty());
+        assert!(normals.is_empty());
+    }
+
+    #[test]
+    fn skin_missing_normals() {
+        let (positions, normals) = skin_positions_normals::<_, Vec3A, u32>(
+            &[Vec3A::X],
+            &[],
+            &[[0; 4]],
+            &[Vec4::X],
+            &[Mat4::from_scale(Vec3::splat(2.0))],
+        );
+        assert_eq!(vec![Vec3A::X * 2.0], positions);
+        assert!(normals.is_empty());
+    }
+
+    #[test]
+    fn skin_rotation() {
+        let rotation = Mat4::from_quat(Quat::from_rotation_z(90f32.to_radians()));
+        let (positions, normals) = skin_positions_normals(
+            &[Vec3A::X],
+            &[Vec3A::X],
+            &[[1u8, 0, 0, 0]],
+            &[Vec4::X],
+            &[Mat4::IDENTITY, rotation],
+        );
+        assert!(positions[0].abs_diff_eq(Vec3A::Y, EPSILON));
+        assert!(normals[0].abs_diff_eq(Vec3A::Y, EPSILON));
+    }
+
+    #[test]
+    fn skin_non_uniform_scale_normals() {
+        let (positions, normals) = skin_positions_normals(
+            &[Vec3A::X, Vec3A::Y],
+            &[Vec3A::new(1.0, 1.0, 0.0).normalize(); 2],
+            &[[0u8; 4]; 2],
+            &[Vec4::X; 2],
+            &[Mat4::from_scale(Vec3::new(2.0, 1.0, 1.0))],
+        );
+        let edge = positions[1] - positions[0];
+        assert!(edge.dot(normals[0]).abs() < EPSILON);
+    }
+
+    #[test]
+    fn limit_influences_empty() {
+        assert_eq!(
+            LimitedBoneInfluences::default(),
+            limit_bone_influences(&[], &[], 4, 4, 0.0)
+        );
+        assert_eq!(
+            LimitedBoneInfluences::default(),
+            limit_bone_influences(&[0], &[1.0], 0, 4, 0.0)
+        );
+    }
+
+    #[test]
+    fn limit_influences_padding() {
+        // Unused influences are padded with zeros.
+        let limited = limit_bone_influences(&[3, 7], &[0.0, 0.5], 2, 4, 0.0);
+        assert_eq!(vec![7, 0, 0, 0], limited.bone_indices);
+        assert_eq!(vec![1.0, 0.0, 0.0, 0.0], limited.bone_weights);
+        assert!(limited.lossy_vertices.is_empty());
+    }
+
+    #[test]
+    fn limit_influences_duplicate_bones() {
+        let limited = limit_bone_influences(&[1, 2, 1], &[0.25, 0.5, 0.25], 3, 1, 0.1);
+        assert_eq!(vec![1], limited.bone_indices);
+        assert_eq!(vec![1.0], limited.bone_weights);
+        assert_eq!(vec![0], limited.lossy_vertices);
+    }
+
+    #[test]
+    fn limit_influences_threshold() {
+        let bone_indices = [0, 1, 2, 0, 1, 2];
+        let bone_weights = [0.5, 0.49, 0.01, 0.5, 0.3, 0.2];
+        let limited = limit_bone_influences(&bone_indices, &bone_weights, 3, 2, 0.05);
+        assert_eq!(vec![0, 1, 0, 1], limited.bone_indices);
+        assert_eq!(vec![1], limited.lossy_vertices);
+    }
+
+    #[test]
+    fn limit_influences_zero_weights() {
+        let limited = limit_bone_influences(&[0, 1], &[0.0, 0.0], 2, 4, 0.0);
+        assert_eq!(vec![0.0; 4], limited.bone_weights);
+        assert!(limited.lossy_vertices.is_empty());
+    }
+}
@@ -0,0 +1,267 @@
+//! Functions for smoothing vertex positions to remove noise from scanned or generated meshes.
+
+use glam::Vec3A;
+
+use crate::topology::MeshAdjacency;
+
+/// Smooths `positions` by moving each vertex towards the average of its neighbors by a factor of `lambda`
+/// for the specified number of `iterations`.
+/// Values for `lambda` are typically between `0.0` and `1.0`.
+/// Vertices on open borders are not moved if `preserve_boundary` is `true`.
+///
+/// Repeated Laplacian smoothing shrinks the mesh.
+/// Use [smooth_taubin] to smooth without shrinking.
+/// # Examples
+/**
+```rust
+use geometry_tools::smooth::smooth_laplacian;
+use glam::Vec3A;
+
+// A fan of triangles around a raised center vertex.
+let positions = [
+    Vec3A::new(0.0, 0.0, 1.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(0.0, 1.0, 0.0),
+    Vec3A::new(-1.0, 0.0, 0.0),
+    Vec3A::new(0.0, -1.0, 0.0),
+];
+let indices = [0, 1, 2, 0, 2, 3, 0, 3, 4, 0, 4, 1];
+
+let smoothed = smooth_laplacian(&positions, &indices, 1, 0.5, true);
+assert_eq!(Vec3A::new(0.0, 0.0, 0.5), smoothed[0]);
+assert_eq!(positions[1], smoothed[1]);
+```
+ */
+pub fn smooth_laplacian<P>(
+    positions: &[P],
+    indices: &[u32],
+    iterations: usize,
+    lambda: f32,
+    preserve_boundary: bool,
+) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let adjacency = MeshAdjacency::new(positions.len(), indices);
+    smooth(
+        positions,
+        &adjacency,
+        iterations,
+        &[lambda],
+        preserve_boundary,
+    )
+}
+
+/// Smooths `positions` using alternating steps of Laplacian smoothing with a positive factor `lambda`
+/// and a negative factor `mu` for the specified number of `iterations`.
+/// The negative step inflates the mesh to counteract the shrinking from the positive step.
+/// Typical values are `lambda = 0.5` and `mu = -0.53` with `mu < -lambda`.
+/// Vertices on open borders are not moved if `preserve_boundary` is `true`.
+///
+/// See "A Signal Processing Approach To Fair Surface Design" by Taubin.
+/// # Examples
+/**
+```rust
+use geometry_tools::smooth::smooth_taubin;
+use glam::Vec3A;
+
+# let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+# let indices = [0, 1, 2];
+let smoothed = smooth_taubin(&positions, &indices, 10, 0.5, -0.53, true);
+```
+ */
+pub fn smooth_taubin<P>(
+    positions: &[P],
+    indices: &[u32],
+    iterations: usize,
+    lambda: f32,
+    mu: f32,
+    preserve_boundary: bool,
+) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let adjacency = MeshAdjacency::new(positions.len(), indices);
+    smooth(
+        positions,
+        &adjacency,
+        iterations,
+        &[lambda, mu],
+        preserve_boundary,
+    )
+}
+
+/// Smooths `positions` like [smooth_laplacian] using the precomputed connectivity in `adjacency`.
+///
+/// # Panics
+/// Panics if `positions` has a different length than the vertex count of `adjacency`.
+/// # Examples
+/**
+```rust
+use geometry_tools::smooth::{smooth_laplacian_with_adjacency, smooth_taubin_with_adjacency};
+use geometry_tools::topology::MeshAdjacency;
+use glam::Vec3A;
+
+# let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+# let indices = [0, 1, 2];
+// Compare different settings without recalculating the connectivity.
+let adjacency = MeshAdjacency::new(positions.len(), &indices);
+let laplacian = smooth_laplacian_with_adjacency(&positions, &adjacency, 10, 0.5, true);
+let taubin = smooth_taubin_with_adjacency(&positions, &adjacency, 10, 0.5, -0.53, true);
+```
+ */
+pub fn smooth_laplacian_with_adjacency<P>(
+    positions: &[P],
+    adjacency: &MeshAdjacency,
+    iterations: usize,
+    lambda: f32,
+    preserve_boundary: bool,
+) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+{
+    smooth(
+        positions,
+        adjacency,
+        iterations,
+        &[lambda],
+        preserve_boundary,
+    )
+}
+
+/// Smooths `positions` like [smooth_taubin] using the precomputed connectivity in `adjacency`.
+///
+/// # Panics
+/// Panics if `positions` has a different length than the vertex count of `adjacency`.
+pub fn smooth_taubin_with_adjacency<P>(
+    positions: &[P],
+    adjacency: &MeshAdjacency,
+    iterations: usize,
+    lambda: f32,
+    mu: f32,
+    preserve_boundary: bool,
+) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+{
+    smooth(
+        positions,
+        adjacency,
+        iterations,
+        &[lambda, mu],
+        preserve_boundary,
+    )
+}
+
+fn smooth<P>(
+    positions: &[P],
+    adjacency: &MeshAdjacency,
+    iterations: usize,
+    factors: &[f32],
+    preserve_boundary: bool,
+) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+{
+    assert_eq!(
+        positions.len(),
+        adjacency.vertex_count(),
+        "positions should have the same length as the adjacency vertex count"
+    );
+
+    let mut positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+
+    let mut smoothed = positions.clone();
+    for _ in 0..iterations {
+        for factor in factors {
+            for i in 0..positions.len() {
+                let neighbors = adjacency.vertex_neighbors(i);
+                if neighbors.is_empty() || (preserve_boundary && adjacency.is_boundary_vertex(i)) {
+                    continue;
+                }
+
+                let average = neighbors
+                    .iter()
+                    .map(|n| positions[*n as usize])
+                    .sum::<Vec3A>()
+                    / neighbors.len() as f32;
+                smoothed[i] = positions[i] + (average - positions[i]) * *factor;
+            }
+            positions.copy_from_slice(&smoothed);
+        }
+    }
+
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::shapes::generate_octahedron;
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn smooth_empty() {
+        assert!(smooth_laplacian::<Vec3A>(&[], &[], 5, 0.5, true).is_empty());
+        assert!(smooth_taubin::<Vec3A>(&[], &[], 5, 0.5, -0.53, false).is_empty());
+    }
+
+    #[test]
+    fn smooth_zero_iterations() {
+        let octahedron = generate_octahedron(1.0);
+        let (positions, indices) = (octahedron.positions, octahedron.indices);
+        assert_eq!(
+            positions,
+            smooth_laplacian(&positions, &indices, 0, 0.5, false)
+        );
+    }
+
+    #[test]
+    fn smooth_unused_vertex() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+        let smoothed = smooth_laplacian(&positions, &[0, 1, 2], 3, 0.5, false);
+        assert_eq!(Vec3A::Z, smoothed[3]);
+    }
+
+    #[test]
+    fn smooth_boundary() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let smoothed = smooth_laplacian(&positions, &[0, 1, 2], 1, 1.0, true);
+        assert_eq!(positions.to_vec(), smoothed);
+
+        let smoothed = smooth_laplacian(&positions, &[0, 1, 2], 1, 1.0, false);
+        assert_eq!(Vec3A::new(0.5, 0.5, 0.0), smoothed[0]);
+        assert_eq!(Vec3A::new(0.0, 0.5, 0.0), smoothed[1]);
+    }
+
+    #[test]
+    fn laplacian_shrinks_taubin_preserves_size() {
+        let octahedron = generate_octahedron(1.0);
+        let (positions, indices) = (octahedron.positions, octahedron.indices);
+
+        // Each vertex is adjacent to all vertices except the opposite vertex.
+        let smoothed = smooth_laplacian(&positions, &indices, 1, 0.5, false);
+        assert_relative_eq!(0.5, smoothed[0].length(), epsilon = EPSILON);
+
+        let smoothed = smooth_taubin(&positions, &indices, 1, 0.5, -0.5, false);
+        assert_relative_eq!(0.75, smoothed[0].length(), epsilon = EPSILON);
+
+        let smoothed = smooth_taubin(&positions, &indices, 1, 0.5, -0.53, false);
+        assert!(smoothed[0].length() > 0.75);
+    }
+
+    #[test]
+    fn smooth_with_adjacency_matches() {
+        let octahedron = generate_octahedron(1.0);
+        let (positions, indices) = (octahedron.positions, octahedron.indices);
+        let adjacency = MeshAdjacency::new(positions.len(), &indices);
+        assert_eq!(
+            smooth_taubin(&positions, &indices, 3, 0.5, -0.53, false),
+            smooth_taubin_with_adjacency(&positions, &adjacency, 3, 0.5, -0.53, false)
+        );
+    }
+}
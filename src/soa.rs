@@ -0,0 +1,320 @@
+//! A structure of arrays (SoA) buffer for processing very large point sets.
+//!
+//! Storing the x, y, and z components in separate arrays allows comparing and summing
+//! a full SIMD register of points at a time instead of one [glam::Vec3A] with an unused lane.
+//! Convert points once with [Vec3Soa::from_points] and reuse the buffer for multiple calculations.
+
+use glam::{Vec3A, Vec4};
+
+use crate::sum::{sum, Accumulator};
+use crate::vec3::Float3;
+
+/// The number of points processed at a time.
+/// This is wide enough for 256-bit registers and still compiles efficiently for 128-bit registers.
+const LANES: usize = 8;
+
+/// Points stored as separate arrays of x, y, and z components.
+/// # Examples
+/**
+```rust
+use geometry_tools::soa::Vec3Soa;
+use glam::{Vec3A, Vec4};
+
+let points = Vec3Soa::from_points(&[Vec3A::new(-1.0, 0.0, 0.0), Vec3A::new(1.0, 2.0, 0.0)]);
+assert_eq!(&[-1.0, 1.0], points.x());
+assert_eq!((Vec3A::new(-1.0, 0.0, 0.0), Vec3A::new(1.0, 2.0, 0.0)), points.aabb());
+assert_eq!(Vec3A::new(0.0, 1.0, 0.0), points.centroid());
+assert_eq!(Vec4::new(0.0, 1.0, 0.0, 2.0f32.sqrt()), points.bounding_sphere());
+```
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec3Soa {
+    x: Vec<f32>,
+    y: Vec<f32>,
+    z: Vec<f32>,
+}
+
+impl Vec3Soa {
+    /// Creates an empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty buffer with space for `capacity` points.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            x: Vec::with_capacity(capacity),
+            y: Vec::with_capacity(capacity),
+            z: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a buffer from separate component arrays.
+    ///
+    /// # Panics
+    /// Panics if the component arrays have different lengths.
+    pub fn from_components(x: Vec<f32>, y: Vec<f32>, z: Vec<f32>) -> Self {
+        assert!(
+            x.len() == y.len() && y.len() == z.len(),
+            "component arrays should have the same length"
+        );
+        Self { x, y, z }
+    }
+
+    /// Converts `points` from an array of structures to separate component arrays.
+    pub fn from_points<P>(points: &[P]) -> Self
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        let mut soa = Self::with_capacity(points.len());
+        soa.extend(points.iter().copied());
+        soa
+    }
+
+    /// Converts tightly packed points such as [glam::Vec3] or `[f32; 3]` to separate component arrays
+    /// without converting each point to [glam::Vec3A].
+    pub fn from_vec3<P: Float3>(points: &[P]) -> Self {
+        let points = P::as_arrays(points);
+        Self {
+            x: points.iter().map(|p| p[0]).collect(),
+            y: points.iter().map(|p| p[1]).collect(),
+            z: points.iter().map(|p| p[2]).collect(),
+        }
+    }
+
+    /// Converts the points back to an array of structures.
+    pub fn to_vec3a(&self) -> Vec<Vec3A> {
+        (0..self.len()).map(|i| self.get(i)).collect()
+    }
+
+    /// The number of points.
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Returns `true` if there are no points.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// The x components of all points.
+    pub fn x(&self) -> &[f32] {
+        &self.x
+    }
+
+    /// The y components of all points.
+    pub fn y(&self) -> &[f32] {
+        &self.y
+    }
+
+    /// The z components of all points.
+    pub fn z(&self) -> &[f32] {
+        &self.z
+    }
+
+    /// The point at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Vec3A {
+        Vec3A::new(self.x[index], self.y[index], self.z[index])
+    }
+
+    /// Appends `point` to the end of the buffer.
+    pub fn push(&mut self, point: Vec3A) {
+        self.x.push(point.x);
+        self.y.push(point.y);
+        self.z.push(point.z);
+    }
+
+    /// Calculates an axis-aligned bounding box of the form `(min_xyz, max_xyz)` containing all the points
+    /// like [calculate_aabb_from_points](crate::bounding::calculate_aabb_from_points).
+    /// If there are no points, both `min_xyz` and `max_xyz` will be zero.
+    pub fn aabb(&self) -> (Vec3A, Vec3A) {
+        if self.is_empty() {
+            return (Vec3A::ZERO, Vec3A::ZERO);
+        }
+        let (min_x, max_x) = min_max(&self.x);
+        let (min_y, max_y) = min_max(&self.y);
+        let (min_z, max_z) = min_max(&self.z);
+        (
+            Vec3A::new(min_x, min_y, min_z),
+            Vec3A::new(max_x, max_y, max_z),
+        )
+    }
+
+    /// Calculates the average of all the points or zero if there are no points.
+    /// The components are summed in a different order than
+    /// [calculate_bounding_sphere_from_points](crate::bounding::calculate_bounding_sphere_from_points),
+    /// so the result may differ slightly due to rounding.
+    pub fn centroid(&self) -> Vec3A {
+        if self.is_empty() {
+            return Vec3A::ZERO;
+        }
+        Vec3A::new(sum_lanes(&self.x), sum_lanes(&self.y), sum_lanes(&self.z)) / self.len() as f32
+    }
+
+    /// Calculates a bounding sphere of the form `(center, radius)` containing all the points
+    /// using [centroid](Vec3Soa::centroid) as the center
+    /// like [calculate_bounding_sphere_from_points](crate::bounding::calculate_bounding_sphere_from_points).
+    /// If there are no points, the center and radius will both be zero.
+    pub fn bounding_sphere(&self) -> Vec4 {
+        let center = self.centroid();
+
+        let mut max_lanes = [0.0f32; LANES];
+        let x = self.x.chunks_exact(LANES);
+        let y = self.y.chunks_exact(LANES);
+        let z = self.z.chunks_exact(LANES);
+        let (rx, ry, rz) = (x.remainder(), y.remainder(), z.remainder());
+        for ((x, y), z) in x.zip(y).zip(z) {
+            for i in 0..LANES {
+                max_lanes[i] = max_lanes[i].max(distance_squared(x[i], y[i], z[i], center));
+            }
+        }
+
+        let mut radius_squared = max_lanes.into_iter().fold(0.0, f32::max);
+        for i in 0..rx.len() {
+            radius_squared = radius_squared.max(distance_squared(rx[i], ry[i], rz[i], center));
+        }
+
+        center.extend(radius_squared.sqrt())
+    }
+}
+
+impl<P> FromIterator<P> for Vec3Soa
+where
+    P: Into<Vec3A>,
+{
+    fn from_iter<T: IntoIterator<Item = P>>(iter: T) -> Self {
+        let mut soa = Self::new();
+        soa.extend(iter);
+        soa
+    }
+}
+
+impl<P> Extend<P> for Vec3Soa
+where
+    P: Into<Vec3A>,
+{
+    fn extend<T: IntoIterator<Item = P>>(&mut self, iter: T) {
+        for point in iter {
+            self.push(point.into());
+        }
+    }
+}
+
+#[inline(always)]
+fn distance_squared(x: f32, y: f32, z: f32, center: Vec3A) -> f32 {
+    let dx = x - center.x;
+    let dy = y - center.y;
+    let dz = z - center.z;
+    dx * dx + dy * dy + dz * dz
+}
+
+fn min_max(values: &[f32]) -> (f32, f32) {
+    // Independent lanes allow comparing a full register of values at a time.
+    let mut min = [f32::INFINITY; LANES];
+    let mut max = [f32::NEG_INFINITY; LANES];
+    let chunks = values.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for i in 0..LANES {
+            min[i] = min[i].min(chunk[i]);
+            max[i] = max[i].max(chunk[i]);
+        }
+    }
+
+    let mut min = min.into_iter().fold(f32::INFINITY, f32::min);
+    let mut max = max.into_iter().fold(f32::NEG_INFINITY, f32::max);
+    for value in remainder {
+        min = min.min(*value);
+        max = max.max(*value);
+    }
+    (min, max)
+}
+
+fn sum_lanes(values: &[f32]) -> f32 {
+    let mut lanes = [Accumulator::new(0.0f32); LANES];
+    let chunks = values.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for i in 0..LANES {
+            lanes[i].add(chunk[i]);
+        }
+    }
+    sum(lanes
+        .iter()
+        .map(Accumulator::sum)
+        .chain(remainder.iter().copied()))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use glam::Vec3;
+
+    use super::*;
+
+    fn points(count: usize) -> Vec<Vec3A> {
+        (0..count)
+            .map(|i| {
+                Vec3A::new(
+                    (i * 7 % 13) as f32 - 6.0,
+                    (i as f32 * 0.5).sin(),
+                    -(i as f32),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let soa = Vec3Soa::new();
+        assert_eq!((Vec3A::ZERO, Vec3A::ZERO), soa.aabb());
+        assert_eq!(Vec3A::ZERO, soa.centroid());
+        assert_eq!(Vec4::ZERO, soa.bounding_sphere());
+    }
+
+    #[test]
+    fn round_trip() {
+        let points = points(11);
+        let soa = Vec3Soa::from_points(&points);
+        assert_eq!(11, soa.len());
+        assert_eq!(points, soa.to_vec3a());
+
+        let vec3: Vec<Vec3> = points.iter().copied().map(Vec3::from).collect();
+        assert_eq!(soa, Vec3Soa::from_vec3(&vec3));
+        assert_eq!(soa, points.iter().copied().collect());
+    }
+
+    #[test]
+    fn aabb_matches_aos() {
+        for count in [1, 7, 8, 9, 100] {
+            let points = points(count);
+            assert_eq!(
+                crate::bounding::calculate_aabb_from_points(&points),
+                Vec3Soa::from_points(&points).aabb()
+            );
+        }
+    }
+
+    #[test]
+    fn bounding_sphere_matches_aos() {
+        for count in [1, 7, 8, 9, 100] {
+            let points = points(count);
+            let expected = crate::bounding::calculate_bounding_sphere_from_points(&points);
+            let sphere = Vec3Soa::from_points(&points).bounding_sphere();
+            assert_relative_eq!(expected.x, sphere.x, epsilon = 0.0001);
+            assert_relative_eq!(expected.y, sphere.y, epsilon = 0.0001);
+            assert_relative_eq!(expected.z, sphere.z, epsilon = 0.0001);
+            assert_relative_eq!(expected.w, sphere.w, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_components_length_mismatch() {
+        Vec3Soa::from_components(vec![0.0; 2], vec![0.0; 2], vec![0.0; 1]);
+    }
+}
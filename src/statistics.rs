@@ -0,0 +1,150 @@
+//! Functions for summarizing mesh data for logging and asset checks.
+
+use glam::{Vec2, Vec3A};
+
+use crate::bounding::calculate_aabb_from_points;
+use crate::uv::calculate_uv_bounds;
+use crate::validation::validate_mesh;
+
+/// A summary of the counts, bounds, and problems for a mesh calculated by [mesh_statistics].
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeshStatistics {
+    pub vertex_count: usize,
+    pub index_count: usize,
+    /// The number of complete triangles in the index buffer.
+    pub triangle_count: usize,
+    /// The axis-aligned bounding box of the form `(min_xyz, max_xyz)` for all positions.
+    pub bounds: (Vec3A, Vec3A),
+    /// The number of indices that are out of range for the vertex count.
+    pub out_of_range_index_count: usize,
+    /// The number of triangles with repeated vertex indices or zero area.
+    pub degenerate_triangle_count: usize,
+    /// The number of vertices with the same attributes as a previous vertex.
+    pub duplicate_vertex_count: usize,
+    /// The length of the shortest triangle edge or `0.0` if there are no triangles.
+    pub min_edge_length: f32,
+    /// The length of the longest triangle edge or `0.0` if there are no triangles.
+    pub max_edge_length: f32,
+    /// The bounding box of the form `(min_uv, max_uv)` for all texture coordinates if present.
+    pub uv_bounds: Option<(Vec2, Vec2)>,
+    /// The shortest and longest normal vector lengths of the form `(min, max)` if present.
+    pub normal_length_range: Option<(f32, f32)>,
+}
+
+/// Calculates counts, bounds, degenerate and duplicate element counts,
+/// edge lengths, and attribute ranges for the triangle list defined by `positions` and `indices`.
+/// Empty `uvs` or `normals` are treated as missing attributes.
+/// Triangles with out of range indices are excluded from edge lengths.
+///
+/// Use [validate_mesh] to find the indices of the problematic elements.
+/// # Examples
+/**
+```rust
+use geometry_tools::statistics::mesh_statistics;
+use glam::{Vec2, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+let normals: [Vec3A; 0] = [];
+let indices = [0, 1, 2];
+
+let statistics = mesh_statistics(&positions, &indices, &uvs, &normals);
+assert_eq!(1, statistics.triangle_count);
+assert_eq!((Vec3A::ZERO, Vec3A::new(1.0, 1.0, 0.0)), statistics.bounds);
+assert_eq!(1.0, statistics.min_edge_length);
+assert_eq!(Some((Vec2::ZERO, Vec2::ONE)), statistics.uv_bounds);
+assert_eq!(None, statistics.normal_length_range);
+```
+ */
+pub fn mesh_statistics<P, N>(
+    positions: &[P],
+    indices: &[u32],
+    uvs: &[Vec2],
+    normals: &[N],
+) -> MeshStatistics
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    let report = validate_mesh(positions, normals, uvs, indices);
+
+    let mut min_edge_length = f32::MAX;
+    let mut max_edge_length = 0.0f32;
+    for face in indices.chunks_exact(3) {
+        if face.iter().all(|i| (*i as usize) < positions.len()) {
+            let [v0, v1, v2] = [face[0], face[1], face[2]].map(|i| positions[i as usize].into());
+            for length in [v0.distance(v1), v1.distance(v2), v2.distance(v0)] {
+                min_edge_length = min_edge_length.min(length);
+                max_edge_length = max_edge_length.max(length);
+            }
+        }
+    }
+    if min_edge_length > max_edge_length {
+        min_edge_length = 0.0;
+    }
+
+    let normal_length_range = normals.iter().map(|n| (*n).into().length()).fold(
+        None,
+        |range: Option<(f32, f32)>, length| match range {
+            Some((min, max)) => Some((min.min(length), max.max(length))),
+            None => Some((length, length)),
+        },
+    );
+
+    MeshStatistics {
+        vertex_count: positions.len(),
+        index_count: indices.len(),
+        triangle_count: indices.len() / 3,
+        bounds: calculate_aabb_from_points(positions),
+        out_of_range_index_count: report.out_of_range_indices.len(),
+        degenerate_triangle_count: report.degenerate_triangles.len(),
+        duplicate_vertex_count: report.duplicate_vertices.len(),
+        min_edge_length,
+        max_edge_length,
+        uv_bounds: (!uvs.is_empty()).then(|| calculate_uv_bounds(uvs)),
+        normal_length_range,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics_empty() {
+        assert_eq!(
+            MeshStatistics::default(),
+            mesh_statistics::<Vec3A, Vec3A>(&[], &[], &[], &[])
+        );
+    }
+
+    #[test]
+    fn statistics_problems() {
+        let positions = [Vec3A::ZERO, Vec3A::X * 2.0, Vec3A::Y, Vec3A::ZERO];
+        let normals = [Vec3A::Z, Vec3A::Z * 0.5, Vec3A::Z, Vec3A::Z];
+        let indices = [0, 1, 2, 0, 0, 1, 0, 1, 9, 0];
+
+        let statistics = mesh_statistics(&positions, &indices, &[], &normals);
+        assert_eq!(4, statistics.vertex_count);
+        assert_eq!(10, statistics.index_count);
+        assert_eq!(3, statistics.triangle_count);
+        assert_eq!(1, statistics.out_of_range_index_count);
+        assert_eq!(1, statistics.degenerate_triangle_count);
+        assert_eq!(1, statistics.duplicate_vertex_count);
+        assert_eq!(0.0, statistics.min_edge_length);
+        assert_eq!(5.0f32.sqrt(), statistics.max_edge_length);
+        assert_eq!(None, statistics.uv_bounds);
+        assert_eq!(Some((0.5, 1.0)), statistics.normal_length_range);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn statistics_serde_round_trip() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let statistics = mesh_statistics::<_, Vec3A>(&positions, &[0, 1, 2], &[], &[]);
+
+        let json = serde_json::to_string(&statistics).unwrap();
+        assert_eq!(statistics, serde_json::from_str(&json).unwrap());
+    }
+}
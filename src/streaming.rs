@@ -0,0 +1,484 @@
+//! Incremental versions of the bounding and vector functions for processing large meshes in chunks.
+//!
+//! Each accumulator takes points or triangle indices one chunk at a time,
+//! so the vertex and index data never needs to be decoded all at once.
+//! Bounding volumes only store a few values while processing.
+//! Normals and tangents use two passes.
+//! The first pass accumulates unnormalized sums for each vertex from chunks of indices,
+//! and the second pass writes the final vectors for chunks of vertices.
+//!
+//! Processing the chunks in order produces the same results as the single threaded
+//! non streaming functions like [calculate_smooth_normals](crate::vectors::calculate_smooth_normals).
+
+use glam::{Vec2, Vec3A, Vec4};
+
+use crate::sum::Accumulator;
+use crate::vectors::{
+    calculate_tangent_w,
+    normal::calculate_normal,
+    tangent::{calculate_tangent_bitangent, finalize_tangent_bitangent},
+};
+
+/// Calculates an axis-aligned bounding box from chunks of points
+/// like [calculate_aabb_from_points](crate::bounding::calculate_aabb_from_points).
+/// # Examples
+/**
+```rust
+use geometry_tools::streaming::AabbAccumulator;
+use glam::Vec3A;
+
+let mut aabb = AabbAccumulator::new();
+aabb.add_points(&[Vec3A::new(-1.0, 0.0, 2.0)]);
+aabb.add_points(&[Vec3A::new(1.0, 3.0, 0.0), Vec3A::ZERO]);
+assert_eq!(
+    (Vec3A::new(-1.0, 0.0, 0.0), Vec3A::new(1.0, 3.0, 2.0)),
+    aabb.finish()
+);
+```
+ */
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AabbAccumulator {
+    min_max: Option<(Vec3A, Vec3A)>,
+}
+
+impl AabbAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expands the bounding box to contain `points`.
+    pub fn add_points<P>(&mut self, points: &[P])
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        for point in points {
+            let point: Vec3A = (*point).into();
+            self.min_max = Some(match self.min_max {
+                Some((min_xyz, max_xyz)) => (min_xyz.min(point), max_xyz.max(point)),
+                None => (point, point),
+            });
+        }
+    }
+
+    /// Returns the bounding box of the form `(min_xyz, max_xyz)`.
+    /// If no points were added, both `min_xyz` and `max_xyz` will be zero.
+    pub fn finish(&self) -> (Vec3A, Vec3A) {
+        self.min_max.unwrap_or((Vec3A::ZERO, Vec3A::ZERO))
+    }
+}
+
+/// The first pass for calculating a bounding sphere from chunks of points
+/// like [calculate_bounding_sphere_from_points](crate::bounding::calculate_bounding_sphere_from_points).
+/// The first pass finds the center, and the second pass finds the radius.
+/// # Examples
+/**
+```rust
+use geometry_tools::streaming::BoundingSphereAccumulator;
+use glam::{Vec3A, Vec4};
+
+let chunks = [
+    vec![Vec3A::new(0.0, -1.0, 0.0)],
+    vec![Vec3A::ZERO, Vec3A::new(0.0, 1.0, 0.0)],
+];
+
+let mut center = BoundingSphereAccumulator::new();
+for chunk in &chunks {
+    center.add_points(chunk);
+}
+
+// The points need to be processed again to find the radius.
+let mut radius = center.radius_pass();
+for chunk in &chunks {
+    radius.add_points(chunk);
+}
+assert_eq!(Vec4::new(0.0, 0.0, 0.0, 1.0), radius.finish());
+```
+ */
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BoundingSphereAccumulator {
+    sum: Accumulator<Vec3A>,
+    // Sum the same chunks as calculate_bounding_sphere_from_points to get identical results.
+    #[cfg(feature = "deterministic")]
+    chunk_sum: Accumulator<Vec3A>,
+    count: usize,
+}
+
+impl BoundingSphereAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `points` to the calculation of the center.
+    pub fn add_points<P>(&mut self, points: &[P])
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        for point in points {
+            #[cfg(feature = "deterministic")]
+            {
+                self.chunk_sum.add((*point).into());
+                if (self.count + 1).is_multiple_of(crate::parallel::CHUNK_SIZE) {
+                    self.sum.add(self.chunk_sum.sum());
+                    self.chunk_sum = Accumulator::default();
+                }
+            }
+
+            #[cfg(not(feature = "deterministic"))]
+            self.sum.add((*point).into());
+
+            self.count += 1;
+        }
+    }
+
+    /// Finishes calculating the center and starts the second pass for calculating the radius.
+    /// The same points should be added again to the returned accumulator.
+    pub fn radius_pass(&self) -> BoundingSphereRadiusAccumulator {
+        #[allow(unused_mut)]
+        let mut sum = self.sum;
+        #[cfg(feature = "deterministic")]
+        if !self.count.is_multiple_of(crate::parallel::CHUNK_SIZE) {
+            sum.add(self.chunk_sum.sum());
+        }
+
+        let center = if self.count > 0 {
+            sum.sum() / self.count as f32
+        } else {
+            Vec3A::ZERO
+        };
+        BoundingSphereRadiusAccumulator {
+            center,
+            radius_squared: 0.0,
+        }
+    }
+}
+
+/// The second pass for calculating a bounding sphere returned by [BoundingSphereAccumulator::radius_pass].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingSphereRadiusAccumulator {
+    center: Vec3A,
+    radius_squared: f32,
+}
+
+impl BoundingSphereRadiusAccumulator {
+    /// Expands the radius to contain `points`.
+    pub fn add_points<P>(&mut self, points: &[P])
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        for point in points {
+            let length_squared = (*point).into().distance_squared(self.center);
+            if length_squared > self.radius_squared {
+                self.radius_squared = length_squared;
+            }
+        }
+    }
+
+    /// Returns the bounding sphere of the form `(center, radius)`.
+    /// If no points were added, the center and radius will both be zero.
+    pub fn finish(&self) -> Vec4 {
+        self.center.extend(self.radius_squared.sqrt())
+    }
+}
+
+/// Calculates smooth per-vertex normals from chunks of triangle indices
+/// like [calculate_smooth_normals](crate::vectors::calculate_smooth_normals).
+///
+/// Only the accumulated normal for each vertex is stored.
+/// The positions are only read for the vertices referenced by each chunk of indices.
+/// # Examples
+/**
+```rust
+use geometry_tools::streaming::SmoothNormalAccumulator;
+use glam::Vec3A;
+
+let positions = vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::NEG_X];
+let indices = vec![0, 1, 2, 0, 2, 3];
+
+// Accumulate one triangle at a time.
+let mut accumulator = SmoothNormalAccumulator::new(positions.len());
+for chunk in indices.chunks(3) {
+    accumulator.add_faces(&positions, chunk);
+}
+
+// Write the normals two vertices at a time.
+let mut normals = vec![Vec3A::ZERO; 2];
+for first_vertex in (0..positions.len()).step_by(2) {
+    accumulator.write_normals(first_vertex, &mut normals);
+    assert_eq!(vec![Vec3A::Z; 2], normals);
+}
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmoothNormalAccumulator {
+    normals: Vec<Accumulator<Vec3A>>,
+}
+
+impl SmoothNormalAccumulator {
+    /// Creates an accumulator for a mesh with `vertex_count` vertices.
+    pub fn new(vertex_count: usize) -> Self {
+        Self {
+            normals: vec![Accumulator::new(Vec3A::ZERO); vertex_count],
+        }
+    }
+
+    /// Adds the face normals for the triangles in `indices`.
+    /// Each chunk of `indices` should only contain whole triangles, so `indices.len()` should be a multiple of 3.
+    ///
+    /// # Panics
+    /// Panics if any index is out of range for `positions` or the vertex count.
+    pub fn add_faces<P>(&mut self, positions: &[P], indices: &[u32])
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        for face in indices.chunks_exact(3) {
+            let [v0, v1, v2] = [face[0] as usize, face[1] as usize, face[2] as usize];
+            let normal = calculate_normal(
+                positions[v0].into(),
+                positions[v1].into(),
+                positions[v2].into(),
+            );
+            self.normals[v0].add(normal);
+            self.normals[v1].add(normal);
+            self.normals[v2].add(normal);
+        }
+    }
+
+    /// Writes the normalized vectors for the vertices starting at `first_vertex` to `normals`.
+    /// Vertices not used by any face are set to zero.
+    ///
+    /// # Panics
+    /// Panics if the range of vertices is out of range for the vertex count.
+    pub fn write_normals(&self, first_vertex: usize, normals: &mut [Vec3A]) {
+        let sums = &self.normals[first_vertex..first_vertex + normals.len()];
+        for (normal, sum) in normals.iter_mut().zip(sums) {
+            *normal = sum.sum().normalize_or_zero();
+        }
+    }
+
+    /// Returns the normalized vectors for all vertices.
+    pub fn finish(self) -> Vec<Vec3A> {
+        self.normals
+            .iter()
+            .map(|sum| sum.sum().normalize_or_zero())
+            .collect()
+    }
+}
+
+/// Calculates smooth per-vertex tangents from chunks of triangle indices
+/// like [calculate_tangents](crate::vectors::calculate_tangents).
+///
+/// Only the accumulated tangent and bitangent for each vertex are stored.
+/// The normals are only needed for the second pass.
+/// # Examples
+/**
+```rust
+use geometry_tools::streaming::TangentAccumulator;
+use glam::{Vec2, Vec3A, Vec4};
+
+let positions = vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let uvs = vec![Vec2::ZERO, Vec2::X, Vec2::Y];
+let normals = vec![Vec3A::Z; 3];
+
+let mut accumulator = TangentAccumulator::new(positions.len());
+accumulator.add_faces(&positions, &uvs, &[0, 1, 2]);
+
+let mut tangents = vec![Vec4::ZERO; 3];
+accumulator.write_tangents(0, &normals, &mut tangents);
+assert_eq!(vec![Vec4::new(1.0, 0.0, 0.0, 1.0); 3], tangents);
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct TangentAccumulator {
+    tangents: Vec<Accumulator<Vec3A>>,
+    bitangents: Vec<Accumulator<Vec3A>>,
+}
+
+impl TangentAccumulator {
+    /// Creates an accumulator for a mesh with `vertex_count` vertices.
+    pub fn new(vertex_count: usize) -> Self {
+        Self {
+            tangents: vec![Accumulator::new(Vec3A::ZERO); vertex_count],
+            bitangents: vec![Accumulator::new(Vec3A::ZERO); vertex_count],
+        }
+    }
+
+    /// Adds the face tangents and bitangents for the triangles in `indices`.
+    /// Each chunk of `indices` should only contain whole triangles, so `indices.len()` should be a multiple of 3.
+    ///
+    /// # Panics
+    /// Panics if any index is out of range for `positions`, `uvs`, or the vertex count.
+    pub fn add_faces<P>(&mut self, positions: &[P], uvs: &[Vec2], indices: &[u32])
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        for face in indices.chunks_exact(3) {
+            let [v0, v1, v2] = [face[0] as usize, face[1] as usize, face[2] as usize];
+            let (tangent, bitangent) = calculate_tangent_bitangent(
+                &positions[v0].into(),
+                &positions[v1].into(),
+                &positions[v2].into(),
+                &uvs[v0],
+                &uvs[v1],
+                &uvs[v2],
+            );
+
+            self.tangents[v0].add(tangent);
+            self.tangents[v1].add(tangent);
+            self.tangents[v2].add(tangent);
+
+            self.bitangents[v0].add(bitangent);
+            self.bitangents[v1].add(bitangent);
+            self.bitangents[v2].add(bitangent);
+        }
+    }
+
+    /// Writes the tangents and tangent signs for the vertices starting at `first_vertex` to `tangents`.
+    /// `normals` contains the normals for the same range of vertices.
+    ///
+    /// # Panics
+    /// Panics if `normals` and `tangents` have different lengths
+    /// or the range of vertices is out of range for the vertex count.
+    pub fn write_tangents<N>(&self, first_vertex: usize, normals: &[N], tangents: &mut [Vec4])
+    where
+        N: Into<Vec3A> + Copy,
+    {
+        assert_eq!(
+            normals.len(),
+            tangents.len(),
+            "tangents should have the same length as normals"
+        );
+        let range = first_vertex..first_vertex + tangents.len();
+        for (((tangent, t), b), n) in tangents
+            .iter_mut()
+            .zip(&self.tangents[range.clone()])
+            .zip(&self.bitangents[range])
+            .zip(normals)
+        {
+            let n = (*n).into();
+            let (t, b) = finalize_tangent_bitangent(t.sum(), b.sum(), n);
+            *tangent = t.extend(calculate_tangent_w(t, b, n));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::bounding::{calculate_aabb_from_points, calculate_bounding_sphere_from_points};
+    use crate::vectors::{calculate_smooth_normals, calculate_tangents};
+
+    fn grid() -> (Vec<Vec3A>, Vec<Vec2>, Vec<u32>) {
+        let mut positions = Vec::new();
+        let mut uvs = Vec::new();
+        for y in 0..8 {
+            for x in 0..8 {
+                let z = ((x * 7 + y * 3) % 5) as f32 * 0.25;
+                positions.push(Vec3A::new(x as f32, y as f32, z));
+                uvs.push(Vec2::new(x as f32 / 7.0, 1.0 - y as f32 / 7.0));
+            }
+        }
+        let mut indices = Vec::new();
+        for y in 0..7 {
+            for x in 0..7 {
+                let i = y * 8 + x;
+                indices.extend_from_slice(&[i, i + 1, i + 8, i + 1, i + 9, i + 8]);
+            }
+        }
+        (positions, uvs, indices)
+    }
+
+    #[test]
+    fn aabb_empty() {
+        assert_eq!((Vec3A::ZERO, Vec3A::ZERO), AabbAccumulator::new().finish());
+    }
+
+    #[test]
+    fn bounding_sphere_empty() {
+        let accumulator = BoundingSphereAccumulator::new();
+        assert_eq!(Vec4::ZERO, accumulator.radius_pass().finish());
+    }
+
+    #[test]
+    fn bounds_match_non_streaming() {
+        let (positions, _, _) = grid();
+
+        let mut aabb = AabbAccumulator::new();
+        let mut center = BoundingSphereAccumulator::new();
+        for chunk in positions.chunks(5) {
+            aabb.add_points(chunk);
+            center.add_points(chunk);
+        }
+        let mut radius = center.radius_pass();
+        for chunk in positions.chunks(5) {
+            radius.add_points(chunk);
+        }
+
+        assert_eq!(calculate_aabb_from_points(&positions), aabb.finish());
+        assert_eq!(
+            calculate_bounding_sphere_from_points(&positions),
+            radius.finish()
+        );
+    }
+
+    // The parallel sum uses a different order unless the deterministic feature is enabled.
+    #[cfg(any(feature = "deterministic", not(feature = "rayon")))]
+    #[test]
+    fn bounding_sphere_multiple_chunks() {
+        let points: Vec<_> = (0..10000)
+            .map(|i| Vec3A::new(i as f32 * 0.1, (i % 7) as f32, 1e4))
+            .collect();
+
+        let mut center = BoundingSphereAccumulator::new();
+        for chunk in points.chunks(1000) {
+            center.add_points(chunk);
+        }
+        let mut radius = center.radius_pass();
+        radius.add_points(&points);
+
+        assert_eq!(
+            calculate_bounding_sphere_from_points(&points),
+            radius.finish()
+        );
+    }
+
+    #[test]
+    fn normals_match_non_streaming() {
+        let (positions, _, indices) = grid();
+
+        let mut accumulator = SmoothNormalAccumulator::new(positions.len());
+        for chunk in indices.chunks(9) {
+            accumulator.add_faces(&positions, chunk);
+        }
+
+        let mut normals = vec![Vec3A::ZERO; positions.len()];
+        for (i, chunk) in normals.chunks_mut(10).enumerate() {
+            accumulator.write_normals(i * 10, chunk);
+        }
+
+        let expected = calculate_smooth_normals(&positions, &indices);
+        assert_eq!(expected, normals);
+        assert_eq!(expected, accumulator.finish());
+    }
+
+    #[test]
+    fn tangents_match_non_streaming() {
+        let (positions, uvs, indices) = grid();
+        let normals = calculate_smooth_normals(&positions, &indices);
+
+        let mut accumulator = TangentAccumulator::new(positions.len());
+        for chunk in indices.chunks(12) {
+            accumulator.add_faces(&positions, &uvs, chunk);
+        }
+
+        let mut tangents = vec![Vec4::ZERO; positions.len()];
+        for (i, (chunk, normals)) in tangents.chunks_mut(7).zip(normals.chunks(7)).enumerate() {
+            accumulator.write_tangents(i * 7, normals, chunk);
+        }
+
+        assert_eq!(
+            calculate_tangents(&positions, &normals, &uvs, &indices).unwrap(),
+            tangents
+        );
+    }
+}
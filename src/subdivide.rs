@@ -0,0 +1,357 @@
+//! Functions for increasing the triangle count of meshes by subdividing triangles.
+
+use std::collections::HashMap;
+
+use glam::{Vec2, Vec3A};
+
+use crate::topology::edge_key;
+
+/// The result of [subdivide_midpoint] or [subdivide_loop].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SubdividedMesh {
+    pub positions: Vec<Vec3A>,
+    /// The interpolated normals or an empty list if there were no input normals.
+    pub normals: Vec<Vec3A>,
+    /// The interpolated texture coordinates or an empty list if there were no input texture coordinates.
+    pub uvs: Vec<Vec2>,
+    pub indices: Vec<u32>,
+}
+
+/// Splits each triangle into four triangles by inserting a vertex at the midpoint of each edge
+/// and repeats this process `levels` times.
+/// The shape of the mesh does not change.
+///
+/// Empty `normals` or `uvs` are treated as missing attributes.
+/// The new vertices interpolate the normals and texture coordinates of the edge's vertices.
+/// # Examples
+/**
+```rust
+use geometry_tools::subdivide::subdivide_midpoint;
+use glam::{Vec2, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+let normals: [Vec3A; 0] = [];
+
+let subdivided = subdivide_midpoint(&positions, &normals, &uvs, &[0, 1, 2], 1);
+assert_eq!(12, subdivided.indices.len());
+assert_eq!(Vec3A::new(0.5, 0.0, 0.0), subdivided.positions[3]);
+assert_eq!(Vec2::new(0.5, 0.0), subdivided.uvs[3]);
+```
+ */
+pub fn subdivide_midpoint<P, N>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[u32],
+    levels: usize,
+) -> SubdividedMesh
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    subdivide(positions, normals, uvs, indices, levels, false)
+}
+
+/// Subdivides the mesh `levels` times using Loop subdivision,
+/// which splits each triangle into four triangles and smooths the positions to approximate a smooth surface.
+/// Open borders are smoothed as curves, and vertices and edges adjacent to non-manifold edges are not smoothed.
+///
+/// Vertices with identical positions are treated as the same vertex when smoothing,
+/// so UV seams and hard edges do not create cracks.
+/// Empty `normals` or `uvs` are treated as missing attributes.
+/// The new vertices interpolate the normals and texture coordinates of the edge's vertices,
+/// so normals may need to be recalculated for the smoothed positions.
+///
+/// See "Smooth Subdivision Surfaces Based on Triangles" by Loop.
+/// # Examples
+/**
+```rust
+use geometry_tools::subdivide::subdivide_loop;
+use glam::Vec3A;
+
+// A tetrahedron becomes smaller and rounder after subdivision.
+let positions = [Vec3A::X, Vec3A::Y, Vec3A::Z, Vec3A::ZERO];
+let indices = [0, 1, 2, 0, 3, 1, 1, 3, 2, 2, 3, 0];
+let normals: [Vec3A; 0] = [];
+
+let subdivided = subdivide_loop(&positions, &normals, &[], &indices, 2);
+assert_eq!(4 * 16 * 3, subdivided.indices.len());
+```
+ */
+pub fn subdivide_loop<P, N>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[u32],
+    levels: usize,
+) -> SubdividedMesh
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    subdivide(positions, normals, uvs, indices, levels, true)
+}
+
+fn subdivide<P, N>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[u32],
+    levels: usize,
+    smooth: bool,
+) -> SubdividedMesh
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    let mut mesh = SubdividedMesh {
+        positions: positions.iter().copied().map(Into::into).collect(),
+        normals: normals.iter().copied().map(Into::into).collect(),
+        uvs: uvs.to_vec(),
+        indices: indices[..indices.len() / 3 * 3].to_vec(),
+    };
+    for _ in 0..levels {
+        mesh = subdivide_once(&mesh, smooth);
+    }
+    mesh
+}
+
+fn subdivide_once(mesh: &SubdividedMesh, smooth: bool) -> SubdividedMesh {
+    // Smooth using the topology for unique positions to avoid cracks along seams.
+    let welded = weld_positions(&mesh.positions);
+    let welded_indices: Vec<u32> = mesh.indices.iter().map(|i| welded[*i as usize]).collect();
+    let welded_edges = WeldedEdges::new(&welded_indices);
+
+    let mut positions = if smooth {
+        smooth_vertices(&mesh.positions, &welded, &welded_edges)
+    } else {
+        mesh.positions.clone()
+    };
+    let mut normals = mesh.normals.clone();
+    let mut uvs = mesh.uvs.clone();
+
+    // Insert a vertex for each unique edge.
+    let mut edge_vertices: HashMap<[u32; 2], u32> = HashMap::new();
+    let mut indices = Vec::with_capacity(mesh.indices.len() * 4);
+    for face in mesh.indices.chunks_exact(3) {
+        let [v0, v1, v2] = [face[0], face[1], face[2]];
+        let [e0, e1, e2] = [(v0, v1), (v1, v2), (v2, v0)].map(|(a, b)| {
+            *edge_vertices.entry(edge_key(a, b)).or_insert_with(|| {
+                let (a, b) = (a as usize, b as usize);
+                positions.push(if smooth {
+                    welded_edges.edge_point(&mesh.positions, welded[a], welded[b])
+                } else {
+                    (mesh.positions[a] + mesh.positions[b]) / 2.0
+                });
+                if !mesh.normals.is_empty() {
+                    normals.push((mesh.normals[a] + mesh.normals[b]).normalize_or_zero());
+                }
+                if !mesh.uvs.is_empty() {
+                    uvs.push((mesh.uvs[a] + mesh.uvs[b]) / 2.0);
+                }
+                positions.len() as u32 - 1
+            })
+        });
+
+        indices.extend_from_slice(&[v0, e0, e2, e0, v1, e1, e2, e1, v2, e0, e1, e2]);
+    }
+
+    SubdividedMesh {
+        positions,
+        normals,
+        uvs,
+        indices,
+    }
+}
+
+fn weld_positions(positions: &[Vec3A]) -> Vec<u32> {
+    let mut first_vertex = HashMap::new();
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            *first_vertex
+                .entry(p.to_array().map(f32::to_bits))
+                .or_insert(i as u32)
+        })
+        .collect()
+}
+
+/// The vertices opposite each edge for the welded topology.
+struct WeldedEdges {
+    opposite: HashMap<[u32; 2], Vec<u32>>,
+}
+
+impl WeldedEdges {
+    fn new(welded_indices: &[u32]) -> Self {
+        let mut opposite: HashMap<[u32; 2], Vec<u32>> = HashMap::new();
+        for face in welded_indices.chunks_exact(3) {
+            let [v0, v1, v2] = [face[0], face[1], face[2]];
+            if v0 == v1 || v1 == v2 || v2 == v0 {
+                continue;
+            }
+            for (a, b, c) in [(v0, v1, v2), (v1, v2, v0), (v2, v0, v1)] {
+                opposite.entry(edge_key(a, b)).or_default().push(c);
+            }
+        }
+        Self { opposite }
+    }
+
+    fn edge_point(&self, positions: &[Vec3A], a: u32, b: u32) -> Vec3A {
+        let pa = positions[a as usize];
+        let pb = positions[b as usize];
+        match self.opposite.get(&edge_key(a, b)).map(Vec::as_slice) {
+            Some([c, d]) => {
+                (pa + pb) * 3.0 / 8.0 + (positions[*c as usize] + positions[*d as usize]) / 8.0
+            }
+            _ => (pa + pb) / 2.0,
+        }
+    }
+}
+
+fn smooth_vertices(positions: &[Vec3A], welded: &[u32], edges: &WeldedEdges) -> Vec<Vec3A> {
+    let mut neighbors = vec![Vec::new(); positions.len()];
+    let mut boundary_neighbors = vec![Vec::new(); positions.len()];
+    let mut is_non_manifold = vec![false; positions.len()];
+    for ([a, b], opposite) in &edges.opposite {
+        neighbors[*a as usize].push(*b);
+        neighbors[*b as usize].push(*a);
+        match opposite.len() {
+            1 => {
+                boundary_neighbors[*a as usize].push(*b);
+                boundary_neighbors[*b as usize].push(*a);
+            }
+            2 => (),
+            _ => {
+                is_non_manifold[*a as usize] = true;
+                is_non_manifold[*b as usize] = true;
+            }
+        }
+    }
+
+    let smoothed: Vec<Vec3A> = (0..positions.len())
+        .map(|i| {
+            let p = positions[i];
+            if is_non_manifold[i] || neighbors[i].is_empty() {
+                return p;
+            }
+
+            match boundary_neighbors[i].as_slice() {
+                [] => {
+                    let n = neighbors[i].len() as f32;
+                    let beta = if neighbors[i].len() == 3 {
+                        3.0 / 16.0
+                    } else {
+                        3.0 / (8.0 * n)
+                    };
+                    let sum: Vec3A = neighbors[i].iter().map(|v| positions[*v as usize]).sum();
+                    p * (1.0 - n * beta) + sum * beta
+                }
+                [b0, b1] => p * 0.75 + (positions[*b0 as usize] + positions[*b1 as usize]) / 8.0,
+                _ => p,
+            }
+        })
+        .collect();
+
+    // Update all vertices with the same position.
+    welded.iter().map(|w| smoothed[*w as usize]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::shapes::generate_octahedron;
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn subdivide_empty() {
+        let subdivided = subdivide_loop::<Vec3A, Vec3A>(&[], &[], &[], &[], 3);
+        assert_eq!(SubdividedMesh::default(), subdivided);
+    }
+
+    #[test]
+    fn subdivide_zero_levels() {
+        let octahedron = generate_octahedron(1.0);
+        let (positions, indices) = (octahedron.positions, octahedron.indices);
+        let subdivided = subdivide_loop::<_, Vec3A>(&positions, &[], &[], &indices, 0);
+        assert_eq!(positions, subdivided.positions);
+        assert_eq!(indices, subdivided.indices);
+    }
+
+    #[test]
+    fn midpoint_shared_edges() {
+        let octahedron = generate_octahedron(1.0);
+        let (positions, indices) = (octahedron.positions, octahedron.indices);
+        let subdivided = subdivide_midpoint(&positions, &positions, &[], &indices, 2);
+
+        // V - E + F = 2 for a closed genus 0 surface.
+        assert_eq!(8 * 16 * 3, subdivided.indices.len());
+        assert_eq!(8 * 16 / 2 + 2, subdivided.positions.len());
+        assert_eq!(subdivided.positions.len(), subdivided.normals.len());
+        assert!(subdivided.uvs.is_empty());
+        for n in subdivided.normals {
+            assert_relative_eq!(1.0, n.length(), epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn loop_octahedron() {
+        let octahedron = generate_octahedron(1.0);
+        let (positions, indices) = (octahedron.positions, octahedron.indices);
+        let subdivided = subdivide_loop::<_, Vec3A>(&positions, &[], &[], &indices, 1);
+
+        // Each original vertex has valence 4.
+        let beta = 3.0 / 32.0;
+        assert_relative_eq!(
+            1.0 - 4.0 * beta,
+            subdivided.positions[0].x,
+            epsilon = EPSILON
+        );
+
+        // Each edge point uses the edge and opposite vertices.
+        let edge = (Vec3A::X + Vec3A::Y) * 3.0 / 8.0 + (Vec3A::Z + Vec3A::NEG_Z) / 8.0;
+        assert!(subdivided.positions[6].abs_diff_eq(edge, EPSILON));
+    }
+
+    #[test]
+    fn loop_boundary_curve() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let subdivided = subdivide_loop::<_, Vec3A>(&positions, &[], &[], &[0, 1, 2], 1);
+        assert!(subdivided.positions[0].abs_diff_eq(Vec3A::new(0.125, 0.125, 0.0), EPSILON));
+        assert_eq!(Vec3A::new(0.5, 0.0, 0.0), subdivided.positions[3]);
+    }
+
+    #[test]
+    fn loop_seam_no_cracks() {
+        // Split vertex 2 and 4 into separate copies for the bottom half.
+        let octahedron = generate_octahedron(1.0);
+        let (mut positions, mut indices) = (octahedron.positions, octahedron.indices);
+        positions.push(positions[2]);
+        positions.push(positions[0]);
+        for i in indices.iter_mut().skip(12) {
+            if *i == 2 {
+                *i = 6;
+            } else if *i == 0 {
+                *i = 7;
+            }
+        }
+
+        let uvs: Vec<_> = (0..positions.len())
+            .map(|i| Vec2::splat(i as f32))
+            .collect();
+        let subdivided = subdivide_loop::<_, Vec3A>(&positions, &[], &uvs, &indices, 1);
+        assert_eq!(subdivided.positions[0], subdivided.positions[7]);
+        assert_eq!(subdivided.positions[2], subdivided.positions[6]);
+        assert_eq!(subdivided.positions.len(), subdivided.uvs.len());
+
+        let octahedron = generate_octahedron(1.0);
+        let (positions, indices) = (octahedron.positions, octahedron.indices);
+        let expected = subdivide_loop::<_, Vec3A>(&positions, &[], &[], &indices, 1);
+        assert_eq!(expected.positions[0], subdivided.positions[0]);
+    }
+}
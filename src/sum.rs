@@ -0,0 +1,162 @@
+//! Support for the optional `deterministic` feature.
+//!
+//! Sums for centroids, inertia tensors, smooth normals, and tangents use compensated (Kahan) summation
+//! in a fixed order when the feature is enabled.
+//! The results are bit-identical for any thread count and with or without the `rayon` feature.
+//! Without the feature, the helpers are plain sums with no additional cost.
+
+use std::ops::{Add, Sub};
+
+/// A running sum of values added in order.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct Accumulator<T> {
+    sum: T,
+    #[cfg(feature = "deterministic")]
+    compensation: T,
+}
+
+impl<T> Accumulator<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new(initial: T) -> Self {
+        Self {
+            sum: initial,
+            #[cfg(feature = "deterministic")]
+            compensation: T::default(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn add(&mut self, value: T) {
+        #[cfg(feature = "deterministic")]
+        kahan_add(&mut self.sum, &mut self.compensation, value);
+
+        #[cfg(not(feature = "deterministic"))]
+        {
+            self.sum = self.sum + value;
+        }
+    }
+
+    pub fn sum(&self) -> T {
+        self.sum
+    }
+}
+
+/// Sums `values` in order like [Iterator::sum].
+pub(crate) fn sum<T, I>(values: I) -> T
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+    I: IntoIterator<Item = T>,
+{
+    let mut accumulator = Accumulator::new(T::default());
+    for value in values {
+        accumulator.add(value);
+    }
+    accumulator.sum()
+}
+
+/// Running sums for each element of `sums` for scattering values to vertices.
+/// The existing values of `sums` are the initial values.
+#[cfg(not(feature = "rayon"))]
+pub(crate) struct ElementSums<'a, T> {
+    sums: &'a mut [T],
+    #[cfg(feature = "deterministic")]
+    compensations: Vec<T>,
+}
+
+#[cfg(not(feature = "rayon"))]
+impl<'a, T> ElementSums<'a, T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new(sums: &'a mut [T]) -> Self {
+        Self {
+            #[cfg(feature = "deterministic")]
+            compensations: vec![T::default(); sums.len()],
+            sums,
+        }
+    }
+
+    #[inline(always)]
+    pub fn add(&mut self, index: usize, value: T) {
+        #[cfg(feature = "deterministic")]
+        kahan_add(&mut self.sums[index], &mut self.compensations[index], value);
+
+        #[cfg(not(feature = "deterministic"))]
+        {
+            self.sums[index] = self.sums[index] + value;
+        }
+    }
+}
+
+#[cfg(feature = "deterministic")]
+#[inline(always)]
+fn kahan_add<T>(sum: &mut T, compensation: &mut T, value: T)
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    // Track the low order bits lost when adding a small value to a large sum.
+    let y = value - *compensation;
+    let t = *sum + y;
+    *compensation = (t - *sum) - y;
+    *sum = t;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_empty() {
+        assert_eq!(0.0f32, sum(std::iter::empty::<f32>()));
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    #[test]
+    fn element_sums_initial_values() {
+        use glam::Vec3A;
+
+        let mut values = [Vec3A::X, Vec3A::Y];
+        let mut sums = ElementSums::new(&mut values);
+        sums.add(1, Vec3A::Z);
+        sums.add(0, Vec3A::Z);
+        assert_eq!(
+            [Vec3A::new(1.0, 0.0, 1.0), Vec3A::new(0.0, 1.0, 1.0)],
+            values
+        );
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn sum_compensated() {
+        // Each value is less than half the spacing between floats near 1.0.
+        let values = std::iter::once(1.0f32).chain(std::iter::repeat_n(1e-8, 10000));
+        assert_eq!(1.0001, sum(values));
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn bounding_sphere_fixed_chunks() {
+        use glam::Vec3A;
+
+        // Use enough points for multiple chunks with or without the rayon feature.
+        let points: Vec<_> = (0..10000)
+            .map(|i| Vec3A::new(i as f32 * 0.1, (i % 7) as f32, 1e4))
+            .collect();
+
+        let center = sum(points
+            .chunks(crate::parallel::CHUNK_SIZE)
+            .map(|chunk| sum(chunk.iter().copied())))
+            / points.len() as f32;
+        let sphere = crate::bounding::calculate_bounding_sphere_from_points(&points);
+        assert_eq!(center, Vec3A::from_vec4(sphere));
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[test]
+    fn sum_uncompensated() {
+        let values = std::iter::once(1.0f32).chain(std::iter::repeat_n(1e-8, 10000));
+        assert_eq!(1.0, sum(values));
+    }
+}
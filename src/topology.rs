@@ -0,0 +1,11 @@
+//! Functions for analyzing the connectivity of triangle meshes.
+
+pub use adjacency::*;
+pub use halfedge::*;
+pub use manifold::*;
+pub use watertight::*;
+
+pub(crate) mod adjacency;
+pub(crate) mod halfedge;
+pub(crate) mod manifold;
+pub(crate) mod watertight;
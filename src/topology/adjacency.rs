@@ -0,0 +1,241 @@
+use std::sync::OnceLock;
+
+use super::edge_key;
+
+/// Vertex to face and vertex to vertex connectivity for a triangle mesh.
+/// Build the adjacency once and pass it to functions like
+/// [calculate_smooth_normals_with_adjacency](crate::vectors::calculate_smooth_normals_with_adjacency)
+/// and [smooth_laplacian_with_adjacency](crate::smooth::smooth_laplacian_with_adjacency)
+/// to avoid recalculating the connectivity for each function.
+/// # Examples
+/**
+```rust
+use geometry_tools::topology::MeshAdjacency;
+
+// Two triangles sharing the edge from vertex 1 to vertex 2.
+let adjacency = MeshAdjacency::new(4, &[0, 1, 2, 2, 1, 3]);
+assert_eq!(&[0, 1], adjacency.vertex_faces(1));
+assert_eq!(&[0, 2, 3], adjacency.vertex_neighbors(1));
+assert!(adjacency.is_boundary_vertex(0));
+assert_eq!(&[[0, 1], [0, 2], [1, 3], [2, 3]], adjacency.boundary_edges());
+```
+ */
+#[derive(Debug, Clone)]
+pub struct MeshAdjacency {
+    vertex_count: usize,
+    faces: Vec<[u32; 3]>,
+    vertex_face_offsets: Vec<u32>,
+    vertex_faces: Vec<u32>,
+    /// The edge connectivity is only calculated when first used,
+    /// so functions that only need the faces for each vertex avoid sorting the edges.
+    edges: OnceLock<EdgeAdjacency>,
+}
+
+#[derive(Debug, Clone)]
+struct EdgeAdjacency {
+    neighbor_offsets: Vec<u32>,
+    neighbors: Vec<u32>,
+    boundary_edges: Vec<[u32; 2]>,
+    is_boundary: Vec<bool>,
+}
+
+// The other connectivity is calculated from the vertex count and faces.
+impl PartialEq for MeshAdjacency {
+    fn eq(&self, other: &Self) -> bool {
+        self.vertex_count == other.vertex_count && self.faces == other.faces
+    }
+}
+
+impl Eq for MeshAdjacency {}
+
+impl MeshAdjacency {
+    /// Calculates the connectivity for `vertex_count` vertices and triangle `indices`.
+    /// Any remaining indices after the last full triangle are ignored.
+    ///
+    /// # Panics
+    /// Panics if any index is out of range for `vertex_count`.
+    pub fn new(vertex_count: usize, indices: &[u32]) -> Self {
+        let faces: Vec<[u32; 3]> = indices
+            .chunks_exact(3)
+            .map(|f| [f[0], f[1], f[2]])
+            .collect();
+        Self::from_faces(vertex_count, faces)
+    }
+
+    /// Calculates the connectivity for `vertex_count` vertices and triangle `faces` like [MeshAdjacency::new].
+    pub(crate) fn from_faces(vertex_count: usize, faces: Vec<[u32; 3]>) -> Self {
+        // Faces are added in increasing order of face index for each vertex.
+        // Faces with repeated vertices are added once for each occurrence like when summing over faces.
+        let (vertex_face_offsets, vertex_faces) = csr(
+            vertex_count,
+            faces
+                .iter()
+                .enumerate()
+                .flat_map(|(f, face)| face.iter().map(move |v| (*v, f as u32))),
+        );
+
+        Self {
+            vertex_count,
+            faces,
+            vertex_face_offsets,
+            vertex_faces,
+            edges: OnceLock::new(),
+        }
+    }
+
+    fn edges(&self) -> &EdgeAdjacency {
+        self.edges.get_or_init(|| {
+            // Sorting is faster than hashing and gives a deterministic order.
+            let mut edges: Vec<[u32; 2]> = self
+                .faces
+                .iter()
+                .flat_map(|[v0, v1, v2]| [(*v0, *v1), (*v1, *v2), (*v2, *v0)])
+                .filter(|(a, b)| a != b)
+                .map(|(a, b)| edge_key(a, b))
+                .collect();
+            edges.sort_unstable();
+
+            let mut unique_edges = Vec::new();
+            let mut boundary_edges = Vec::new();
+            let mut is_boundary = vec![false; self.vertex_count];
+            for run in edges.chunk_by(|a, b| a == b) {
+                let edge = run[0];
+                unique_edges.push(edge);
+                if run.len() == 1 {
+                    boundary_edges.push(edge);
+                    is_boundary[edge[0] as usize] = true;
+                    is_boundary[edge[1] as usize] = true;
+                }
+            }
+
+            // Edges are sorted, so the neighbors of each vertex are also sorted.
+            let (neighbor_offsets, neighbors) = csr(
+                self.vertex_count,
+                unique_edges.iter().flat_map(|[a, b]| [(*a, *b), (*b, *a)]),
+            );
+
+            EdgeAdjacency {
+                neighbor_offsets,
+                neighbors,
+                boundary_edges,
+                is_boundary,
+            }
+        })
+    }
+
+    /// The number of vertices used to create the adjacency.
+    pub fn vertex_count(&self) -> usize {
+        self.vertex_count
+    }
+
+    /// The vertex indices for each triangle.
+    pub fn faces(&self) -> &[[u32; 3]] {
+        &self.faces
+    }
+
+    /// The indices of the faces containing `vertex` in ascending order.
+    pub fn vertex_faces(&self, vertex: usize) -> &[u32] {
+        let start = self.vertex_face_offsets[vertex] as usize;
+        let end = self.vertex_face_offsets[vertex + 1] as usize;
+        &self.vertex_faces[start..end]
+    }
+
+    /// The faces for all vertices as `(offsets, faces)`,
+    /// where the faces for vertex `v` are `faces[offsets[v]..offsets[v + 1]]`.
+    #[cfg(any(feature = "gpu", test))]
+    pub(crate) fn vertex_face_offsets(&self) -> (&[u32], &[u32]) {
+        (&self.vertex_face_offsets, &self.vertex_faces)
+    }
+
+    /// The vertices sharing an edge with `vertex` in ascending order.
+    pub fn vertex_neighbors(&self, vertex: usize) -> &[u32] {
+        let edges = self.edges();
+        let start = edges.neighbor_offsets[vertex] as usize;
+        let end = edges.neighbor_offsets[vertex + 1] as usize;
+        &edges.neighbors[start..end]
+    }
+
+    /// Returns `true` if `vertex` is part of a boundary edge.
+    pub fn is_boundary_vertex(&self, vertex: usize) -> bool {
+        self.edges().is_boundary[vertex]
+    }
+
+    /// Edges used by exactly one face with the smaller vertex index first in ascending order.
+    /// This includes open borders as well as attribute seams where vertices are split.
+    pub fn boundary_edges(&self) -> &[[u32; 2]] {
+        &self.edges().boundary_edges
+    }
+}
+
+/// Groups `(key, value)` pairs by key while preserving the order of values.
+fn csr(key_count: usize, pairs: impl Iterator<Item = (u32, u32)> + Clone) -> (Vec<u32>, Vec<u32>) {
+    let mut offsets = vec![0u32; key_count + 1];
+    for (key, _) in pairs.clone() {
+        offsets[key as usize + 1] += 1;
+    }
+    for i in 0..key_count {
+        offsets[i + 1] += offsets[i];
+    }
+
+    let mut next = offsets.clone();
+    let mut values = vec![0; offsets[key_count] as usize];
+    for (key, value) in pairs {
+        values[next[key as usize] as usize] = value;
+        next[key as usize] += 1;
+    }
+    (offsets, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacency_empty() {
+        let adjacency = MeshAdjacency::new(0, &[]);
+        assert_eq!(0, adjacency.vertex_count());
+        assert!(adjacency.faces().is_empty());
+        assert!(adjacency.boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn adjacency_unused_vertex() {
+        let adjacency = MeshAdjacency::new(4, &[0, 1, 2]);
+        assert!(adjacency.vertex_faces(3).is_empty());
+        assert!(adjacency.vertex_neighbors(3).is_empty());
+        assert!(!adjacency.is_boundary_vertex(3));
+    }
+
+    #[test]
+    fn adjacency_vertex_face_order() {
+        let adjacency = MeshAdjacency::new(4, &[0, 1, 2, 2, 1, 3, 3, 0, 2]);
+        assert_eq!(&[0, 2], adjacency.vertex_faces(0));
+        assert_eq!(&[0, 1, 2], adjacency.vertex_faces(2));
+        assert_eq!(&[1, 2], adjacency.vertex_faces(3));
+
+        let (offsets, faces) = adjacency.vertex_face_offsets();
+        assert_eq!(&[0, 2, 4, 7, 9], offsets);
+        assert_eq!(&[0, 2, 0, 1, 0, 1, 2, 1, 2], faces);
+    }
+
+    #[test]
+    fn adjacency_closed_mesh() {
+        // A tetrahedron has no boundary edges.
+        let adjacency = MeshAdjacency::new(4, &[0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3]);
+        assert!(adjacency.boundary_edges().is_empty());
+        for v in 0..4 {
+            assert_eq!(3, adjacency.vertex_faces(v).len());
+            assert_eq!(3, adjacency.vertex_neighbors(v).len());
+            assert!(!adjacency.is_boundary_vertex(v));
+        }
+    }
+
+    #[test]
+    fn adjacency_degenerate_face() {
+        // Repeated vertices add the face multiple times but no degenerate edges.
+        let adjacency = MeshAdjacency::new(2, &[0, 0, 1]);
+        assert_eq!(&[0, 0], adjacency.vertex_faces(0));
+        assert_eq!(&[1], adjacency.vertex_neighbors(0));
+        assert!(adjacency.boundary_edges().is_empty());
+    }
+}
@@ -0,0 +1,450 @@
+use std::collections::HashMap;
+
+use glam::Vec3A;
+use thiserror::Error;
+
+use super::manifold::{find_non_manifold_vertices, is_degenerate};
+
+/// Errors that can occur while building a [HalfEdgeMesh].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum HalfEdgeMeshError {
+    #[error(
+        "A vertex index count of {} is not supported. Expected {} to be divisible by 3.",
+        index_count,
+        index_count
+    )]
+    InvalidIndexCount { index_count: usize },
+
+    #[error(
+        "Vertex index {} is out of range for a vertex count of {}.",
+        index,
+        vertex_count
+    )]
+    IndexOutOfRange { index: u32, vertex_count: usize },
+
+    #[error("Face {} is degenerate and contains a repeated vertex index.", face)]
+    DegenerateFace { face: u32 },
+
+    #[error(
+        "The edge from vertex {} to vertex {} is shared by more than two faces or faces with inconsistent winding.",
+        vertices[0],
+        vertices[1]
+    )]
+    NonManifoldEdge { vertices: [u32; 2] },
+
+    #[error("The faces adjacent to vertex {} do not form a single fan.", vertex)]
+    NonManifoldVertex { vertex: u32 },
+}
+
+/// A directed edge of a single face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HalfEdge {
+    /// The vertex at the start of the half-edge.
+    pub origin: u32,
+    /// The oppositely directed half-edge of the adjacent face
+    /// or `None` if the edge is on the boundary of the mesh.
+    pub twin: Option<u32>,
+}
+
+/// A half-edge representation of a manifold triangle mesh with consistent winding.
+///
+/// The half-edges for face `f` are stored in order at indices `3 * f`, `3 * f + 1`, and `3 * f + 2`,
+/// so the face and next and previous half-edges are implicit.
+/// Boundary edges are represented by half-edges without a twin.
+/// # Examples
+/**
+```rust
+use geometry_tools::topology::HalfEdgeMesh;
+use glam::Vec3A;
+
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+let positions = vec![
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(0.0, 1.0, 0.0),
+    Vec3A::new(1.0, 1.0, 0.0),
+];
+let indices = vec![0, 1, 2, 2, 1, 3];
+
+let mesh = HalfEdgeMesh::new(&positions, &indices)?;
+assert_eq!(5, mesh.edges().count());
+
+let mut ring: Vec<_> = mesh.vertex_ring(1).collect();
+ring.sort();
+assert_eq!(vec![0, 2, 3], ring);
+# Ok(())
+# }
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct HalfEdgeMesh {
+    positions: Vec<Vec3A>,
+    half_edges: Vec<HalfEdge>,
+    // An outgoing half-edge for each vertex or None for unused vertices.
+    // Boundary vertices use the first outgoing half-edge when rotating around the vertex.
+    vertex_half_edges: Vec<Option<u32>>,
+}
+
+impl HalfEdgeMesh {
+    /// Builds the half-edge connectivity for the triangle list defined by `positions` and `indices`.
+    /// The mesh must be manifold, have consistently wound faces, and not contain degenerate faces.
+    pub fn new<P>(positions: &[P], indices: &[u32]) -> Result<Self, HalfEdgeMeshError>
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        if !indices.len().is_multiple_of(3) {
+            return Err(HalfEdgeMeshError::InvalidIndexCount {
+                index_count: indices.len(),
+            });
+        }
+
+        if let Some(index) = indices.iter().find(|i| **i as usize >= positions.len()) {
+            return Err(HalfEdgeMeshError::IndexOutOfRange {
+                index: *index,
+                vertex_count: positions.len(),
+            });
+        }
+
+        let mut half_edges = Vec::with_capacity(indices.len());
+        let mut directed_edges = HashMap::with_capacity(indices.len());
+
+        for (face, vertices) in indices.chunks_exact(3).enumerate() {
+            if is_degenerate(vertices[0], vertices[1], vertices[2]) {
+                return Err(HalfEdgeMeshError::DegenerateFace { face: face as u32 });
+            }
+
+            for i in 0..3 {
+                let origin = vertices[i];
+                let target = vertices[(i + 1) % 3];
+                let h = half_edges.len() as u32;
+                if directed_edges.insert((origin, target), h).is_some() {
+                    return Err(HalfEdgeMeshError::NonManifoldEdge {
+                        vertices: [origin, target],
+                    });
+                }
+
+                half_edges.push(HalfEdge { origin, twin: None });
+            }
+        }
+
+        for h in 0..half_edges.len() {
+            let origin = half_edges[h].origin;
+            let target = half_edges[next(h as u32) as usize].origin;
+            half_edges[h].twin = directed_edges.get(&(target, origin)).copied();
+        }
+
+        if let Some(vertex) = find_non_manifold_vertices(indices).first() {
+            return Err(HalfEdgeMeshError::NonManifoldVertex { vertex: *vertex });
+        }
+
+        // Rotating around a boundary vertex only visits every face
+        // when starting from the outgoing boundary half-edge.
+        let mut vertex_half_edges = vec![None; positions.len()];
+        for (h, half_edge) in half_edges.iter().enumerate() {
+            let current = &mut vertex_half_edges[half_edge.origin as usize];
+            if current.is_none() || half_edge.twin.is_none() {
+                *current = Some(h as u32);
+            }
+        }
+
+        Ok(Self {
+            positions: positions.iter().copied().map(Into::into).collect(),
+            half_edges,
+            vertex_half_edges,
+        })
+    }
+
+    /// The vertex positions of the mesh.
+    pub fn positions(&self) -> &[Vec3A] {
+        &self.positions
+    }
+
+    /// All half-edges of the mesh.
+    pub fn half_edges(&self) -> &[HalfEdge] {
+        &self.half_edges
+    }
+
+    /// The number of vertices in the mesh.
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// The number of triangle faces in the mesh.
+    pub fn face_count(&self) -> usize {
+        self.half_edges.len() / 3
+    }
+
+    /// The next half-edge in the same face.
+    #[inline]
+    pub fn next(&self, half_edge: u32) -> u32 {
+        next(half_edge)
+    }
+
+    /// The previous half-edge in the same face.
+    #[inline]
+    pub fn prev(&self, half_edge: u32) -> u32 {
+        prev(half_edge)
+    }
+
+    /// The oppositely directed half-edge or `None` for boundary edges.
+    #[inline]
+    pub fn twin(&self, half_edge: u32) -> Option<u32> {
+        self.half_edges[half_edge as usize].twin
+    }
+
+    /// The face containing `half_edge`.
+    #[inline]
+    pub fn face(&self, half_edge: u32) -> u32 {
+        half_edge / 3
+    }
+
+    /// The vertex at the start of `half_edge`.
+    #[inline]
+    pub fn origin(&self, half_edge: u32) -> u32 {
+        self.half_edges[half_edge as usize].origin
+    }
+
+    /// The vertex at the end of `half_edge`.
+    #[inline]
+    pub fn target(&self, half_edge: u32) -> u32 {
+        self.origin(next(half_edge))
+    }
+
+    /// Returns `true` if `half_edge` has no adjacent face on the other side.
+    pub fn is_boundary_edge(&self, half_edge: u32) -> bool {
+        self.twin(half_edge).is_none()
+    }
+
+    /// Returns `true` if `vertex` is on the boundary of the mesh.
+    /// Unused vertices are not considered boundary vertices.
+    pub fn is_boundary_vertex(&self, vertex: u32) -> bool {
+        self.vertex_half_edges[vertex as usize]
+            .map(|h| self.is_boundary_edge(h) || self.is_boundary_edge(prev(h)))
+            .unwrap_or(false)
+    }
+
+    /// The half-edges in the loop of `face` in winding order.
+    pub fn face_half_edges(&self, face: u32) -> [u32; 3] {
+        [face * 3, face * 3 + 1, face * 3 + 2]
+    }
+
+    /// The vertex indices of `face` in winding order.
+    pub fn face_vertices(&self, face: u32) -> [u32; 3] {
+        self.face_half_edges(face).map(|h| self.origin(h))
+    }
+
+    /// The outgoing half-edges of `vertex` in rotation order.
+    pub fn vertex_half_edges(&self, vertex: u32) -> VertexHalfEdges<'_> {
+        let start = self.vertex_half_edges[vertex as usize];
+        VertexHalfEdges {
+            mesh: self,
+            start,
+            current: start,
+        }
+    }
+
+    /// The vertices connected to `vertex` by an edge.
+    pub fn vertex_ring(&self, vertex: u32) -> impl Iterator<Item = u32> + '_ {
+        // The last face of a boundary vertex also contributes its previous edge.
+        let boundary_vertex = self
+            .vertex_half_edges(vertex)
+            .last()
+            .filter(|h| self.is_boundary_edge(prev(*h)))
+            .map(|h| self.origin(prev(h)));
+
+        self.vertex_half_edges(vertex)
+            .map(|h| self.target(h))
+            .chain(boundary_vertex)
+    }
+
+    /// The faces adjacent to `vertex` in rotation order.
+    pub fn vertex_faces(&self, vertex: u32) -> impl Iterator<Item = u32> + '_ {
+        self.vertex_half_edges(vertex).map(|h| self.face(h))
+    }
+
+    /// The unique edges of the mesh as a representative half-edge.
+    /// Interior edges are only visited once.
+    pub fn edges(&self) -> impl Iterator<Item = u32> + '_ {
+        self.half_edges
+            .iter()
+            .enumerate()
+            .filter(|(h, e)| e.twin.map(|t| *h < t as usize).unwrap_or(true))
+            .map(|(h, _)| h as u32)
+    }
+
+    /// The vertex indices of the unique edges of the mesh.
+    pub fn edge_vertices(&self) -> impl Iterator<Item = [u32; 2]> + '_ {
+        self.edges().map(|h| [self.origin(h), self.target(h)])
+    }
+}
+
+/// An iterator over the outgoing half-edges of a vertex.
+/// See [HalfEdgeMesh::vertex_half_edges].
+pub struct VertexHalfEdges<'a> {
+    mesh: &'a HalfEdgeMesh,
+    start: Option<u32>,
+    current: Option<u32>,
+}
+
+impl Iterator for VertexHalfEdges<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let h = self.current?;
+
+        // The twin of the incoming half-edge is the next outgoing half-edge.
+        self.current = self.mesh.twin(prev(h)).filter(|n| Some(*n) != self.start);
+        Some(h)
+    }
+}
+
+#[inline]
+fn next(half_edge: u32) -> u32 {
+    if half_edge % 3 == 2 {
+        half_edge - 2
+    } else {
+        half_edge + 1
+    }
+}
+
+#[inline]
+fn prev(half_edge: u32) -> u32 {
+    if half_edge.is_multiple_of(3) {
+        half_edge + 2
+    } else {
+        half_edge - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tetrahedron() -> (Vec<Vec3A>, Vec<u32>) {
+        (
+            vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3],
+        )
+    }
+
+    fn sorted(mut values: Vec<u32>) -> Vec<u32> {
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn empty_mesh() {
+        let mesh = HalfEdgeMesh::new::<Vec3A>(&[], &[]).unwrap();
+        assert_eq!(0, mesh.face_count());
+        assert_eq!(0, mesh.edges().count());
+    }
+
+    #[test]
+    fn closed_tetrahedron() {
+        let (positions, indices) = tetrahedron();
+        let mesh = HalfEdgeMesh::new(&positions, &indices).unwrap();
+
+        assert_eq!(4, mesh.vertex_count());
+        assert_eq!(4, mesh.face_count());
+        assert_eq!(6, mesh.edges().count());
+        assert!(mesh.half_edges().iter().all(|h| h.twin.is_some()));
+
+        for v in 0..4 {
+            assert!(!mesh.is_boundary_vertex(v));
+            let expected: Vec<_> = (0..4).filter(|i| *i != v).collect();
+            assert_eq!(expected, sorted(mesh.vertex_ring(v).collect()));
+            assert_eq!(3, mesh.vertex_faces(v).count());
+        }
+    }
+
+    #[test]
+    fn twins_are_reversed() {
+        let (positions, indices) = tetrahedron();
+        let mesh = HalfEdgeMesh::new(&positions, &indices).unwrap();
+        for h in 0..mesh.half_edges().len() as u32 {
+            let twin = mesh.twin(h).unwrap();
+            assert_eq!(Some(h), mesh.twin(twin));
+            assert_eq!(mesh.origin(h), mesh.target(twin));
+            assert_eq!(mesh.target(h), mesh.origin(twin));
+        }
+    }
+
+    #[test]
+    fn face_loops() {
+        let (positions, indices) = tetrahedron();
+        let mesh = HalfEdgeMesh::new(&positions, &indices).unwrap();
+        assert_eq!([1, 2, 3], mesh.face_vertices(2));
+        assert_eq!([6, 7, 8], mesh.face_half_edges(2));
+        assert_eq!(7, mesh.next(6));
+        assert_eq!(6, mesh.next(8));
+        assert_eq!(8, mesh.prev(6));
+        assert_eq!(2, mesh.face(8));
+    }
+
+    #[test]
+    fn boundary_fan() {
+        // A fan of three triangles around vertex 0.
+        let positions = vec![Vec3A::ZERO; 5];
+        let indices = vec![0, 1, 2, 0, 2, 3, 0, 3, 4];
+        let mesh = HalfEdgeMesh::new(&positions, &indices).unwrap();
+
+        assert!(mesh.is_boundary_vertex(0));
+        assert_eq!(vec![1, 2, 3, 4], sorted(mesh.vertex_ring(0).collect()));
+        assert_eq!(vec![0, 1, 2], sorted(mesh.vertex_faces(0).collect()));
+        assert_eq!(vec![0, 2], sorted(mesh.vertex_ring(1).collect()));
+        assert_eq!(7, mesh.edge_vertices().count());
+    }
+
+    #[test]
+    fn unused_vertex() {
+        let positions = vec![Vec3A::ZERO; 4];
+        let mesh = HalfEdgeMesh::new(&positions, &[0, 1, 2]).unwrap();
+        assert_eq!(0, mesh.vertex_ring(3).count());
+        assert!(!mesh.is_boundary_vertex(3));
+    }
+
+    #[test]
+    fn invalid_index_count() {
+        let result = HalfEdgeMesh::new(&[Vec3A::ZERO; 3], &[0, 1]);
+        assert_eq!(
+            Err(HalfEdgeMeshError::InvalidIndexCount { index_count: 2 }),
+            result
+        );
+    }
+
+    #[test]
+    fn index_out_of_range() {
+        let result = HalfEdgeMesh::new(&[Vec3A::ZERO; 3], &[0, 1, 3]);
+        assert_eq!(
+            Err(HalfEdgeMeshError::IndexOutOfRange {
+                index: 3,
+                vertex_count: 3
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn degenerate_face() {
+        let result = HalfEdgeMesh::new(&[Vec3A::ZERO; 3], &[0, 1, 2, 0, 0, 1]);
+        assert_eq!(Err(HalfEdgeMeshError::DegenerateFace { face: 1 }), result);
+    }
+
+    #[test]
+    fn inconsistent_winding() {
+        let result = HalfEdgeMesh::new(&[Vec3A::ZERO; 4], &[0, 1, 2, 0, 1, 3]);
+        assert_eq!(
+            Err(HalfEdgeMeshError::NonManifoldEdge { vertices: [0, 1] }),
+            result
+        );
+    }
+
+    #[test]
+    fn non_manifold_vertex() {
+        let result = HalfEdgeMesh::new(&[Vec3A::ZERO; 5], &[0, 1, 2, 0, 3, 4]);
+        assert_eq!(
+            Err(HalfEdgeMeshError::NonManifoldVertex { vertex: 0 }),
+            result
+        );
+    }
+}
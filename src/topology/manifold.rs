@@ -0,0 +1,259 @@
+use std::collections::{BTreeMap, HashMap};
+
+/// An edge shared by more than two faces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NonManifoldEdge {
+    /// The vertex indices of the edge with the smaller index first.
+    pub vertices: [u32; 2],
+    /// The indices of the faces containing this edge in ascending order.
+    pub faces: Vec<u32>,
+}
+
+/// The non-manifold edges and vertices found in a triangle mesh.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NonManifoldGeometry {
+    /// Edges shared by more than two faces.
+    pub edges: Vec<NonManifoldEdge>,
+    /// Vertices whose faces do not form a single connected fan in ascending order.
+    pub vertices: Vec<u32>,
+}
+
+impl NonManifoldGeometry {
+    /// Returns `true` if no non-manifold edges or vertices were found.
+    pub fn is_manifold(&self) -> bool {
+        self.edges.is_empty() && self.vertices.is_empty()
+    }
+}
+
+/// Finds all edges shared by more than two faces.
+/// `indices` is assumed to contain triangle indices, so `indices.len()` should be a multiple of 3.
+/// Degenerate triangles with repeated vertex indices are ignored.
+/// # Examples
+/**
+```rust
+use geometry_tools::topology::find_non_manifold_edges;
+
+// Three triangles share the edge from vertex 0 to vertex 1.
+let indices = [0, 1, 2, 1, 0, 3, 0, 1, 4];
+let edges = find_non_manifold_edges(&indices);
+assert_eq!(1, edges.len());
+assert_eq!([0, 1], edges[0].vertices);
+assert_eq!(vec![0, 1, 2], edges[0].faces);
+```
+ */
+pub fn find_non_manifold_edges(indices: &[u32]) -> Vec<NonManifoldEdge> {
+    // Use an ordered map to produce a deterministic result.
+    let mut edge_faces: BTreeMap<[u32; 2], Vec<u32>> = BTreeMap::new();
+    for (face_index, face) in indices.chunks(3).enumerate() {
+        if let [v0, v1, v2] = face {
+            if is_degenerate(*v0, *v1, *v2) {
+                continue;
+            }
+
+            for (a, b) in [(*v0, *v1), (*v1, *v2), (*v2, *v0)] {
+                edge_faces
+                    .entry(edge_key(a, b))
+                    .or_default()
+                    .push(face_index as u32);
+            }
+        }
+    }
+
+    edge_faces
+        .into_iter()
+        .filter(|(_, faces)| faces.len() > 2)
+        .map(|(vertices, faces)| NonManifoldEdge { vertices, faces })
+        .collect()
+}
+
+/// Finds all vertices whose adjacent faces do not form a single fan connected by edges.
+/// A manifold vertex has a neighborhood that is a disk for interior vertices or a half disk for boundary vertices.
+/// Two cones of faces touching only at their shared tip is an example of a non-manifold vertex.
+///
+/// `indices` is assumed to contain triangle indices, so `indices.len()` should be a multiple of 3.
+/// Degenerate triangles with repeated vertex indices are ignored.
+/// # Examples
+/**
+```rust
+use geometry_tools::topology::find_non_manifold_vertices;
+
+// Two triangles touching only at vertex 0.
+let indices = [0, 1, 2, 0, 3, 4];
+assert_eq!(vec![0], find_non_manifold_vertices(&indices));
+```
+ */
+pub fn find_non_manifold_vertices(indices: &[u32]) -> Vec<u32> {
+    // Find the faces adjacent to each vertex.
+    let mut vertex_faces: BTreeMap<u32, Vec<[u32; 2]>> = BTreeMap::new();
+    for face in indices.chunks(3) {
+        if let [v0, v1, v2] = face {
+            if is_degenerate(*v0, *v1, *v2) {
+                continue;
+            }
+
+            // Store the other two vertices of the face for each vertex.
+            vertex_faces.entry(*v0).or_default().push([*v1, *v2]);
+            vertex_faces.entry(*v1).or_default().push([*v2, *v0]);
+            vertex_faces.entry(*v2).or_default().push([*v0, *v1]);
+        }
+    }
+
+    vertex_faces
+        .into_iter()
+        .filter(|(_, faces)| fan_count(faces) > 1)
+        .map(|(vertex, _)| vertex)
+        .collect()
+}
+
+/// Finds all non-manifold edges and vertices.
+/// This combines the results of [find_non_manifold_edges] and [find_non_manifold_vertices].
+/// # Examples
+/**
+```rust
+use geometry_tools::topology::find_non_manifold_geometry;
+
+// A single triangle is manifold.
+let non_manifold = find_non_manifold_geometry(&[0, 1, 2]);
+assert!(non_manifold.is_manifold());
+```
+ */
+pub fn find_non_manifold_geometry(indices: &[u32]) -> NonManifoldGeometry {
+    NonManifoldGeometry {
+        edges: find_non_manifold_edges(indices),
+        vertices: find_non_manifold_vertices(indices),
+    }
+}
+
+#[inline]
+pub(crate) fn edge_key(a: u32, b: u32) -> [u32; 2] {
+    if a < b {
+        [a, b]
+    } else {
+        [b, a]
+    }
+}
+
+#[inline]
+pub(crate) fn is_degenerate(v0: u32, v1: u32, v2: u32) -> bool {
+    v0 == v1 || v1 == v2 || v2 == v0
+}
+
+fn fan_count(faces: &[[u32; 2]]) -> usize {
+    // Faces around a vertex are connected if they share an edge with that vertex.
+    // Each shared edge is identified by the other vertex of the edge.
+    let mut parents: Vec<usize> = (0..faces.len()).collect();
+    let mut first_face_by_vertex = HashMap::new();
+
+    for (i, face) in faces.iter().enumerate() {
+        for v in face {
+            match first_face_by_vertex.get(v) {
+                Some(j) => union(&mut parents, i, *j),
+                None => {
+                    first_face_by_vertex.insert(*v, i);
+                }
+            }
+        }
+    }
+
+    (0..faces.len())
+        .filter(|i| find(&mut parents, *i) == *i)
+        .count()
+}
+
+fn find(parents: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parents[root] != root {
+        root = parents[root];
+    }
+
+    // Compress the path to speed up future queries.
+    let mut i = i;
+    while parents[i] != root {
+        let next = parents[i];
+        parents[i] = root;
+        i = next;
+    }
+
+    root
+}
+
+fn union(parents: &mut [usize], a: usize, b: usize) {
+    let root_a = find(parents, a);
+    let root_b = find(parents, b);
+    if root_a != root_b {
+        parents[root_a] = root_b;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_indices() {
+        let non_manifold = find_non_manifold_geometry(&[]);
+        assert!(non_manifold.is_manifold());
+    }
+
+    #[test]
+    fn closed_tetrahedron() {
+        let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+        assert!(find_non_manifold_geometry(&indices).is_manifold());
+    }
+
+    #[test]
+    fn open_quad() {
+        // Boundary vertices have a half disk neighborhood.
+        let indices = [0, 1, 2, 2, 1, 3];
+        assert!(find_non_manifold_geometry(&indices).is_manifold());
+    }
+
+    #[test]
+    fn edge_shared_by_three_faces() {
+        let indices = [0, 1, 2, 1, 0, 3, 0, 1, 4];
+        assert_eq!(
+            vec![NonManifoldEdge {
+                vertices: [0, 1],
+                faces: vec![0, 1, 2]
+            }],
+            find_non_manifold_edges(&indices)
+        );
+    }
+
+    #[test]
+    fn edge_shared_by_four_faces() {
+        let indices = [0, 1, 2, 1, 0, 3, 0, 1, 4, 1, 0, 5];
+        let edges = find_non_manifold_edges(&indices);
+        assert_eq!(1, edges.len());
+        assert_eq!(vec![0, 1, 2, 3], edges[0].faces);
+    }
+
+    #[test]
+    fn bowtie_vertex() {
+        let indices = [0, 1, 2, 0, 3, 4];
+        let non_manifold = find_non_manifold_geometry(&indices);
+        assert!(non_manifold.edges.is_empty());
+        assert_eq!(vec![0], non_manifold.vertices);
+    }
+
+    #[test]
+    fn two_cones_sharing_tip() {
+        // Two closed fans of three faces each touching at vertex 0.
+        let indices = [0, 1, 2, 0, 2, 3, 0, 3, 1, 0, 4, 5, 0, 5, 6, 0, 6, 4];
+        assert_eq!(vec![0], find_non_manifold_vertices(&indices));
+    }
+
+    #[test]
+    fn degenerate_faces_ignored() {
+        let indices = [0, 0, 1, 0, 1, 1, 0, 1, 2];
+        assert!(find_non_manifold_geometry(&indices).is_manifold());
+    }
+
+    #[test]
+    fn partial_face_ignored() {
+        let indices = [0, 1, 2, 0, 1];
+        assert!(find_non_manifold_geometry(&indices).is_manifold());
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use super::manifold::{edge_key, is_degenerate};
+
+/// The edges preventing a triangle mesh from being watertight.
+/// See [check_watertight].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WatertightReport {
+    /// Edges used by only a single face in the winding order of that face.
+    pub boundary_edges: Vec<[u32; 2]>,
+    /// Edges shared by more than two faces with the smaller vertex index first.
+    pub non_manifold_edges: Vec<[u32; 2]>,
+    /// Edges shared by two faces that traverse the edge in the same direction.
+    pub inconsistent_edges: Vec<[u32; 2]>,
+}
+
+impl WatertightReport {
+    /// Returns `true` if every edge is shared by exactly two consistently wound faces.
+    pub fn is_watertight(&self) -> bool {
+        self.boundary_edges.is_empty()
+            && self.non_manifold_edges.is_empty()
+            && self.inconsistent_edges.is_empty()
+    }
+}
+
+/// Checks if every edge in the triangle list `indices` is shared by exactly two faces with consistent winding.
+/// A watertight mesh is closed and has a well defined inside and outside.
+/// Degenerate triangles with repeated vertex indices are ignored.
+/// # Examples
+/**
+```rust
+use geometry_tools::topology::check_watertight;
+
+// A tetrahedron is closed.
+let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+assert!(check_watertight(&indices).is_watertight());
+
+// Removing a face leaves a hole.
+let report = check_watertight(&indices[..9]);
+assert_eq!(vec![[0, 2], [3, 0], [2, 3]], report.boundary_edges);
+```
+ */
+pub fn check_watertight(indices: &[u32]) -> WatertightReport {
+    // Track the directed edges for each undirected edge.
+    let mut edges: BTreeMap<[u32; 2], Vec<[u32; 2]>> = BTreeMap::new();
+    for face in indices.chunks(3) {
+        if let [v0, v1, v2] = face {
+            if is_degenerate(*v0, *v1, *v2) {
+                continue;
+            }
+
+            for (a, b) in [(*v0, *v1), (*v1, *v2), (*v2, *v0)] {
+                edges.entry(edge_key(a, b)).or_default().push([a, b]);
+            }
+        }
+    }
+
+    let mut report = WatertightReport::default();
+    for (key, directed_edges) in edges {
+        match directed_edges[..] {
+            [edge] => report.boundary_edges.push(edge),
+            [e0, e1] => {
+                if e0 == e1 {
+                    report.inconsistent_edges.push(key);
+                }
+            }
+            _ => report.non_manifold_edges.push(key),
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_mesh() {
+        // An empty mesh has no edges that would allow it to leak.
+        assert!(check_watertight(&[]).is_watertight());
+    }
+
+    #[test]
+    fn single_triangle() {
+        let report = check_watertight(&[0, 1, 2]);
+        assert_eq!(vec![[0, 1], [2, 0], [1, 2]], report.boundary_edges);
+        assert!(!report.is_watertight());
+    }
+
+    #[test]
+    fn closed_tetrahedron() {
+        let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+        assert_eq!(WatertightReport::default(), check_watertight(&indices));
+    }
+
+    #[test]
+    fn flipped_face() {
+        let indices = [0, 1, 2, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+        let report = check_watertight(&indices);
+        assert!(report.boundary_edges.is_empty());
+        assert_eq!(vec![[0, 1], [0, 2], [1, 2]], report.inconsistent_edges);
+    }
+
+    #[test]
+    fn non_manifold_edge() {
+        let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3, 0, 1, 4];
+        let report = check_watertight(&indices);
+        assert_eq!(vec![[0, 1]], report.non_manifold_edges);
+    }
+
+    #[test]
+    fn degenerate_faces_ignored() {
+        let indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3, 0, 0, 1];
+        assert!(check_watertight(&indices).is_watertight());
+    }
+}
@@ -0,0 +1,171 @@
+//! Functions for copying vertex attributes from a source mesh to the vertices of a target mesh.
+//!
+//! Each target vertex samples the closest point on the surface of the source mesh.
+//! This is useful for LOD and proxy meshes that should match the normals, UVs, or vertex colors of a high poly source.
+
+use std::ops::{Add, Mul};
+
+use glam::Vec3A;
+
+use crate::barycentric::interpolate_barycentric;
+use crate::bvh::Bvh;
+
+/// The closest points on a source mesh for the vertices of a target mesh.
+/// The closest points only need to be calculated once to transfer multiple attributes.
+/// # Examples
+/**
+```rust
+use geometry_tools::bvh::Bvh;
+use geometry_tools::transfer::AttributeTransfer;
+use glam::{Vec2, Vec3A, Vec4};
+
+// A high poly source mesh with normals, UVs, and colors.
+let source_positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let source_normals = [Vec3A::Z; 3];
+let source_uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+let source_colors = [Vec4::X, Vec4::Y, Vec4::Z];
+let bvh = Bvh::new(&source_positions, &[0, 1, 2]);
+
+// A simplified target mesh slightly offset from the source.
+let target_positions = [Vec3A::new(0.5, 0.25, 0.1), Vec3A::new(0.0, 0.0, 5.0)];
+let transfer = AttributeTransfer::new(&bvh, &target_positions, 1.0);
+
+let normals = transfer.transfer_normals(&source_normals);
+assert_eq!(vec![Some(Vec3A::Z), None], normals);
+
+let uvs = transfer.transfer_attribute(&source_uvs);
+assert_eq!(vec![Some(Vec2::new(0.5, 0.25)), None], uvs);
+
+let colors = transfer.transfer_attribute(&source_colors);
+assert_eq!(Some(Vec4::new(0.25, 0.5, 0.25, 0.0)), colors[0]);
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeTransfer {
+    /// The source vertex indices and barycentric coordinates for each target vertex.
+    samples: Vec<Option<([u32; 3], Vec3A)>>,
+}
+
+impl AttributeTransfer {
+    /// Finds the closest point on the source mesh in `bvh` within `max_distance` for each of the target `positions`.
+    /// Use [f32::INFINITY] to sample every target vertex.
+    pub fn new<P>(bvh: &Bvh, positions: &[P], max_distance: f32) -> Self
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        let samples = positions
+            .iter()
+            .map(|p| {
+                bvh.closest_point((*p).into(), max_distance)
+                    .map(|c| (bvh.triangles()[c.triangle as usize], c.barycentrics))
+            })
+            .collect();
+        Self { samples }
+    }
+
+    /// The number of target vertices.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if there are no target vertices.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Interpolates the per vertex `attribute` of the source mesh at the closest point for each target vertex.
+    /// The result is [None] for target vertices with no source surface within the max distance.
+    /// This works for any attribute that supports scaling and addition like [f32], [glam::Vec2], [Vec3A], or [glam::Vec4].
+    ///
+    /// # Panics
+    /// Panics if `attribute` does not have an element for each vertex of the source mesh.
+    pub fn transfer_attribute<T>(&self, attribute: &[T]) -> Vec<Option<T>>
+    where
+        T: Mul<f32, Output = T> + Add<Output = T> + Copy,
+    {
+        self.samples
+            .iter()
+            .map(|sample| {
+                sample.map(|(face, barycentrics)| {
+                    interpolate_barycentric(face.map(|v| attribute[v as usize]), barycentrics)
+                })
+            })
+            .collect()
+    }
+
+    /// Interpolates the source `normals` like [transfer_attribute](AttributeTransfer::transfer_attribute)
+    /// and normalizes the result.
+    /// Normals that interpolate to zero are also zero.
+    ///
+    /// # Panics
+    /// Panics if `normals` does not have an element for each vertex of the source mesh.
+    pub fn transfer_normals<N>(&self, normals: &[N]) -> Vec<Option<Vec3A>>
+    where
+        N: Into<Vec3A> + Copy,
+    {
+        self.samples
+            .iter()
+            .map(|sample| {
+                sample.map(|(face, barycentrics)| {
+                    interpolate_barycentric(face.map(|v| normals[v as usize].into()), barycentrics)
+                        .normalize_or_zero()
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use glam::Vec2;
+
+    use crate::shapes::{generate_icosphere, generate_uv_sphere};
+
+    #[test]
+    fn empty() {
+        let bvh = Bvh::new::<Vec3A>(&[], &[]);
+        let transfer = AttributeTransfer::new(&bvh, &[Vec3A::ZERO], f32::INFINITY);
+        assert_eq!(vec![None], transfer.transfer_attribute::<f32>(&[]));
+
+        let transfer = AttributeTransfer::new::<Vec3A>(&bvh, &[], f32::INFINITY);
+        assert!(transfer.is_empty());
+    }
+
+    #[test]
+    fn transfer_sphere_normals() {
+        let source = generate_uv_sphere(1.0, 64, 32);
+        let target = generate_icosphere(0.9, 2);
+        let bvh = Bvh::new(&source.positions, &source.indices);
+
+        let transfer = AttributeTransfer::new(&bvh, &target.positions, f32::INFINITY);
+        assert_eq!(target.positions.len(), transfer.len());
+        let normals = transfer.transfer_normals(&source.normals);
+        for (normal, expected) in normals.iter().zip(&target.normals) {
+            assert!(normal.unwrap().dot(*expected) > 0.999);
+        }
+    }
+
+    #[test]
+    fn transfer_vertex_attribute() {
+        // Transferring to the source vertices returns the source attribute.
+        let source = generate_uv_sphere(1.0, 16, 8);
+        let bvh = Bvh::new(&source.positions, &source.indices);
+        let transfer = AttributeTransfer::new(&bvh, &source.positions, 0.0001);
+
+        let values: Vec<_> = source.positions.iter().map(|p| p.x + p.y * p.z).collect();
+        for (value, expected) in transfer.transfer_attribute(&values).iter().zip(&values) {
+            assert!((value.unwrap() - expected).abs() < 0.0001);
+        }
+
+        let uvs: Vec<_> = source
+            .positions
+            .iter()
+            .map(|p| Vec2::new(p.x, p.z))
+            .collect();
+        for (uv, expected) in transfer.transfer_attribute(&uvs).iter().zip(&uvs) {
+            assert!(uv.unwrap().abs_diff_eq(*expected, 0.0001));
+        }
+    }
+}
@@ -0,0 +1,372 @@
+//! Functions for transforming the vertex attributes of meshes.
+
+use glam::{Mat3A, Mat4, Vec2, Vec3A, Vec4};
+
+/// Transforms the mesh in place by the affine transform `transform`.
+/// Empty `normals` or `tangents` are treated as missing attributes.
+///
+/// Positions are transformed by `transform`.
+/// Normals are transformed by the inverse transpose to remain perpendicular to the surface for non uniform scales.
+/// Tangents are transformed by `transform` and keep their w sign for rotations and scales.
+/// Normals and tangents are normalized after transforming.
+///
+/// Transforms with a negative determinant like mirroring on a single axis reverse the orientation of each triangle.
+/// In this case, the winding order of `indices` is flipped to keep the triangles front facing,
+/// and tangent w signs are negated to keep the bitangents consistent with the texture coordinates.
+/// # Examples
+/**
+```rust
+use geometry_tools::transform::transform_mesh;
+use glam::{Mat4, Vec3, Vec3A, Vec4};
+
+let mut positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let mut normals = [Vec3A::Z; 3];
+let mut tangents = [Vec4::new(1.0, 0.0, 0.0, 1.0); 3];
+let mut indices = [0, 1, 2];
+
+// Mirror along the x-axis.
+let transform = Mat4::from_scale(Vec3::new(-1.0, 1.0, 1.0));
+transform_mesh(transform, &mut positions, &mut normals, &mut tangents, &mut indices);
+
+assert_eq!(Vec3A::NEG_X, positions[1]);
+assert_eq!(Vec3A::Z, normals[0]);
+assert_eq!(Vec4::new(-1.0, 0.0, 0.0, -1.0), tangents[0]);
+assert_eq!([0, 2, 1], indices);
+```
+ */
+pub fn transform_mesh(
+    transform: Mat4,
+    positions: &mut [Vec3A],
+    normals: &mut [Vec3A],
+    tangents: &mut [Vec4],
+    indices: &mut [u32],
+) {
+    for position in positions.iter_mut() {
+        *position = transform.transform_point3a(*position);
+    }
+
+    let linear = Mat3A::from_mat4(transform);
+    let normal_transform = linear.inverse().transpose();
+    for normal in normals.iter_mut() {
+        *normal = (normal_transform * *normal).normalize_or_zero();
+    }
+
+    let is_mirrored = linear.determinant() < 0.0;
+    let w_sign = if is_mirrored { -1.0 } else { 1.0 };
+    for tangent in tangents.iter_mut() {
+        let t = (linear * Vec3A::from(tangent.truncate())).normalize_or_zero();
+        *tangent = t.extend(tangent.w * w_sign);
+    }
+
+    if is_mirrored {
+        flip_winding(indices);
+    }
+}
+
+/// Reverses the winding order of each triangle in `indices` by swapping the last two vertices.
+/// `indices` is assumed to contain triangle indices, so `indices.len()` should be a multiple of 3.
+/// # Examples
+/**
+```rust
+use geometry_tools::transform::flip_winding;
+
+let mut indices = [0, 1, 2, 2, 1, 3];
+flip_winding(&mut indices);
+assert_eq!([0, 2, 1, 2, 3, 1], indices);
+```
+ */
+pub fn flip_winding(indices: &mut [u32]) {
+    for face in indices.chunks_exact_mut(3) {
+        face.swap(1, 2);
+    }
+}
+
+/// The axis pointing up for a [CoordinateSystem].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpAxis {
+    Y,
+    Z,
+}
+
+/// The handedness of the axes for a [CoordinateSystem].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+    Right,
+    Left,
+}
+
+/// The location of the texture coordinate `(0.0, 0.0)` in the texture for a [CoordinateSystem].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UvOrigin {
+    /// V increases upwards like in OpenGL.
+    BottomLeft,
+    /// V increases downwards like in DirectX, Vulkan, and glTF.
+    TopLeft,
+}
+
+/// The axis and texture coordinate conventions used by an application or file format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoordinateSystem {
+    pub up: UpAxis,
+    pub handedness: Handedness,
+    pub uv_origin: UvOrigin,
+}
+
+impl CoordinateSystem {
+    // The transform from this coordinate system to Y up right-handed.
+    fn to_y_up_right_handed(self) -> Mat3A {
+        match (self.up, self.handedness) {
+            (UpAxis::Y, Handedness::Right) => Mat3A::IDENTITY,
+            (UpAxis::Y, Handedness::Left) => Mat3A::from_diagonal(glam::Vec3::new(1.0, 1.0, -1.0)),
+            // (x, y, z) -> (x, z, -y)
+            (UpAxis::Z, Handedness::Right) => Mat3A::from_cols(Vec3A::X, Vec3A::NEG_Z, Vec3A::Y),
+            // (x, y, z) -> (x, z, y)
+            (UpAxis::Z, Handedness::Left) => Mat3A::from_cols(Vec3A::X, Vec3A::Z, Vec3A::Y),
+        }
+    }
+}
+
+/// Calculates the transform that converts positions in the `from` coordinate system to the `to` coordinate system.
+/// Right-handed Z up coordinates convert to right-handed Y up coordinates as `(x, y, z) -> (x, z, -y)`,
+/// and left and right-handed coordinates with the same up axis differ by the sign of the remaining non up axis.
+/// # Examples
+/**
+```rust
+use geometry_tools::transform::{
+    coordinate_system_transform, CoordinateSystem, Handedness, UpAxis, UvOrigin,
+};
+use glam::Vec3;
+
+let z_up = CoordinateSystem {
+    up: UpAxis::Z,
+    handedness: Handedness::Right,
+    uv_origin: UvOrigin::BottomLeft,
+};
+let y_up = CoordinateSystem {
+    up: UpAxis::Y,
+    handedness: Handedness::Right,
+    uv_origin: UvOrigin::BottomLeft,
+};
+
+let transform = coordinate_system_transform(z_up, y_up);
+assert_eq!(Vec3::Y, transform.transform_point3(Vec3::Z));
+```
+ */
+pub fn coordinate_system_transform(from: CoordinateSystem, to: CoordinateSystem) -> Mat4 {
+    // The axis matrices are orthogonal, so the inverse is the transpose.
+    let matrix = to.to_y_up_right_handed().transpose() * from.to_y_up_right_handed();
+    Mat4::from_mat3a(matrix)
+}
+
+/// Converts the mesh in place from the `from` coordinate system to the `to` coordinate system.
+/// Empty `normals`, `tangents`, or `uvs` are treated as missing attributes.
+///
+/// The axes are converted using [transform_mesh] with [coordinate_system_transform],
+/// which flips the winding order of `indices` and tangent w signs when changing handedness.
+/// Changing the UV origin flips the V coordinate and also negates the tangent w signs,
+/// since mirroring the texture coordinates reverses the direction of the bitangents.
+/// # Examples
+/**
+```rust
+use geometry_tools::transform::{
+    convert_coordinate_system, CoordinateSystem, Handedness, UpAxis, UvOrigin,
+};
+use glam::{Vec2, Vec3A, Vec4};
+
+let blender = CoordinateSystem {
+    up: UpAxis::Z,
+    handedness: Handedness::Right,
+    uv_origin: UvOrigin::BottomLeft,
+};
+let gltf = CoordinateSystem {
+    up: UpAxis::Y,
+    handedness: Handedness::Right,
+    uv_origin: UvOrigin::TopLeft,
+};
+
+let mut positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Z];
+let mut normals = [Vec3A::NEG_Y; 3];
+let mut tangents = [Vec4::new(1.0, 0.0, 0.0, 1.0); 3];
+let mut uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+let mut indices = [0, 1, 2];
+
+convert_coordinate_system(
+    blender,
+    gltf,
+    &mut positions,
+    &mut normals,
+    &mut tangents,
+    &mut uvs,
+    &mut indices,
+);
+assert_eq!(Vec3A::Y, positions[2]);
+assert_eq!(Vec3A::Z, normals[0]);
+assert_eq!(-1.0, tangents[0].w);
+assert_eq!(Vec2::new(0.0, 1.0), uvs[0]);
+assert_eq!([0, 1, 2], indices);
+```
+ */
+pub fn convert_coordinate_system(
+    from: CoordinateSystem,
+    to: CoordinateSystem,
+    positions: &mut [Vec3A],
+    normals: &mut [Vec3A],
+    tangents: &mut [Vec4],
+    uvs: &mut [Vec2],
+    indices: &mut [u32],
+) {
+    let transform = coordinate_system_transform(from, to);
+    transform_mesh(transform, positions, normals, tangents, indices);
+
+    if from.uv_origin != to.uv_origin {
+        for uv in uvs.iter_mut() {
+            uv.y = 1.0 - uv.y;
+        }
+        for tangent in tangents.iter_mut() {
+            tangent.w = -tangent.w;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use glam::{Quat, Vec3};
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn transform_empty() {
+        transform_mesh(Mat4::IDENTITY, &mut [], &mut [], &mut [], &mut []);
+    }
+
+    #[test]
+    fn transform_rotation_translation() {
+        let transform = Mat4::from_rotation_translation(
+            Quat::from_rotation_z(90f32.to_radians()),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        let mut positions = [Vec3A::X];
+        let mut normals = [Vec3A::X];
+        let mut tangents = [Vec4::new(0.0, 1.0, 0.0, -1.0)];
+        let mut indices = [0, 0, 0];
+        transform_mesh(
+            transform,
+            &mut positions,
+            &mut normals,
+            &mut tangents,
+            &mut indices,
+        );
+        assert!(positions[0].abs_diff_eq(Vec3A::new(1.0, 3.0, 3.0), EPSILON));
+        assert!(normals[0].abs_diff_eq(Vec3A::Y, EPSILON));
+        assert!(tangents[0].abs_diff_eq(Vec4::new(-1.0, 0.0, 0.0, -1.0), EPSILON));
+    }
+
+    #[test]
+    fn transform_non_uniform_scale_normals() {
+        // The normal of a 45 degree slope should remain perpendicular after stretching.
+        let transform = Mat4::from_scale(Vec3::new(2.0, 1.0, 1.0));
+        let mut positions = [Vec3A::X, Vec3A::Y];
+        let mut normals = [Vec3A::new(1.0, 1.0, 0.0).normalize()];
+        transform_mesh(transform, &mut positions, &mut normals, &mut [], &mut []);
+
+        let edge = positions[1] - positions[0];
+        assert!(edge.dot(normals[0]).abs() < EPSILON);
+        assert!((normals[0].length() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn transform_mirror_keeps_front_facing() {
+        let mut positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let mut normals = [Vec3A::Z; 3];
+        let mut indices = [0, 1, 2];
+        let transform = Mat4::from_scale(Vec3::new(1.0, -1.0, 1.0));
+        transform_mesh(
+            transform,
+            &mut positions,
+            &mut normals,
+            &mut [],
+            &mut indices,
+        );
+
+        let [v0, v1, v2] = indices.map(|i| positions[i as usize]);
+        let face_normal = (v1 - v0).cross(v2 - v0);
+        assert!(face_normal.dot(normals[0]) > 0.0);
+    }
+
+    fn systems() -> Vec<CoordinateSystem> {
+        let mut systems = Vec::new();
+        for up in [UpAxis::Y, UpAxis::Z] {
+            for handedness in [Handedness::Right, Handedness::Left] {
+                for uv_origin in [UvOrigin::BottomLeft, UvOrigin::TopLeft] {
+                    systems.push(CoordinateSystem {
+                        up,
+                        handedness,
+                        uv_origin,
+                    });
+                }
+            }
+        }
+        systems
+    }
+
+    #[test]
+    fn coordinate_system_up_axis() {
+        for from in systems() {
+            for to in systems() {
+                let transform = coordinate_system_transform(from, to);
+                let up = |s: CoordinateSystem| match s.up {
+                    UpAxis::Y => Vec3::Y,
+                    UpAxis::Z => Vec3::Z,
+                };
+                assert_eq!(up(to), transform.transform_vector3(up(from)));
+
+                // Only changing handedness should mirror the mesh.
+                let determinant = transform.determinant();
+                if from.handedness == to.handedness {
+                    assert_eq!(1.0, determinant);
+                } else {
+                    assert_eq!(-1.0, determinant);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn coordinate_system_round_trip() {
+        let positions = [Vec3A::new(1.0, 2.0, 3.0), Vec3A::X, Vec3A::Y];
+        let tangents = [Vec4::new(0.0, 0.0, 1.0, 1.0); 3];
+        let uvs = [Vec2::new(0.25, 0.75); 3];
+        for from in systems() {
+            for to in systems() {
+                let mut new_positions = positions;
+                let mut new_tangents = tangents;
+                let mut new_uvs = uvs;
+                let mut indices = [0, 1, 2];
+                convert_coordinate_system(
+                    from,
+                    to,
+                    &mut new_positions,
+                    &mut [],
+                    &mut new_tangents,
+                    &mut new_uvs,
+                    &mut indices,
+                );
+                convert_coordinate_system(
+                    to,
+                    from,
+                    &mut new_positions,
+                    &mut [],
+                    &mut new_tangents,
+                    &mut new_uvs,
+                    &mut indices,
+                );
+                assert_eq!(positions, new_positions);
+                assert_eq!(tangents, new_tangents);
+                assert_eq!(uvs, new_uvs);
+                assert_eq!([0, 1, 2], indices);
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Functions for calculating per triangle values like areas, centroids, and face normals.
+//!
+//! Each function returns one value for each complete triangle in `indices`
+//! in the same order as the triangles.
+//! Any remaining indices after the last full triangle are ignored.
+
+use glam::Vec3A;
+
+use crate::vectors::normal::calculate_normal;
+
+/// Calculates the area of each triangle in the triangle list defined by `positions` and `indices`.
+/// # Examples
+/**
+```rust
+use geometry_tools::triangles::triangle_areas;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::new(2.0, 2.0, 0.0)];
+let areas = triangle_areas(&positions, &[0, 1, 2, 0, 1, 3]);
+assert_eq!(vec![0.5, 1.0], areas);
+```
+ */
+pub fn triangle_areas<P>(positions: &[P], indices: &[u32]) -> Vec<f32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    map_triangles(positions, indices, |v0, v1, v2| {
+        calculate_normal(v0, v1, v2).length() / 2.0
+    })
+}
+
+/// Calculates the centroid of each triangle in the triangle list defined by `positions` and `indices`.
+/// # Examples
+/**
+```rust
+use geometry_tools::triangles::triangle_centroids;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X * 3.0, Vec3A::Y * 3.0];
+let centroids = triangle_centroids(&positions, &[0, 1, 2]);
+assert_eq!(vec![Vec3A::new(1.0, 1.0, 0.0)], centroids);
+```
+ */
+pub fn triangle_centroids<P>(positions: &[P], indices: &[u32]) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+{
+    map_triangles(positions, indices, |v0, v1, v2| (v0 + v1 + v2) / 3.0)
+}
+
+/// Calculates the normalized face normal of each triangle in the triangle list defined by `positions` and `indices`.
+/// The normals use the same counterclockwise winding convention as
+/// [calculate_smooth_normals](crate::vectors::calculate_smooth_normals).
+/// Degenerate triangles with zero area have a normal of zero.
+/// # Examples
+/**
+```rust
+use geometry_tools::triangles::triangle_normals;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::X * 2.0];
+let normals = triangle_normals(&positions, &[0, 1, 2, 2, 1, 0, 0, 1, 3]);
+assert_eq!(vec![Vec3A::Z, Vec3A::NEG_Z, Vec3A::ZERO], normals);
+```
+ */
+pub fn triangle_normals<P>(positions: &[P], indices: &[u32]) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+{
+    map_triangles(positions, indices, |v0, v1, v2| {
+        calculate_normal(v0, v1, v2).normalize_or_zero()
+    })
+}
+
+fn map_triangles<P, T, F>(positions: &[P], indices: &[u32], f: F) -> Vec<T>
+where
+    P: Into<Vec3A> + Copy,
+    F: Fn(Vec3A, Vec3A, Vec3A) -> T,
+{
+    indices
+        .chunks_exact(3)
+        .map(|face| {
+            f(
+                positions[face[0] as usize].into(),
+                positions[face[1] as usize].into(),
+                positions[face[2] as usize].into(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert!(triangle_areas::<Vec3A>(&[], &[]).is_empty());
+        assert!(triangle_centroids::<Vec3A>(&[], &[]).is_empty());
+        assert!(triangle_normals::<Vec3A>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn trailing_indices() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        assert_eq!(1, triangle_areas(&positions, &[0, 1, 2, 0, 1]).len());
+    }
+
+    #[test]
+    fn sphere_area() {
+        let sphere = crate::shapes::generate_uv_sphere(1.0, 64, 32);
+        let area: f32 = triangle_areas(&sphere.positions, &sphere.indices)
+            .iter()
+            .sum();
+        assert!((area - 4.0 * std::f32::consts::PI).abs() < 0.05);
+    }
+}
@@ -0,0 +1,464 @@
+//! Functions for converting quads and polygons to triangle lists.
+
+use glam::{Vec2, Vec3A};
+
+/// Converts the quad list `quads` to a triangle list by splitting each quad along the diagonal
+/// that produces the most planar pair of triangles.
+/// `quads` is assumed to contain 4 vertex indices for each quad in winding order,
+/// so `quads.len()` should be a multiple of 4.
+///
+/// Concave quads are always split along the diagonal inside the quad
+/// since the other diagonal produces triangles with opposite winding.
+/// # Examples
+/**
+```rust
+use geometry_tools::triangulation::triangulate_quads;
+use glam::Vec3A;
+
+// Vertex 2 is raised, so the 1-3 diagonal creates a sharp fold.
+let positions = [
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(1.0, 1.0, 1.0),
+    Vec3A::new(0.0, 1.0, 0.0),
+];
+
+let indices = triangulate_quads(&positions, &[0, 1, 2, 3]);
+assert_eq!(vec![0, 1, 3, 1, 2, 3], indices);
+```
+ */
+pub fn triangulate_quads<P>(positions: &[P], quads: &[u32]) -> Vec<u32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    triangulate_quads_by(quads, |triangles| {
+        let [n0, n1] = triangles.map(|t| face_normal(positions, t).normalize_or_zero());
+        n0.dot(n1)
+    })
+}
+
+/// Converts the quad list `quads` to a triangle list by splitting each quad along the diagonal
+/// that produces triangles whose face normals best match the vertex normals in `normals`.
+/// This can better preserve the intended shading of quads from other applications.
+/// `quads` is assumed to contain 4 vertex indices for each quad in winding order,
+/// so `quads.len()` should be a multiple of 4.
+/// # Examples
+/**
+```rust
+use geometry_tools::triangulation::triangulate_quads_with_normals;
+use glam::Vec3A;
+
+let positions = [
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(1.0, 1.0, 0.0),
+    Vec3A::new(0.0, 1.0, 0.0),
+];
+let normals = [Vec3A::Z; 4];
+
+let indices = triangulate_quads_with_normals(&positions, &normals, &[0, 1, 2, 3]);
+assert_eq!(6, indices.len());
+```
+ */
+pub fn triangulate_quads_with_normals<P, N>(
+    positions: &[P],
+    normals: &[N],
+    quads: &[u32],
+) -> Vec<u32>
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    triangulate_quads_by(quads, |triangles| {
+        triangles
+            .iter()
+            .map(|t| {
+                let face_normal = face_normal(positions, *t).normalize_or_zero();
+                t.iter()
+                    .map(|v| face_normal.dot(normals[*v as usize].into()))
+                    .sum::<f32>()
+            })
+            .sum()
+    })
+}
+
+fn triangulate_quads_by<F>(quads: &[u32], score: F) -> Vec<u32>
+where
+    F: Fn([[u32; 3]; 2]) -> f32,
+{
+    let mut indices = Vec::with_capacity(quads.len() / 4 * 6);
+    for quad in quads.chunks(4) {
+        if let [v0, v1, v2, v3] = *quad {
+            let split02 = [[v0, v1, v2], [v0, v2, v3]];
+            let split13 = [[v0, v1, v3], [v1, v2, v3]];
+
+            // Prefer the conventional 0-2 diagonal for planar quads.
+            let triangles = if score(split13) > score(split02) {
+                split13
+            } else {
+                split02
+            };
+            indices.extend(triangles.iter().flatten());
+        }
+    }
+    indices
+}
+
+fn face_normal<P>(positions: &[P], [v0, v1, v2]: [u32; 3]) -> Vec3A
+where
+    P: Into<Vec3A> + Copy,
+{
+    let v0: Vec3A = positions[v0 as usize].into();
+    let v1: Vec3A = positions[v1 as usize].into();
+    let v2: Vec3A = positions[v2 as usize].into();
+    (v1 - v0).cross(v2 - v0)
+}
+
+/// Triangulates the polygon with vertex indices `polygon` in winding order using ear clipping.
+/// The vertices are projected onto the best fit plane of the polygon,
+/// so nearly planar and concave polygons produce non overlapping triangles with the same winding as the polygon.
+/// Polygons with fewer than 3 vertices produce no triangles.
+/// # Examples
+/**
+```rust
+use geometry_tools::triangulation::triangulate_polygon;
+use glam::Vec3A;
+
+// An L-shaped polygon that would produce overlapping triangles if triangulated as a fan from vertex 0.
+let positions = [
+    Vec3A::new(1.0, 1.0, 0.0),
+    Vec3A::new(0.0, 2.0, 0.0),
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(2.0, 0.0, 0.0),
+    Vec3A::new(2.0, 1.0, 0.0),
+    Vec3A::new(1.0, 1.0, 0.0),
+    Vec3A::new(1.0, 2.0, 0.0),
+];
+
+let indices = triangulate_polygon(&positions, &[1, 2, 3, 4, 5, 6]);
+assert_eq!(12, indices.len());
+```
+ */
+pub fn triangulate_polygon<P>(positions: &[P], polygon: &[u32]) -> Vec<u32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let mut indices = Vec::new();
+    triangulate_polygon_into(positions, polygon, &mut indices);
+    indices
+}
+
+/// Triangulates multiple polygons using [triangulate_polygon] and returns a combined triangle list.
+/// `polygon_sizes` contains the vertex count of each polygon in `polygons`.
+/// # Examples
+/**
+```rust
+use geometry_tools::triangulation::triangulate_polygons;
+use glam::Vec3A;
+
+let positions = [
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(1.0, 0.0, 0.0),
+    Vec3A::new(1.0, 1.0, 0.0),
+    Vec3A::new(0.0, 1.0, 0.0),
+    Vec3A::new(0.0, 2.0, 0.0),
+];
+
+// A quad followed by a triangle.
+let indices = triangulate_polygons(&positions, &[0, 1, 2, 3, 3, 2, 4], &[4, 3]);
+assert_eq!(9, indices.len());
+```
+ */
+pub fn triangulate_polygons<P>(positions: &[P], polygons: &[u32], polygon_sizes: &[u32]) -> Vec<u32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let mut indices = Vec::new();
+    let mut start = 0;
+    for size in polygon_sizes {
+        let end = (start + *size as usize).min(polygons.len());
+        triangulate_polygon_into(positions, &polygons[start..end], &mut indices);
+        start = end;
+    }
+    indices
+}
+
+fn triangulate_polygon_into<P>(positions: &[P], polygon: &[u32], indices: &mut Vec<u32>)
+where
+    P: Into<Vec3A> + Copy,
+{
+    if polygon.len() < 3 {
+        return;
+    }
+
+    let points: Vec<Vec3A> = polygon
+        .iter()
+        .map(|i| positions[*i as usize].into())
+        .collect();
+
+    let normal = polygon_normal(&points);
+    if normal == Vec3A::ZERO {
+        // There is no well defined plane, so use a fan to at least preserve the vertex count.
+        for i in 1..polygon.len() - 1 {
+            indices.extend_from_slice(&[polygon[0], polygon[i], polygon[i + 1]]);
+        }
+        return;
+    }
+
+    // Project onto the plane with the polygon in counterclockwise order.
+    let u = normal.any_orthonormal_vector();
+    let v = normal.cross(u);
+    let points: Vec<Vec2> = points
+        .iter()
+        .map(|p| Vec2::new(p.dot(u), p.dot(v)))
+        .collect();
+
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let ear = (0..n)
+            .find(|i| is_ear(&points, &remaining, *i))
+            // Self intersecting or degenerate polygons may not have an ear.
+            // Clip a convex vertex or any vertex to guarantee progress.
+            .or_else(|| (0..n).find(|i| is_convex(&points, &remaining, *i)))
+            .unwrap_or(0);
+
+        let a = remaining[(ear + n - 1) % n];
+        let b = remaining[ear];
+        let c = remaining[(ear + 1) % n];
+        indices.extend_from_slice(&[polygon[a], polygon[b], polygon[c]]);
+        remaining.remove(ear);
+    }
+
+    indices.extend(remaining.iter().map(|i| polygon[*i]));
+}
+
+fn polygon_normal(points: &[Vec3A]) -> Vec3A {
+    // Newell's method is robust to nearly planar and concave polygons.
+    let mut normal = Vec3A::ZERO;
+    for (i, current) in points.iter().enumerate() {
+        let next = points[(i + 1) % points.len()];
+        normal.x += (current.y - next.y) * (current.z + next.z);
+        normal.y += (current.z - next.z) * (current.x + next.x);
+        normal.z += (current.x - next.x) * (current.y + next.y);
+    }
+    normal.normalize_or_zero()
+}
+
+fn is_convex(points: &[Vec2], remaining: &[usize], i: usize) -> bool {
+    let n = remaining.len();
+    let a = points[remaining[(i + n - 1) % n]];
+    let b = points[remaining[i]];
+    let c = points[remaining[(i + 1) % n]];
+    (b - a).perp_dot(c - b) > 0.0
+}
+
+fn is_ear(points: &[Vec2], remaining: &[usize], i: usize) -> bool {
+    if !is_convex(points, remaining, i) {
+        return false;
+    }
+
+    let n = remaining.len();
+    let a = points[remaining[(i + n - 1) % n]];
+    let b = points[remaining[i]];
+    let c = points[remaining[(i + 1) % n]];
+
+    // No other vertex can be inside the ear.
+    // Vertices with the same position as a corner of the ear are allowed.
+    remaining
+        .iter()
+        .map(|j| points[*j])
+        .filter(|p| *p != a && *p != b && *p != c)
+        .all(|p| !point_in_triangle(p, a, b, c))
+}
+
+fn point_in_triangle(p: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    // Points on the edges count as inside to avoid creating overlapping triangles.
+    (b - a).perp_dot(p - a) >= 0.0
+        && (c - b).perp_dot(p - b) >= 0.0
+        && (a - c).perp_dot(p - c) >= 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quads_empty() {
+        assert!(triangulate_quads::<Vec3A>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn planar_quad_uses_default_diagonal() {
+        let positions = [
+            Vec3A::new(0.0, 0.0, 0.0),
+            Vec3A::new(1.0, 0.0, 0.0),
+            Vec3A::new(1.0, 1.0, 0.0),
+            Vec3A::new(0.0, 1.0, 0.0),
+        ];
+        assert_eq!(
+            vec![0, 1, 2, 0, 2, 3],
+            triangulate_quads(&positions, &[0, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn non_planar_quad() {
+        let positions = [
+            Vec3A::new(0.0, 0.0, 1.0),
+            Vec3A::new(1.0, 0.0, 0.0),
+            Vec3A::new(1.0, 1.0, 0.0),
+            Vec3A::new(0.0, 1.0, 0.0),
+        ];
+        assert_eq!(
+            vec![0, 1, 3, 1, 2, 3],
+            triangulate_quads(&positions, &[0, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn concave_quad() {
+        // Vertex 2 is inside the triangle formed by the other vertices.
+        let positions = [
+            Vec3A::new(0.0, 0.0, 0.0),
+            Vec3A::new(2.0, 0.0, 0.0),
+            Vec3A::new(0.5, 0.5, 0.0),
+            Vec3A::new(0.0, 2.0, 0.0),
+        ];
+        assert_eq!(
+            vec![0, 1, 2, 0, 2, 3],
+            triangulate_quads(&positions, &[1, 2, 3, 0])
+                .chunks(3)
+                .flat_map(|t| {
+                    // Rotate each triangle to start at its smallest index for comparison.
+                    let i = t.iter().position(|v| v == t.iter().min().unwrap()).unwrap();
+                    [t[i], t[(i + 1) % 3], t[(i + 2) % 3]]
+                })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn quad_with_normals() {
+        // The normals indicate a fold along the 1-3 diagonal.
+        let positions = [
+            Vec3A::new(0.0, 0.0, 0.0),
+            Vec3A::new(1.0, 0.0, 0.0),
+            Vec3A::new(1.0, 1.0, 0.0),
+            Vec3A::new(0.0, 1.0, 0.0),
+        ];
+        let normals = [
+            Vec3A::new(-1.0, -1.0, 1.0).normalize(),
+            Vec3A::Z,
+            Vec3A::new(1.0, 1.0, 1.0).normalize(),
+            Vec3A::Z,
+        ];
+        assert_eq!(
+            vec![0, 1, 3, 1, 2, 3],
+            triangulate_quads_with_normals(&positions, &normals, &[0, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn partial_quad_ignored() {
+        let positions = [Vec3A::ZERO; 4];
+        assert_eq!(
+            vec![0, 1, 2, 0, 2, 3],
+            triangulate_quads(&positions, &[0, 1, 2, 3, 0, 1])
+        );
+    }
+
+    fn triangulated_area(positions: &[Vec3A], indices: &[u32], normal: Vec3A) -> f32 {
+        indices
+            .chunks(3)
+            .map(|t| face_normal(positions, [t[0], t[1], t[2]]).dot(normal) / 2.0)
+            .sum()
+    }
+
+    #[test]
+    fn polygon_too_few_vertices() {
+        let positions = [Vec3A::ZERO; 2];
+        assert!(triangulate_polygon(&positions, &[]).is_empty());
+        assert!(triangulate_polygon(&positions, &[0, 1]).is_empty());
+    }
+
+    #[test]
+    fn polygon_triangle() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        assert_eq!(vec![2, 0, 1], triangulate_polygon(&positions, &[2, 0, 1]));
+    }
+
+    #[test]
+    fn polygon_concave() {
+        // An L shape with an area of 3.
+        let positions = [
+            Vec3A::new(0.0, 0.0, 0.0),
+            Vec3A::new(2.0, 0.0, 0.0),
+            Vec3A::new(2.0, 1.0, 0.0),
+            Vec3A::new(1.0, 1.0, 0.0),
+            Vec3A::new(1.0, 2.0, 0.0),
+            Vec3A::new(0.0, 2.0, 0.0),
+        ];
+        let polygon = [3, 4, 5, 0, 1, 2];
+        let indices = triangulate_polygon(&positions, &polygon);
+
+        // Overlapping or flipped triangles would change the total signed area.
+        assert_eq!(12, indices.len());
+        assert_eq!(3.0, triangulated_area(&positions, &indices, Vec3A::Z));
+        for t in indices.chunks(3) {
+            assert!(face_normal(&positions, [t[0], t[1], t[2]]).z > 0.0);
+        }
+    }
+
+    #[test]
+    fn polygon_clockwise_concave() {
+        // A reversed arrow shape that is not planar along the z-axis.
+        let positions = [
+            Vec3A::new(0.0, 0.0, 0.0),
+            Vec3A::new(1.0, 3.0, 0.1),
+            Vec3A::new(2.0, 0.0, 0.0),
+            Vec3A::new(1.0, 1.0, -0.1),
+        ];
+        let indices = triangulate_polygon(&positions, &[0, 1, 2, 3]);
+        assert_eq!(6, indices.len());
+        for t in indices.chunks(3) {
+            assert!(face_normal(&positions, [t[0], t[1], t[2]]).z < 0.0);
+        }
+    }
+
+    #[test]
+    fn polygon_collinear_vertices() {
+        let positions = [
+            Vec3A::new(0.0, 0.0, 0.0),
+            Vec3A::new(1.0, 0.0, 0.0),
+            Vec3A::new(2.0, 0.0, 0.0),
+            Vec3A::new(2.0, 2.0, 0.0),
+            Vec3A::new(0.0, 2.0, 0.0),
+        ];
+        let indices = triangulate_polygon(&positions, &[0, 1, 2, 3, 4]);
+        assert_eq!(9, indices.len());
+        assert_eq!(4.0, triangulated_area(&positions, &indices, Vec3A::Z));
+    }
+
+    #[test]
+    fn polygon_degenerate() {
+        let positions = [Vec3A::ZERO; 4];
+        assert_eq!(
+            vec![0, 1, 2, 0, 2, 3],
+            triangulate_polygon(&positions, &[0, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn polygons_multiple() {
+        let positions = [
+            Vec3A::new(0.0, 0.0, 0.0),
+            Vec3A::new(1.0, 0.0, 0.0),
+            Vec3A::new(1.0, 1.0, 0.0),
+            Vec3A::new(0.0, 1.0, 0.0),
+        ];
+        let indices = triangulate_polygons(&positions, &[0, 1, 2, 3, 0, 1, 2], &[4, 3]);
+        assert_eq!(9, indices.len());
+        assert_eq!(&[0, 1, 2], &indices[6..]);
+    }
+}
@@ -0,0 +1,760 @@
+//! Functions for analyzing and generating texture coordinates.
+
+use glam::{UVec2, Vec2, Vec3A};
+pub use projection::*;
+pub use unwrap::*;
+
+mod projection;
+mod unwrap;
+
+/// The texel density of each triangle calculated by [calculate_texel_density].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TexelDensity {
+    /// The texels per world unit for each triangle.
+    /// Triangles with zero world area have a density of `0.0`.
+    pub triangle_densities: Vec<f32>,
+    /// The smallest density of triangles with non zero world area.
+    pub min: f32,
+    /// The largest density of triangles with non zero world area.
+    pub max: f32,
+    /// The average density weighted by the world area of each triangle.
+    pub average: f32,
+}
+
+/// Calculates the texel density in texels per world unit for each triangle
+/// for a texture with dimensions `texture_size`.
+/// The density is the square root of the ratio of the texel area to the world area,
+/// so a density of `256.0` means one world unit spans 256 texels along each axis.
+/// # Examples
+/**
+```rust
+use geometry_tools::uv::calculate_texel_density;
+use glam::{UVec2, Vec2, Vec3A};
+
+// A 2x2 quad mapped to the full UV range.
+let positions = [
+    Vec3A::new(0.0, 0.0, 0.0),
+    Vec3A::new(2.0, 0.0, 0.0),
+    Vec3A::new(2.0, 2.0, 0.0),
+    Vec3A::new(0.0, 2.0, 0.0),
+];
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y];
+let indices = [0, 1, 2, 2, 3, 0];
+
+let density = calculate_texel_density(&positions, &uvs, &indices, UVec2::new(512, 512));
+assert_eq!(vec![256.0, 256.0], density.triangle_densities);
+assert_eq!(256.0, density.average);
+```
+ */
+pub fn calculate_texel_density<P>(
+    positions: &[P],
+    uvs: &[Vec2],
+    indices: &[u32],
+    texture_size: UVec2,
+) -> TexelDensity
+where
+    P: Into<Vec3A> + Copy,
+{
+    let texture_area = texture_size.x as f32 * texture_size.y as f32;
+
+    let mut triangle_densities = Vec::with_capacity(indices.len() / 3);
+    let mut min = f32::MAX;
+    let mut max = 0.0f32;
+    let mut weighted_sum = 0.0;
+    let mut total_area = 0.0;
+    for face in indices.chunks_exact(3) {
+        let world_area = triangle_area(
+            positions[face[0] as usize].into(),
+            positions[face[1] as usize].into(),
+            positions[face[2] as usize].into(),
+        );
+        let uv_area = uv_signed_area(
+            uvs[face[0] as usize],
+            uvs[face[1] as usize],
+            uvs[face[2] as usize],
+        )
+        .abs();
+
+        if world_area > 0.0 {
+            let density = (uv_area * texture_area / world_area).sqrt();
+            min = min.min(density);
+            max = max.max(density);
+            weighted_sum += density * world_area;
+            total_area += world_area;
+            triangle_densities.push(density);
+        } else {
+            triangle_densities.push(0.0);
+        }
+    }
+
+    if total_area > 0.0 {
+        TexelDensity {
+            triangle_densities,
+            min,
+            max,
+            average: weighted_sum / total_area,
+        }
+    } else {
+        TexelDensity {
+            triangle_densities,
+            min: 0.0,
+            max: 0.0,
+            average: 0.0,
+        }
+    }
+}
+
+/// Finds pairs of triangles with overlapping UV coordinates as `[first, second]` with `first < second`.
+/// Triangles that only touch along an edge or at a vertex do not overlap.
+/// Triangles with identical UV coordinates are assumed to be intentionally mirrored or stacked and are ignored.
+/// Triangles with zero UV area are also ignored.
+/// # Examples
+/**
+```rust
+use geometry_tools::uv::find_overlapping_uv_triangles;
+use glam::Vec2;
+
+let uvs = [
+    Vec2::new(0.0, 0.0),
+    Vec2::new(1.0, 0.0),
+    Vec2::new(0.0, 1.0),
+    Vec2::new(1.0, 1.0),
+    Vec2::new(0.2, 0.2),
+];
+
+// The first two triangles share an edge.
+// The last triangle overlaps both of them.
+let indices = [0, 1, 2, 1, 3, 2, 4, 1, 3];
+assert_eq!(vec![[0, 2], [1, 2]], find_overlapping_uv_triangles(&uvs, &indices));
+```
+ */
+pub fn find_overlapping_uv_triangles(uvs: &[Vec2], indices: &[u32]) -> Vec<[u32; 2]> {
+    let triangles: Vec<_> = indices
+        .chunks_exact(3)
+        .map(|face| {
+            [
+                uvs[face[0] as usize],
+                uvs[face[1] as usize],
+                uvs[face[2] as usize],
+            ]
+        })
+        .collect();
+
+    // Sort by the minimum U coordinate to only test triangles with overlapping U ranges.
+    let mut sorted: Vec<_> = triangles
+        .iter()
+        .enumerate()
+        .filter(|(_, [uv0, uv1, uv2])| uv_signed_area(*uv0, *uv1, *uv2) != 0.0)
+        .map(|(i, t)| {
+            let min = t[0].min(t[1]).min(t[2]);
+            let max = t[0].max(t[1]).max(t[2]);
+            (i, min, max)
+        })
+        .collect();
+    sorted.sort_by(|a, b| a.1.x.total_cmp(&b.1.x));
+
+    let mut pairs = Vec::new();
+    for (i, (a, min_a, max_a)) in sorted.iter().enumerate() {
+        for (b, min_b, max_b) in &sorted[i + 1..] {
+            if min_b.x >= max_a.x {
+                break;
+            }
+            if min_b.y >= max_a.y || min_a.y >= max_b.y {
+                continue;
+            }
+
+            let ta = &triangles[*a];
+            let tb = &triangles[*b];
+            if !is_same_triangle(ta, tb) && triangles_overlap(ta, tb) {
+                pairs.push([(*a).min(*b) as u32, (*a).max(*b) as u32]);
+            }
+        }
+    }
+    pairs.sort();
+    pairs
+}
+
+fn is_same_triangle(a: &[Vec2; 3], b: &[Vec2; 3]) -> bool {
+    a.iter().all(|uv| b.contains(uv))
+}
+
+fn triangles_overlap(a: &[Vec2; 3], b: &[Vec2; 3]) -> bool {
+    // Use the separating axis theorem with the edge normals of both triangles.
+    // Scale the tolerance by the triangle size to ignore shared edges and vertices.
+    let scale = (a[1] - a[0])
+        .abs()
+        .max((a[2] - a[0]).abs())
+        .max((b[1] - b[0]).abs())
+        .max((b[2] - b[0]).abs())
+        .max_element();
+    let epsilon = scale * 1e-5;
+
+    let edges = [
+        a[1] - a[0],
+        a[2] - a[1],
+        a[0] - a[2],
+        b[1] - b[0],
+        b[2] - b[1],
+        b[0] - b[2],
+    ];
+    !edges.iter().any(|edge| {
+        let axis = edge.perp().normalize_or_zero();
+        let (min_a, max_a) = project(a, axis);
+        let (min_b, max_b) = project(b, axis);
+        max_a <= min_b + epsilon || max_b <= min_a + epsilon
+    })
+}
+
+fn project(triangle: &[Vec2; 3], axis: Vec2) -> (f32, f32) {
+    let d = triangle.map(|uv| uv.dot(axis));
+    (d[0].min(d[1]).min(d[2]), d[0].max(d[1]).max(d[2]))
+}
+
+/// Calculates a bounding box of the form `(min_uv, max_uv)` containing all the texture coordinates in `uvs`.
+/// If `uvs` is empty, both `min_uv` and `max_uv` will be zero.
+/// # Examples
+/**
+```rust
+use geometry_tools::uv::calculate_uv_bounds;
+use glam::Vec2;
+
+let (min, max) = calculate_uv_bounds(&[Vec2::new(0.5, -1.0), Vec2::new(2.0, 0.25)]);
+assert_eq!(Vec2::new(0.5, -1.0), min);
+assert_eq!(Vec2::new(2.0, 0.25), max);
+```
+ */
+pub fn calculate_uv_bounds(uvs: &[Vec2]) -> (Vec2, Vec2) {
+    crate::bounding2d::calculate_aabb_from_points(uvs)
+}
+
+/// Finds the vertices with texture coordinates outside the unit square from `0.0` to `1.0`.
+/// These vertices will sample outside the page when the texture is part of an atlas.
+/// # Examples
+/**
+```rust
+use geometry_tools::uv::find_uvs_outside_unit_square;
+use glam::Vec2;
+
+let uvs = [Vec2::ZERO, Vec2::ONE, Vec2::new(1.5, 0.5), Vec2::new(0.5, -0.1)];
+assert_eq!(vec![2, 3], find_uvs_outside_unit_square(&uvs));
+```
+ */
+pub fn find_uvs_outside_unit_square(uvs: &[Vec2]) -> Vec<u32> {
+    uvs.iter()
+        .enumerate()
+        .filter(|(_, uv)| uv.cmplt(Vec2::ZERO).any() || uv.cmpgt(Vec2::ONE).any())
+        .map(|(i, _)| i as u32)
+        .collect()
+}
+
+/// Estimates the fraction of the unit square covered by the UV triangles
+/// by sampling the center of each texel in a `resolution` by `resolution` grid.
+/// Overlapping triangles are only counted once, and areas outside the unit square are not counted.
+/// A low coverage indicates that most of the texture is unused.
+/// # Examples
+/**
+```rust
+use geometry_tools::uv::calculate_uv_coverage;
+use glam::Vec2;
+
+// A triangle covering half of the unit square.
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+let coverage = calculate_uv_coverage(&uvs, &[0, 1, 2], 64);
+assert!((coverage - 0.5).abs() < 0.05);
+```
+ */
+pub fn calculate_uv_coverage(uvs: &[Vec2], indices: &[u32], resolution: u32) -> f32 {
+    if resolution == 0 {
+        return 0.0;
+    }
+
+    let size = resolution as usize;
+    let mut covered = vec![false; size * size];
+    for face in indices.chunks_exact(3) {
+        let [uv0, uv1, uv2] = [face[0], face[1], face[2]].map(|i| uvs[i as usize]);
+
+        // Only check texels within the bounding box of the triangle.
+        let min = uv0.min(uv1).min(uv2).clamp(Vec2::ZERO, Vec2::ONE) * resolution as f32;
+        let max = uv0.max(uv1).max(uv2).clamp(Vec2::ZERO, Vec2::ONE) * resolution as f32;
+        let x_range = (min.x.floor() as usize)..(max.x.ceil() as usize).min(size);
+        let y_range = (min.y.floor() as usize)..(max.y.ceil() as usize).min(size);
+
+        for y in y_range {
+            for x in x_range.clone() {
+                let p = (Vec2::new(x as f32, y as f32) + 0.5) / resolution as f32;
+                if point_in_triangle(p, uv0, uv1, uv2) {
+                    covered[y * size + x] = true;
+                }
+            }
+        }
+    }
+
+    covered.iter().filter(|c| **c).count() as f32 / covered.len() as f32
+}
+
+fn point_in_triangle(p: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    // Accept either winding order.
+    let d0 = (b - a).perp_dot(p - a);
+    let d1 = (c - b).perp_dot(p - b);
+    let d2 = (a - c).perp_dot(p - c);
+    (d0 >= 0.0 && d1 >= 0.0 && d2 >= 0.0) || (d0 <= 0.0 && d1 <= 0.0 && d2 <= 0.0)
+}
+
+/// Finds the triangles whose winding order in UV space is clockwise
+/// and inverted relative to the counterclockwise winding of the triangle in `indices`.
+/// These triangles will have a tangent sign of `-1.0` from [calculate_tangents](crate::vectors::calculate_tangents),
+/// so edges between flipped and unflipped triangles will often produce normal map seams.
+/// Triangles with zero UV area are not flipped.
+/// Texture coordinates with V pointing down instead of up will have the opposite result.
+/// # Examples
+/**
+```rust
+use geometry_tools::uv::find_flipped_uv_triangles;
+use glam::Vec2;
+
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+
+// The second triangle has the same UVs mirrored.
+let indices = [0, 1, 2, 0, 2, 1];
+assert_eq!(vec![1], find_flipped_uv_triangles(&uvs, &indices));
+```
+ */
+pub fn find_flipped_uv_triangles(uvs: &[Vec2], indices: &[u32]) -> Vec<u32> {
+    indices
+        .chunks_exact(3)
+        .enumerate()
+        .filter(|(_, face)| {
+            uv_signed_area(
+                uvs[face[0] as usize],
+                uvs[face[1] as usize],
+                uvs[face[2] as usize],
+            ) < 0.0
+        })
+        .map(|(i, _)| i as u32)
+        .collect()
+}
+
+/// The distortion of the mapping from UV space to world space calculated by [calculate_uv_distortion].
+/// A value of `1.0` for both metrics indicates no distortion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UvDistortion {
+    /// The ratio of the largest to smallest stretch along any direction.
+    /// This is `1.0` if angles are preserved and infinite for triangles with zero UV area.
+    pub conformal: f32,
+    /// The ratio of the fraction of the total UV area to the fraction of the total world area.
+    /// Values below `1.0` have fewer texels than average, and values above `1.0` have more texels than average.
+    pub area: f32,
+}
+
+/// Calculates the conformal (angle) and area distortion for each triangle
+/// by comparing the triangle in world space with the triangle in UV space.
+/// The conformal distortion uses the singular values of the Jacobian of the mapping from UV space to world space.
+/// Triangles with zero world area have no distortion.
+/// # Examples
+/**
+```rust
+use geometry_tools::uv::calculate_uv_distortion;
+use glam::{Vec2, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+
+// The UVs are stretched along the U axis.
+let uvs = [Vec2::ZERO, Vec2::X * 2.0, Vec2::Y];
+
+let distortion = calculate_uv_distortion(&positions, &uvs, &[0, 1, 2]);
+assert_eq!(2.0, distortion[0].conformal);
+assert_eq!(1.0, distortion[0].area);
+```
+ */
+pub fn calculate_uv_distortion<P>(
+    positions: &[P],
+    uvs: &[Vec2],
+    indices: &[u32],
+) -> Vec<UvDistortion>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let triangles: Vec<_> = indices
+        .chunks_exact(3)
+        .map(|face| {
+            let [v0, v1, v2] = [face[0], face[1], face[2]].map(|i| positions[i as usize].into());
+            let [uv0, uv1, uv2] = [face[0], face[1], face[2]].map(|i| uvs[i as usize]);
+            ([v0, v1, v2], [uv0, uv1, uv2])
+        })
+        .collect();
+
+    let total_world_area: f32 = triangles
+        .iter()
+        .map(|([v0, v1, v2], _)| triangle_area(*v0, *v1, *v2))
+        .sum();
+    let total_uv_area: f32 = triangles
+        .iter()
+        .map(|(_, [uv0, uv1, uv2])| uv_signed_area(*uv0, *uv1, *uv2).abs())
+        .sum();
+
+    triangles
+        .iter()
+        .map(|(v, uv)| {
+            let world_area = triangle_area(v[0], v[1], v[2]);
+            let uv_area = uv_signed_area(uv[0], uv[1], uv[2]);
+            if world_area == 0.0 {
+                UvDistortion {
+                    conformal: 1.0,
+                    area: 1.0,
+                }
+            } else if uv_area == 0.0 {
+                UvDistortion {
+                    conformal: f32::INFINITY,
+                    area: 0.0,
+                }
+            } else {
+                let (max, min) = stretch_singular_values(v, uv, uv_area);
+                UvDistortion {
+                    conformal: max / min,
+                    area: (uv_area.abs() / total_uv_area) / (world_area / total_world_area),
+                }
+            }
+        })
+        .collect()
+}
+
+/// Calculates the distortion for each vertex by averaging the results of [calculate_uv_distortion]
+/// for adjacent triangles weighted by world area.
+/// Vertices not used by any triangles have no distortion.
+/// # Examples
+/**
+```rust
+use geometry_tools::uv::calculate_vertex_uv_distortion;
+use glam::{Vec2, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ZERO];
+
+let distortion = calculate_vertex_uv_distortion(&positions, &uvs, &[0, 1, 2]);
+assert_eq!(4, distortion.len());
+assert_eq!(1.0, distortion[0].conformal);
+```
+ */
+pub fn calculate_vertex_uv_distortion<P>(
+    positions: &[P],
+    uvs: &[Vec2],
+    indices: &[u32],
+) -> Vec<UvDistortion>
+where
+    P: Into<Vec3A> + Copy,
+{
+    let triangle_distortion = calculate_uv_distortion(positions, uvs, indices);
+
+    let mut conformal = vec![0.0; positions.len()];
+    let mut area = vec![0.0; positions.len()];
+    let mut weights = vec![0.0; positions.len()];
+    for (face, distortion) in indices.chunks_exact(3).zip(triangle_distortion) {
+        let [v0, v1, v2] = [face[0], face[1], face[2]].map(|i| positions[i as usize].into());
+        let weight = triangle_area(v0, v1, v2);
+        if weight > 0.0 {
+            for i in face {
+                conformal[*i as usize] += distortion.conformal * weight;
+                area[*i as usize] += distortion.area * weight;
+                weights[*i as usize] += weight;
+            }
+        }
+    }
+
+    conformal
+        .iter()
+        .zip(area)
+        .zip(weights)
+        .map(|((conformal, area), weight)| {
+            if weight > 0.0 {
+                UvDistortion {
+                    conformal: conformal / weight,
+                    area: area / weight,
+                }
+            } else {
+                UvDistortion {
+                    conformal: 1.0,
+                    area: 1.0,
+                }
+            }
+        })
+        .collect()
+}
+
+fn stretch_singular_values(v: &[Vec3A; 3], uv: &[Vec2; 3], uv_area: f32) -> (f32, f32) {
+    // Partial derivatives of position with respect to U and V.
+    // See "Texture Mapping Progressive Meshes" by Sander et al.
+    let [q0, q1, q2] = *v;
+    let [s, t] = [uv.map(|uv| uv.x), uv.map(|uv| uv.y)];
+    let ds = (q0 * (t[1] - t[2]) + q1 * (t[2] - t[0]) + q2 * (t[0] - t[1])) / (2.0 * uv_area);
+    let dt = (q0 * (s[2] - s[1]) + q1 * (s[0] - s[2]) + q2 * (s[1] - s[0])) / (2.0 * uv_area);
+
+    let a = ds.dot(ds);
+    let b = ds.dot(dt);
+    let c = dt.dot(dt);
+    let root = ((a - c) * (a - c) + 4.0 * b * b).sqrt();
+    let max = ((a + c + root) / 2.0).sqrt();
+    let min = ((a + c - root).max(0.0) / 2.0).sqrt();
+    (max, min)
+}
+
+#[inline]
+fn triangle_area(v0: Vec3A, v1: Vec3A, v2: Vec3A) -> f32 {
+    (v1 - v0).cross(v2 - v0).length() / 2.0
+}
+
+/// The area is positive for counterclockwise triangles.
+#[inline]
+pub(crate) fn uv_signed_area(uv0: Vec2, uv1: Vec2, uv2: Vec2) -> f32 {
+    (uv1 - uv0).perp_dot(uv2 - uv0) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    const EPSILON: f32 = 0.0001;
+
+    #[test]
+    fn texel_density_empty() {
+        let density = calculate_texel_density::<Vec3A>(&[], &[], &[], UVec2::new(1024, 1024));
+        assert!(density.triangle_densities.is_empty());
+        assert_eq!(0.0, density.min);
+        assert_eq!(0.0, density.max);
+        assert_eq!(0.0, density.average);
+    }
+
+    #[test]
+    fn texel_density_non_square_texture() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+        let density = calculate_texel_density(&positions, &uvs, &[0, 1, 2], UVec2::new(256, 64));
+        assert_relative_eq!(128.0, density.triangle_densities[0], epsilon = EPSILON);
+    }
+
+    #[test]
+    fn texel_density_weighted_average() {
+        // The second triangle has 4x the world area and half the density.
+        let positions = [
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+            Vec3A::new(0.0, 0.0, 1.0),
+            Vec3A::new(2.0, 0.0, 1.0),
+            Vec3A::new(0.0, 2.0, 1.0),
+        ];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ZERO, Vec2::X, Vec2::Y];
+        let indices = [0, 1, 2, 3, 4, 5];
+        let density = calculate_texel_density(&positions, &uvs, &indices, UVec2::splat(100));
+        assert_relative_eq!(50.0, density.min, epsilon = EPSILON);
+        assert_relative_eq!(100.0, density.max, epsilon = EPSILON);
+        assert_relative_eq!(
+            (100.0 * 0.5 + 50.0 * 2.0) / 2.5,
+            density.average,
+            epsilon = EPSILON
+        );
+    }
+
+    #[test]
+    fn texel_density_degenerate_triangle() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::X * 2.0, Vec3A::Y];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::Y];
+        let density =
+            calculate_texel_density(&positions, &uvs, &[0, 1, 2, 0, 1, 3], UVec2::splat(64));
+        assert_eq!(0.0, density.triangle_densities[0]);
+        assert_relative_eq!(64.0, density.min, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn overlap_empty() {
+        assert!(find_overlapping_uv_triangles(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn overlap_shared_vertex() {
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::NEG_X, Vec2::NEG_Y];
+        assert!(find_overlapping_uv_triangles(&uvs, &[0, 1, 2, 0, 3, 4]).is_empty());
+    }
+
+    #[test]
+    fn overlap_contained_triangle() {
+        let uvs = [
+            Vec2::ZERO,
+            Vec2::new(4.0, 0.0),
+            Vec2::new(0.0, 4.0),
+            Vec2::new(0.5, 0.5),
+            Vec2::new(1.0, 0.5),
+            Vec2::new(0.5, 1.0),
+        ];
+        assert_eq!(
+            vec![[0, 1]],
+            find_overlapping_uv_triangles(&uvs, &[3, 4, 5, 0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn overlap_mirrored_triangles_ignored() {
+        // Mirrored geometry often reuses the same UVs with the opposite winding.
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ZERO, Vec2::X, Vec2::Y];
+        assert!(find_overlapping_uv_triangles(&uvs, &[0, 1, 2, 3, 5, 4]).is_empty());
+    }
+
+    #[test]
+    fn overlap_separated_by_bounds() {
+        // The bounding boxes overlap but the triangles do not.
+        let uvs = [
+            Vec2::ZERO,
+            Vec2::X,
+            Vec2::Y,
+            Vec2::ONE,
+            Vec2::new(0.6, 1.0),
+            Vec2::new(1.0, 0.6),
+        ];
+        assert!(find_overlapping_uv_triangles(&uvs, &[0, 1, 2, 3, 4, 5]).is_empty());
+    }
+
+    #[test]
+    fn uv_bounds_empty() {
+        assert_eq!((Vec2::ZERO, Vec2::ZERO), calculate_uv_bounds(&[]));
+    }
+
+    #[test]
+    fn uvs_outside_unit_square_boundary() {
+        // The edges of the unit square are still inside.
+        let uvs = [Vec2::ZERO, Vec2::ONE, Vec2::X, Vec2::new(1.0001, 0.0)];
+        assert_eq!(vec![3], find_uvs_outside_unit_square(&uvs));
+    }
+
+    #[test]
+    fn coverage_empty() {
+        assert_eq!(0.0, calculate_uv_coverage(&[], &[], 16));
+        assert_eq!(0.0, calculate_uv_coverage(&[Vec2::ZERO], &[0, 0, 0], 0));
+    }
+
+    #[test]
+    fn coverage_full_overlapping() {
+        // Overlapping triangles should not count twice.
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y];
+        let indices = [0, 1, 2, 2, 3, 0, 0, 1, 2];
+        assert_eq!(1.0, calculate_uv_coverage(&uvs, &indices, 32));
+    }
+
+    #[test]
+    fn coverage_outside_unit_square() {
+        // Only the quarter of the quad inside the unit square counts.
+        let uvs = [
+            Vec2::splat(0.5),
+            Vec2::new(1.5, 0.5),
+            Vec2::splat(1.5),
+            Vec2::new(0.5, 1.5),
+        ];
+        let coverage = calculate_uv_coverage(&uvs, &[0, 1, 2, 2, 3, 0], 32);
+        assert_relative_eq!(0.25, coverage, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn flipped_uvs_empty() {
+        assert!(find_flipped_uv_triangles(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn flipped_uvs_degenerate() {
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::X * 2.0];
+        assert!(find_flipped_uv_triangles(&uvs, &[0, 2, 1]).is_empty());
+    }
+
+    #[test]
+    fn flipped_uvs_match_tangent_sign() {
+        let positions = [
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+            Vec3A::new(1.0, 0.0, 1.0),
+            Vec3A::new(2.0, 0.0, 1.0),
+            Vec3A::new(1.0, 1.0, 1.0),
+        ];
+        let normals = [Vec3A::Z; 6];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::X, Vec2::ZERO, Vec2::Y];
+        let indices = [0u32, 1, 2, 3, 4, 5];
+
+        let tangents =
+            crate::vectors::calculate_tangents(&positions, &normals, &uvs, &indices).unwrap();
+        assert_eq!(1.0, tangents[0].w);
+        assert_eq!(-1.0, tangents[3].w);
+        assert_eq!(vec![1], find_flipped_uv_triangles(&uvs, &indices));
+    }
+
+    #[test]
+    fn distortion_empty() {
+        assert!(calculate_uv_distortion::<Vec3A>(&[], &[], &[]).is_empty());
+        assert!(calculate_vertex_uv_distortion::<Vec3A>(&[], &[], &[]).is_empty());
+    }
+
+    #[test]
+    fn distortion_uniform_scale() {
+        // Uniform scaling and rotation preserve angles and relative areas.
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let uvs = [Vec2::ZERO, Vec2::new(0.0, 0.5), Vec2::new(-0.5, 0.0)];
+        let distortion = calculate_uv_distortion(&positions, &uvs, &[0, 1, 2]);
+        assert_relative_eq!(1.0, distortion[0].conformal, epsilon = EPSILON);
+        assert_relative_eq!(1.0, distortion[0].area, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn distortion_area() {
+        // The second triangle has 4x the UV area for the same world area.
+        let positions = [
+            Vec3A::ZERO,
+            Vec3A::X,
+            Vec3A::Y,
+            Vec3A::Z,
+            Vec3A::new(1.0, 0.0, 1.0),
+            Vec3A::new(0.0, 1.0, 1.0),
+        ];
+        let uvs = [
+            Vec2::ZERO,
+            Vec2::X,
+            Vec2::Y,
+            Vec2::ZERO,
+            Vec2::X * 2.0,
+            Vec2::Y * 2.0,
+        ];
+        let distortion = calculate_uv_distortion(&positions, &uvs, &[0, 1, 2, 3, 4, 5]);
+        assert_relative_eq!(0.4, distortion[0].area, epsilon = EPSILON);
+        assert_relative_eq!(1.6, distortion[1].area, epsilon = EPSILON);
+        assert_relative_eq!(1.0, distortion[1].conformal, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn distortion_degenerate_uvs() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::X * 2.0];
+        let distortion = calculate_uv_distortion(&positions, &uvs, &[0, 1, 2]);
+        assert_eq!(f32::INFINITY, distortion[0].conformal);
+        assert_eq!(0.0, distortion[0].area);
+    }
+
+    #[test]
+    fn vertex_distortion_weighted_average() {
+        // The first triangle has 4x the world area of the second triangle.
+        let positions = [
+            Vec3A::ZERO,
+            Vec3A::X * 2.0,
+            Vec3A::Y * 2.0,
+            Vec3A::NEG_X,
+            Vec3A::NEG_Y,
+        ];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::NEG_X * 2.0, Vec2::NEG_Y];
+        let triangles = calculate_uv_distortion(&positions, &uvs, &[0, 1, 2, 0, 3, 4]);
+        let vertices = calculate_vertex_uv_distortion(&positions, &uvs, &[0, 1, 2, 0, 3, 4]);
+        assert_relative_eq!(
+            (triangles[0].conformal * 4.0 + triangles[1].conformal) / 5.0,
+            vertices[0].conformal,
+            epsilon = EPSILON
+        );
+        assert_relative_eq!(triangles[1].area, vertices[3].area, epsilon = EPSILON);
+    }
+}
@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+use std::f32::consts::{PI, TAU};
+
+use glam::{Mat4, Vec2, Vec3A};
+
+/// Texture coordinates from a projection that splits vertices along seams
+/// returned by [generate_spherical_uvs], [generate_cylindrical_uvs], or [generate_box_uvs].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProjectedUvs {
+    /// The texture coordinates for each output vertex.
+    pub uvs: Vec<Vec2>,
+    /// The original vertex for each output vertex.
+    /// Use [gather_attribute](crate::remap::gather_attribute) to create the other attributes for the output vertices.
+    pub vertices: Vec<u32>,
+    /// The triangle indices for the output vertices.
+    pub indices: Vec<u32>,
+}
+
+/// Projects `positions` onto the XY plane after applying `transform`.
+/// Scale the transform to control the size of the texture on the surface.
+/// Planar projections have no seams, so each vertex keeps a single texture coordinate.
+/// # Examples
+/**
+```rust
+use geometry_tools::uv::generate_planar_uvs;
+use glam::{Mat4, Vec2, Vec3, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::new(2.0, 0.0, 1.0), Vec3A::new(0.0, 0.0, 2.0)];
+
+// Project from above with a texture repeating every 2 units.
+let transform = Mat4::from_scale(Vec3::splat(0.5)) * Mat4::from_rotation_x(-90f32.to_radians());
+let uvs = generate_planar_uvs(&positions, transform);
+assert!(uvs[1].abs_diff_eq(Vec2::new(1.0, 0.5), 1e-6));
+assert!(uvs[2].abs_diff_eq(Vec2::new(0.0, 1.0), 1e-6));
+```
+ */
+pub fn generate_planar_uvs<P>(positions: &[P], transform: Mat4) -> Vec<Vec2>
+where
+    P: Into<Vec3A> + Copy,
+{
+    positions
+        .iter()
+        .map(|p| transform.transform_point3a((*p).into()).truncate())
+        .collect()
+}
+
+/// Projects `positions` onto a sphere around the origin after applying `transform`.
+/// The texture coordinates match [generate_uv_sphere](crate::shapes::generate_uv_sphere)
+/// with `u` increasing counterclockwise around the Y axis starting from +Z and `v` from `0.0` at +Y to `1.0` at -Y.
+///
+/// Vertices of triangles crossing the seam where `u` wraps from `1.0` to `0.0` are split,
+/// so the texture does not repeat across these triangles.
+/// Vertices on the Y axis have no unique `u` coordinate and are split for each triangle to use the average `u` of the triangle.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_icosphere;
+use geometry_tools::uv::generate_spherical_uvs;
+use glam::Mat4;
+
+let sphere = generate_icosphere(1.0, 2);
+let projected = generate_spherical_uvs(&sphere.positions, &sphere.indices, Mat4::IDENTITY);
+assert!(projected.vertices.len() > sphere.positions.len());
+assert_eq!(sphere.indices.len(), projected.indices.len());
+assert!(projected.uvs.iter().all(|uv| uv.y >= 0.0 && uv.y <= 1.0));
+```
+ */
+pub fn generate_spherical_uvs<P>(positions: &[P], indices: &[u32], transform: Mat4) -> ProjectedUvs
+where
+    P: Into<Vec3A> + Copy,
+{
+    let positions = transform_positions(positions, transform);
+    split_face_uvs(indices, |face| {
+        wrap_face_uvs(face.map(|v| {
+            let p = positions[v as usize];
+            let v = (p.y / p.length()).clamp(-1.0, 1.0).acos() / PI;
+            (angle_around_y(p), if v.is_nan() { 0.5 } else { v })
+        }))
+    })
+}
+
+/// Projects `positions` onto a cylinder around the Y axis after applying `transform`.
+/// The `u` coordinate increases counterclockwise around the Y axis starting from +Z like [generate_spherical_uvs].
+/// The `v` coordinate is the Y coordinate, so scale the transform to control the texture size along the axis.
+///
+/// Vertices of triangles crossing the seam where `u` wraps from `1.0` to `0.0` are split
+/// like [generate_spherical_uvs].
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_cylinder;
+use geometry_tools::uv::generate_cylindrical_uvs;
+use glam::Mat4;
+
+let cylinder = generate_cylinder(1.0, 2.0, 16);
+let projected = generate_cylindrical_uvs(&cylinder.positions, &cylinder.indices, Mat4::IDENTITY);
+assert!(projected.uvs.iter().all(|uv| uv.y >= -1.0 && uv.y <= 1.0));
+```
+ */
+pub fn generate_cylindrical_uvs<P>(
+    positions: &[P],
+    indices: &[u32],
+    transform: Mat4,
+) -> ProjectedUvs
+where
+    P: Into<Vec3A> + Copy,
+{
+    let positions = transform_positions(positions, transform);
+    split_face_uvs(indices, |face| {
+        wrap_face_uvs(face.map(|v| {
+            let p = positions[v as usize];
+            (angle_around_y(p), p.y)
+        }))
+    })
+}
+
+/// Projects each triangle onto the axis-aligned plane most facing its normal after applying `transform`
+/// similar to triplanar mapping.
+/// Scale the transform to control the size of the texture on the surface.
+///
+/// The projections are oriented so textures are not mirrored when viewed from outside the mesh.
+/// Faces pointing along X and Z use Y as the `v` direction,
+/// and faces pointing along Y use Z as the `v` direction.
+/// Vertices shared by triangles using different planes are split.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_icosphere;
+use geometry_tools::uv::generate_box_uvs;
+use geometry_tools::remap::gather_attribute;
+use glam::Mat4;
+
+let sphere = generate_icosphere(1.0, 1);
+let projected = generate_box_uvs(&sphere.positions, &sphere.indices, Mat4::IDENTITY);
+
+// Split the other attributes to match the new vertices.
+let positions = gather_attribute(&sphere.positions, &projected.vertices);
+let normals = gather_attribute(&sphere.normals, &projected.vertices);
+assert_eq!(projected.uvs.len(), positions.len());
+```
+ */
+pub fn generate_box_uvs<P>(positions: &[P], indices: &[u32], transform: Mat4) -> ProjectedUvs
+where
+    P: Into<Vec3A> + Copy,
+{
+    let positions = transform_positions(positions, transform);
+    split_face_uvs(indices, |face| {
+        let [p0, p1, p2] = face.map(|v| positions[v as usize]);
+        let normal = (p1 - p0).cross(p2 - p0);
+        let abs = normal.abs();
+        let project = |p: Vec3A| {
+            if abs.x >= abs.y && abs.x >= abs.z {
+                Vec2::new(-p.z * normal.x.signum(), p.y)
+            } else if abs.y >= abs.z {
+                Vec2::new(p.x, -p.z * normal.y.signum())
+            } else {
+                Vec2::new(p.x * normal.z.signum(), p.y)
+            }
+        };
+        [p0, p1, p2].map(project)
+    })
+}
+
+fn transform_positions<P>(positions: &[P], transform: Mat4) -> Vec<Vec3A>
+where
+    P: Into<Vec3A> + Copy,
+{
+    positions
+        .iter()
+        .map(|p| transform.transform_point3a((*p).into()))
+        .collect()
+}
+
+/// The angle around the Y axis in the range `0.0..1.0` or [None] for points on the axis.
+fn angle_around_y(p: Vec3A) -> Option<f32> {
+    // Allow for rounding errors in points generated on the axis.
+    if Vec2::new(p.x, p.z).length() <= p.length() * 1e-5 {
+        None
+    } else {
+        Some(p.x.atan2(p.z).rem_euclid(TAU) / TAU)
+    }
+}
+
+/// Moves `u` coordinates across the seam to be continuous within the face
+/// and replaces undefined `u` coordinates on the axis with the average of the other vertices.
+fn wrap_face_uvs(uvs: [(Option<f32>, f32); 3]) -> [Vec2; 3] {
+    let us: Vec<f32> = uvs.iter().filter_map(|(u, _)| *u).collect();
+    let (min, max) = us.iter().fold((f32::MAX, f32::MIN), |(min, max), u| {
+        (min.min(*u), max.max(*u))
+    });
+    let wrap = |u: f32| {
+        if max - min > 0.5 && u < 0.5 {
+            u + 1.0
+        } else {
+            u
+        }
+    };
+
+    let average = if us.is_empty() {
+        0.0
+    } else {
+        us.iter().map(|u| wrap(*u)).sum::<f32>() / us.len() as f32
+    };
+    uvs.map(|(u, v)| Vec2::new(u.map(wrap).unwrap_or(average), v))
+}
+
+/// Creates a new vertex for each unique combination of vertex and texture coordinate
+/// in the order they first appear in `indices`.
+fn split_face_uvs(indices: &[u32], face_uvs: impl Fn([u32; 3]) -> [Vec2; 3]) -> ProjectedUvs {
+    let mut projected = ProjectedUvs::default();
+    let mut unique_vertices = HashMap::new();
+    for face in indices.chunks_exact(3) {
+        let face = [face[0], face[1], face[2]];
+        for (v, uv) in face.into_iter().zip(face_uvs(face)) {
+            let index = *unique_vertices
+                .entry((v, uv.to_array().map(f32::to_bits)))
+                .or_insert_with(|| {
+                    projected.uvs.push(uv);
+                    projected.vertices.push(v);
+                    projected.vertices.len() as u32 - 1
+                });
+            projected.indices.push(index);
+        }
+    }
+    projected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::shapes::{generate_cube, generate_uv_sphere};
+    use crate::uv::find_flipped_uv_triangles;
+
+    #[test]
+    fn projections_empty() {
+        assert!(generate_planar_uvs::<Vec3A>(&[], Mat4::IDENTITY).is_empty());
+        assert_eq!(
+            ProjectedUvs::default(),
+            generate_spherical_uvs::<Vec3A>(&[], &[], Mat4::IDENTITY)
+        );
+        assert_eq!(
+            ProjectedUvs::default(),
+            generate_cylindrical_uvs::<Vec3A>(&[], &[], Mat4::IDENTITY)
+        );
+        assert_eq!(
+            ProjectedUvs::default(),
+            generate_box_uvs::<Vec3A>(&[], &[], Mat4::IDENTITY)
+        );
+    }
+
+    #[test]
+    fn spherical_uvs_match_uv_sphere() {
+        let sphere = generate_uv_sphere(2.0, 16, 8);
+        let projected = generate_spherical_uvs(&sphere.positions, &sphere.indices, Mat4::IDENTITY);
+        for (i, v) in projected.indices.iter().enumerate() {
+            let expected = sphere.uvs[sphere.indices[i] as usize];
+            let uv = projected.uvs[*v as usize];
+            // The poles use the average u of each triangle.
+            if expected.y != 0.0 && expected.y != 1.0 {
+                assert!(uv.abs_diff_eq(expected, 0.0001), "{uv} != {expected}");
+            }
+        }
+    }
+
+    #[test]
+    fn spherical_uvs_seam() {
+        // A triangle crossing the seam at +Z.
+        let positions = [
+            Vec3A::new(-0.1, 0.0, 1.0),
+            Vec3A::new(0.1, 0.0, 1.0),
+            Vec3A::new(0.0, 1.0, 1.0),
+        ];
+        let projected = generate_spherical_uvs(&positions, &[0, 1, 2], Mat4::IDENTITY);
+        let [u0, u1, u2] = [0, 1, 2].map(|i| projected.uvs[i].x);
+        assert!(u0 > 0.5 && u0 < 1.0);
+        assert!(u1 > 1.0);
+        assert!((u2 - 1.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn box_uvs_cube() {
+        let cube = generate_cube(2.0);
+        let projected = generate_box_uvs(&cube.positions, &cube.indices, Mat4::IDENTITY);
+        // The cube already has separate vertices for each face.
+        assert_eq!(cube.positions.len(), projected.vertices.len());
+        assert!(projected.uvs.iter().all(|uv| uv.abs() == Vec2::ONE));
+        // The projections are not mirrored.
+        assert!(find_flipped_uv_triangles(&projected.uvs, &projected.indices).is_empty());
+    }
+
+    #[test]
+    fn box_uvs_split_shared_vertices() {
+        // A shared corner vertex for faces pointing along X, Y, and Z.
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::Z];
+        let indices = [0, 2, 3, 0, 3, 1, 0, 1, 2];
+        let projected = generate_box_uvs(&positions, &indices, Mat4::IDENTITY);
+        assert_eq!(3, projected.vertices.iter().filter(|v| **v == 0).count());
+    }
+}
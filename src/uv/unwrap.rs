@@ -0,0 +1,560 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use glam::{DVec2, Vec2, Vec3A};
+
+use crate::topology::edge_key;
+
+/// Parameters for [generate_unwrapped_uvs].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnwrapOptions {
+    /// The maximum angle in radians between the normal of a triangle and the average normal of its chart.
+    /// Smaller angles create more charts with less distortion.
+    pub max_chart_angle: f32,
+    /// The minimum space between charts and around the border of the unit square in UV units.
+    pub padding: f32,
+}
+
+impl Default for UnwrapOptions {
+    fn default() -> Self {
+        Self {
+            max_chart_angle: 60f32.to_radians(),
+            padding: 0.01,
+        }
+    }
+}
+
+/// The result of [generate_unwrapped_uvs].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UnwrappedUvs {
+    /// The texture coordinates for each output vertex in the range `0.0` to `1.0`.
+    pub uvs: Vec<Vec2>,
+    /// The original vertex for each output vertex.
+    /// Use [gather_attribute](crate::remap::gather_attribute) to create the other attributes for the output vertices.
+    pub vertices: Vec<u32>,
+    /// The triangle indices for the output vertices.
+    pub indices: Vec<u32>,
+    /// The chart for each triangle.
+    pub charts: Vec<u32>,
+}
+
+/// Generates non overlapping texture coordinates for baking ambient occlusion or lightmaps
+/// for meshes without suitable UVs like procedural or scanned geometry.
+///
+/// Triangles are grouped into charts by growing regions of connected triangles with similar normals.
+/// Charts are only connected across edges shared by exactly two triangles that are not in `seams`,
+/// which contains additional edges as pairs of vertex indices to cut along.
+/// Each chart is flattened using least squares conformal maps (LSCM)
+/// and the charts are packed into the unit square with the same texel density.
+///
+/// Vertices are split along the chart boundaries.
+/// The results are not as efficient as an artist created unwrap but have low angle distortion.
+/// # Examples
+/**
+```rust
+use geometry_tools::shapes::generate_cube;
+use geometry_tools::uv::{generate_unwrapped_uvs, find_overlapping_uv_triangles, UnwrapOptions};
+
+let cube = generate_cube(1.0);
+let unwrapped = generate_unwrapped_uvs(&cube.positions, &cube.indices, &[], UnwrapOptions::default());
+
+// Each face of the cube is a separate chart.
+assert_eq!(Some(&5), unwrapped.charts.iter().max());
+assert!(find_overlapping_uv_triangles(&unwrapped.uvs, &unwrapped.indices).is_empty());
+```
+ */
+pub fn generate_unwrapped_uvs<P>(
+    positions: &[P],
+    indices: &[u32],
+    seams: &[[u32; 2]],
+    options: UnwrapOptions,
+) -> UnwrappedUvs
+where
+    P: Into<Vec3A> + Copy,
+{
+    let positions: Vec<Vec3A> = positions.iter().map(|p| (*p).into()).collect();
+    let faces: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|f| [f[0], f[1], f[2]])
+        .collect();
+
+    let charts = find_charts(&positions, &faces, seams, options.max_chart_angle);
+    let chart_count = charts.iter().max().map(|c| c + 1).unwrap_or(0);
+
+    let mut chart_faces = vec![Vec::new(); chart_count as usize];
+    for (f, chart) in charts.iter().enumerate() {
+        chart_faces[*chart as usize].push(f);
+    }
+
+    // Each chart has its own copy of its vertices.
+    let mut unwrapped = UnwrappedUvs {
+        indices: vec![0; faces.len() * 3],
+        charts,
+        ..Default::default()
+    };
+    let mut chart_ranges = Vec::new();
+    for faces_in_chart in &chart_faces {
+        let start = unwrapped.vertices.len();
+        let mut chart_vertices = HashMap::new();
+        let mut chart_faces = Vec::new();
+        for f in faces_in_chart {
+            let face = faces[*f].map(|v| {
+                *chart_vertices.entry(v).or_insert_with(|| {
+                    unwrapped.vertices.push(v);
+                    (unwrapped.vertices.len() - start) as u32 - 1
+                })
+            });
+            for (i, v) in face.iter().enumerate() {
+                unwrapped.indices[f * 3 + i] = *v + start as u32;
+            }
+            chart_faces.push(face);
+        }
+
+        let chart_positions: Vec<_> = unwrapped.vertices[start..]
+            .iter()
+            .map(|v| positions[*v as usize])
+            .collect();
+        unwrapped
+            .uvs
+            .extend(parameterize_chart(&chart_positions, &chart_faces));
+        chart_ranges.push(start..unwrapped.vertices.len());
+    }
+
+    pack_charts(&mut unwrapped.uvs, &chart_ranges, options.padding);
+    unwrapped
+}
+
+/// Assigns each face to a chart by flood filling faces with similar normals.
+fn find_charts(
+    positions: &[Vec3A],
+    faces: &[[u32; 3]],
+    seams: &[[u32; 2]],
+    max_angle: f32,
+) -> Vec<u32> {
+    let seams: HashSet<_> = seams.iter().map(|[a, b]| edge_key(*a, *b)).collect();
+
+    // Sorting is faster than hashing and gives a deterministic order.
+    let mut edges: Vec<([u32; 2], u32)> = faces
+        .iter()
+        .enumerate()
+        .flat_map(|(f, [v0, v1, v2])| {
+            [(*v0, *v1), (*v1, *v2), (*v2, *v0)].map(|(a, b)| (edge_key(a, b), f as u32))
+        })
+        .filter(|(edge, _)| edge[0] != edge[1] && !seams.contains(edge))
+        .collect();
+    edges.sort_unstable();
+
+    let mut face_neighbors = vec![Vec::new(); faces.len()];
+    for run in edges.chunk_by(|a, b| a.0 == b.0) {
+        // Only cross manifold edges to keep charts simple to flatten.
+        if let [(_, f0), (_, f1)] = run {
+            face_neighbors[*f0 as usize].push(*f1);
+            face_neighbors[*f1 as usize].push(*f0);
+        }
+    }
+
+    let face_normals: Vec<Vec3A> = faces
+        .iter()
+        .map(|f| {
+            let [p0, p1, p2] = f.map(|v| positions[v as usize]);
+            (p1 - p0).cross(p2 - p0)
+        })
+        .collect();
+
+    let min_cos_angle = max_angle.cos();
+    let mut charts = vec![u32::MAX; faces.len()];
+    let mut chart_count = 0;
+    let mut queue = VecDeque::new();
+    for seed in 0..faces.len() {
+        if charts[seed] != u32::MAX {
+            continue;
+        }
+
+        charts[seed] = chart_count;
+        let mut normal_sum = face_normals[seed];
+        queue.push_back(seed);
+        while let Some(f) = queue.pop_front() {
+            for neighbor in &face_neighbors[f] {
+                let neighbor = *neighbor as usize;
+                let normal = face_normals[neighbor];
+                if charts[neighbor] == u32::MAX
+                    && normal
+                        .normalize_or_zero()
+                        .dot(normal_sum.normalize_or_zero())
+                        >= min_cos_angle
+                {
+                    charts[neighbor] = chart_count;
+                    normal_sum += normal;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        chart_count += 1;
+    }
+    charts
+}
+
+/// Flattens a chart using least squares conformal maps (LSCM).
+/// See "Least Squares Conformal Maps for Automatic Texture Atlas Generation" by Lévy et al.
+fn parameterize_chart(positions: &[Vec3A], faces: &[[u32; 3]]) -> Vec<Vec2> {
+    // Pin the two vertices farthest apart along the longest axis.
+    let (min, max) = positions.iter().fold(
+        (Vec3A::splat(f32::MAX), Vec3A::splat(f32::MIN)),
+        |(min, max), p| (min.min(*p), max.max(*p)),
+    );
+    let extent = max - min;
+    let axis = if extent.x >= extent.y && extent.x >= extent.z {
+        0
+    } else if extent.y >= extent.z {
+        1
+    } else {
+        2
+    };
+    let pin0 = (0..positions.len())
+        .min_by(|a, b| positions[*a][axis].total_cmp(&positions[*b][axis]))
+        .unwrap();
+    let pin1 = (0..positions.len())
+        .max_by(|a, b| positions[*a][axis].total_cmp(&positions[*b][axis]))
+        .unwrap();
+
+    // Start from a projection onto the average plane of the chart with the pins along the u axis.
+    let normal = faces
+        .iter()
+        .map(|f| {
+            let [p0, p1, p2] = f.map(|v| positions[v as usize]);
+            (p1 - p0).cross(p2 - p0)
+        })
+        .sum::<Vec3A>()
+        .normalize_or_zero();
+    let pin_direction = positions[pin1] - positions[pin0];
+    let u_axis = (pin_direction - normal * normal.dot(pin_direction)).normalize_or_zero();
+    if u_axis == Vec3A::ZERO {
+        // The chart is degenerate, so there is nothing to flatten.
+        return vec![Vec2::ZERO; positions.len()];
+    }
+    let v_axis = normal.cross(u_axis);
+    let mut uvs: Vec<DVec2> = positions
+        .iter()
+        .map(|p| {
+            let d = *p - positions[pin0];
+            DVec2::new(d.dot(u_axis) as f64, d.dot(v_axis) as f64)
+        })
+        .collect();
+
+    // The conformal energy of each triangle has two rows with weights for the u and v of each vertex.
+    let mut rows = Vec::new();
+    for face in faces {
+        let [p0, p1, p2] = face.map(|v| positions[v as usize]);
+        let (e1, e2) = (p1 - p0, p2 - p0);
+        let double_area = e1.cross(e2).length();
+        if double_area == 0.0 {
+            continue;
+        }
+
+        // Express the triangle in an orthonormal basis for its plane.
+        let x_axis = e1 / e1.length();
+        let y_axis = e1.cross(e2).cross(e1).normalize();
+        let local = [
+            DVec2::ZERO,
+            DVec2::new(e1.length() as f64, 0.0),
+            DVec2::new(e2.dot(x_axis) as f64, e2.dot(y_axis) as f64),
+        ];
+        let scale = 1.0 / (double_area as f64).sqrt();
+        let w = [0, 1, 2].map(|j| (local[(j + 2) % 3] - local[(j + 1) % 3]) * scale);
+        rows.push(
+            face.map(|v| v as usize)
+                .into_iter()
+                .zip(w)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    solve_lscm(&mut uvs, &rows, [pin0, pin1]);
+
+    // The orientation of the solution depends on the basis, so mirror to keep triangles counterclockwise.
+    let signed_area: f64 = faces
+        .iter()
+        .map(|f| {
+            let [a, b, c] = f.map(|v| uvs[v as usize]);
+            (b - a).perp_dot(c - a)
+        })
+        .sum();
+    if signed_area < 0.0 {
+        for uv in &mut uvs {
+            uv.y = -uv.y;
+        }
+    }
+
+    uvs.iter().map(|uv| uv.as_vec2()).collect()
+}
+
+/// Minimizes the conformal energy with the pinned vertices fixed
+/// using conjugate gradients on the normal equations (CGLS).
+fn solve_lscm(uvs: &mut [DVec2], rows: &[Vec<(usize, DVec2)>], pins: [usize; 2]) {
+    // Each complex row `sum(w * (u + iv))` is a real and an imaginary row.
+    let apply = |x: &[DVec2]| -> Vec<DVec2> {
+        rows.iter()
+            .map(|row| {
+                row.iter().fold(DVec2::ZERO, |sum, (v, w)| {
+                    let uv = x[*v];
+                    sum + DVec2::new(w.x * uv.x - w.y * uv.y, w.x * uv.y + w.y * uv.x)
+                })
+            })
+            .collect()
+    };
+    let vertex_count = uvs.len();
+    let apply_transpose = |r: &[DVec2]| -> Vec<DVec2> {
+        let mut x = vec![DVec2::ZERO; vertex_count];
+        for (row, r) in rows.iter().zip(r) {
+            for (v, w) in row {
+                if !pins.contains(v) {
+                    x[*v] += DVec2::new(w.x * r.x + w.y * r.y, w.x * r.y - w.y * r.x);
+                }
+            }
+        }
+        x
+    };
+    let dot = |a: &[DVec2], b: &[DVec2]| -> f64 { a.iter().zip(b).map(|(a, b)| a.dot(*b)).sum() };
+
+    let mut residual: Vec<DVec2> = apply(uvs).iter().map(|r| -*r).collect();
+    let mut gradient = apply_transpose(&residual);
+    let mut direction = gradient.clone();
+    let mut gamma = dot(&gradient, &gradient);
+    let tolerance = gamma * 1e-20;
+
+    let max_iterations = vertex_count * 2 + 100;
+    for _ in 0..max_iterations {
+        if gamma <= tolerance || gamma == 0.0 {
+            break;
+        }
+
+        let q = apply(&direction);
+        let q_length = dot(&q, &q);
+        if q_length == 0.0 {
+            break;
+        }
+        let alpha = gamma / q_length;
+        for (uv, d) in uvs.iter_mut().zip(&direction) {
+            *uv += *d * alpha;
+        }
+        for (r, q) in residual.iter_mut().zip(&q) {
+            *r -= *q * alpha;
+        }
+
+        gradient = apply_transpose(&residual);
+        let new_gamma = dot(&gradient, &gradient);
+        let beta = new_gamma / gamma;
+        gamma = new_gamma;
+        for (d, g) in direction.iter_mut().zip(&gradient) {
+            *d = *g + *d * beta;
+        }
+    }
+}
+
+/// Packs the charts into rows and scales the result to fit in the unit square.
+fn pack_charts(uvs: &mut [Vec2], chart_ranges: &[std::ops::Range<usize>], padding: f32) {
+    // Move each chart to start at the origin.
+    let sizes: Vec<Vec2> = chart_ranges
+        .iter()
+        .map(|range| {
+            let chart = &mut uvs[range.clone()];
+            let (min, max) = chart.iter().fold(
+                (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+                |(min, max), uv| (min.min(*uv), max.max(*uv)),
+            );
+            for uv in chart.iter_mut() {
+                *uv -= min;
+            }
+            max - min
+        })
+        .collect();
+    if sizes.is_empty() {
+        return;
+    }
+
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by(|a, b| sizes[*b].y.total_cmp(&sizes[*a].y).then(a.cmp(b)));
+
+    // The padding depends on the final scale, so repack until the padding is large enough.
+    let mut side = sizes.iter().map(|s| s.x * s.y).sum::<f32>().sqrt();
+    let mut offsets = vec![Vec2::ZERO; sizes.len()];
+    for _ in 0..16 {
+        let gap = padding * side;
+        let width = sizes
+            .iter()
+            .map(|s| (*s + gap).x * (*s + gap).y)
+            .sum::<f32>()
+            .sqrt()
+            .max(sizes.iter().map(|s| s.x).fold(0.0, f32::max) + gap * 2.0);
+
+        let mut position = Vec2::splat(gap);
+        let mut row_height = 0.0f32;
+        let mut extent = Vec2::ZERO;
+        for i in &order {
+            let size = sizes[*i];
+            if position.x + size.x + gap > width && position.x > gap {
+                position = Vec2::new(gap, position.y + row_height + gap);
+                row_height = 0.0;
+            }
+            offsets[*i] = position;
+            position.x += size.x + gap;
+            row_height = row_height.max(size.y);
+            extent = extent.max(position + Vec2::new(0.0, row_height + gap));
+        }
+
+        let packed_side = extent.max_element();
+        if packed_side <= side || packed_side == 0.0 {
+            side = packed_side;
+            break;
+        }
+        side = packed_side;
+    }
+
+    let scale = if side > 0.0 { 1.0 / side } else { 0.0 };
+    for (range, offset) in chart_ranges.iter().zip(&offsets) {
+        for uv in &mut uvs[range.clone()] {
+            *uv = (*uv + *offset) * scale;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::shapes::{generate_cube, generate_cylinder, generate_icosphere, generate_plane};
+    use crate::uv::{
+        calculate_uv_distortion, find_flipped_uv_triangles, find_overlapping_uv_triangles,
+    };
+    use glam::UVec2;
+
+    fn check_unwrap(unwrapped: &UnwrappedUvs) {
+        assert!(unwrapped
+            .uvs
+            .iter()
+            .all(|uv| uv.min_element() >= 0.0 && uv.max_element() <= 1.0));
+        assert!(find_flipped_uv_triangles(&unwrapped.uvs, &unwrapped.indices).is_empty());
+        assert!(find_overlapping_uv_triangles(&unwrapped.uvs, &unwrapped.indices).is_empty());
+    }
+
+    #[test]
+    fn unwrap_empty() {
+        assert_eq!(
+            UnwrappedUvs::default(),
+            generate_unwrapped_uvs::<Vec3A>(&[], &[], &[], UnwrapOptions::default())
+        );
+    }
+
+    #[test]
+    fn unwrap_plane_no_distortion() {
+        let plane = generate_plane(Vec2::new(2.0, 1.0), UVec2::new(4, 4));
+        let unwrapped = generate_unwrapped_uvs(
+            &plane.positions,
+            &plane.indices,
+            &[],
+            UnwrapOptions::default(),
+        );
+        assert_eq!(vec![0; plane.indices.len() / 3], unwrapped.charts);
+        assert_eq!(plane.positions.len(), unwrapped.vertices.len());
+        check_unwrap(&unwrapped);
+
+        let positions: Vec<_> = unwrapped
+            .vertices
+            .iter()
+            .map(|v| plane.positions[*v as usize])
+            .collect();
+        for d in calculate_uv_distortion(&positions, &unwrapped.uvs, &unwrapped.indices) {
+            assert!((d.conformal - 1.0).abs() < 0.001);
+            assert!((d.area - 1.0).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn unwrap_cube() {
+        let cube = generate_cube(1.0);
+        let unwrapped = generate_unwrapped_uvs(
+            &cube.positions,
+            &cube.indices,
+            &[],
+            UnwrapOptions::default(),
+        );
+        check_unwrap(&unwrapped);
+
+        // Check the padding between charts.
+        let options = UnwrapOptions {
+            padding: 0.1,
+            ..Default::default()
+        };
+        let unwrapped = generate_unwrapped_uvs(&cube.positions, &cube.indices, &[], options);
+        check_unwrap(&unwrapped);
+        for uv in &unwrapped.uvs {
+            assert!(uv.min_element() >= 0.1 - 1e-6 && uv.max_element() <= 0.9 + 1e-6);
+        }
+    }
+
+    #[test]
+    fn unwrap_sphere() {
+        let sphere = generate_icosphere(1.0, 3);
+        let unwrapped = generate_unwrapped_uvs(
+            &sphere.positions,
+            &sphere.indices,
+            &[],
+            UnwrapOptions::default(),
+        );
+        assert!(unwrapped.charts.iter().max().unwrap() > &1);
+        check_unwrap(&unwrapped);
+    }
+
+    #[test]
+    fn unwrap_seams() {
+        // Split a plane into two charts along a seam.
+        let plane = generate_plane(Vec2::ONE, UVec2::new(2, 1));
+        let seams = [[1, 4]];
+        let unwrapped = generate_unwrapped_uvs(
+            &plane.positions,
+            &plane.indices,
+            &seams,
+            UnwrapOptions::default(),
+        );
+        assert_eq!(Some(&1), unwrapped.charts.iter().max());
+        check_unwrap(&unwrapped);
+    }
+
+    #[test]
+    fn unwrap_cylinder_angle() {
+        let cylinder = generate_cylinder(1.0, 2.0, 32);
+        let charts = |max_chart_angle: f32| {
+            let options = UnwrapOptions {
+                max_chart_angle,
+                ..Default::default()
+            };
+            let unwrapped =
+                generate_unwrapped_uvs(&cylinder.positions, &cylinder.indices, &[], options);
+            check_unwrap(&unwrapped);
+            unwrapped.charts.iter().max().unwrap() + 1
+        };
+        assert!(charts(30f32.to_radians()) > charts(80f32.to_radians()));
+    }
+
+    #[test]
+    fn unwrap_cylinder_side_no_angle_distortion() {
+        // The side of a cylinder can be flattened without distortion.
+        let cylinder = generate_cylinder(1.0, 2.0, 32);
+        let side_indices = &cylinder.indices[..32 * 6];
+        let options = UnwrapOptions {
+            max_chart_angle: 170f32.to_radians(),
+            ..Default::default()
+        };
+        let unwrapped = generate_unwrapped_uvs(&cylinder.positions, side_indices, &[], options);
+        assert!(unwrapped.charts.iter().max().unwrap() < &3);
+        check_unwrap(&unwrapped);
+
+        let positions: Vec<_> = unwrapped
+            .vertices
+            .iter()
+            .map(|v| cylinder.positions[*v as usize])
+            .collect();
+        for d in calculate_uv_distortion(&positions, &unwrapped.uvs, &unwrapped.indices) {
+            assert!((d.conformal - 1.0).abs() < 0.01, "{d:?}");
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Functions for detecting invalid or problematic mesh data.
+
+use std::collections::HashMap;
+
+use glam::{Vec2, Vec3A};
+use thiserror::Error;
+
+/// Errors for index buffers that are not valid triangle lists.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexError {
+    #[error(
+        "A vertex index count of {} is not supported. Expected {} to be divisible by 3.",
+        index_count,
+        index_count
+    )]
+    InvalidIndexCount { index_count: usize },
+
+    #[error(
+        "Vertex index {} at position {} is out of range for a vertex count of {}.",
+        index,
+        position,
+        vertex_count
+    )]
+    IndexOutOfRange {
+        position: usize,
+        index: u32,
+        vertex_count: usize,
+    },
+}
+
+/// The number of elements in each vertex attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttributeCounts {
+    pub position_count: usize,
+    pub normal_count: usize,
+    pub uv_count: usize,
+}
+
+/// The problems found by [validate_mesh].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationReport {
+    /// The number of indices after the last complete triangle.
+    pub trailing_index_count: usize,
+    /// The positions in the index buffer of indices that are out of range for the vertex count.
+    pub out_of_range_indices: Vec<usize>,
+    /// The vertex attribute counts if the normals or UVs do not match the position count.
+    pub attribute_count_mismatch: Option<AttributeCounts>,
+    /// Vertices with a position component that is `NaN` or infinite.
+    pub non_finite_positions: Vec<u32>,
+    /// Vertices with a normal component that is `NaN` or infinite.
+    pub non_finite_normals: Vec<u32>,
+    /// Vertices with a UV component that is `NaN` or infinite.
+    pub non_finite_uvs: Vec<u32>,
+    /// Triangles with repeated vertex indices or zero area.
+    pub degenerate_triangles: Vec<u32>,
+    /// Pairs of `(duplicate, original)` vertices with identical attributes.
+    pub duplicate_vertices: Vec<[u32; 2]>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if the mesh has no problems that would prevent other computations
+    /// from producing correct results.
+    /// Duplicate vertices are reported but do not make the mesh invalid.
+    pub fn is_valid(&self) -> bool {
+        self.trailing_index_count == 0
+            && self.out_of_range_indices.is_empty()
+            && self.attribute_count_mismatch.is_none()
+            && self.non_finite_positions.is_empty()
+            && self.non_finite_normals.is_empty()
+            && self.non_finite_uvs.is_empty()
+            && self.degenerate_triangles.is_empty()
+    }
+}
+
+/// Checks the triangle list defined by `positions` and `indices` for problems
+/// like out of range indices, `NaN` or infinite values, and degenerate triangles.
+/// Empty `normals` or `uvs` are treated as missing attributes and are not validated.
+/// # Examples
+/**
+```rust
+use geometry_tools::validation::validate_mesh;
+use glam::{Vec2, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::NAN];
+let normals = [Vec3A::Z; 4];
+let uvs: [Vec2; 0] = [];
+let indices = [0, 1, 2, 0, 1, 5];
+
+let report = validate_mesh(&positions, &normals, &uvs, &indices);
+assert!(!report.is_valid());
+assert_eq!(vec![5], report.out_of_range_indices);
+assert_eq!(vec![3], report.non_finite_positions);
+```
+ */
+pub fn validate_mesh<P, N>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[u32],
+) -> ValidationReport
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+    let normals: Vec<Vec3A> = normals.iter().copied().map(Into::into).collect();
+
+    let attribute_count_mismatch = if (normals.is_empty() || normals.len() == positions.len())
+        && (uvs.is_empty() || uvs.len() == positions.len())
+    {
+        None
+    } else {
+        Some(AttributeCounts {
+            position_count: positions.len(),
+            normal_count: normals.len(),
+            uv_count: uvs.len(),
+        })
+    };
+
+    let out_of_range_indices = indices
+        .iter()
+        .enumerate()
+        .filter(|(_, index)| **index as usize >= positions.len())
+        .map(|(i, _)| i)
+        .collect();
+
+    let degenerate_triangles = indices
+        .chunks_exact(3)
+        .enumerate()
+        .filter(|(_, face)| face.iter().all(|i| (*i as usize) < positions.len()))
+        .filter(|(_, face)| {
+            let [v0, v1, v2] = [face[0], face[1], face[2]].map(|i| positions[i as usize]);
+            face[0] == face[1]
+                || face[1] == face[2]
+                || face[2] == face[0]
+                || (v1 - v0).cross(v2 - v0).length_squared() == 0.0
+        })
+        .map(|(i, _)| i as u32)
+        .collect();
+
+    ValidationReport {
+        trailing_index_count: indices.len() % 3,
+        out_of_range_indices,
+        attribute_count_mismatch,
+        non_finite_positions: non_finite(&positions, |p| p.is_finite()),
+        non_finite_normals: non_finite(&normals, |n| n.is_finite()),
+        non_finite_uvs: non_finite(uvs, |uv| uv.is_finite()),
+        degenerate_triangles,
+        duplicate_vertices: duplicate_vertices(&positions, &normals, uvs),
+    }
+}
+
+/// Checks that `indices` contains only complete triangles with indices less than `vertex_count`.
+///
+/// Most functions ignore any indices after the last complete triangle and panic on out of range indices.
+/// The `_strict` variants like [calculate_smooth_normals_strict](crate::vectors::calculate_smooth_normals_strict)
+/// use this check to return an error before processing any faces instead.
+/// # Examples
+/**
+```rust
+use geometry_tools::validation::{check_triangle_indices, IndexError};
+
+assert_eq!(Ok(()), check_triangle_indices(&[0u32, 1, 2], 3));
+assert_eq!(
+    Err(IndexError::InvalidIndexCount { index_count: 4 }),
+    check_triangle_indices(&[0u32, 1, 2, 0], 3)
+);
+assert_eq!(
+    Err(IndexError::IndexOutOfRange { position: 2, index: 3, vertex_count: 3 }),
+    check_triangle_indices(&[0u32, 1, 3], 3)
+);
+```
+ */
+pub fn check_triangle_indices<I>(indices: &[I], vertex_count: usize) -> Result<(), IndexError>
+where
+    I: Into<u32> + Copy,
+{
+    if !indices.len().is_multiple_of(3) {
+        return Err(IndexError::InvalidIndexCount {
+            index_count: indices.len(),
+        });
+    }
+
+    match indices
+        .iter()
+        .position(|i| (*i).into() as usize >= vertex_count)
+    {
+        Some(position) => Err(IndexError::IndexOutOfRange {
+            position,
+            index: indices[position].into(),
+            vertex_count,
+        }),
+        None => Ok(()),
+    }
+}
+
+fn non_finite<T, F>(values: &[T], is_finite: F) -> Vec<u32>
+where
+    F: Fn(&T) -> bool,
+{
+    values
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| !is_finite(v))
+        .map(|(i, _)| i as u32)
+        .collect()
+}
+
+fn duplicate_vertices(positions: &[Vec3A], normals: &[Vec3A], uvs: &[Vec2]) -> Vec<[u32; 2]> {
+    // Compare bit patterns since floats don't implement Eq or Hash.
+    let mut first_vertex = HashMap::new();
+    let mut duplicates = Vec::new();
+    for (i, position) in positions.iter().enumerate() {
+        let key = (
+            position.to_array().map(f32::to_bits),
+            normals.get(i).map(|n| n.to_array().map(f32::to_bits)),
+            uvs.get(i).map(|uv| uv.to_array().map(f32::to_bits)),
+        );
+        match first_vertex.get(&key) {
+            Some(original) => duplicates.push([i as u32, *original]),
+            None => {
+                first_vertex.insert(key, i as u32);
+            }
+        }
+    }
+    duplicates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_mesh() {
+        let report = validate_mesh::<Vec3A, Vec3A>(&[], &[], &[], &[]);
+        assert!(report.is_valid());
+        assert_eq!(ValidationReport::default(), report);
+    }
+
+    #[test]
+    fn valid_triangle() {
+        let report = validate_mesh(
+            &[Vec3A::ZERO, Vec3A::X, Vec3A::Y],
+            &[Vec3A::Z; 3],
+            &[Vec2::ZERO, Vec2::X, Vec2::Y],
+            &[0, 1, 2],
+        );
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn trailing_indices() {
+        let report =
+            validate_mesh::<_, Vec3A>(&[Vec3A::ZERO, Vec3A::X, Vec3A::Y], &[], &[], &[0, 1, 2, 0]);
+        assert_eq!(1, report.trailing_index_count);
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn attribute_count_mismatch() {
+        let report = validate_mesh(&[Vec3A::ZERO; 3], &[Vec3A::Z; 2], &[Vec2::ZERO; 3], &[]);
+        assert_eq!(
+            Some(AttributeCounts {
+                position_count: 3,
+                normal_count: 2,
+                uv_count: 3
+            }),
+            report.attribute_count_mismatch
+        );
+    }
+
+    #[test]
+    fn non_finite_attributes() {
+        let report = validate_mesh(
+            &[Vec3A::ZERO, Vec3A::INFINITY],
+            &[Vec3A::NAN, Vec3A::Z],
+            &[Vec2::ZERO, Vec2::new(0.0, f32::NEG_INFINITY)],
+            &[],
+        );
+        assert_eq!(vec![1], report.non_finite_positions);
+        assert_eq!(vec![0], report.non_finite_normals);
+        assert_eq!(vec![1], report.non_finite_uvs);
+    }
+
+    #[test]
+    fn degenerate_triangles() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y, Vec3A::X * 2.0];
+        let indices = [0, 1, 2, 0, 0, 1, 0, 1, 3, 0, 1, 9];
+        let report = validate_mesh::<_, Vec3A>(&positions, &[], &[], &indices);
+        assert_eq!(vec![1, 2], report.degenerate_triangles);
+        assert_eq!(vec![11], report.out_of_range_indices);
+    }
+
+    #[test]
+    fn duplicate_vertices_all_attributes() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::ZERO, Vec3A::ZERO];
+        let normals = [Vec3A::Z, Vec3A::Z, Vec3A::Z, Vec3A::Z];
+        let uvs = [Vec2::ZERO, Vec2::ZERO, Vec2::ZERO, Vec2::ONE];
+        let report = validate_mesh(&positions, &normals, &uvs, &[]);
+        assert_eq!(vec![[2, 0]], report.duplicate_vertices);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn check_triangle_indices_empty() {
+        assert_eq!(Ok(()), check_triangle_indices::<u32>(&[], 0));
+    }
+
+    #[test]
+    fn check_triangle_indices_trailing_before_range() {
+        // The count is checked before any of the indices.
+        assert_eq!(
+            Err(IndexError::InvalidIndexCount { index_count: 5 }),
+            check_triangle_indices(&[0u16, 1, 2, 9, 9], 3)
+        );
+    }
+
+    #[test]
+    fn check_triangle_indices_first_out_of_range() {
+        assert_eq!(
+            Err(IndexError::IndexOutOfRange {
+                position: 4,
+                index: 5,
+                vertex_count: 4
+            }),
+            check_triangle_indices(&[0u32, 1, 2, 3, 5, 6], 4)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn report_serde_round_trip() {
+        let report = validate_mesh::<_, Vec3A>(&[Vec3A::ZERO, Vec3A::NAN], &[], &[], &[0, 1, 5]);
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(report, serde_json::from_str(&json).unwrap());
+    }
+}
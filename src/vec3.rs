@@ -0,0 +1,130 @@
+//! Faster conversions for tightly packed 3 component positions.
+//!
+//! Converting [glam::Vec3] to [glam::Vec3A] for every element access adds overhead
+//! for functions that read the same vertex multiple times.
+//! Types implementing [Float3] are converted in batches once instead.
+//! Functions ending in `_vec3` such as [calculate_smooth_normals_vec3](crate::vectors::calculate_smooth_normals_vec3)
+//! use these conversions and produce the same results as the generic functions.
+
+use glam::{Vec3, Vec3A, Vec4};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for glam::Vec3 {}
+    impl Sealed for [f32; 3] {}
+}
+
+/// A tightly packed 3 component vector with the same memory layout as `[f32; 3]`.
+/// This trait is implemented for [glam::Vec3] and `[f32; 3]` and cannot be implemented outside this crate.
+pub trait Float3: Copy + Into<Vec3A> + private::Sealed {
+    /// Reinterprets `points` as arrays without copying.
+    fn as_arrays(points: &[Self]) -> &[[f32; 3]];
+}
+
+impl Float3 for [f32; 3] {
+    fn as_arrays(points: &[Self]) -> &[[f32; 3]] {
+        points
+    }
+}
+
+impl Float3 for Vec3 {
+    fn as_arrays(points: &[Self]) -> &[[f32; 3]] {
+        // Safety: Vec3 is repr(C) with three f32 fields, so it has the same size and alignment as [f32; 3].
+        unsafe { std::slice::from_raw_parts(points.as_ptr() as *const [f32; 3], points.len()) }
+    }
+}
+
+/// Converts `points` to [glam::Vec3A] by processing 4 points at a time.
+/// # Examples
+/**
+```rust
+use geometry_tools::vec3::convert_to_vec3a;
+use glam::{Vec3, Vec3A};
+
+let points = convert_to_vec3a(&[Vec3::X, Vec3::Y]);
+assert_eq!(vec![Vec3A::X, Vec3A::Y], points);
+```
+ */
+pub fn convert_to_vec3a<P: Float3>(points: &[P]) -> Vec<Vec3A> {
+    let points = P::as_arrays(points);
+
+    let mut result = Vec::with_capacity(points.len());
+    let chunks = points.chunks_exact(4);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        // Copying the whole chunk allows loading 12 floats without bounds checks.
+        let [a, b, c, d]: [[f32; 3]; 4] = chunk.try_into().unwrap();
+        result.extend([a, b, c, d].map(Vec3A::from_array));
+    }
+    result.extend(remainder.iter().copied().map(Vec3A::from_array));
+    result
+}
+
+/// Calculates the axis-aligned bounding box by comparing the x, y, and z components of 4 points at a time.
+pub(crate) fn aabb_from_arrays(points: &[[f32; 3]]) -> (Vec3A, Vec3A) {
+    if points.is_empty() {
+        return (Vec3A::ZERO, Vec3A::ZERO);
+    }
+
+    // Store the components separately to compare 4 points with a single instruction.
+    let mut min_xyz = [Vec4::splat(f32::INFINITY); 3];
+    let mut max_xyz = [Vec4::splat(f32::NEG_INFINITY); 3];
+
+    let chunks = points.chunks_exact(4);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        let [a, b, c, d]: [[f32; 3]; 4] = chunk.try_into().unwrap();
+        for i in 0..3 {
+            let values = Vec4::new(a[i], b[i], c[i], d[i]);
+            min_xyz[i] = min_xyz[i].min(values);
+            max_xyz[i] = max_xyz[i].max(values);
+        }
+    }
+
+    let mut min = Vec3A::new(
+        min_xyz[0].min_element(),
+        min_xyz[1].min_element(),
+        min_xyz[2].min_element(),
+    );
+    let mut max = Vec3A::new(
+        max_xyz[0].max_element(),
+        max_xyz[1].max_element(),
+        max_xyz[2].max_element(),
+    );
+    for point in remainder {
+        min = min.min(Vec3A::from_array(*point));
+        max = max.max(Vec3A::from_array(*point));
+    }
+    (min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_remainder() {
+        let points: Vec<_> = (0..7).map(|i| [i as f32, 1.0, -(i as f32)]).collect();
+        let expected: Vec<_> = points.iter().copied().map(Vec3A::from).collect();
+        assert_eq!(expected, convert_to_vec3a(&points));
+    }
+
+    #[test]
+    fn aabb_matches_generic() {
+        let points: Vec<_> = (0..11)
+            .map(|i| Vec3::new((i * 7 % 5) as f32, -(i as f32), (i * 3 % 4) as f32))
+            .collect();
+        assert_eq!(
+            crate::bounding::calculate_aabb_from_points(&points),
+            aabb_from_arrays(Vec3::as_arrays(&points))
+        );
+    }
+
+    #[test]
+    fn aabb_fewer_than_4_points() {
+        assert_eq!(
+            (Vec3A::new(-1.0, 0.0, 0.0), Vec3A::new(0.0, 2.0, 0.0)),
+            aabb_from_arrays(&[[0.0, 2.0, 0.0], [-1.0, 0.0, 0.0]])
+        );
+    }
+}
@@ -1,15 +1,64 @@
 //! Functions for computing normal, tangent, and bitangent (binormal) vectors.
 
-use glam::Vec3A;
+pub use deformed::*;
+use glam::{Vec2, Vec3, Vec3A, Vec4};
 pub use normal::*;
 pub use tangent::*;
+pub use workspace::*;
 
+mod deformed;
 pub(crate) mod normal;
 pub(crate) mod tangent;
+mod workspace;
 
-fn orthonormalize(target: &Vec3A, source: &Vec3A) -> Vec3A {
-    // Returns a normalized vector based on `target` that is orthogonal to `source` using the Gran-Schmidt process.
-    Vec3A::normalize(*target - *source * source.dot(*target))
+mod private {
+    pub trait Sealed {}
+}
+
+/// A vector type supported by [orthonormalize].
+/// This trait is implemented for [Vec2], [Vec3], [Vec3A], and [Vec4] and cannot be implemented outside this crate.
+pub trait Orthonormalize: Copy + private::Sealed {
+    #[doc(hidden)]
+    fn orthonormalize(self, source: Self) -> Self;
+}
+
+macro_rules! impl_orthonormalize {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl Orthonormalize for $t {
+                #[inline(always)]
+                fn orthonormalize(self, source: Self) -> Self {
+                    (self - source * source.dot(self)).normalize_or_zero()
+                }
+            }
+        )*
+    };
+}
+
+impl_orthonormalize!(Vec2, Vec3, Vec3A, Vec4);
+
+/// Returns a normalized vector based on `target` that is orthogonal to the normalized vector `source`
+/// using a single step of the Gram-Schmidt process.
+/// This is the same step used by [calculate_tangents] to make bitangents orthogonal to the normals.
+///
+/// If `target` is parallel to `source` or zero, the result is zero.
+/// # Examples
+/**
+```rust
+use geometry_tools::vectors::orthonormalize;
+use glam::{Vec2, Vec3A};
+
+let bitangent = orthonormalize(&Vec3A::new(1.0, 1.0, 0.0), &Vec3A::X);
+assert_eq!(Vec3A::Y, bitangent);
+
+assert_eq!(Vec2::Y, orthonormalize(&Vec2::new(0.5, 2.0), &Vec2::X));
+assert_eq!(Vec2::ZERO, orthonormalize(&Vec2::new(2.0, 0.0), &Vec2::X));
+```
+ */
+pub fn orthonormalize<V: Orthonormalize>(target: &V, source: &V) -> V {
+    target.orthonormalize(*source)
 }
 
 #[cfg(test)]
@@ -44,4 +93,22 @@ mod tests {
         let a_ortho_to_b = orthonormalize(&a, &b);
         assert_eq!(a, a_ortho_to_b);
     }
+
+    #[test]
+    fn orthonormalize_parallel() {
+        assert_eq!(
+            Vec3A::ZERO,
+            orthonormalize(&Vec3A::new(-2.0, 0.0, 0.0), &Vec3A::X)
+        );
+        assert_eq!(Vec3A::ZERO, orthonormalize(&Vec3A::ZERO, &Vec3A::X));
+    }
+
+    #[test]
+    fn orthonormalize_vec4() {
+        let a = Vec4::new(1.0, 2.0, 0.0, 3.0);
+        let b = Vec4::new(0.0, 0.0, 0.0, 1.0);
+        let a_ortho_to_b = orthonormalize(&a, &b);
+        assert_relative_eq!(0.0, a_ortho_to_b.dot(b), epsilon = EPSILON);
+        assert_relative_eq!(1.0, a_ortho_to_b.length(), epsilon = EPSILON);
+    }
 }
@@ -0,0 +1,162 @@
+use glam::{Vec2, Vec3A, Vec4};
+
+use crate::topology::MeshAdjacency;
+use crate::validation::check_triangle_indices;
+
+use super::tangent::{tangent_bitangent_from_gradient, tangents_from_face_vectors, uv_gradient};
+use super::TangentBitangentError;
+
+/// Cached data for recalculating tangents like [calculate_tangents](super::calculate_tangents)
+/// when only the positions and normals change.
+///
+/// Skinning and other deformations move the vertices but don't change the texture coordinates or indices.
+/// The per face texture coordinate gradients and the vertex to face connectivity only need to be calculated once,
+/// which makes recalculating tangents for each frame of CPU deformed meshes several times faster.
+/// The results are identical to [calculate_tangents](super::calculate_tangents)
+/// with the texture coordinates and indices used to create the cache.
+/// # Examples
+/**
+```rust
+use geometry_tools::vectors::{calculate_smooth_normals_with_adjacency, calculate_tangents, DeformedTangents};
+use glam::{Vec2, Vec3A};
+
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+let uvs = vec![Vec2::ZERO, Vec2::X, Vec2::Y];
+let indices = [0, 1, 2];
+let cache = DeformedTangents::new(&uvs, &indices)?;
+
+for frame in 0..3 {
+    // Positions calculated by skinning.
+    let angle = frame as f32 * 0.5;
+    let positions = vec![
+        Vec3A::ZERO,
+        Vec3A::new(angle.cos(), angle.sin(), 0.0),
+        Vec3A::new(-angle.sin(), angle.cos(), 0.0),
+    ];
+    let normals = calculate_smooth_normals_with_adjacency(&positions, cache.adjacency());
+
+    let tangents = cache.calculate_tangents(&positions, &normals)?;
+    assert_eq!(calculate_tangents(&positions, &normals, &uvs, &indices)?, tangents);
+}
+# Ok(())
+# }
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeformedTangents {
+    adjacency: MeshAdjacency,
+    face_gradients: Vec<(Vec2, Vec2, f32)>,
+}
+
+impl DeformedTangents {
+    /// Caches the texture coordinate gradients for each face and the connectivity of `indices`.
+    pub fn new(uvs: &[Vec2], indices: &[u32]) -> Result<Self, TangentBitangentError> {
+        check_triangle_indices(indices, uvs.len())?;
+
+        let adjacency = MeshAdjacency::new(uvs.len(), indices);
+        let face_gradients = adjacency
+            .faces()
+            .iter()
+            .map(|[v0, v1, v2]| {
+                uv_gradient(&uvs[*v0 as usize], &uvs[*v1 as usize], &uvs[*v2 as usize])
+            })
+            .collect();
+
+        Ok(Self {
+            adjacency,
+            face_gradients,
+        })
+    }
+
+    /// The cached connectivity for calculating other attributes
+    /// like [calculate_smooth_normals_with_adjacency](super::calculate_smooth_normals_with_adjacency).
+    pub fn adjacency(&self) -> &MeshAdjacency {
+        &self.adjacency
+    }
+
+    /// Calculates smooth per-vertex tangents for the deformed `positions` and `normals`.
+    /// See [calculate_tangents](super::calculate_tangents) for details.
+    pub fn calculate_tangents<P, N>(
+        &self,
+        positions: &[P],
+        normals: &[N],
+    ) -> Result<Vec<Vec4>, TangentBitangentError>
+    where
+        P: Into<Vec3A> + Copy,
+        N: Into<Vec3A> + Copy,
+    {
+        let vertex_count = self.adjacency.vertex_count();
+        if !(positions.len() == vertex_count && normals.len() == vertex_count) {
+            return Err(TangentBitangentError::AttributeCountMismatch {
+                position_count: positions.len(),
+                normal_count: normals.len(),
+                uv_count: vertex_count,
+            });
+        }
+
+        let face_vectors: Vec<(Vec3A, Vec3A)> = self
+            .adjacency
+            .faces()
+            .iter()
+            .zip(&self.face_gradients)
+            .map(|([v0, v1, v2], (uv_a, uv_b, r))| {
+                let v0: Vec3A = positions[*v0 as usize].into();
+                let pos_a = positions[*v1 as usize].into() - v0;
+                let pos_b = positions[*v2 as usize].into() - v0;
+                tangent_bitangent_from_gradient(pos_a, pos_b, *uv_a, *uv_b, *r)
+            })
+            .collect();
+
+        Ok(tangents_from_face_vectors(
+            &face_vectors,
+            normals,
+            &self.adjacency,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shapes::generate_torus;
+    use crate::vectors::{calculate_smooth_normals, calculate_tangents};
+
+    #[test]
+    fn matches_calculate_tangents() {
+        let torus = generate_torus(1.0, 0.25, 16, 8);
+        let (positions, uvs, indices) = (torus.positions, torus.uvs, torus.indices);
+        let cache = DeformedTangents::new(&uvs, &indices).unwrap();
+
+        for frame in 0..4 {
+            let scale = Vec3A::new(1.0, 1.0 + frame as f32 * 0.3, 1.0 - frame as f32 * 0.1);
+            let deformed: Vec<_> = positions
+                .iter()
+                .map(|p| *p * scale + Vec3A::X * p.y * frame as f32)
+                .collect();
+            let normals = calculate_smooth_normals(&deformed, &indices);
+
+            assert_eq!(
+                calculate_tangents(&deformed, &normals, &uvs, &indices).unwrap(),
+                cache.calculate_tangents(&deformed, &normals).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn attribute_count_mismatch() {
+        let cache = DeformedTangents::new(&[Vec2::ZERO; 3], &[0, 1, 2]).unwrap();
+        assert!(matches!(
+            cache.calculate_tangents(&[Vec3A::ZERO; 2], &[Vec3A::Z; 3]),
+            Err(TangentBitangentError::AttributeCountMismatch {
+                position_count: 2,
+                normal_count: 3,
+                uv_count: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn invalid_indices() {
+        assert!(DeformedTangents::new(&[Vec2::ZERO; 3], &[0, 1, 3]).is_err());
+    }
+}
@@ -1,5 +1,13 @@
 use glam::Vec3A;
 
+#[cfg(feature = "rayon")]
+use crate::sum::Accumulator;
+#[cfg(not(feature = "rayon"))]
+use crate::sum::ElementSums;
+use crate::topology::MeshAdjacency;
+use crate::validation::{check_triangle_indices, IndexError};
+use crate::vec3::{convert_to_vec3a, Float3};
+
 /// Calculates smooth per-vertex normals by by averaging over the vertices in each face.
 /// `indices` is assumed to contain triangle indices for `positions`, so `indices.len()` should be a multiple of 3.
 /// If either of `positions` or `indices` is empty, the result is empty.
@@ -16,23 +24,214 @@ where
     normals
 }
 
-// Use an existing piece of memory for the result to make FFI easier.
-// This allows another language such as C# to manage its own memory.
-fn update_smooth_normals<P>(positions: &[P], normals: &mut [Vec3A], indices: &[u32])
+/// Calculates smooth per-vertex normals like [calculate_smooth_normals]
+/// and writes the results to `normals` without allocating.
+/// Vertices not used by any face are set to zero.
+///
+/// # Panics
+/// Panics if `normals` and `positions` have different lengths.
+/// # Examples
+/**
+```rust
+use geometry_tools::vectors::calculate_smooth_normals_into;
+use glam::Vec3A;
+
+let mut positions = vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let mut normals = vec![Vec3A::ZERO; 3];
+calculate_smooth_normals_into(&positions, &[0, 1, 2], &mut normals);
+assert_eq!(vec![Vec3A::Z; 3], normals);
+
+// Reuse the buffer after changing the positions.
+positions.swap(1, 2);
+calculate_smooth_normals_into(&positions, &[0, 1, 2], &mut normals);
+assert_eq!(vec![Vec3A::NEG_Z; 3], normals);
+```
+ */
+pub fn calculate_smooth_normals_into<P>(positions: &[P], indices: &[u32], normals: &mut [Vec3A])
+where
+    P: Into<Vec3A> + Copy,
+{
+    assert_eq!(
+        positions.len(),
+        normals.len(),
+        "normals should have the same length as positions"
+    );
+    normals.fill(Vec3A::ZERO);
+    update_smooth_normals(positions, normals, indices);
+}
+
+/// Calculates smooth per-vertex normals like [calculate_smooth_normals]
+/// after checking that `indices` contains only complete triangles that are in range for `positions`.
+/// Use this to detect truncated or corrupted index buffers instead of silently ignoring trailing indices.
+/// # Examples
+/**
+```rust
+use geometry_tools::validation::IndexError;
+use geometry_tools::vectors::calculate_smooth_normals_strict;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+assert_eq!(Ok(vec![Vec3A::Z; 3]), calculate_smooth_normals_strict(&positions, &[0, 1, 2]));
+assert_eq!(
+    Err(IndexError::InvalidIndexCount { index_count: 4 }),
+    calculate_smooth_normals_strict(&positions, &[0, 1, 2, 0])
+);
+```
+ */
+pub fn calculate_smooth_normals_strict<P>(
+    positions: &[P],
+    indices: &[u32],
+) -> Result<Vec<Vec3A>, IndexError>
+where
+    P: Into<Vec3A> + Copy,
+{
+    check_triangle_indices(indices, positions.len())?;
+    Ok(calculate_smooth_normals(positions, indices))
+}
+
+/// Calculates smooth per-vertex normals like [calculate_smooth_normals]
+/// for tightly packed positions such as [glam::Vec3] or `[f32; 3]`.
+/// The positions are converted once in batches instead of for each face.
+/// # Examples
+/**
+```rust
+use geometry_tools::vectors::calculate_smooth_normals_vec3;
+use glam::Vec3A;
+
+let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+let normals = calculate_smooth_normals_vec3(&positions, &[0, 1, 2]);
+assert_eq!(vec![Vec3A::Z; 3], normals);
+```
+ */
+pub fn calculate_smooth_normals_vec3<P: Float3>(positions: &[P], indices: &[u32]) -> Vec<Vec3A> {
+    calculate_smooth_normals(&convert_to_vec3a(positions), indices)
+}
+
+/// Calculates smooth per-vertex normals like [calculate_smooth_normals]
+/// using the precomputed connectivity in `adjacency`.
+/// The results are identical to [calculate_smooth_normals] with the indices used to create `adjacency`.
+///
+/// # Panics
+/// Panics if `positions` has a different length than the vertex count of `adjacency`.
+/// # Examples
+/**
+```rust
+use geometry_tools::topology::MeshAdjacency;
+use geometry_tools::vectors::calculate_smooth_normals_with_adjacency;
+use glam::Vec3A;
+
+let positions = vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let adjacency = MeshAdjacency::new(positions.len(), &[0, 1, 2]);
+let normals = calculate_smooth_normals_with_adjacency(&positions, &adjacency);
+assert_eq!(vec![Vec3A::Z; 3], normals);
+```
+ */
+pub fn calculate_smooth_normals_with_adjacency<P>(
+    positions: &[P],
+    adjacency: &MeshAdjacency,
+) -> Vec<Vec3A>
 where
     P: Into<Vec3A> + Copy,
 {
-    for face in indices.chunks(3) {
-        if let [v0, v1, v2] = face {
-            let normal = calculate_normal(
+    assert_eq!(
+        positions.len(),
+        adjacency.vertex_count(),
+        "positions should have the same length as the adjacency vertex count"
+    );
+
+    let face_normals: Vec<Vec3A> = adjacency
+        .faces()
+        .iter()
+        .map(|[v0, v1, v2]| {
+            calculate_normal(
                 positions[*v0 as usize].into(),
                 positions[*v1 as usize].into(),
                 positions[*v2 as usize].into(),
-            );
-            normals[*v0 as usize] += normal;
-            normals[*v1 as usize] += normal;
-            normals[*v2 as usize] += normal;
+            )
+        })
+        .collect();
+
+    // Faces are in increasing order to match the sums for calculate_smooth_normals.
+    let vertex_normal = |v: usize| {
+        let mut sum = crate::sum::Accumulator::new(Vec3A::ZERO);
+        for f in adjacency.vertex_faces(v) {
+            sum.add(face_normals[*f as usize]);
         }
+        sum.sum().normalize_or_zero()
+    };
+
+    #[cfg(feature = "rayon")]
+    let normals = {
+        use rayon::prelude::*;
+        (0..positions.len())
+            .into_par_iter()
+            .map(vertex_normal)
+            .collect()
+    };
+    #[cfg(not(feature = "rayon"))]
+    let normals = (0..positions.len()).map(vertex_normal).collect();
+
+    normals
+}
+
+// Use an existing piece of memory for the result to make FFI easier.
+// This allows another language such as C# to manage its own memory.
+#[cfg(feature = "rayon")]
+fn update_smooth_normals<P, I>(positions: &[P], normals: &mut [Vec3A], indices: &[I])
+where
+    P: Into<Vec3A> + Copy,
+    I: Into<u32> + Copy,
+{
+    use rayon::prelude::*;
+
+    // Convert first since the input type may not be safe to share between threads.
+    let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+
+    let faces: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|f| [f[0].into(), f[1].into(), f[2].into()])
+        .collect();
+    let face_normals: Vec<Vec3A> = faces
+        .par_iter()
+        .map(|[v0, v1, v2]| {
+            calculate_normal(
+                positions[*v0 as usize],
+                positions[*v1 as usize],
+                positions[*v2 as usize],
+            )
+        })
+        .collect();
+
+    // Gather the face normals for each vertex in face order to match the single threaded sums.
+    let adjacency = MeshAdjacency::from_faces(normals.len(), faces);
+    normals.par_iter_mut().enumerate().for_each(|(v, normal)| {
+        let mut sum = Accumulator::new(*normal);
+        for f in adjacency.vertex_faces(v) {
+            sum.add(face_normals[*f as usize]);
+        }
+        *normal = sum.sum().normalize_or_zero();
+    });
+}
+
+#[cfg(not(feature = "rayon"))]
+fn update_smooth_normals<P, I>(positions: &[P], normals: &mut [Vec3A], indices: &[I])
+where
+    P: Into<Vec3A> + Copy,
+    I: Into<u32> + Copy,
+{
+    let mut sums = ElementSums::new(normals);
+    for face in indices.chunks_exact(3) {
+        let v0 = face[0].into() as usize;
+        let v1 = face[1].into() as usize;
+        let v2 = face[2].into() as usize;
+        let normal = calculate_normal(
+            positions[v0].into(),
+            positions[v1].into(),
+            positions[v2].into(),
+        );
+        sums.add(v0, normal);
+        sums.add(v1, normal);
+        sums.add(v2, normal);
     }
 
     for normal in normals.iter_mut() {
@@ -40,8 +239,23 @@ where
     }
 }
 
+/// Calculates the face normal of the triangle with vertices `v1`, `v2`, and `v3` in counterclockwise order.
+/// The result is not normalized and has a length of twice the area of the triangle,
+/// which weights the contribution of each face for [calculate_smooth_normals].
+/// Degenerate triangles with zero area have a normal of zero.
+/// # Examples
+/**
+```rust
+use geometry_tools::vectors::calculate_normal;
+use glam::Vec3A;
+
+let normal = calculate_normal(Vec3A::ZERO, Vec3A::X * 2.0, Vec3A::Y);
+assert_eq!(Vec3A::Z * 2.0, normal);
+assert_eq!(Vec3A::Z, normal.normalize_or_zero());
+```
+ */
 #[inline(always)]
-fn calculate_normal(v1: Vec3A, v2: Vec3A, v3: Vec3A) -> Vec3A {
+pub fn calculate_normal(v1: Vec3A, v2: Vec3A, v3: Vec3A) -> Vec3A {
     let u = v2 - v1;
     let v = v3 - v1;
     u.cross(v)
@@ -49,34 +263,149 @@ fn calculate_normal(v1: Vec3A, v2: Vec3A, v3: Vec3A) -> Vec3A {
 
 pub mod ffi {
     use super::*;
+    use crate::ffi::{catch_status, check_triangle_indices, slice, slice_mut, FfiStatus};
 
     /// A wrapper for [calculate_smooth_normals](crate::vectors::calculate_smooth_normals).
     /// `indices` and `indices_length` define the collection of vertex indices.
-    /// The function writes the resulting smooth normals to the first `pos_nrm_length` elements of `normals`.
+    /// The function overwrites the first `normals_length` elements of `normals` with the resulting smooth normals.
+    ///
+    /// Returns [FfiStatus::LengthMismatch] if `positions_length` and `normals_length` differ
+    /// and [FfiStatus::IndexOutOfRange] if any index is not a valid vertex index.
+    /// `normals` is not modified if the function fails.
     ///
     /// # Safety
     ///
-    /// `positions` and `normals` must both have length `pos_nrm_length`.
+    /// `positions` and `normals` must have lengths `positions_length` and `normals_length`.
     /// The memory layout of the `positions` and `normals` array should have the xyz values in the first three floats
     /// of each vector of four floats to ensure compatibility with the 16 byte alignment of the [Vec3A] type.
     ///
     /// Example: `x0 y0 z0 _ x1 y1 z1 _ x2 y2 z2 _ ...`
     ///
     /// The fourth value of each vector is included only for alignment purposes and does not affect the computation.
-    /// This gives a required size of at least `pos_nrm_length * 16` bytes for both arrays.
+    /// This gives a required size of at least `positions_length * 16` bytes for both arrays.
     #[no_mangle]
-    pub unsafe extern "C" fn calculate_smooth_normals(
+    pub unsafe extern "C" fn geo_calculate_smooth_normals(
         positions: *const glam::Vec3A,
+        positions_length: u32,
         normals: *mut glam::Vec3A,
-        pos_nrm_length: u32,
+        normals_length: u32,
         indices: *const u32,
         indices_length: u32,
-    ) {
-        let pos = std::slice::from_raw_parts(positions, pos_nrm_length as usize);
-        let nrm = std::slice::from_raw_parts_mut(normals, pos_nrm_length as usize);
-        let indices = std::slice::from_raw_parts(indices, indices_length as usize);
+    ) -> FfiStatus {
+        catch_status(|| {
+            if positions_length != normals_length {
+                return Err(FfiStatus::LengthMismatch);
+            }
+            let pos = slice(positions, positions_length)?;
+            let nrm = slice_mut(normals, normals_length)?;
+            let indices = slice(indices, indices_length)?;
+            check_triangle_indices(indices, pos.len())?;
 
-        update_smooth_normals(pos, nrm, indices);
+            nrm.fill(Vec3A::ZERO);
+            update_smooth_normals(pos, nrm, indices);
+            Ok(())
+        })
+    }
+
+    /// A version of [calculate_smooth_normals](crate::vectors::calculate_smooth_normals) for tightly packed positions and normals
+    /// with three floats for each vector.
+    ///
+    /// Example: `x0 y0 z0 x1 y1 z1 x2 y2 z2 ...`
+    ///
+    /// This gives a required size of at least `positions_length * 12` bytes for both arrays.
+    /// The function overwrites the first `normals_length` elements of `normals`.
+    ///
+    /// # Safety
+    ///
+    /// `positions` and `normals` must have lengths `positions_length` and `normals_length`.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_calculate_smooth_normals_vec3(
+        positions: *const glam::Vec3,
+        positions_length: u32,
+        normals: *mut glam::Vec3,
+        normals_length: u32,
+        indices: *const u32,
+        indices_length: u32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            if positions_length != normals_length {
+                return Err(FfiStatus::LengthMismatch);
+            }
+            let pos = slice(positions, positions_length)?;
+            let nrm = slice_mut(normals, normals_length)?;
+            let indices = slice(indices, indices_length)?;
+            check_triangle_indices(indices, pos.len())?;
+
+            let pos = convert_to_vec3a(pos);
+            let mut aligned_normals = vec![Vec3A::ZERO; pos.len()];
+            update_smooth_normals(&pos, &mut aligned_normals, indices);
+            for (n, aligned) in nrm.iter_mut().zip(aligned_normals) {
+                *n = aligned.into();
+            }
+            Ok(())
+        })
+    }
+
+    /// A version of [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals) for 16 bit indices.
+    ///
+    /// # Safety
+    ///
+    /// See [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_calculate_smooth_normals_u16(
+        positions: *const glam::Vec3A,
+        positions_length: u32,
+        normals: *mut glam::Vec3A,
+        normals_length: u32,
+        indices: *const u16,
+        indices_length: u32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            if positions_length != normals_length {
+                return Err(FfiStatus::LengthMismatch);
+            }
+            let pos = slice(positions, positions_length)?;
+            let nrm = slice_mut(normals, normals_length)?;
+            let indices = slice(indices, indices_length)?;
+            check_triangle_indices(indices, pos.len())?;
+
+            nrm.fill(Vec3A::ZERO);
+            update_smooth_normals(pos, nrm, indices);
+            Ok(())
+        })
+    }
+
+    /// A version of [geo_calculate_smooth_normals_vec3](crate::ffi::geo_calculate_smooth_normals_vec3) for 16 bit indices.
+    ///
+    /// # Safety
+    ///
+    /// See [geo_calculate_smooth_normals_vec3](crate::ffi::geo_calculate_smooth_normals_vec3).
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_calculate_smooth_normals_vec3_u16(
+        positions: *const glam::Vec3,
+        positions_length: u32,
+        normals: *mut glam::Vec3,
+        normals_length: u32,
+        indices: *const u16,
+        indices_length: u32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            if positions_length != normals_length {
+                return Err(FfiStatus::LengthMismatch);
+            }
+            let pos = slice(positions, positions_length)?;
+            let nrm = slice_mut(normals, normals_length)?;
+            let indices = slice(indices, indices_length)?;
+            check_triangle_indices(indices, pos.len())?;
+
+            let pos = convert_to_vec3a(pos);
+            let mut aligned_normals = vec![Vec3A::ZERO; pos.len()];
+            update_smooth_normals(&pos, &mut aligned_normals, indices);
+            for (n, aligned) in nrm.iter_mut().zip(aligned_normals) {
+                *n = aligned.into();
+            }
+            Ok(())
+        })
     }
 }
 
@@ -172,16 +501,139 @@ mod tests {
         let pos = [Vec3A::ONE, Vec3A::ONE];
         let mut nrm = [Vec3A::ONE, Vec3A::ONE];
         let indices = [0, 1, 0, 1, 0, 1, 1, 1, 0];
-        unsafe {
-            ffi::calculate_smooth_normals(
+        let status = unsafe {
+            ffi::geo_calculate_smooth_normals(
                 pos.as_ptr(),
+                pos.len() as u32,
                 nrm.as_mut_ptr(),
+                nrm.len() as u32,
+                indices.as_ptr(),
+                indices.len() as u32,
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::Success, status);
+
+        // The previous contents of the output are not included in the sums.
+        assert_eq!([Vec3A::ZERO; 2], nrm);
+    }
+
+    #[test]
+    fn smooth_normals_ffi_invalid_inputs() {
+        let pos = [Vec3A::ONE, Vec3A::ONE];
+        let mut nrm = [Vec3A::ONE; 3];
+        let indices = [0, 1, 2];
+        let status = unsafe {
+            ffi::geo_calculate_smooth_normals(
+                pos.as_ptr(),
                 pos.len() as u32,
+                nrm.as_mut_ptr(),
+                nrm.len() as u32,
                 indices.as_ptr(),
                 indices.len() as u32,
-            );
-        }
-        assert_eq!(nrm[0], Vec3A::ONE.normalize());
-        assert_eq!(nrm[1], Vec3A::ONE.normalize());
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::LengthMismatch, status);
+
+        let status = unsafe {
+            ffi::geo_calculate_smooth_normals(
+                pos.as_ptr(),
+                pos.len() as u32,
+                nrm.as_mut_ptr(),
+                pos.len() as u32,
+                indices.as_ptr(),
+                indices.len() as u32,
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::IndexOutOfRange, status);
+        assert_eq!([Vec3A::ONE; 3], nrm);
+    }
+
+    #[test]
+    fn smooth_normals_vec3_ffi() {
+        let pos = [glam::Vec3::ZERO, glam::Vec3::X, glam::Vec3::Y];
+        let mut nrm = [glam::Vec3::ONE; 3];
+        let indices = [0, 1, 2];
+        let status = unsafe {
+            ffi::geo_calculate_smooth_normals_vec3(
+                pos.as_ptr(),
+                pos.len() as u32,
+                nrm.as_mut_ptr(),
+                nrm.len() as u32,
+                indices.as_ptr(),
+                indices.len() as u32,
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::Success, status);
+        assert_eq!([glam::Vec3::Z; 3], nrm);
+    }
+
+    #[test]
+    fn smooth_normals_u16_ffi() {
+        let pos = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        // The previous contents of the output are overwritten.
+        let mut nrm = [Vec3A::NEG_Z; 3];
+        let indices = [0u16, 1, 2];
+        let status = unsafe {
+            ffi::geo_calculate_smooth_normals_u16(
+                pos.as_ptr(),
+                pos.len() as u32,
+                nrm.as_mut_ptr(),
+                nrm.len() as u32,
+                indices.as_ptr(),
+                indices.len() as u32,
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::Success, status);
+        assert_eq!([Vec3A::Z; 3], nrm);
+
+        let mut nrm = [glam::Vec3::ZERO; 3];
+        let indices = [0u16, 1, 3];
+        let status = unsafe {
+            ffi::geo_calculate_smooth_normals_vec3_u16(
+                pos.map(glam::Vec3::from).as_ptr(),
+                pos.len() as u32,
+                nrm.as_mut_ptr(),
+                nrm.len() as u32,
+                indices.as_ptr(),
+                indices.len() as u32,
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::IndexOutOfRange, status);
+    }
+
+    #[test]
+    fn smooth_normals_with_adjacency_matches() {
+        let mesh = crate::shapes::generate_uv_sphere(1.0, 16, 8);
+        let adjacency = MeshAdjacency::new(mesh.positions.len(), &mesh.indices);
+        assert_eq!(
+            calculate_smooth_normals(&mesh.positions, &mesh.indices),
+            calculate_smooth_normals_with_adjacency(&mesh.positions, &adjacency)
+        );
+    }
+
+    #[test]
+    fn smooth_normals_strict_trailing_indices() {
+        // The default function ignores the partial triangle.
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        assert_eq!(
+            vec![Vec3A::Z; 3],
+            calculate_smooth_normals(&positions, &[0, 1, 2, 0, 1])
+        );
+        assert_eq!(
+            Err(IndexError::InvalidIndexCount { index_count: 5 }),
+            calculate_smooth_normals_strict(&positions, &[0, 1, 2, 0, 1])
+        );
+    }
+
+    #[test]
+    fn smooth_normals_strict_out_of_range() {
+        assert_eq!(
+            Err(IndexError::IndexOutOfRange {
+                position: 1,
+                index: 3,
+                vertex_count: 3
+            }),
+            calculate_smooth_normals_strict(&[Vec3A::ZERO; 3], &[0, 3, 1])
+        );
     }
 }
@@ -3,6 +3,14 @@ use thiserror::Error;
 
 use glam::{Vec2, Vec3A, Vec4};
 
+use crate::flat::FlatLengthError;
+#[cfg(feature = "rayon")]
+use crate::sum::Accumulator;
+#[cfg(not(feature = "rayon"))]
+use crate::sum::ElementSums;
+use crate::topology::MeshAdjacency;
+use crate::validation::{check_triangle_indices, IndexError};
+use crate::vec3::{convert_to_vec3a, Float3};
 use crate::vectors::orthonormalize;
 
 /// The value returned when any component of the calculated tangent would be `NaN` or infinite.
@@ -31,10 +39,32 @@ pub enum TangentBitangentError {
         index_count
     )]
     InvalidIndexCont { index_count: usize },
+    #[error(
+        "The output sizes do not match the vertex count {}. Tangents: {}, Bitangents: {}.",
+        vertex_count,
+        tangent_count,
+        bitangent_count
+    )]
+    OutputCountMismatch {
+        vertex_count: usize,
+        tangent_count: usize,
+        bitangent_count: usize,
+    },
+    #[error(
+        "The vertex count {} does not match the adjacency vertex count {}.",
+        vertex_count,
+        adjacency_vertex_count
+    )]
+    AdjacencyVertexCountMismatch {
+        vertex_count: usize,
+        adjacency_vertex_count: usize,
+    },
+    #[error(transparent)]
+    InvalidIndices(#[from] IndexError),
+    #[error(transparent)]
+    InvalidFlatLength(#[from] FlatLengthError),
 }
 
-// TODO: Rewrite these functions to update existing array to better support ffi.
-
 /// Calculates smooth per-vertex tangents and bitangents by averaging over the vertices in each face.
 /// `indices` is assumed to contain triangle indices for `positions`, so `indices.len()` should be a multiple of 3.
 /// If either of `positions` or `indices` is empty, the result is empty.
@@ -61,6 +91,57 @@ pub fn calculate_tangents_bitangents<P, N, I>(
     uvs: &[Vec2],
     indices: &[I],
 ) -> Result<(Vec<Vec3A>, Vec<Vec3A>), TangentBitangentError>
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+    I: TryInto<usize> + Copy,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+{
+    let mut tangents = vec![Vec3A::ZERO; positions.len()];
+    let mut bitangents = vec![Vec3A::ZERO; positions.len()];
+    calculate_tangents_bitangents_into(
+        positions,
+        normals,
+        uvs,
+        indices,
+        &mut tangents,
+        &mut bitangents,
+    )?;
+    Ok((tangents, bitangents))
+}
+
+/// Calculates smooth per-vertex tangents and bitangents like [calculate_tangents_bitangents]
+/// and writes the results to `tangents` and `bitangents` without allocating the outputs.
+/// `tangents` and `bitangents` must have the same length as `positions`.
+/// # Examples
+/**
+```rust
+use geometry_tools::vectors::calculate_tangents_bitangents_into;
+use glam::{Vec2, Vec3A};
+
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let normals = [Vec3A::Z; 3];
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+
+// The buffers can be reused when the positions change.
+let mut tangents = vec![Vec3A::ZERO; 3];
+let mut bitangents = vec![Vec3A::ZERO; 3];
+calculate_tangents_bitangents_into(&positions, &normals, &uvs, &[0, 1, 2], &mut tangents, &mut bitangents)?;
+assert_eq!(vec![Vec3A::X; 3], tangents);
+assert_eq!(vec![Vec3A::Y; 3], bitangents);
+# Ok(())
+# }
+```
+ */
+pub fn calculate_tangents_bitangents_into<P, N, I>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[I],
+    tangents: &mut [Vec3A],
+    bitangents: &mut [Vec3A],
+) -> Result<(), TangentBitangentError>
 where
     P: Into<Vec3A> + Copy,
     N: Into<Vec3A> + Copy,
@@ -68,7 +149,7 @@ where
     <I as TryInto<usize>>::Error: std::fmt::Debug,
 {
     // TODO: This can be generic over the face count?
-    if indices.len() % 3 != 0 {
+    if !indices.len().is_multiple_of(3) {
         return Err(TangentBitangentError::InvalidIndexCont {
             index_count: indices.len(),
         });
@@ -82,61 +163,151 @@ where
         });
     }
 
-    let mut tangents = vec![Vec3A::ZERO; positions.len()];
-    let mut bitangents = vec![Vec3A::ZERO; positions.len()];
+    if tangents.len() != positions.len() || bitangents.len() != positions.len() {
+        return Err(TangentBitangentError::OutputCountMismatch {
+            vertex_count: positions.len(),
+            tangent_count: tangents.len(),
+            bitangent_count: bitangents.len(),
+        });
+    }
+
+    accumulate_tangents_bitangents(positions, uvs, indices, tangents, bitangents);
+    finalize_tangents_bitangents(tangents, bitangents, normals);
+    Ok(())
+}
+
+#[cfg(not(feature = "rayon"))]
+fn accumulate_tangents_bitangents<P, I>(
+    positions: &[P],
+    uvs: &[Vec2],
+    indices: &[I],
+    tangents: &mut [Vec3A],
+    bitangents: &mut [Vec3A],
+) where
+    P: Into<Vec3A> + Copy,
+    I: TryInto<usize> + Copy,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+{
+    tangents.fill(Vec3A::ZERO);
+    bitangents.fill(Vec3A::ZERO);
+    let mut tangent_sums = ElementSums::new(tangents);
+    let mut bitangent_sums = ElementSums::new(bitangents);
 
     // Calculate the vectors.
-    for face in indices.chunks(3) {
-        if let [v0, v1, v2] = face {
-            let v0 = (*v0).try_into().unwrap();
-            let v1 = (*v1).try_into().unwrap();
-            let v2 = (*v2).try_into().unwrap();
-            let (tangent, bitangent) = calculate_tangent_bitangent(
-                &positions[v0].into(),
-                &positions[v1].into(),
-                &positions[v2].into(),
+    for face in indices.chunks_exact(3) {
+        let v0 = face[0].try_into().unwrap();
+        let v1 = face[1].try_into().unwrap();
+        let v2 = face[2].try_into().unwrap();
+        let (tangent, bitangent) = calculate_tangent_bitangent(
+            &positions[v0].into(),
+            &positions[v1].into(),
+            &positions[v2].into(),
+            &uvs[v0],
+            &uvs[v1],
+            &uvs[v2],
+        );
+
+        tangent_sums.add(v0, tangent);
+        tangent_sums.add(v1, tangent);
+        tangent_sums.add(v2, tangent);
+
+        bitangent_sums.add(v0, bitangent);
+        bitangent_sums.add(v1, bitangent);
+        bitangent_sums.add(v2, bitangent);
+    }
+}
+
+#[cfg(feature = "rayon")]
+fn accumulate_tangents_bitangents<P, I>(
+    positions: &[P],
+    uvs: &[Vec2],
+    indices: &[I],
+    tangents: &mut [Vec3A],
+    bitangents: &mut [Vec3A],
+) where
+    P: Into<Vec3A> + Copy,
+    I: TryInto<usize> + Copy,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+{
+    use rayon::prelude::*;
+
+    // Convert first since the input type may not be safe to share between threads.
+    let positions: Vec<Vec3A> = positions.iter().copied().map(Into::into).collect();
+
+    let faces: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|f| [f[0], f[1], f[2]].map(|v| v.try_into().unwrap() as u32))
+        .collect();
+    let face_vectors: Vec<(Vec3A, Vec3A)> = faces
+        .par_iter()
+        .map(|face| {
+            let [v0, v1, v2] = face.map(|v| v as usize);
+            calculate_tangent_bitangent(
+                &positions[v0],
+                &positions[v1],
+                &positions[v2],
                 &uvs[v0],
                 &uvs[v1],
                 &uvs[v2],
-            );
+            )
+        })
+        .collect();
 
-            tangents[v0] += tangent;
-            tangents[v1] += tangent;
-            tangents[v2] += tangent;
+    // Gather the face vectors for each vertex in face order to match the single threaded sums.
+    let adjacency = MeshAdjacency::from_faces(positions.len(), faces);
+    tangents
+        .par_iter_mut()
+        .zip(bitangents.par_iter_mut())
+        .enumerate()
+        .for_each(|(v, (tangent, bitangent))| {
+            let mut tangent_sum = Accumulator::new(Vec3A::ZERO);
+            let mut bitangent_sum = Accumulator::new(Vec3A::ZERO);
+            for f in adjacency.vertex_faces(v) {
+                tangent_sum.add(face_vectors[*f as usize].0);
+                bitangent_sum.add(face_vectors[*f as usize].1);
+            }
+            *tangent = tangent_sum.sum();
+            *bitangent = bitangent_sum.sum();
+        });
+}
 
-            bitangents[v0] += bitangent;
-            bitangents[v1] += bitangent;
-            bitangents[v2] += bitangent;
-        }
+fn finalize_tangents_bitangents<N>(tangents: &mut [Vec3A], bitangents: &mut [Vec3A], normals: &[N])
+where
+    N: Into<Vec3A> + Copy,
+{
+    for ((tangent, bitangent), normal) in
+        tangents.iter_mut().zip(bitangents.iter_mut()).zip(normals)
+    {
+        (*tangent, *bitangent) = finalize_tangent_bitangent(*tangent, *bitangent, (*normal).into());
     }
+}
 
+/// Normalizes the accumulated `tangent` and `bitangent` for a single vertex.
+pub(crate) fn finalize_tangent_bitangent(
+    tangent: Vec3A,
+    bitangent: Vec3A,
+    normal: Vec3A,
+) -> (Vec3A, Vec3A) {
     // Even if the vectors are not zero, they may still sum to zero.
-    for tangent in tangents.iter_mut() {
-        if tangent.length_squared() == 0.0 {
-            *tangent = DEFAULT_TANGENT;
-        }
-
-        *tangent = tangent.normalize_or_zero();
-    }
-
-    for bitangent in bitangents.iter_mut() {
-        if bitangent.length_squared() == 0.0 {
-            *bitangent = DEFAULT_BITANGENT;
-        }
-    }
+    let tangent = if tangent.length_squared() == 0.0 {
+        DEFAULT_TANGENT
+    } else {
+        tangent
+    };
 
-    for (bitangent, normal) in bitangents.iter_mut().zip(normals.iter()) {
-        // Account for mirrored normal maps.
-        // The default bitangent may be parallel to the normal vector.
-        let normal = (*normal).into();
-        if bitangent.cross(normal).length_squared() != 0.0 {
-            *bitangent = orthonormalize(bitangent, &normal);
-        }
+    let mut bitangent = if bitangent.length_squared() == 0.0 {
+        DEFAULT_BITANGENT
+    } else {
+        bitangent
+    };
 
-        *bitangent = bitangent.normalize_or_zero();
+    // Account for mirrored normal maps.
+    // The default bitangent may be parallel to the normal vector.
+    if bitangent.cross(normal).length_squared() != 0.0 {
+        bitangent = orthonormalize(&bitangent, &normal);
     }
 
-    Ok((tangents, bitangents))
+    (tangent.normalize_or_zero(), bitangent.normalize_or_zero())
 }
 
 /// Calculates smooth per-vertex tangents by averaging over the vertices in each face.
@@ -181,20 +352,252 @@ where
     I: TryInto<usize> + Copy,
     <I as TryInto<usize>>::Error: std::fmt::Debug,
 {
-    let (tangents, bitangents) = calculate_tangents_bitangents(positions, normals, uvs, indices)?;
+    let mut tangents = vec![Vec4::ZERO; positions.len()];
+    calculate_tangents_into(positions, normals, uvs, indices, &mut tangents)?;
+    Ok(tangents)
+}
 
-    // Compute the w component for each tangent.
-    // TODO: Compute this without computing and immediately discarding bitangent vectors?
-    let tangents_with_w = tangents
+/// Calculates smooth per-vertex tangents like [calculate_tangents]
+/// and writes the results to `tangents` without allocating the output.
+/// `tangents` must have the same length as `positions`.
+/// Temporary bitangents are still allocated for calculating the tangent sign.
+pub fn calculate_tangents_into<P, N, I>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[I],
+    tangents: &mut [Vec4],
+) -> Result<(), TangentBitangentError>
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+    I: TryInto<usize> + Copy,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+{
+    if tangents.len() != positions.len() {
+        return Err(TangentBitangentError::OutputCountMismatch {
+            vertex_count: positions.len(),
+            tangent_count: tangents.len(),
+            bitangent_count: positions.len(),
+        });
+    }
+
+    let mut tangents_xyz = Vec::new();
+    let mut bitangents = Vec::new();
+    tangents_into_with_scratch(
+        positions,
+        normals,
+        uvs,
+        indices,
+        tangents,
+        &mut tangents_xyz,
+        &mut bitangents,
+    )
+}
+
+/// Calculates smooth per-vertex tangents like [calculate_tangents]
+/// after checking that `indices` contains only complete triangles that are in range for `positions`.
+/// Invalid indices return [TangentBitangentError::InvalidIndices] before processing any faces.
+/// # Examples
+/**
+```rust
+use geometry_tools::validation::IndexError;
+use geometry_tools::vectors::{calculate_tangents_strict, TangentBitangentError};
+use glam::{Vec2, Vec3A};
+
+let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let normals = [Vec3A::Z; 3];
+let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+
+let result = calculate_tangents_strict(&positions, &normals, &uvs, &[0, 1, 3]);
+assert!(matches!(
+    result,
+    Err(TangentBitangentError::InvalidIndices(IndexError::IndexOutOfRange { index: 3, .. }))
+));
+```
+ */
+pub fn calculate_tangents_strict<P, N>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[u32],
+) -> Result<Vec<Vec4>, TangentBitangentError>
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    check_triangle_indices(indices, positions.len())?;
+    calculate_tangents(positions, normals, uvs, indices)
+}
+
+/// Calculates smooth per-vertex tangents like [calculate_tangents]
+/// for tightly packed positions such as [glam::Vec3] or `[f32; 3]`.
+/// The positions are converted once in batches instead of for each face.
+pub fn calculate_tangents_vec3<P, N, I>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[I],
+) -> Result<Vec<Vec4>, TangentBitangentError>
+where
+    P: Float3,
+    N: Into<Vec3A> + Copy,
+    I: TryInto<usize> + Copy,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+{
+    calculate_tangents(&convert_to_vec3a(positions), normals, uvs, indices)
+}
+
+/// Calculates smooth per-vertex tangents like [calculate_tangents]
+/// using the precomputed connectivity in `adjacency`.
+/// The results are identical to [calculate_tangents] with the indices used to create `adjacency`.
+/// Returns [TangentBitangentError::AdjacencyVertexCountMismatch]
+/// if the vertex count of `adjacency` differs from the length of `positions`.
+/// # Examples
+/**
+```rust
+use geometry_tools::topology::MeshAdjacency;
+use geometry_tools::vectors::{calculate_smooth_normals_with_adjacency, calculate_tangents_with_adjacency};
+use glam::{Vec2, Vec3A, Vec4};
+
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+let positions = vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+let uvs = vec![Vec2::ZERO, Vec2::X, Vec2::Y];
+
+// Reuse the connectivity for normals and tangents.
+let adjacency = MeshAdjacency::new(positions.len(), &[0, 1, 2]);
+let normals = calculate_smooth_normals_with_adjacency(&positions, &adjacency);
+let tangents = calculate_tangents_with_adjacency(&positions, &normals, &uvs, &adjacency)?;
+assert_eq!(vec![Vec4::new(1.0, 0.0, 0.0, 1.0); 3], tangents);
+# Ok(())
+# }
+```
+ */
+pub fn calculate_tangents_with_adjacency<P, N>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    adjacency: &MeshAdjacency,
+) -> Result<Vec<Vec4>, TangentBitangentError>
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    if !(positions.len() == normals.len() && normals.len() == uvs.len()) {
+        return Err(TangentBitangentError::AttributeCountMismatch {
+            position_count: positions.len(),
+            normal_count: normals.len(),
+            uv_count: uvs.len(),
+        });
+    }
+    if positions.len() != adjacency.vertex_count() {
+        return Err(TangentBitangentError::AdjacencyVertexCountMismatch {
+            vertex_count: positions.len(),
+            adjacency_vertex_count: adjacency.vertex_count(),
+        });
+    }
+
+    let face_vectors: Vec<(Vec3A, Vec3A)> = adjacency
+        .faces()
         .iter()
-        .zip(bitangents.iter())
-        .zip(normals.iter())
-        .map(|((t, b), n)| {
-            let w = calculate_tangent_w(*t, *b, (*n).into());
-            Vec4::new(t.x, t.y, t.z, w)
+        .map(|[v0, v1, v2]| {
+            let [v0, v1, v2] = [*v0 as usize, *v1 as usize, *v2 as usize];
+            calculate_tangent_bitangent(
+                &positions[v0].into(),
+                &positions[v1].into(),
+                &positions[v2].into(),
+                &uvs[v0],
+                &uvs[v1],
+                &uvs[v2],
+            )
         })
         .collect();
-    Ok(tangents_with_w)
+
+    Ok(tangents_from_face_vectors(
+        &face_vectors,
+        normals,
+        adjacency,
+    ))
+}
+
+/// Sums the per face tangents and bitangents for each vertex and calculates the final tangents.
+/// `normals` should have the vertex count of `adjacency`.
+pub(crate) fn tangents_from_face_vectors<N>(
+    face_vectors: &[(Vec3A, Vec3A)],
+    normals: &[N],
+    adjacency: &MeshAdjacency,
+) -> Vec<Vec4>
+where
+    N: Into<Vec3A> + Copy,
+{
+    // Faces are in increasing order to match the sums for calculate_tangents.
+    let vertex_tangent = |v: usize, normal: Vec3A| {
+        let mut tangent = crate::sum::Accumulator::new(Vec3A::ZERO);
+        let mut bitangent = crate::sum::Accumulator::new(Vec3A::ZERO);
+        for f in adjacency.vertex_faces(v) {
+            tangent.add(face_vectors[*f as usize].0);
+            bitangent.add(face_vectors[*f as usize].1);
+        }
+
+        let (t, b) = finalize_tangent_bitangent(tangent.sum(), bitangent.sum(), normal);
+        t.extend(calculate_tangent_w(t, b, normal))
+    };
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        // Convert first since the input type may not be safe to share between threads.
+        let normals: Vec<Vec3A> = normals.iter().copied().map(Into::into).collect();
+        normals
+            .into_par_iter()
+            .enumerate()
+            .map(|(v, normal)| vertex_tangent(v, normal))
+            .collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    normals
+        .iter()
+        .enumerate()
+        .map(|(v, normal)| vertex_tangent(v, (*normal).into()))
+        .collect()
+}
+
+/// Calculates tangents using existing buffers for the intermediate tangents and bitangents.
+pub(crate) fn tangents_into_with_scratch<P, N, I>(
+    positions: &[P],
+    normals: &[N],
+    uvs: &[Vec2],
+    indices: &[I],
+    tangents: &mut [Vec4],
+    tangents_xyz: &mut Vec<Vec3A>,
+    bitangents: &mut Vec<Vec3A>,
+) -> Result<(), TangentBitangentError>
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+    I: TryInto<usize> + Copy,
+    <I as TryInto<usize>>::Error: std::fmt::Debug,
+{
+    // Resizing after clearing reuses the existing allocation.
+    tangents_xyz.clear();
+    tangents_xyz.resize(positions.len(), Vec3A::ZERO);
+    bitangents.clear();
+    bitangents.resize(positions.len(), Vec3A::ZERO);
+    calculate_tangents_bitangents_into(positions, normals, uvs, indices, tangents_xyz, bitangents)?;
+
+    // Compute the w component for each tangent.
+    // TODO: Compute this without computing and immediately discarding bitangent vectors?
+    for (((tangent, t), b), n) in tangents
+        .iter_mut()
+        .zip(tangents_xyz.iter())
+        .zip(bitangents.iter())
+        .zip(normals)
+    {
+        let w = calculate_tangent_w(*t, *b, (*n).into());
+        *tangent = t.extend(w);
+    }
+    Ok(())
 }
 
 /// Calculates the tangent sign of 1.0 or -1.0, which is often stored in the W component for a 4 component tangent vector.
@@ -225,7 +628,31 @@ pub fn calculate_tangent_w(tangent: Vec3A, bitangent: Vec3A, normal: Vec3A) -> f
     }
 }
 
-fn calculate_tangent_bitangent(
+/// Calculates the unnormalized tangent and bitangent of a single triangle
+/// from the vertex positions `v0`, `v1`, `v2` and texture coordinates `uv0`, `uv1`, `uv2`.
+/// These are the per face values summed by [calculate_tangents_bitangents] before normalizing.
+///
+/// Zero tangents or bitangents from degenerate positions or texture coordinates are replaced
+/// with [DEFAULT_TANGENT] and [DEFAULT_BITANGENT] respectively.
+/// # Examples
+/**
+```rust
+use geometry_tools::vectors::calculate_tangent_bitangent;
+use glam::{Vec2, Vec3A};
+
+let (tangent, bitangent) = calculate_tangent_bitangent(
+    &Vec3A::ZERO,
+    &Vec3A::X,
+    &Vec3A::Y,
+    &Vec2::ZERO,
+    &Vec2::X,
+    &Vec2::Y,
+);
+assert_eq!(Vec3A::X, tangent);
+assert_eq!(Vec3A::Y, bitangent);
+```
+ */
+pub fn calculate_tangent_bitangent(
     v0: &Vec3A,
     v1: &Vec3A,
     v2: &Vec3A,
@@ -236,6 +663,12 @@ fn calculate_tangent_bitangent(
     let pos_a = *v1 - *v0;
     let pos_b = *v2 - *v0;
 
+    let (uv_a, uv_b, r) = uv_gradient(uv0, uv1, uv2);
+    tangent_bitangent_from_gradient(pos_a, pos_b, uv_a, uv_b, r)
+}
+
+/// The texture coordinate edges and inverse determinant that only depend on the texture coordinates of a face.
+pub(crate) fn uv_gradient(uv0: &Vec2, uv1: &Vec2, uv2: &Vec2) -> (Vec2, Vec2, f32) {
     let uv_a = *uv1 - *uv0;
     let uv_b = *uv2 - *uv0;
 
@@ -244,7 +677,17 @@ fn calculate_tangent_bitangent(
     // Fix +/- infinity from division by zero.
     // TODO: Make this check less strict?
     let r = if div != 0.0 { 1.0 / div } else { 1.0 };
+    (uv_a, uv_b, r)
+}
 
+/// Calculates the tangent and bitangent for a face from its position edges and [uv_gradient].
+pub(crate) fn tangent_bitangent_from_gradient(
+    pos_a: Vec3A,
+    pos_b: Vec3A,
+    uv_a: Vec2,
+    uv_b: Vec2,
+    r: f32,
+) -> (Vec3A, Vec3A) {
     let tangent = calculate_tangent(&pos_a, &pos_b, &uv_a, &uv_b, r);
     let bitangent = calculate_bitangent(&pos_a, &pos_b, &uv_a, &uv_b, r);
 
@@ -281,100 +724,6 @@ mod tests {
 
     const EPSILON: f32 = 0.0001;
 
-    fn cube_positions() -> Vec<Vec3A> {
-        vec![
-            Vec3A::new(-0.5, -0.5, 0.5),
-            Vec3A::new(0.5, -0.5, 0.5),
-            Vec3A::new(-0.5, 0.5, 0.5),
-            Vec3A::new(0.5, 0.5, 0.5),
-            Vec3A::new(-0.5, 0.5, 0.5),
-            Vec3A::new(0.5, 0.5, 0.5),
-            Vec3A::new(-0.5, 0.5, -0.5),
-            Vec3A::new(0.5, 0.5, -0.5),
-            Vec3A::new(-0.5, 0.5, -0.5),
-            Vec3A::new(0.5, 0.5, -0.5),
-            Vec3A::new(-0.5, -0.5, -0.5),
-            Vec3A::new(0.5, -0.5, -0.5),
-            Vec3A::new(-0.5, -0.5, -0.5),
-            Vec3A::new(0.5, -0.5, -0.5),
-            Vec3A::new(-0.5, -0.5, 0.5),
-            Vec3A::new(0.5, -0.5, 0.5),
-            Vec3A::new(0.5, -0.5, 0.5),
-            Vec3A::new(0.5, -0.5, -0.5),
-            Vec3A::new(0.5, 0.5, 0.5),
-            Vec3A::new(0.5, 0.5, -0.5),
-            Vec3A::new(-0.5, -0.5, -0.5),
-            Vec3A::new(-0.5, -0.5, 0.5),
-            Vec3A::new(-0.5, 0.5, -0.5),
-            Vec3A::new(-0.5, 0.5, 0.5),
-        ]
-    }
-
-    fn cube_normals() -> Vec<Vec3A> {
-        vec![
-            Vec3A::new(0.0, 0.0, 1.0),
-            Vec3A::new(0.0, 0.0, 1.0),
-            Vec3A::new(0.0, 0.0, 1.0),
-            Vec3A::new(0.0, 0.0, 1.0),
-            Vec3A::new(0.0, 1.0, 0.0),
-            Vec3A::new(0.0, 1.0, 0.0),
-            Vec3A::new(0.0, 1.0, 0.0),
-            Vec3A::new(0.0, 1.0, 0.0),
-            Vec3A::new(0.0, 0.0, -1.0),
-            Vec3A::new(0.0, 0.0, -1.0),
-            Vec3A::new(0.0, 0.0, -1.0),
-            Vec3A::new(0.0, 0.0, -1.0),
-            Vec3A::new(0.0, -1.0, 0.0),
-            Vec3A::new(0.0, -1.0, 0.0),
-            Vec3A::new(0.0, -1.0, 0.0),
-            Vec3A::new(0.0, -1.0, 0.0),
-            Vec3A::new(1.0, 0.0, 0.0),
-            Vec3A::new(1.0, 0.0, 0.0),
-            Vec3A::new(1.0, 0.0, 0.0),
-            Vec3A::new(1.0, 0.0, 0.0),
-            Vec3A::new(-1.0, 0.0, 0.0),
-            Vec3A::new(-1.0, 0.0, 0.0),
-            Vec3A::new(-1.0, 0.0, 0.0),
-            Vec3A::new(-1.0, 0.0, 0.0),
-        ]
-    }
-
-    fn cube_uvs() -> Vec<Vec2> {
-        vec![
-            Vec2::new(0.375, 1.0),
-            Vec2::new(0.625, 1.0),
-            Vec2::new(0.375, 0.75),
-            Vec2::new(0.625, 0.75),
-            Vec2::new(0.375, 0.75),
-            Vec2::new(0.625, 0.75),
-            Vec2::new(0.375, 0.5),
-            Vec2::new(0.625, 0.5),
-            Vec2::new(0.375, 0.5),
-            Vec2::new(0.625, 0.5),
-            Vec2::new(0.375, 0.25),
-            Vec2::new(0.625, 0.25),
-            Vec2::new(0.375, 0.25),
-            Vec2::new(0.625, 0.25),
-            Vec2::new(0.375, 0.0),
-            Vec2::new(0.625, 0.0),
-            Vec2::new(0.625, 1.0),
-            Vec2::new(0.875, 1.0),
-            Vec2::new(0.625, 0.75),
-            Vec2::new(0.875, 0.75),
-            Vec2::new(0.125, 1.0),
-            Vec2::new(0.375, 1.0),
-            Vec2::new(0.125, 0.75),
-            Vec2::new(0.375, 0.75),
-        ]
-    }
-
-    fn cube_indices() -> Vec<u32> {
-        vec![
-            0, 1, 2, 2, 1, 3, 4, 5, 6, 6, 5, 7, 8, 9, 10, 10, 9, 11, 12, 13, 14, 14, 13, 15, 16,
-            17, 18, 18, 17, 19, 20, 21, 22, 22, 21, 23,
-        ]
-    }
-
     #[test]
     fn different_uvs_different_positions() {
         let v1 = Vec3A::new(1.0, 0.0, 0.0);
@@ -539,13 +888,10 @@ mod tests {
 
     #[test]
     fn triangle_list_basic_cube_normalized_no_weird_floats() {
-        let (tangents, bitangents) = calculate_tangents_bitangents(
-            &cube_positions(),
-            &cube_normals(),
-            &cube_uvs(),
-            &cube_indices(),
-        )
-        .unwrap();
+        let cube = crate::shapes::generate_cube(1.0);
+        let (tangents, bitangents) =
+            calculate_tangents_bitangents(&cube.positions, &cube.normals, &cube.uvs, &cube.indices)
+                .unwrap();
 
         assert_eq!(24, tangents.len());
         assert_eq!(24, bitangents.len());
@@ -657,4 +1003,98 @@ mod tests {
         let w = calculate_tangent_w(tangent, bitangent, normal);
         assert_eq!(1.0, w);
     }
+
+    #[test]
+    fn tangents_into_output_count_mismatch() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let normals = [Vec3A::Z; 3];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+        let mut tangents = [Vec4::ZERO; 2];
+        let result =
+            calculate_tangents_into(&positions, &normals, &uvs, &[0u32, 1, 2], &mut tangents);
+        assert!(matches!(
+            result,
+            Err(TangentBitangentError::OutputCountMismatch {
+                vertex_count: 3,
+                tangent_count: 2,
+                bitangent_count: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn tangents_into_reused_buffer() {
+        let positions = [Vec3A::ZERO, Vec3A::X, Vec3A::Y];
+        let normals = [Vec3A::Z; 3];
+        let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+        let mut tangents = [Vec4::ONE; 3];
+        calculate_tangents_into(&positions, &normals, &uvs, &[0u32, 1, 2], &mut tangents).unwrap();
+        calculate_tangents_into(&positions, &normals, &uvs, &[0u32, 1, 2], &mut tangents).unwrap();
+        assert_eq!([Vec4::new(1.0, 0.0, 0.0, 1.0); 3], tangents);
+    }
+
+    #[test]
+    fn tangents_with_adjacency_matches() {
+        let mesh = crate::shapes::generate_uv_sphere(1.0, 16, 8);
+        let adjacency = MeshAdjacency::new(mesh.positions.len(), &mesh.indices);
+        assert_eq!(
+            calculate_tangents(&mesh.positions, &mesh.normals, &mesh.uvs, &mesh.indices).unwrap(),
+            calculate_tangents_with_adjacency(
+                &mesh.positions,
+                &mesh.normals,
+                &mesh.uvs,
+                &adjacency
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn tangents_with_adjacency_count_mismatch() {
+        let adjacency = MeshAdjacency::new(3, &[0, 1, 2]);
+        let result = calculate_tangents_with_adjacency(
+            &[Vec3A::ZERO; 3],
+            &[Vec3A::Z; 2],
+            &[Vec2::ZERO; 3],
+            &adjacency,
+        );
+        assert!(matches!(
+            result,
+            Err(TangentBitangentError::AttributeCountMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn tangents_with_adjacency_vertex_count_mismatch() {
+        let adjacency = MeshAdjacency::new(4, &[0, 1, 2]);
+        let result = calculate_tangents_with_adjacency(
+            &[Vec3A::ZERO; 3],
+            &[Vec3A::Z; 3],
+            &[Vec2::ZERO; 3],
+            &adjacency,
+        );
+        assert!(matches!(
+            result,
+            Err(TangentBitangentError::AdjacencyVertexCountMismatch {
+                vertex_count: 3,
+                adjacency_vertex_count: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn tangents_strict_trailing_indices() {
+        let result = calculate_tangents_strict(
+            &[Vec3A::ZERO; 3],
+            &[Vec3A::Z; 3],
+            &[Vec2::ZERO; 3],
+            &[0, 1, 2, 0],
+        );
+        assert!(matches!(
+            result,
+            Err(TangentBitangentError::InvalidIndices(
+                IndexError::InvalidIndexCount { index_count: 4 }
+            ))
+        ));
+    }
 }
@@ -0,0 +1,151 @@
+use glam::{Vec2, Vec3A, Vec4};
+
+use super::{
+    calculate_smooth_normals_into, calculate_tangents_bitangents_into, tangents_into_with_scratch,
+    TangentBitangentError,
+};
+
+/// Reusable buffers for calculating normals and tangents for many meshes without allocating for each mesh.
+/// The returned slices are valid until the next calculation using the same workspace.
+///
+/// The `rayon` feature still allocates some temporary buffers for each call.
+/// # Examples
+/**
+```rust
+use geometry_tools::vectors::Workspace;
+use glam::{Vec2, Vec3A};
+
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+let meshes = vec![
+    (vec![Vec3A::ZERO, Vec3A::X, Vec3A::Y], vec![0, 1, 2]),
+    (vec![Vec3A::ZERO, Vec3A::Y, Vec3A::X], vec![0, 1, 2]),
+];
+let uvs = vec![Vec2::ZERO, Vec2::X, Vec2::Y];
+
+let mut workspace = Workspace::new();
+for (positions, indices) in &meshes {
+    let normals = workspace.smooth_normals(positions, indices).to_vec();
+    let tangents = workspace.tangents(positions, &normals, &uvs, indices)?;
+    assert_eq!(3, tangents.len());
+}
+# Ok(())
+# }
+```
+ */
+#[derive(Debug, Default, Clone)]
+pub struct Workspace {
+    normals: Vec<Vec3A>,
+    tangents: Vec<Vec3A>,
+    bitangents: Vec<Vec3A>,
+    tangents_w: Vec<Vec4>,
+}
+
+impl Workspace {
+    /// Creates a workspace with empty buffers that grow as needed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calculates smooth normals using [calculate_smooth_normals_into].
+    pub fn smooth_normals<P>(&mut self, positions: &[P], indices: &[u32]) -> &[Vec3A]
+    where
+        P: Into<Vec3A> + Copy,
+    {
+        resize(&mut self.normals, positions.len(), Vec3A::ZERO);
+        calculate_smooth_normals_into(positions, indices, &mut self.normals);
+        &self.normals
+    }
+
+    /// Calculates tangents and bitangents using [calculate_tangents_bitangents_into].
+    pub fn tangents_bitangents<P, N, I>(
+        &mut self,
+        positions: &[P],
+        normals: &[N],
+        uvs: &[Vec2],
+        indices: &[I],
+    ) -> Result<(&[Vec3A], &[Vec3A]), TangentBitangentError>
+    where
+        P: Into<Vec3A> + Copy,
+        N: Into<Vec3A> + Copy,
+        I: TryInto<usize> + Copy,
+        <I as TryInto<usize>>::Error: std::fmt::Debug,
+    {
+        resize(&mut self.tangents, positions.len(), Vec3A::ZERO);
+        resize(&mut self.bitangents, positions.len(), Vec3A::ZERO);
+        calculate_tangents_bitangents_into(
+            positions,
+            normals,
+            uvs,
+            indices,
+            &mut self.tangents,
+            &mut self.bitangents,
+        )?;
+        Ok((&self.tangents, &self.bitangents))
+    }
+
+    /// Calculates tangents using [calculate_tangents_into](super::calculate_tangents_into)
+    /// without allocating the intermediate bitangents.
+    pub fn tangents<P, N, I>(
+        &mut self,
+        positions: &[P],
+        normals: &[N],
+        uvs: &[Vec2],
+        indices: &[I],
+    ) -> Result<&[Vec4], TangentBitangentError>
+    where
+        P: Into<Vec3A> + Copy,
+        N: Into<Vec3A> + Copy,
+        I: TryInto<usize> + Copy,
+        <I as TryInto<usize>>::Error: std::fmt::Debug,
+    {
+        resize(&mut self.tangents_w, positions.len(), Vec4::ZERO);
+        tangents_into_with_scratch(
+            positions,
+            normals,
+            uvs,
+            indices,
+            &mut self.tangents_w,
+            &mut self.tangents,
+            &mut self.bitangents,
+        )?;
+        Ok(&self.tangents_w)
+    }
+}
+
+fn resize<T: Copy>(values: &mut Vec<T>, len: usize, value: T) {
+    // Clearing first avoids copying old values when growing.
+    values.clear();
+    values.resize(len, value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::{calculate_smooth_normals, calculate_tangents};
+
+    #[test]
+    fn workspace_matches_allocating_functions() {
+        let mesh = crate::shapes::generate_torus(1.0, 0.25, 16, 8);
+        let small = crate::shapes::generate_cube(1.0);
+
+        let mut workspace = Workspace::new();
+        // Use a larger mesh first to check that shrinking the buffers works.
+        for mesh in [&mesh, &small] {
+            let normals = workspace
+                .smooth_normals(&mesh.positions, &mesh.indices)
+                .to_vec();
+            assert_eq!(
+                calculate_smooth_normals(&mesh.positions, &mesh.indices),
+                normals
+            );
+
+            let tangents = workspace
+                .tangents(&mesh.positions, &normals, &mesh.uvs, &mesh.indices)
+                .unwrap();
+            assert_eq!(
+                calculate_tangents(&mesh.positions, &normals, &mesh.uvs, &mesh.indices).unwrap(),
+                tangents
+            );
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Traits for using user defined vertex types with the functions in this crate.
+//!
+//! Implementing the traits for an interleaved vertex struct allows calculating data
+//! directly from a slice of vertices instead of separate slices for each attribute.
+
+use glam::{Vec2, Vec3, Vec3A, Vec4};
+
+use crate::bounding::{calculate_aabb_from_points, calculate_bounding_sphere_from_points};
+use crate::vectors::{calculate_smooth_normals, calculate_tangents, TangentBitangentError};
+
+/// A vertex with a position.
+pub trait PositionSource {
+    fn position(&self) -> Vec3A;
+}
+
+/// A vertex with a normal vector.
+pub trait NormalSource {
+    fn normal(&self) -> Vec3A;
+}
+
+/// A vertex with texture coordinates.
+pub trait UvSource {
+    fn uv(&self) -> Vec2;
+}
+
+impl PositionSource for Vec3A {
+    fn position(&self) -> Vec3A {
+        *self
+    }
+}
+
+impl PositionSource for Vec3 {
+    fn position(&self) -> Vec3A {
+        (*self).into()
+    }
+}
+
+impl PositionSource for [f32; 3] {
+    fn position(&self) -> Vec3A {
+        Vec3A::from_array(*self)
+    }
+}
+
+impl UvSource for Vec2 {
+    fn uv(&self) -> Vec2 {
+        *self
+    }
+}
+
+impl UvSource for [f32; 2] {
+    fn uv(&self) -> Vec2 {
+        Vec2::from_array(*self)
+    }
+}
+
+fn positions<V: PositionSource>(vertices: &[V]) -> Vec<Vec3A> {
+    vertices.iter().map(PositionSource::position).collect()
+}
+
+/// Calculates smooth normals for `vertices` like [calculate_smooth_normals].
+/// # Examples
+/**
+```rust
+use geometry_tools::vertex::{calculate_smooth_normals_from_vertices, PositionSource};
+use glam::Vec3A;
+
+struct Vertex {
+    position: [f32; 3],
+    color: [u8; 4],
+}
+
+impl PositionSource for Vertex {
+    fn position(&self) -> Vec3A {
+        self.position.into()
+    }
+}
+
+let vertices = [
+    Vertex { position: [0.0, 0.0, 0.0], color: [255; 4] },
+    Vertex { position: [1.0, 0.0, 0.0], color: [255; 4] },
+    Vertex { position: [0.0, 1.0, 0.0], color: [255; 4] },
+];
+let normals = calculate_smooth_normals_from_vertices(&vertices, &[0, 1, 2]);
+assert_eq!(vec![Vec3A::Z; 3], normals);
+```
+ */
+pub fn calculate_smooth_normals_from_vertices<V>(vertices: &[V], indices: &[u32]) -> Vec<Vec3A>
+where
+    V: PositionSource,
+{
+    calculate_smooth_normals(&positions(vertices), indices)
+}
+
+/// Calculates tangents for `vertices` like [calculate_tangents].
+pub fn calculate_tangents_from_vertices<V>(
+    vertices: &[V],
+    indices: &[u32],
+) -> Result<Vec<Vec4>, TangentBitangentError>
+where
+    V: PositionSource + NormalSource + UvSource,
+{
+    let normals: Vec<_> = vertices.iter().map(NormalSource::normal).collect();
+    let uvs: Vec<_> = vertices.iter().map(UvSource::uv).collect();
+    calculate_tangents(&positions(vertices), &normals, &uvs, indices)
+}
+
+/// Calculates an axis-aligned bounding box for `vertices` like [calculate_aabb_from_points].
+pub fn calculate_aabb_from_vertices<V>(vertices: &[V]) -> (Vec3A, Vec3A)
+where
+    V: PositionSource,
+{
+    calculate_aabb_from_points(&positions(vertices))
+}
+
+/// Calculates a bounding sphere for `vertices` like [calculate_bounding_sphere_from_points].
+pub fn calculate_bounding_sphere_from_vertices<V>(vertices: &[V]) -> Vec4
+where
+    V: PositionSource,
+{
+    calculate_bounding_sphere_from_points(&positions(vertices))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Vertex {
+        position: Vec3,
+        normal: Vec3,
+        uv: Vec2,
+    }
+
+    impl PositionSource for Vertex {
+        fn position(&self) -> Vec3A {
+            self.position.into()
+        }
+    }
+
+    impl NormalSource for Vertex {
+        fn normal(&self) -> Vec3A {
+            self.normal.into()
+        }
+    }
+
+    impl UvSource for Vertex {
+        fn uv(&self) -> Vec2 {
+            self.uv
+        }
+    }
+
+    fn vertices() -> Vec<Vertex> {
+        [
+            (Vec3::ZERO, Vec2::ZERO),
+            (Vec3::X, Vec2::X),
+            (Vec3::Y, Vec2::Y),
+        ]
+        .into_iter()
+        .map(|(position, uv)| Vertex {
+            position,
+            normal: Vec3::Z,
+            uv,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn vertices_empty() {
+        assert!(calculate_smooth_normals_from_vertices::<Vec3A>(&[], &[]).is_empty());
+        assert_eq!(
+            (Vec3A::ZERO, Vec3A::ZERO),
+            calculate_aabb_from_vertices::<Vec3A>(&[])
+        );
+    }
+
+    #[test]
+    fn vertices_match_attribute_slices() {
+        let vertices = vertices();
+        let positions: Vec<_> = vertices.iter().map(|v| v.position).collect();
+        let normals: Vec<_> = vertices.iter().map(|v| v.normal).collect();
+        let uvs: Vec<_> = vertices.iter().map(|v| v.uv).collect();
+        let indices = [0, 1, 2];
+
+        assert_eq!(
+            calculate_smooth_normals(&positions, &indices),
+            calculate_smooth_normals_from_vertices(&vertices, &indices)
+        );
+        assert_eq!(
+            calculate_tangents(&positions, &normals, &uvs, &indices).unwrap(),
+            calculate_tangents_from_vertices(&vertices, &indices).unwrap()
+        );
+        assert_eq!(
+            calculate_aabb_from_points(&positions),
+            calculate_aabb_from_vertices(&vertices)
+        );
+        assert_eq!(
+            calculate_bounding_sphere_from_points(&positions),
+            calculate_bounding_sphere_from_vertices(&vertices)
+        );
+    }
+
+    #[test]
+    fn array_sources() {
+        assert_eq!(Vec3A::new(1.0, 2.0, 3.0), [1.0, 2.0, 3.0].position());
+        assert_eq!(Vec2::new(1.0, 2.0), [1.0, 2.0].uv());
+    }
+}
@@ -0,0 +1,414 @@
+//! Functions for merging vertices with nearly identical positions.
+//!
+//! The results use remap tables like [crate::remap], so any vertex attribute can be compacted
+//! using [scatter_attribute](crate::remap::scatter_attribute)
+//! and the indices updated using [remap_indices](crate::remap::remap_indices).
+
+use std::collections::HashMap;
+
+use glam::{IVec3, Vec3A};
+
+/// The result of [weld_positions].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeldedVertices {
+    /// The remap table from each original vertex to its welded vertex.
+    pub remap: Vec<u32>,
+    /// The number of unique vertices after welding.
+    pub vertex_count: usize,
+}
+
+/// Merges vertices with positions within `epsilon` of an earlier unique vertex.
+/// An `epsilon` of `0.0` only merges vertices with identical positions.
+///
+/// Each vertex is compared with the unique vertices found so far,
+/// so vertices are never chained together over distances larger than `epsilon`.
+/// Welded vertices are numbered in the order they first appear in `positions`.
+/// # Examples
+/**
+```rust
+use geometry_tools::remap::{remap_indices, scatter_attribute};
+use geometry_tools::weld::weld_positions;
+use glam::Vec3A;
+
+// Two triangles with duplicate vertices along the shared edge.
+let positions = vec![
+    Vec3A::ZERO,
+    Vec3A::X,
+    Vec3A::Y,
+    Vec3A::new(1.0, 0.00001, 0.0),
+    Vec3A::ONE,
+    Vec3A::Y,
+];
+let indices = vec![0, 1, 2, 3, 4, 5];
+
+let welded = weld_positions(&positions, 0.0001);
+assert_eq!(vec![0, 1, 2, 1, 3, 2], welded.remap);
+assert_eq!(4, welded.vertex_count);
+
+let new_positions = scatter_attribute(&positions, &welded.remap, welded.vertex_count);
+let new_indices = remap_indices(&indices, &welded.remap);
+assert_eq!(vec![0, 1, 2, 1, 3, 2], new_indices);
+```
+ */
+pub fn weld_positions<P>(positions: &[P], epsilon: f32) -> WeldedVertices
+where
+    P: Into<Vec3A> + Copy,
+{
+    weld(positions, epsilon, |_, _| true)
+}
+
+/// Merges vertices like [weld_positions] but only if the angle between their `normals`
+/// is at most `max_angle` in radians.
+/// This merges duplicate vertices on smooth surfaces while keeping the vertices along hard edges split.
+/// The normals do not need to be normalized.
+///
+/// # Panics
+/// Panics if `positions` and `normals` have different lengths.
+/// # Examples
+/**
+```rust
+use geometry_tools::weld::weld_positions_normals;
+use glam::Vec3A;
+
+let positions = [Vec3A::ZERO, Vec3A::ZERO, Vec3A::ZERO];
+let normals = [Vec3A::Z, Vec3A::new(0.0, 0.01, 1.0), Vec3A::X];
+
+// The third vertex is on the other side of a hard edge.
+let welded = weld_positions_normals(&positions, &normals, 0.0001, 1.0f32.to_radians());
+assert_eq!(vec![0, 0, 1], welded.remap);
+assert_eq!(2, welded.vertex_count);
+```
+ */
+pub fn weld_positions_normals<P, N>(
+    positions: &[P],
+    normals: &[N],
+    epsilon: f32,
+    max_angle: f32,
+) -> WeldedVertices
+where
+    P: Into<Vec3A> + Copy,
+    N: Into<Vec3A> + Copy,
+{
+    assert_eq!(
+        positions.len(),
+        normals.len(),
+        "positions and normals should have the same length"
+    );
+
+    let normals: Vec<Vec3A> = normals
+        .iter()
+        .map(|n| (*n).into().normalize_or_zero())
+        .collect();
+    let min_cos_angle = max_angle.cos();
+    weld(positions, epsilon, |a, b| {
+        normals[a].dot(normals[b]) >= min_cos_angle
+    })
+}
+
+/// Welds positions while only merging a vertex with a unique vertex if `can_merge`
+/// returns `true` for the index of the unique vertex's first vertex and the vertex index.
+fn weld<P>(
+    positions: &[P],
+    epsilon: f32,
+    can_merge: impl Fn(usize, usize) -> bool,
+) -> WeldedVertices
+where
+    P: Into<Vec3A> + Copy,
+{
+    let mut unique = Vec::new();
+    let remap = if epsilon > 0.0 {
+        weld_epsilon(positions, epsilon, &mut unique, can_merge)
+    } else {
+        weld_exact(positions, &mut unique, can_merge)
+    };
+
+    WeldedVertices {
+        remap,
+        vertex_count: unique.len(),
+    }
+}
+
+/// The first vertex index and position of each unique vertex.
+type UniqueVertices = Vec<(usize, Vec3A)>;
+
+fn weld_exact<P>(
+    positions: &[P],
+    unique: &mut UniqueVertices,
+    can_merge: impl Fn(usize, usize) -> bool,
+) -> Vec<u32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    // Unique vertices with the same position form a linked list starting from the first vertex.
+    let mut indices = HashMap::new();
+    let mut next: Vec<Option<u32>> = Vec::new();
+    positions
+        .iter()
+        .enumerate()
+        .map(|(v, p)| {
+            let p: Vec3A = (*p).into();
+            // Adding zero treats -0.0 and 0.0 as the same value.
+            let key = (p + Vec3A::ZERO).to_array().map(f32::to_bits);
+
+            let mut last = None;
+            let mut current = indices.get(&key).copied();
+            while let Some(i) = current {
+                if can_merge(unique[i as usize].0, v) {
+                    return i;
+                }
+                last = Some(i);
+                current = next[i as usize];
+            }
+
+            let index = unique.len() as u32;
+            unique.push((v, p));
+            next.push(None);
+            match last {
+                Some(last) => next[last as usize] = Some(index),
+                None => {
+                    indices.insert(key, index);
+                }
+            }
+            index
+        })
+        .collect()
+}
+
+fn weld_epsilon<P>(
+    positions: &[P],
+    epsilon: f32,
+    unique: &mut UniqueVertices,
+    can_merge: impl Fn(usize, usize) -> bool,
+) -> Vec<u32>
+where
+    P: Into<Vec3A> + Copy,
+{
+    // Any unique vertex within epsilon is in one of the 27 neighboring cells.
+    let mut cells: HashMap<IVec3, Vec<u32>> = HashMap::new();
+    let cell = |p: Vec3A| (p / epsilon).floor().as_ivec3();
+    let epsilon_squared = epsilon * epsilon;
+
+    positions
+        .iter()
+        .enumerate()
+        .map(|(v, p)| {
+            let p: Vec3A = (*p).into();
+            let key = cell(p);
+
+            let mut closest = None;
+            let mut closest_distance = f32::INFINITY;
+            for z in -1..=1 {
+                for y in -1..=1 {
+                    for x in -1..=1 {
+                        let neighbor = key.saturating_add(IVec3::new(x, y, z));
+                        for i in cells.get(&neighbor).into_iter().flatten() {
+                            let (first, position) = unique[*i as usize];
+                            let distance = position.distance_squared(p);
+                            if distance <= epsilon_squared
+                                && distance < closest_distance
+                                && can_merge(first, v)
+                            {
+                                closest = Some(*i);
+                                closest_distance = distance;
+                            }
+                        }
+                    }
+                }
+            }
+
+            closest.unwrap_or_else(|| {
+                let index = unique.len() as u32;
+                unique.push((v, p));
+                cells.entry(key).or_default().push(index);
+                index
+            })
+        })
+        .collect()
+}
+
+pub mod ffi {
+    use crate::ffi::{catch_status, output, slice, slice_mut, FfiStatus};
+
+    /// A wrapper for [weld_positions](crate::weld::weld_positions).
+    /// The function writes the remap table for each vertex to `out_remap`
+    /// and the number of unique vertices to `vertex_count`.
+    ///
+    /// # Safety
+    ///
+    /// `positions` and `out_remap` must have length `positions_length`.
+    /// `positions` uses the same 16 byte layout as [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+    /// `vertex_count` must point to a writable value.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_weld_positions(
+        positions: *const glam::Vec3A,
+        positions_length: u32,
+        epsilon: f32,
+        out_remap: *mut u32,
+        vertex_count: *mut u32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            let positions = slice(positions, positions_length)?;
+            let out_remap = slice_mut(out_remap, positions_length)?;
+            let vertex_count = output(vertex_count)?;
+
+            let welded = super::weld_positions(positions, epsilon);
+            out_remap.copy_from_slice(&welded.remap);
+            *vertex_count = welded.vertex_count as u32;
+            Ok(())
+        })
+    }
+
+    /// A wrapper for [weld_positions_normals](crate::weld::weld_positions_normals)
+    /// that writes the results like [geo_weld_positions].
+    ///
+    /// # Safety
+    ///
+    /// `positions`, `normals`, and `out_remap` must have length `positions_length`.
+    /// `positions` and `normals` use the same 16 byte layout as [geo_calculate_smooth_normals](crate::ffi::geo_calculate_smooth_normals).
+    /// `vertex_count` must point to a writable value.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_weld_positions_normals(
+        positions: *const glam::Vec3A,
+        normals: *const glam::Vec3A,
+        positions_length: u32,
+        epsilon: f32,
+        max_angle: f32,
+        out_remap: *mut u32,
+        vertex_count: *mut u32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            let positions = slice(positions, positions_length)?;
+            let normals = slice(normals, positions_length)?;
+            let out_remap = slice_mut(out_remap, positions_length)?;
+            let vertex_count = output(vertex_count)?;
+
+            let welded = super::weld_positions_normals(positions, normals, epsilon, max_angle);
+            out_remap.copy_from_slice(&welded.remap);
+            *vertex_count = welded.vertex_count as u32;
+            Ok(())
+        })
+    }
+
+    /// A version of [geo_weld_positions] for tightly packed positions with three floats for each position.
+    ///
+    /// # Safety
+    ///
+    /// `positions` and `out_remap` must have length `positions_length`.
+    /// `vertex_count` must point to a writable value.
+    #[no_mangle]
+    pub unsafe extern "C" fn geo_weld_positions_vec3(
+        positions: *const glam::Vec3,
+        positions_length: u32,
+        epsilon: f32,
+        out_remap: *mut u32,
+        vertex_count: *mut u32,
+    ) -> FfiStatus {
+        catch_status(|| {
+            let positions = slice(positions, positions_length)?;
+            let out_remap = slice_mut(out_remap, positions_length)?;
+            let vertex_count = output(vertex_count)?;
+
+            let welded = super::weld_positions(positions, epsilon);
+            out_remap.copy_from_slice(&welded.remap);
+            *vertex_count = welded.vertex_count as u32;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weld_empty() {
+        assert_eq!(
+            WeldedVertices {
+                remap: Vec::new(),
+                vertex_count: 0
+            },
+            weld_positions::<Vec3A>(&[], 0.1)
+        );
+    }
+
+    #[test]
+    fn weld_exact_signed_zero() {
+        let positions = [Vec3A::ZERO, Vec3A::new(-0.0, 0.0, 0.0), Vec3A::X];
+        assert_eq!(vec![0, 0, 1], weld_positions(&positions, 0.0).remap);
+    }
+
+    #[test]
+    fn weld_across_cell_boundary() {
+        // The positions are in different cells but within epsilon.
+        let positions = [Vec3A::splat(0.099), Vec3A::splat(0.101)];
+        let welded = weld_positions(&positions, 0.1);
+        assert_eq!(vec![0, 0], welded.remap);
+        assert_eq!(1, welded.vertex_count);
+    }
+
+    #[test]
+    fn weld_no_chaining() {
+        // Each position is within epsilon of the previous position but not the first.
+        let positions = [
+            Vec3A::ZERO,
+            Vec3A::new(0.08, 0.0, 0.0),
+            Vec3A::new(0.16, 0.0, 0.0),
+        ];
+        assert_eq!(vec![0, 0, 1], weld_positions(&positions, 0.1).remap);
+    }
+
+    #[test]
+    fn weld_ffi_vec3() {
+        let positions = [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.00001];
+        let mut remap = [0; 3];
+        let mut vertex_count = 0;
+        let status = unsafe {
+            ffi::geo_weld_positions_vec3(
+                positions.as_ptr() as *const glam::Vec3,
+                3,
+                0.001,
+                remap.as_mut_ptr(),
+                &mut vertex_count,
+            )
+        };
+        assert_eq!(crate::ffi::FfiStatus::Success, status);
+        assert_eq!([0, 1, 0], remap);
+        assert_eq!(2, vertex_count);
+    }
+
+    #[test]
+    fn weld_normals_hard_edge() {
+        // The corner of a cube with one vertex for each face.
+        let positions = [Vec3A::ONE; 6];
+        let normals = [Vec3A::X, Vec3A::Y, Vec3A::Z, Vec3A::X, Vec3A::Y, Vec3A::Z];
+        for epsilon in [0.0, 0.001] {
+            let welded = weld_positions_normals(&positions, &normals, epsilon, 0.1);
+            assert_eq!(vec![0, 1, 2, 0, 1, 2], welded.remap);
+            assert_eq!(3, welded.vertex_count);
+        }
+    }
+
+    #[test]
+    fn weld_normals_smooth() {
+        let positions = [Vec3A::ZERO, Vec3A::new(0.0001, 0.0, 0.0), Vec3A::X];
+        let normals = [Vec3A::Z, Vec3A::new(0.0, 0.001, 1.0) * 2.0, Vec3A::Z];
+        let welded = weld_positions_normals(&positions, &normals, 0.001, 0.01);
+        assert_eq!(vec![0, 0, 1], welded.remap);
+    }
+
+    #[test]
+    fn weld_normals_matches_positions_for_large_angle() {
+        let sphere = crate::shapes::generate_uv_sphere(1.0, 16, 8);
+        let normals = vec![Vec3A::Z; sphere.positions.len()];
+        assert_eq!(
+            weld_positions(&sphere.positions, 0.0001),
+            weld_positions_normals(&sphere.positions, &normals, 0.0001, std::f32::consts::PI)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn weld_normals_length_mismatch() {
+        weld_positions_normals(&[Vec3A::ZERO], &[Vec3A::Z, Vec3A::Z], 0.0, 0.1);
+    }
+}